@@ -0,0 +1,120 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+
+declare_id!("5jTBjfBp8mre3ee2wpv55XP4MkViquQeTJwWved8vPwz");
+
+// Repays flash swaps and flash loans of invariant from the owner accounts
+// `data` may hold a little endian u64 which is withheld from the repayment
+#[program]
+pub mod flash_callback {
+    use super::*;
+
+    pub fn flash_swap_callback(
+        ctx: Context<FlashSwapCallback>,
+        _x_to_y: bool,
+        amount_in: u64,
+        _amount_out: u64,
+        data: Vec<u8>,
+    ) -> Result<()> {
+        let amount = amount_in.saturating_sub(shortfall(&data));
+        repay(
+            &ctx.accounts.token_in_program,
+            &ctx.accounts.token_in,
+            &ctx.accounts.account_in,
+            &ctx.accounts.reserve_in,
+            &ctx.accounts.owner,
+            amount,
+        )
+    }
+
+    pub fn flash_loan_callback(
+        ctx: Context<FlashLoanCallback>,
+        amount_x: u64,
+        amount_y: u64,
+        fee_x: u64,
+        fee_y: u64,
+        data: Vec<u8>,
+    ) -> Result<()> {
+        let accounts = &ctx.accounts;
+        let amount_x = (amount_x + fee_x).saturating_sub(shortfall(&data));
+        repay(
+            &accounts.token_x_program,
+            &accounts.token_x,
+            &accounts.account_x,
+            &accounts.reserve_x,
+            &accounts.owner,
+            amount_x,
+        )?;
+        repay(
+            &accounts.token_y_program,
+            &accounts.token_y,
+            &accounts.account_y,
+            &accounts.reserve_y,
+            &accounts.owner,
+            amount_y + fee_y,
+        )
+    }
+}
+
+fn shortfall(data: &[u8]) -> u64 {
+    data.get(..8)
+        .map(|bytes| u64::from_le_bytes(bytes.try_into().unwrap()))
+        .unwrap_or(0)
+}
+
+fn repay<'info>(
+    token_program: &Interface<'info, TokenInterface>,
+    mint: &InterfaceAccount<'info, Mint>,
+    from: &InterfaceAccount<'info, TokenAccount>,
+    to: &InterfaceAccount<'info, TokenAccount>,
+    authority: &Signer<'info>,
+    amount: u64,
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+    token_interface::transfer_checked(
+        CpiContext::new(
+            token_program.to_account_info(),
+            TransferChecked {
+                mint: mint.to_account_info(),
+                from: from.to_account_info(),
+                to: to.to_account_info(),
+                authority: authority.to_account_info(),
+            },
+        ),
+        amount,
+        mint.decimals,
+    )
+}
+
+#[derive(Accounts)]
+pub struct FlashSwapCallback<'info> {
+    #[account(mut, token::authority = owner, token::mint = token_in, token::token_program = token_in_program)]
+    pub account_in: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut, token::mint = token_in, token::token_program = token_in_program)]
+    pub reserve_in: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mint::token_program = token_in_program)]
+    pub token_in: Box<InterfaceAccount<'info, Mint>>,
+    pub owner: Signer<'info>,
+    pub token_in_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct FlashLoanCallback<'info> {
+    #[account(mut, token::authority = owner, token::mint = token_x, token::token_program = token_x_program)]
+    pub account_x: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut, token::authority = owner, token::mint = token_y, token::token_program = token_y_program)]
+    pub account_y: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut, token::mint = token_x, token::token_program = token_x_program)]
+    pub reserve_x: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut, token::mint = token_y, token::token_program = token_y_program)]
+    pub reserve_y: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mint::token_program = token_x_program)]
+    pub token_x: Box<InterfaceAccount<'info, Mint>>,
+    #[account(mint::token_program = token_y_program)]
+    pub token_y: Box<InterfaceAccount<'info, Mint>>,
+    pub owner: Signer<'info>,
+    pub token_x_program: Interface<'info, TokenInterface>,
+    pub token_y_program: Interface<'info, TokenInterface>,
+}
@@ -29,5 +29,16 @@ pub struct Pool {
     pub oracle_address: Pubkey,
     pub oracle_initialized: bool,
     pub bump: u8,
+    // fields below extend the original layout, existing pools are resized by `migrate_pool`
+    pub dynamic_fee_enabled: bool,
+    pub volatility_fee_per_tick: FixedPoint, // fee added for every tick spacing of accumulated volatility
+    pub max_volatility_fee: FixedPoint,
+    pub volatility_half_life: u64, // seconds after which accumulator is halved
+    pub volatility_accumulator: u64,
+    pub volatility_last_update: u64,
+    pub paused: u8,               // PAUSE_* bits applied on top of the global ones
+    pub fee_override: FixedPoint, // replaces the creation fee once set by governance
+    pub fee_overridden: bool,
+    pub open_range_orders: u32, // created and not yet claimed, the pool can not be closed before
 }
 size!(Pool);
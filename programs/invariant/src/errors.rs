@@ -72,4 +72,62 @@ pub enum ErrorCode {
     DisabledPool = 33, // 1791
     #[msg("Not supported Token2022 extension for mint account")]
     UnsupportedExtension = 34, // 1792
+    #[msg("Provided oracle is different than expected")]
+    InvalidOracle = 35, // 1793
+    #[msg("Oracle not found in context")]
+    OracleNotFound = 36, // 1794
+    #[msg("Oracle does not contain enough records for requested window")]
+    InsufficientOracleData = 37, // 1795
+    #[msg("TWAP window cannot be zero")]
+    InvalidTwapWindow = 38, // 1796
+    #[msg("Provided route is invalid")]
+    InvalidRoute = 39, // 1797
+    #[msg("Amount out is lower than provided minimum")]
+    AmountOutBelowMinimum = 40, // 1798
+    #[msg("Amount in is higher than provided maximum")]
+    AmountInAboveMaximum = 41, // 1799
+    #[msg("Invalid referral fee")]
+    InvalidReferralFee = 42, // 179a
+    #[msg("Invalid callback program")]
+    InvalidCallbackProgram = 43, // 179b
+    #[msg("Borrowed tokens were not repaid")]
+    InsufficientRepayment = 44, // 179c
+    #[msg("Invalid dynamic fee parameters")]
+    InvalidDynamicFee = 45, // 179d
+    #[msg("Range order has to be placed entirely on one side of the price")]
+    InvalidRangeOrder = 46, // 179e
+    #[msg("Range order is not filled yet")]
+    RangeOrderNotFilled = 47, // 179f
+    #[msg("Range order is already settled")]
+    RangeOrderSettled = 48, // 17a0
+    #[msg("Range order is not settled yet")]
+    RangeOrderNotSettled = 49, // 17a1
+    #[msg("Provided token account does not hold the position token")]
+    InvalidPositionToken = 50, // 17a2
+    #[msg("Remaining accounts are not (position, lower tick, upper tick) triples")]
+    InvalidRemainingAccounts = 51, // 17a3
+    #[msg("Liquidity supported by the amounts is below the minimum")]
+    LiquidityBelowMinimum = 52, // 17a4
+    #[msg("Invalid operator permissions")]
+    InvalidPermissions = 53, // 17a5
+    #[msg("Pool still holds liquidity, ticks or fees")]
+    PoolNotEmpty = 54, // 17a6
+    #[msg("Position list still contains positions")]
+    PositionListNotEmpty = 55, // 17a7
+    #[msg("Operation is paused")]
+    OperationPaused = 56, // 17a8
+    #[msg("Invalid pause flags")]
+    InvalidPauseFlags = 57, // 17a9
+    #[msg("Fee tier is disabled")]
+    FeeTierDisabled = 58, // 17aa
+    #[msg("Fee is outside of the fee tier bounds")]
+    FeeOutOfBounds = 59, // 17ab
+    #[msg("Fee split needs up to 4 recipients with shares summing up to 10000 bps")]
+    InvalidFeeSplit = 60, // 17ac
+    #[msg("Requested amount exceeds collected protocol fee")]
+    InsufficientProtocolFee = 61, // 17ad
+    #[msg("Amount out of a route hop does not match amount in of the next one")]
+    RouteAmountMismatch = 62, // 17ae
+    #[msg("Account already has the current layout")]
+    AlreadyMigrated = 63, // 17af
 }
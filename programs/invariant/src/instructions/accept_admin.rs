@@ -0,0 +1,29 @@
+use crate::structs::{AcceptAdminEvent, State};
+use crate::ErrorCode::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct AcceptAdmin<'info> {
+    #[account(mut, seeds = [b"statev1".as_ref()], bump = state.load()?.bump)]
+    pub state: AccountLoader<'info, State>,
+    #[account(constraint = &state.load()?.pending_admin == pending_admin.key @ InvalidAdmin)]
+    pub pending_admin: Signer<'info>,
+}
+
+impl<'info> AcceptAdmin<'info> {
+    pub fn handler(&self) -> Result<()> {
+        msg!("INVARIANT: ACCEPT ADMIN");
+
+        let mut state = self.state.load_mut()?;
+        let previous_admin = state.admin;
+        state.admin = state.pending_admin;
+        state.pending_admin = Pubkey::default();
+
+        emit!(AcceptAdminEvent {
+            previous_admin,
+            admin: state.admin,
+        });
+
+        Ok(())
+    }
+}
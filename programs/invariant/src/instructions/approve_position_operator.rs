@@ -0,0 +1,63 @@
+use crate::structs::position::Position;
+use crate::structs::position_operator::{PositionOperator, PERMISSION_ALL};
+use crate::structs::ApprovePositionOperatorEvent;
+use crate::ErrorCode::{self, *};
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::system_program;
+
+#[derive(Accounts)]
+pub struct ApprovePositionOperator<'info> {
+    #[account(init,
+        seeds = [b"positionoperatorv1",
+        owner.key().as_ref(),
+        position.load()?.pool.as_ref(),
+        &position.load()?.id.to_le_bytes(),
+        operator.key().as_ref()],
+        bump, payer = owner,
+        space = PositionOperator::LEN
+    )]
+    pub position_operator: AccountLoader<'info, PositionOperator>,
+    #[account(constraint = position.load()?.owner == owner.key() @ InvalidOwner)]
+    pub position: AccountLoader<'info, Position>,
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    /// CHECK: Ignore
+    pub operator: AccountInfo<'info>,
+    pub rent: Sysvar<'info, Rent>,
+    #[account(address = system_program::ID)]
+    /// CHECK: Ignore
+    pub system_program: AccountInfo<'info>,
+}
+
+impl<'info> ApprovePositionOperator<'info> {
+    pub fn handler(&self, permissions: u8, bump: u8) -> Result<()> {
+        msg!("INVARIANT: APPROVE POSITION OPERATOR");
+
+        require!(
+            permissions != 0 && permissions & !PERMISSION_ALL == 0,
+            ErrorCode::InvalidPermissions
+        );
+
+        let position = self.position.load()?;
+        let position_operator = &mut self.position_operator.load_init()?;
+
+        **position_operator = PositionOperator {
+            owner: position.owner,
+            pool: position.pool,
+            position_id: position.id,
+            operator: self.operator.key(),
+            permissions,
+            bump,
+        };
+
+        emit!(ApprovePositionOperatorEvent {
+            owner: position.owner,
+            pool: position.pool,
+            id: position.id,
+            operator: self.operator.key(),
+            permissions,
+        });
+
+        Ok(())
+    }
+}
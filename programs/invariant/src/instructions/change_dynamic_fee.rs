@@ -0,0 +1,62 @@
+use crate::decimals::*;
+use crate::structs::{ChangeDynamicFeeEvent, Pool, State};
+use crate::util::get_current_timestamp;
+use crate::ErrorCode::{self, *};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+#[derive(Accounts)]
+pub struct ChangeDynamicFee<'info> {
+    #[account(seeds = [b"statev1".as_ref()], bump = state.load()?.bump )]
+    pub state: AccountLoader<'info, State>,
+    #[account(mut,
+        seeds = [b"poolv1", token_x.to_account_info().key.as_ref(), token_y.to_account_info().key.as_ref(), &pool.load()?.fee.v.to_le_bytes(), &pool.load()?.tick_spacing.to_le_bytes()],
+        bump = pool.load()?.bump
+    )]
+    pub pool: AccountLoader<'info, Pool>,
+    #[account(constraint = token_x.to_account_info().key == &pool.load()?.token_x @ InvalidTokenAccount) ]
+    pub token_x: InterfaceAccount<'info, Mint>,
+    #[account(constraint = token_y.to_account_info().key == &pool.load()?.token_y @ InvalidTokenAccount)]
+    pub token_y: InterfaceAccount<'info, Mint>,
+    #[account(constraint = &state.load()?.admin == admin.key @ InvalidAdmin)]
+    pub admin: Signer<'info>,
+}
+
+impl<'info> ChangeDynamicFee<'info> {
+    pub fn handler(
+        &self,
+        enabled: bool,
+        volatility_fee_per_tick: FixedPoint,
+        max_volatility_fee: FixedPoint,
+        volatility_half_life: u64,
+    ) -> Result<()> {
+        msg!("INVARIANT: CHANGE DYNAMIC FEE");
+
+        let pool = &mut self.pool.load_mut()?;
+
+        if enabled {
+            require!(volatility_half_life != 0, ErrorCode::InvalidDynamicFee);
+            // effective fee can never reach 100%
+            require!(
+                pool.base_fee() + max_volatility_fee < FixedPoint::from_integer(1),
+                ErrorCode::InvalidDynamicFee
+            );
+        }
+
+        pool.dynamic_fee_enabled = enabled;
+        pool.volatility_fee_per_tick = volatility_fee_per_tick;
+        pool.max_volatility_fee = max_volatility_fee;
+        pool.volatility_half_life = volatility_half_life;
+        pool.volatility_accumulator = 0;
+        pool.volatility_last_update = get_current_timestamp();
+
+        emit!(ChangeDynamicFeeEvent {
+            pool: self.pool.key(),
+            enabled,
+            volatility_fee_per_tick,
+            max_volatility_fee,
+            volatility_half_life,
+        });
+
+        Ok(())
+    }
+}
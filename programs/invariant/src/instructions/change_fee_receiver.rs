@@ -1,4 +1,4 @@
-use crate::structs::{Pool, State};
+use crate::structs::{ChangeFeeReceiverEvent, Pool, Role, State};
 use crate::ErrorCode::*;
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::Mint;
@@ -16,7 +16,7 @@ pub struct ChangeFeeReceiver<'info> {
     pub token_x: InterfaceAccount<'info, Mint>,
     #[account(constraint = token_y.to_account_info().key == &pool.load()?.token_y @ InvalidTokenAccount)]
     pub token_y: InterfaceAccount<'info, Mint>,
-    #[account(constraint = &state.load()?.admin == admin.key @ InvalidAdmin)]
+    #[account(constraint = state.load()?.has_role(admin.key, Role::ProtocolFeeManager) @ InvalidAdmin)]
     pub admin: Signer<'info>,
     /// CHECK: Ignore
     pub fee_receiver: AccountInfo<'info>,
@@ -27,6 +27,11 @@ impl<'info> ChangeFeeReceiver<'info> {
         let mut pool = self.pool.load_mut()?;
         pool.fee_receiver = self.fee_receiver.key();
 
+        emit!(ChangeFeeReceiverEvent {
+            pool: self.pool.key(),
+            fee_receiver: pool.fee_receiver,
+        });
+
         Ok(())
     }
 }
@@ -0,0 +1,27 @@
+use crate::structs::fee_split::FeeSplit;
+use crate::structs::{ChangeFeeSplitEvent, Role, State};
+use crate::ErrorCode::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ChangeFeeSplit<'info> {
+    #[account(seeds = [b"statev1".as_ref()], bump = state.load()?.bump)]
+    pub state: AccountLoader<'info, State>,
+    #[account(mut, seeds = [b"feesplitv1"], bump = fee_split.load()?.bump)]
+    pub fee_split: AccountLoader<'info, FeeSplit>,
+    #[account(constraint = state.load()?.has_role(admin.key, Role::ProtocolFeeManager) @ InvalidAdmin)]
+    pub admin: Signer<'info>,
+}
+
+impl<'info> ChangeFeeSplit<'info> {
+    pub fn handler(&self, recipients: Vec<Pubkey>, shares: Vec<u16>) -> Result<()> {
+        msg!("INVARIANT: CHANGE FEE SPLIT");
+
+        let fee_split = &mut self.fee_split.load_mut()?;
+        fee_split.set(&recipients, &shares)?;
+
+        emit!(ChangeFeeSplitEvent { recipients, shares });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,48 @@
+use crate::decimals::*;
+use crate::structs::fee_tier::FeeTier;
+use crate::structs::{ChangeFeeTierEvent, Role, State};
+use crate::ErrorCode::{self, *};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ChangeFeeTier<'info> {
+    #[account(seeds = [b"statev1".as_ref()], bump = state.load()?.bump)]
+    pub state: AccountLoader<'info, State>,
+    #[account(mut,
+        seeds = [b"feetierv1", __program_id.as_ref(), &fee_tier.load()?.fee.v.to_le_bytes(), &fee_tier.load()?.tick_spacing.to_le_bytes()],
+        bump = fee_tier.load()?.bump
+    )]
+    pub fee_tier: AccountLoader<'info, FeeTier>,
+    #[account(constraint = state.load()?.has_role(admin.key, Role::FeeTierManager) @ InvalidAdmin)]
+    pub admin: Signer<'info>,
+}
+
+impl<'info> ChangeFeeTier<'info> {
+    pub fn handler(&self, disabled: bool, min_fee: FixedPoint, max_fee: FixedPoint) -> Result<()> {
+        msg!("INVARIANT: CHANGE FEE TIER");
+
+        let fee_tier = &mut self.fee_tier.load_mut()?;
+        // bounds always contain the fee used in addresses of the tier pools
+        require!(
+            min_fee <= { fee_tier.fee } && { fee_tier.fee } <= max_fee,
+            ErrorCode::FeeOutOfBounds
+        );
+        require!(
+            max_fee < FixedPoint::from_integer(1),
+            ErrorCode::FeeOutOfBounds
+        );
+
+        fee_tier.disabled = disabled;
+        fee_tier.min_fee = min_fee;
+        fee_tier.max_fee = max_fee;
+
+        emit!(ChangeFeeTierEvent {
+            fee_tier: self.fee_tier.key(),
+            disabled,
+            min_fee,
+            max_fee,
+        });
+
+        Ok(())
+    }
+}
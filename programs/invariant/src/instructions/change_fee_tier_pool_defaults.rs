@@ -0,0 +1,48 @@
+use crate::decimals::*;
+use crate::structs::fee_tier::FeeTier;
+use crate::structs::{ChangeFeeTierPoolDefaultsEvent, Role, State};
+use crate::ErrorCode::{self, *};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ChangeFeeTierPoolDefaults<'info> {
+    #[account(seeds = [b"statev1".as_ref()], bump = state.load()?.bump)]
+    pub state: AccountLoader<'info, State>,
+    #[account(mut,
+        seeds = [b"feetierv1", __program_id.as_ref(), &fee_tier.load()?.fee.v.to_le_bytes(), &fee_tier.load()?.tick_spacing.to_le_bytes()],
+        bump = fee_tier.load()?.bump
+    )]
+    pub fee_tier: AccountLoader<'info, FeeTier>,
+    #[account(constraint = state.load()?.has_role(admin.key, Role::ProtocolFeeManager) @ InvalidAdmin)]
+    pub admin: Signer<'info>,
+}
+
+impl<'info> ChangeFeeTierPoolDefaults<'info> {
+    // `None` clears the override so new pools fall back to the State defaults
+    pub fn handler(
+        &self,
+        protocol_fee: Option<FixedPoint>,
+        fee_receiver: Option<Pubkey>,
+    ) -> Result<()> {
+        msg!("INVARIANT: CHANGE FEE TIER POOL DEFAULTS");
+
+        if let Some(protocol_fee) = protocol_fee {
+            require!(
+                protocol_fee <= FixedPoint::from_integer(1),
+                ErrorCode::InvalidProtocolFee
+            );
+        }
+        let fee_tier = &mut self.fee_tier.load_mut()?;
+        fee_tier.protocol_fee_overridden = protocol_fee.is_some();
+        fee_tier.protocol_fee_override = protocol_fee.unwrap_or(FixedPoint::new(0));
+        fee_tier.fee_receiver_override = fee_receiver.unwrap_or_default();
+
+        emit!(ChangeFeeTierPoolDefaultsEvent {
+            fee_tier: self.fee_tier.key(),
+            protocol_fee,
+            fee_receiver,
+        });
+
+        Ok(())
+    }
+}
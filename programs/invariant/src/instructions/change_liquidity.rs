@@ -1,6 +1,8 @@
 use crate::interfaces::take_tokens::TakeTokens;
 use crate::structs::pool::Pool;
 use crate::structs::position::Position;
+use crate::structs::position_operator::{PositionOperator, PERMISSION_CHANGE_LIQUIDITY};
+use crate::structs::state::{PAUSE_ADD_LIQUIDITY, PAUSE_REMOVE_LIQUIDITY};
 use crate::structs::tick::Tick;
 use crate::ErrorCode::{self, *};
 use crate::*;
@@ -11,17 +13,14 @@ use anchor_spl::token_interface::TokenInterface;
 use anchor_spl::token_interface::{Mint, TokenAccount};
 use decimals::*;
 use interfaces::send_tokens::SendTokens;
+use structs::ChangeLiquidityEvent;
 
 #[derive(Accounts)]
-#[instruction( index: u32)]
 pub struct ChangeLiquidity<'info> {
     #[account(seeds = [b"statev1".as_ref()], bump = state.load()?.bump)]
     pub state: AccountLoader<'info, State>,
+    // legacy (owner, index) or stable (pool, id) position, both store their owner
     #[account(mut,
-        seeds = [b"positionv1",
-        owner.key().as_ref(),
-        &index.to_le_bytes()],
-        bump = position.load()?.bump,
         constraint = position.load()?.pool == pool.key() @ InvalidPositionIndex
     )]
     pub position: AccountLoader<'info, Position>,
@@ -30,8 +29,7 @@ pub struct ChangeLiquidity<'info> {
         bump = pool.load()?.bump
     )]
     pub pool: AccountLoader<'info, Pool>,
-    #[account(mut)]
-    pub payer: Signer<'info>,
+    // position owner or an operator approved to change liquidity
     pub owner: Signer<'info>,
     #[account(mut,
         seeds = [b"tickv1", pool.key().as_ref(), &position.load()?.lower_tick_index.to_le_bytes()],
@@ -47,15 +45,16 @@ pub struct ChangeLiquidity<'info> {
     pub token_x: InterfaceAccount<'info, Mint>,
     #[account(constraint = token_y.key() == pool.load()?.token_y @ InvalidTokenAccount, mint::token_program = token_y_program)]
     pub token_y: InterfaceAccount<'info, Mint>,
+    // operator adds liquidity from owner accounts it is a token delegate of
     #[account(mut,
         constraint = account_x.mint == token_x.key() @ InvalidMint,
-        constraint = &account_x.owner == owner.key @ InvalidOwner,
+        constraint = account_x.owner == position.load()?.owner @ InvalidOwner,
         token::token_program = token_x_program,
     )]
     pub account_x: Box<InterfaceAccount<'info, TokenAccount>>,
     #[account(mut,
         constraint = account_y.mint == token_y.key() @ InvalidMint,
-        constraint = &account_y.owner == owner.key @ InvalidOwner,
+        constraint = account_y.owner == position.load()?.owner @ InvalidOwner,
         token::token_program = token_y_program,
     )]
     pub account_y: Box<InterfaceAccount<'info, TokenAccount>>,
@@ -76,8 +75,20 @@ pub struct ChangeLiquidity<'info> {
     #[account(constraint = &state.load()?.authority == program_authority.key @ InvalidAuthority)]
     /// CHECK: ignore
     pub program_authority: AccountInfo<'info>,
+
+    #[account(constraint = token_x_program.key() == token::ID || token_x_program.key() == token_2022::ID)]
     pub token_x_program: Interface<'info, TokenInterface>,
+    #[account(constraint = token_y_program.key() == token::ID || token_y_program.key() == token_2022::ID)]
     pub token_y_program: Interface<'info, TokenInterface>,
+    #[account(
+        seeds = [b"positionoperatorv1",
+        position.load()?.owner.as_ref(),
+        pool.key().as_ref(),
+        &position.load()?.id.to_le_bytes(),
+        owner.key().as_ref()],
+        bump = position_operator.load()?.bump
+    )]
+    pub position_operator: Option<AccountLoader<'info, PositionOperator>>,
 }
 
 impl<'info> TakeTokens<'info> for ChangeLiquidity<'info> {
@@ -183,8 +194,15 @@ impl<'info> ChangeLiquidity<'info> {
         add_liquidity: bool,
         slippage_limit_lower: Price,
         slippage_limit_upper: Price,
+        amount_x_limit: u64, // maximum amount in when adding, minimum amount out when removing
+        amount_y_limit: u64,
     ) -> Result<()> {
         msg!("INVARIANT: CHANGE POSITION LIQUIDITY");
+        let operation = match add_liquidity {
+            true => PAUSE_ADD_LIQUIDITY,
+            false => PAUSE_REMOVE_LIQUIDITY,
+        };
+        check_not_paused(&*self.state.load()?, &*self.pool.load()?, operation)?;
 
         let mut position = self.position.load_mut()?;
         let pool = &mut self.pool.load_mut()?;
@@ -193,6 +211,13 @@ impl<'info> ChangeLiquidity<'info> {
         let current_timestamp = get_current_timestamp();
         let slot = get_current_slot();
 
+        check_position_authority(
+            &position,
+            self.owner.key,
+            &self.position_operator,
+            PERMISSION_CHANGE_LIQUIDITY,
+        )?;
+
         let liquidity = position.liquidity;
         require!(
             add_liquidity || liquidity_delta != liquidity,
@@ -212,13 +237,13 @@ impl<'info> ChangeLiquidity<'info> {
             ErrorCode::PriceLimitReached
         );
 
-        position.seconds_per_liquidity_inside = calculate_seconds_per_liquidity_inside(
+        position.update_seconds_per_liquidity(
+            pool,
             **lower_tick,
             **upper_tick,
-            pool,
             current_timestamp,
+            slot,
         );
-        position.last_slot = slot;
 
         let (amount_x, amount_y) = position.modify(
             pool,
@@ -234,6 +259,19 @@ impl<'info> ChangeLiquidity<'info> {
             ErrorCode::ZeroOutput
         );
 
+        // validate token amounts
+        if add_liquidity {
+            require!(
+                amount_x <= TokenAmount(amount_x_limit) && amount_y <= TokenAmount(amount_y_limit),
+                ErrorCode::AmountInAboveMaximum
+            );
+        } else {
+            require!(
+                amount_x >= TokenAmount(amount_x_limit) && amount_y >= TokenAmount(amount_y_limit),
+                ErrorCode::AmountOutBelowMinimum
+            );
+        }
+
         if add_liquidity {
             match self.token_x_program.key() {
                 token_2022::ID => token_2022::transfer_checked(
@@ -278,6 +316,17 @@ impl<'info> ChangeLiquidity<'info> {
             };
         }
 
+        emit!(ChangeLiquidityEvent {
+            owner: position.owner,
+            pool: position.pool,
+            id: position.id,
+            liquidity_delta,
+            add_liquidity,
+            amount_x: amount_x.0,
+            amount_y: amount_y.0,
+            current_timestamp,
+        });
+
         Ok(())
     }
 }
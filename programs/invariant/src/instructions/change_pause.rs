@@ -0,0 +1,26 @@
+use crate::structs::state::PAUSE_ALL;
+use crate::structs::{ChangePauseEvent, Role, State};
+use crate::ErrorCode::{self, *};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ChangePause<'info> {
+    #[account(mut, seeds = [b"statev1".as_ref()], bump = state.load()?.bump)]
+    pub state: AccountLoader<'info, State>,
+    #[account(constraint = state.load()?.has_role(pauser.key, Role::Pauser) @ InvalidAdmin)]
+    pub pauser: Signer<'info>,
+}
+
+impl<'info> ChangePause<'info> {
+    pub fn handler(&self, paused: u8) -> Result<()> {
+        msg!("INVARIANT: CHANGE PAUSE");
+
+        require!(paused & !PAUSE_ALL == 0, ErrorCode::InvalidPauseFlags);
+        let mut state = self.state.load_mut()?;
+        state.paused = paused;
+
+        emit!(ChangePauseEvent { paused });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,35 @@
+use crate::decimals::*;
+use crate::structs::{ChangePoolDefaultsEvent, Role, State};
+use crate::ErrorCode::{self, *};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ChangePoolDefaults<'info> {
+    #[account(mut, seeds = [b"statev1".as_ref()], bump = state.load()?.bump)]
+    pub state: AccountLoader<'info, State>,
+    #[account(constraint = state.load()?.has_role(admin.key, Role::ProtocolFeeManager) @ InvalidAdmin)]
+    pub admin: Signer<'info>,
+    /// CHECK: Ignore
+    pub fee_receiver: AccountInfo<'info>,
+}
+
+impl<'info> ChangePoolDefaults<'info> {
+    pub fn handler(&self, protocol_fee: FixedPoint) -> Result<()> {
+        msg!("INVARIANT: CHANGE POOL DEFAULTS");
+
+        require!(
+            protocol_fee <= FixedPoint::from_integer(1),
+            ErrorCode::InvalidProtocolFee
+        );
+        let mut state = self.state.load_mut()?;
+        state.default_protocol_fee = protocol_fee;
+        state.default_fee_receiver = self.fee_receiver.key();
+
+        emit!(ChangePoolDefaultsEvent {
+            protocol_fee,
+            fee_receiver: state.default_fee_receiver,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,61 @@
+use crate::decimals::*;
+use crate::structs::fee_tier::FeeTier;
+use crate::structs::{ChangePoolFeeEvent, Pool, Role, State};
+use crate::ErrorCode::{self, *};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+#[derive(Accounts)]
+pub struct ChangePoolFee<'info> {
+    #[account(seeds = [b"statev1".as_ref()], bump = state.load()?.bump)]
+    pub state: AccountLoader<'info, State>,
+    #[account(mut,
+        seeds = [b"poolv1", token_x.to_account_info().key.as_ref(), token_y.to_account_info().key.as_ref(), &pool.load()?.fee.v.to_le_bytes(), &pool.load()?.tick_spacing.to_le_bytes()],
+        bump = pool.load()?.bump
+    )]
+    pub pool: AccountLoader<'info, Pool>,
+    // tier the pool was created with, its bounds govern the fee change
+    #[account(
+        seeds = [b"feetierv1", __program_id.as_ref(), &pool.load()?.fee.v.to_le_bytes(), &pool.load()?.tick_spacing.to_le_bytes()],
+        bump = fee_tier.load()?.bump
+    )]
+    pub fee_tier: AccountLoader<'info, FeeTier>,
+    #[account(constraint = token_x.to_account_info().key == &pool.load()?.token_x @ InvalidTokenAccount)]
+    pub token_x: InterfaceAccount<'info, Mint>,
+    #[account(constraint = token_y.to_account_info().key == &pool.load()?.token_y @ InvalidTokenAccount)]
+    pub token_y: InterfaceAccount<'info, Mint>,
+    #[account(constraint = state.load()?.has_role(admin.key, Role::FeeTierManager) @ InvalidAdmin)]
+    pub admin: Signer<'info>,
+}
+
+impl<'info> ChangePoolFee<'info> {
+    pub fn handler(&self, fee: FixedPoint) -> Result<()> {
+        msg!("INVARIANT: CHANGE POOL FEE");
+
+        let pool = &mut self.pool.load_mut()?;
+        let fee_tier = self.fee_tier.load()?;
+
+        require!(
+            { fee_tier.min_fee } <= fee && fee <= { fee_tier.max_fee },
+            ErrorCode::FeeOutOfBounds
+        );
+        if pool.dynamic_fee_enabled {
+            // effective fee can never reach 100%
+            require!(
+                fee + pool.max_volatility_fee < FixedPoint::from_integer(1),
+                ErrorCode::InvalidDynamicFee
+            );
+        }
+
+        // address of the pool still derives from the creation fee
+        pool.fee_override = fee;
+        pool.fee_overridden = true;
+
+        emit!(ChangePoolFeeEvent {
+            pool: self.pool.key(),
+            fee,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,39 @@
+use crate::structs::state::PAUSE_ALL;
+use crate::structs::{ChangePoolPauseEvent, Pool, Role, State};
+use crate::ErrorCode::{self, *};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+#[derive(Accounts)]
+pub struct ChangePoolPause<'info> {
+    #[account(seeds = [b"statev1".as_ref()], bump = state.load()?.bump)]
+    pub state: AccountLoader<'info, State>,
+    #[account(mut,
+        seeds = [b"poolv1", token_x.to_account_info().key.as_ref(), token_y.to_account_info().key.as_ref(), &pool.load()?.fee.v.to_le_bytes(), &pool.load()?.tick_spacing.to_le_bytes()],
+        bump = pool.load()?.bump
+    )]
+    pub pool: AccountLoader<'info, Pool>,
+    #[account(constraint = token_x.to_account_info().key == &pool.load()?.token_x @ InvalidTokenAccount)]
+    pub token_x: InterfaceAccount<'info, Mint>,
+    #[account(constraint = token_y.to_account_info().key == &pool.load()?.token_y @ InvalidTokenAccount)]
+    pub token_y: InterfaceAccount<'info, Mint>,
+    #[account(constraint = state.load()?.has_role(pauser.key, Role::Pauser) @ InvalidAdmin)]
+    pub pauser: Signer<'info>,
+}
+
+impl<'info> ChangePoolPause<'info> {
+    pub fn handler(&self, paused: u8) -> Result<()> {
+        msg!("INVARIANT: CHANGE POOL PAUSE");
+
+        require!(paused & !PAUSE_ALL == 0, ErrorCode::InvalidPauseFlags);
+        let mut pool = self.pool.load_mut()?;
+        pool.paused = paused;
+
+        emit!(ChangePoolPauseEvent {
+            pool: self.pool.key(),
+            paused,
+        });
+
+        Ok(())
+    }
+}
@@ -1,5 +1,5 @@
 use crate::decimals::*;
-use crate::structs::{Pool, State};
+use crate::structs::{ChangeProtocolFeeEvent, Pool, Role, State};
 use crate::ErrorCode::{self, *};
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::Mint;
@@ -16,7 +16,7 @@ pub struct ChangeProtocolFee<'info> {
     pub token_x: InterfaceAccount<'info, Mint>,
     #[account(constraint = token_y.to_account_info().key == &pool.load()?.token_y @ InvalidTokenAccount)]
     pub token_y: InterfaceAccount<'info, Mint>,
-    #[account(constraint = &state.load()?.admin == admin.key @ InvalidAdmin)]
+    #[account(constraint = state.load()?.has_role(admin.key, Role::ProtocolFeeManager) @ InvalidAdmin)]
     pub admin: Signer<'info>,
     #[account(constraint = &state.load()?.authority == program_authority.key @ InvalidAuthority)]
     /// CHECK: Ignore
@@ -32,6 +32,11 @@ impl<'info> ChangeProtocolFee<'info> {
         let pool = &mut self.pool.load_mut()?;
         pool.protocol_fee = protocol_fee;
 
+        emit!(ChangeProtocolFeeEvent {
+            pool: self.pool.key(),
+            protocol_fee,
+        });
+
         Ok(())
     }
 }
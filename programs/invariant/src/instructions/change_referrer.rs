@@ -0,0 +1,42 @@
+use crate::decimals::*;
+use crate::structs::referrer::Referrer;
+use crate::structs::ChangeReferrerEvent;
+use crate::ErrorCode::{self, *};
+use crate::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ChangeReferrer<'info> {
+    #[account(mut,
+        seeds = [b"referrerv1", referrer.load()?.owner.as_ref()],
+        bump = referrer.load()?.bump
+    )]
+    pub referrer: AccountLoader<'info, Referrer>,
+    #[account(seeds = [b"statev1".as_ref()], bump = state.load()?.bump)]
+    pub state: AccountLoader<'info, State>,
+    #[account(constraint = &state.load()?.admin == admin.key @ InvalidAdmin)]
+    pub admin: Signer<'info>,
+}
+
+impl<'info> ChangeReferrer<'info> {
+    pub fn handler(&self, fee_share: FixedPoint, enabled: bool) -> Result<()> {
+        msg!("INVARIANT: CHANGE REFERRER");
+
+        require!(
+            fee_share <= FixedPoint::from_integer(1),
+            ErrorCode::InvalidReferralFee
+        );
+        let referrer = &mut self.referrer.load_mut()?;
+        referrer.fee_share = fee_share;
+        referrer.enabled = enabled;
+
+        emit!(ChangeReferrerEvent {
+            referrer: self.referrer.key(),
+            owner: referrer.owner,
+            fee_share,
+            enabled,
+        });
+
+        Ok(())
+    }
+}
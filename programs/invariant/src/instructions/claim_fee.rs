@@ -2,7 +2,9 @@ use crate::decimals::*;
 use crate::interfaces::send_tokens::SendTokens;
 use crate::structs::pool::Pool;
 use crate::structs::position::Position;
+use crate::structs::position_operator::{PositionOperator, PERMISSION_CLAIM_FEE};
 use crate::structs::tick::Tick;
+use crate::structs::ClaimFeeEvent;
 use crate::util::*;
 use crate::ErrorCode::{self, *};
 use crate::*;
@@ -22,11 +24,9 @@ pub struct ClaimFee<'info> {
         bump = pool.load()?.bump
     )]
     pub pool: AccountLoader<'info, Pool>,
+    // legacy (owner, index) or stable (pool, id) position, both store their owner
     #[account(mut,
-        seeds = [b"positionv1",
-        owner.key().as_ref(),
-        &index.to_le_bytes()],
-        bump = position.load()?.bump
+        constraint = position.load()?.pool == pool.key() @ InvalidPositionIndex
     )]
     pub position: AccountLoader<'info, Position>,
     #[account(mut,
@@ -41,6 +41,7 @@ pub struct ClaimFee<'info> {
         constraint = upper_tick_index == position.load()?.upper_tick_index @ WrongTick
     )]
     pub upper_tick: AccountLoader<'info, Tick>,
+    // position owner or an operator approved to claim fees
     pub owner: Signer<'info>,
     #[account(constraint = token_x.key() == pool.load()?.token_x @ InvalidTokenAccount, mint::token_program = token_x_program)]
     pub token_x: InterfaceAccount<'info, Mint>,
@@ -48,13 +49,13 @@ pub struct ClaimFee<'info> {
     pub token_y: InterfaceAccount<'info, Mint>,
     #[account(mut,
         constraint = account_x.mint == token_x.key() @ InvalidMint,
-        constraint = &account_x.owner == owner.key @ InvalidOwner,
+        constraint = account_x.owner == position.load()?.owner @ InvalidOwner,
         token::token_program = token_x_program
     )]
     pub account_x: Box<InterfaceAccount<'info, TokenAccount>>,
     #[account(mut,
         constraint = account_y.mint == token_y.key() @ InvalidMint,
-        constraint = &account_y.owner == owner.key @ InvalidOwner,
+        constraint = account_y.owner == position.load()?.owner @ InvalidOwner,
         token::token_program = token_y_program
     )]
     pub account_y: Box<InterfaceAccount<'info, TokenAccount>>,
@@ -80,6 +81,15 @@ pub struct ClaimFee<'info> {
     pub token_x_program: Interface<'info, TokenInterface>,
     #[account(constraint = token_y_program.key() == token::ID || token_y_program.key() == token_2022::ID)]
     pub token_y_program: Interface<'info, TokenInterface>,
+    #[account(
+        seeds = [b"positionoperatorv1",
+        position.load()?.owner.as_ref(),
+        pool.key().as_ref(),
+        &position.load()?.id.to_le_bytes(),
+        owner.key().as_ref()],
+        bump = position_operator.load()?.bump
+    )]
+    pub position_operator: Option<AccountLoader<'info, PositionOperator>>,
 }
 
 impl<'info> interfaces::send_tokens::SendTokens<'info> for ClaimFee<'info> {
@@ -141,6 +151,12 @@ impl<'info> ClaimFee<'info> {
         let upper_tick = &mut self.upper_tick.load_mut()?;
         let current_timestamp = get_current_timestamp();
 
+        check_position_authority(
+            position,
+            self.owner.key,
+            &self.position_operator,
+            PERMISSION_CLAIM_FEE,
+        )?;
         check_ticks(lower_tick.index, upper_tick.index, pool.tick_spacing)?;
 
         position
@@ -185,6 +201,15 @@ impl<'info> ClaimFee<'info> {
             token::ID => token::transfer(self.send_y().with_signer(signer), fee_to_collect_y.0)?,
             _ => return Err(ErrorCode::InvalidTokenProgram.into()),
         };
+
+        emit!(ClaimFeeEvent {
+            owner: position.owner,
+            pool: position.pool,
+            id: position.id,
+            amount_x: fee_to_collect_x.0,
+            amount_y: fee_to_collect_y.0,
+            current_timestamp,
+        });
         Ok(())
     }
 }
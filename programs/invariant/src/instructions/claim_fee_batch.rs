@@ -0,0 +1,216 @@
+use crate::decimals::*;
+use crate::interfaces::send_tokens::SendTokens;
+use crate::structs::pool::Pool;
+use crate::structs::position::Position;
+use crate::structs::tick::Tick;
+use crate::structs::ClaimFeeEvent;
+use crate::util::*;
+use crate::ErrorCode::{self, *};
+use crate::*;
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::Transfer;
+use anchor_spl::token_2022;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+#[derive(Accounts)]
+pub struct ClaimFeeBatch<'info> {
+    #[account(seeds = [b"statev1".as_ref()], bump = state.load()?.bump)]
+    pub state: AccountLoader<'info, State>,
+    #[account(mut,
+        seeds = [b"poolv1", token_x.key().as_ref(), token_y.key().as_ref(), &pool.load()?.fee.v.to_le_bytes(), &pool.load()?.tick_spacing.to_le_bytes()],
+        bump = pool.load()?.bump
+    )]
+    pub pool: AccountLoader<'info, Pool>,
+    pub owner: Signer<'info>,
+    #[account(constraint = token_x.key() == pool.load()?.token_x @ InvalidTokenAccount, mint::token_program = token_x_program)]
+    pub token_x: InterfaceAccount<'info, Mint>,
+    #[account(constraint = token_y.key() == pool.load()?.token_y @ InvalidTokenAccount, mint::token_program = token_y_program)]
+    pub token_y: InterfaceAccount<'info, Mint>,
+    #[account(mut,
+        constraint = account_x.mint == token_x.key() @ InvalidMint,
+        constraint = &account_x.owner == owner.key @ InvalidOwner,
+        token::token_program = token_x_program
+    )]
+    pub account_x: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut,
+        constraint = account_y.mint == token_y.key() @ InvalidMint,
+        constraint = &account_y.owner == owner.key @ InvalidOwner,
+        token::token_program = token_y_program
+    )]
+    pub account_y: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut,
+        constraint = reserve_x.mint == token_x.key() @ InvalidMint,
+        constraint = &reserve_x.owner == program_authority.key @ InvalidAuthority,
+        constraint = reserve_x.key() == pool.load()?.token_x_reserve @ InvalidTokenAccount,
+        token::token_program = token_x_program,
+    )]
+    pub reserve_x: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut,
+        constraint = reserve_y.mint == token_y.key() @ InvalidMint,
+        constraint = &reserve_y.owner == program_authority.key @ InvalidAuthority,
+        constraint = reserve_y.key() == pool.load()?.token_y_reserve @ InvalidTokenAccount,
+        token::token_program = token_y_program,
+    )]
+    pub reserve_y: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(constraint = &state.load()?.authority == program_authority.key @ InvalidAuthority)]
+    /// CHECK: ignore
+    pub program_authority: AccountInfo<'info>,
+
+    #[account(constraint = token_x_program.key() == token::ID || token_x_program.key() == token_2022::ID)]
+    pub token_x_program: Interface<'info, TokenInterface>,
+    #[account(constraint = token_y_program.key() == token::ID || token_y_program.key() == token_2022::ID)]
+    pub token_y_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> interfaces::send_tokens::SendTokens<'info> for ClaimFeeBatch<'info> {
+    fn send_x(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.token_x_program.to_account_info(),
+            Transfer {
+                from: self.reserve_x.to_account_info(),
+                to: self.account_x.to_account_info(),
+                authority: self.program_authority.clone(),
+            },
+        )
+    }
+
+    fn send_y(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.token_y_program.to_account_info(),
+            Transfer {
+                from: self.reserve_y.to_account_info(),
+                to: self.account_y.to_account_info(),
+                authority: self.program_authority.clone(),
+            },
+        )
+    }
+
+    fn send_x_2022(&self) -> CpiContext<'_, '_, '_, 'info, token_2022::TransferChecked<'info>> {
+        CpiContext::new(
+            self.token_x_program.to_account_info(),
+            token_2022::TransferChecked {
+                mint: self.token_x.to_account_info(),
+                from: self.reserve_x.to_account_info(),
+                to: self.account_x.to_account_info(),
+                authority: self.program_authority.clone(),
+            },
+        )
+    }
+
+    fn send_y_2022(&self) -> CpiContext<'_, '_, '_, 'info, token_2022::TransferChecked<'info>> {
+        CpiContext::new(
+            self.token_y_program.to_account_info(),
+            token_2022::TransferChecked {
+                mint: self.token_y.to_account_info(),
+                from: self.reserve_y.to_account_info(),
+                to: self.account_y.to_account_info(),
+                authority: self.program_authority.clone(),
+            },
+        )
+    }
+}
+
+impl<'info> ClaimFeeBatch<'info> {
+    // Remaining accounts are (position, lower tick, upper tick) triples of the pool
+    pub fn handler(ctx: Context<'_, '_, 'info, 'info, ClaimFeeBatch<'info>>) -> Result<()> {
+        msg!("INVARIANT: CLAIM FEE BATCH");
+
+        let remaining_accounts = ctx.remaining_accounts;
+        let triples = remaining_accounts.chunks_exact(3);
+        require!(
+            !remaining_accounts.is_empty() && triples.remainder().is_empty(),
+            ErrorCode::InvalidRemainingAccounts
+        );
+
+        let accounts = &ctx.accounts;
+        let state = accounts.state.load()?;
+        let pool = &mut accounts.pool.load_mut()?;
+        let pool_address = accounts.pool.key();
+        let owner = accounts.owner.key();
+        let current_timestamp = get_current_timestamp();
+
+        let mut total_x = TokenAmount(0);
+        let mut total_y = TokenAmount(0);
+
+        for triple in triples {
+            let position_loader = AccountLoader::<'info, Position>::try_from(&triple[0])?;
+            let lower_tick_loader = AccountLoader::<'info, Tick>::try_from(&triple[1])?;
+            let upper_tick_loader = AccountLoader::<'info, Tick>::try_from(&triple[2])?;
+
+            let position = &mut position_loader.load_mut()?;
+            require_keys_eq!(position.owner, owner, ErrorCode::InvalidOwner);
+            require_keys_eq!(position.pool, pool_address, ErrorCode::InvalidPositionIndex);
+
+            // ticks are owned by the program, so their pool and index can be trusted
+            let lower_tick = &mut lower_tick_loader.load_mut()?;
+            let upper_tick = &mut upper_tick_loader.load_mut()?;
+            require!(
+                lower_tick.pool == pool_address && lower_tick.index == position.lower_tick_index,
+                ErrorCode::WrongTick
+            );
+            require!(
+                upper_tick.pool == pool_address && upper_tick.index == position.upper_tick_index,
+                ErrorCode::WrongTick
+            );
+
+            check_ticks(lower_tick.index, upper_tick.index, pool.tick_spacing)?;
+
+            position
+                .modify(
+                    pool,
+                    upper_tick,
+                    lower_tick,
+                    Liquidity::new(0),
+                    true,
+                    current_timestamp,
+                )
+                .unwrap();
+
+            let fee_to_collect_x = TokenAmount::from_decimal(position.tokens_owed_x);
+            let fee_to_collect_y = TokenAmount::from_decimal(position.tokens_owed_y);
+            // trunk-ignore(clippy/assign_op_pattern)
+            position.tokens_owed_x =
+                position.tokens_owed_x - FixedPoint::from_decimal(fee_to_collect_x);
+            // trunk-ignore(clippy/assign_op_pattern)
+            position.tokens_owed_y =
+                position.tokens_owed_y - FixedPoint::from_decimal(fee_to_collect_y);
+
+            total_x += fee_to_collect_x;
+            total_y += fee_to_collect_y;
+
+            emit!(ClaimFeeEvent {
+                owner: position.owner,
+                pool: position.pool,
+                id: position.id,
+                amount_x: fee_to_collect_x.0,
+                amount_y: fee_to_collect_y.0,
+                current_timestamp,
+            });
+        }
+
+        let signer: &[&[&[u8]]] = get_signer!(state.nonce);
+
+        match accounts.token_x_program.key() {
+            token_2022::ID => token_2022::transfer_checked(
+                accounts.send_x_2022().with_signer(signer),
+                total_x.0,
+                accounts.token_x.decimals,
+            )?,
+            token::ID => token::transfer(accounts.send_x().with_signer(signer), total_x.0)?,
+            _ => return Err(ErrorCode::InvalidTokenProgram.into()),
+        };
+
+        match accounts.token_y_program.key() {
+            token_2022::ID => token_2022::transfer_checked(
+                accounts.send_y_2022().with_signer(signer),
+                total_y.0,
+                accounts.token_y.decimals,
+            )?,
+            token::ID => token::transfer(accounts.send_y().with_signer(signer), total_y.0)?,
+            _ => return Err(ErrorCode::InvalidTokenProgram.into()),
+        };
+
+        Ok(())
+    }
+}
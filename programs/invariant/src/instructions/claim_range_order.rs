@@ -0,0 +1,161 @@
+use crate::interfaces::send_tokens::SendTokens;
+use crate::structs::pool::Pool;
+use crate::structs::range_order::RangeOrder;
+use crate::structs::ClaimRangeOrderEvent;
+use crate::ErrorCode::{self, *};
+use crate::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+use anchor_spl::token_2022;
+use anchor_spl::token_interface::TokenInterface;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+use decimals::*;
+
+#[derive(Accounts)]
+pub struct ClaimRangeOrder<'info> {
+    #[account(seeds = [b"statev1".as_ref()], bump = state.load()?.bump)]
+    pub state: AccountLoader<'info, State>,
+    #[account(mut,
+        close = owner,
+        seeds = [b"rangeorderv1", owner.key.as_ref(), pool.key().as_ref(), &range_order.load()?.position.lower_tick_index.to_le_bytes()],
+        bump = range_order.load()?.bump
+    )]
+    pub range_order: AccountLoader<'info, RangeOrder>,
+    #[account(mut,
+        seeds = [b"poolv1", token_x.key().as_ref(), token_y.key().as_ref(), &pool.load()?.fee.v.to_le_bytes(), &pool.load()?.tick_spacing.to_le_bytes()],
+        bump = pool.load()?.bump
+    )]
+    pub pool: AccountLoader<'info, Pool>,
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    #[account(constraint = token_x.key() == pool.load()?.token_x @ InvalidTokenAccount, mint::token_program = token_x_program)]
+    pub token_x: InterfaceAccount<'info, Mint>,
+    #[account(constraint = token_y.key() == pool.load()?.token_y @ InvalidTokenAccount, mint::token_program = token_y_program)]
+    pub token_y: InterfaceAccount<'info, Mint>,
+    #[account(mut,
+        constraint = account_x.mint == token_x.key() @ InvalidMint,
+        token::token_program = token_x_program,
+    )]
+    pub account_x: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut,
+        constraint = account_y.mint == token_y.key() @ InvalidMint,
+        token::token_program = token_y_program,
+    )]
+    pub account_y: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut,
+        constraint = reserve_x.mint == token_x.key() @ InvalidMint,
+        constraint = &reserve_x.owner == program_authority.key @ InvalidOwner,
+        constraint = reserve_x.key() == pool.load()?.token_x_reserve @ InvalidTokenAccount,
+        token::token_program = token_x_program,
+    )]
+    pub reserve_x: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut,
+        constraint = reserve_y.mint == token_y.key() @ InvalidMint,
+        constraint = &reserve_y.owner == program_authority.key @ InvalidOwner,
+        constraint = reserve_y.key() == pool.load()?.token_y_reserve @ InvalidTokenAccount,
+        token::token_program = token_y_program,
+    )]
+    pub reserve_y: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(constraint = &state.load()?.authority == program_authority.key @ InvalidAuthority)]
+    /// CHECK: ignore
+    pub program_authority: AccountInfo<'info>,
+
+    #[account(constraint = token_x_program.key() == token::ID || token_x_program.key() == token_2022::ID)]
+    pub token_x_program: Interface<'info, TokenInterface>,
+    #[account(constraint = token_y_program.key() == token::ID || token_y_program.key() == token_2022::ID)]
+    pub token_y_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> SendTokens<'info> for ClaimRangeOrder<'info> {
+    fn send_x(&self) -> CpiContext<'_, '_, '_, 'info, token::Transfer<'info>> {
+        CpiContext::new(
+            self.token_x_program.to_account_info(),
+            token::Transfer {
+                from: self.reserve_x.to_account_info(),
+                to: self.account_x.to_account_info(),
+                authority: self.program_authority.clone(),
+            },
+        )
+    }
+
+    fn send_y(&self) -> CpiContext<'_, '_, '_, 'info, token::Transfer<'info>> {
+        CpiContext::new(
+            self.token_y_program.to_account_info(),
+            token::Transfer {
+                from: self.reserve_y.to_account_info(),
+                to: self.account_y.to_account_info(),
+                authority: self.program_authority.clone(),
+            },
+        )
+    }
+
+    fn send_x_2022(&self) -> CpiContext<'_, '_, '_, 'info, token_2022::TransferChecked<'info>> {
+        CpiContext::new(
+            self.token_x_program.to_account_info(),
+            token_2022::TransferChecked {
+                mint: self.token_x.to_account_info(),
+                from: self.reserve_x.to_account_info(),
+                to: self.account_x.to_account_info(),
+                authority: self.program_authority.clone(),
+            },
+        )
+    }
+
+    fn send_y_2022(&self) -> CpiContext<'_, '_, '_, 'info, token_2022::TransferChecked<'info>> {
+        CpiContext::new(
+            self.token_y_program.to_account_info(),
+            token_2022::TransferChecked {
+                mint: self.token_y.to_account_info(),
+                from: self.reserve_y.to_account_info(),
+                to: self.account_y.to_account_info(),
+                authority: self.program_authority.clone(),
+            },
+        )
+    }
+}
+
+impl<'info> ClaimRangeOrder<'info> {
+    pub fn handler(&self) -> Result<()> {
+        msg!("INVARIANT: CLAIM RANGE ORDER");
+
+        let state = self.state.load()?;
+        let range_order = self.range_order.load()?;
+        require!(range_order.settled, ErrorCode::RangeOrderNotSettled);
+        self.pool.load_mut()?.open_range_orders -= 1;
+
+        let amount_x = TokenAmount::from_decimal(range_order.position.tokens_owed_x);
+        let amount_y = TokenAmount::from_decimal(range_order.position.tokens_owed_y);
+
+        let signer: &[&[&[u8]]] = get_signer!(state.nonce);
+
+        match self.token_x_program.key() {
+            token_2022::ID => token_2022::transfer_checked(
+                self.send_x_2022().with_signer(signer),
+                amount_x.0,
+                self.token_x.decimals,
+            )?,
+            token::ID => token::transfer(self.send_x().with_signer(signer), amount_x.0)?,
+            _ => return Err(ErrorCode::InvalidTokenProgram.into()),
+        };
+
+        match self.token_y_program.key() {
+            token_2022::ID => token_2022::transfer_checked(
+                self.send_y_2022().with_signer(signer),
+                amount_y.0,
+                self.token_y.decimals,
+            )?,
+            token::ID => token::transfer(self.send_y().with_signer(signer), amount_y.0)?,
+            _ => return Err(ErrorCode::InvalidTokenProgram.into()),
+        };
+
+        emit!(ClaimRangeOrderEvent {
+            owner: range_order.position.owner,
+            pool: range_order.position.pool,
+            id: range_order.position.id,
+            amount_x: amount_x.0,
+            amount_y: amount_y.0,
+        });
+
+        Ok(())
+    }
+}
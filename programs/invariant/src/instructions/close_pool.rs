@@ -0,0 +1,175 @@
+use crate::structs::oracle::Oracle;
+use crate::structs::pool::Pool;
+use crate::structs::tickmap::Tickmap;
+use crate::structs::ClosePoolEvent;
+use crate::structs::State;
+use crate::util::transfer_tokens;
+use crate::ErrorCode::{self, *};
+use crate::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+use anchor_spl::token_2022;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface};
+
+// rounding leaves at most a unit per token in every position operation, anything above is owed to users
+pub const MAX_CLOSE_POOL_DUST: u64 = 1000;
+
+#[derive(Accounts)]
+pub struct ClosePool<'info> {
+    #[account(seeds = [b"statev1".as_ref()], bump = state.load()?.bump)]
+    pub state: AccountLoader<'info, State>,
+    #[account(mut,
+        close = admin,
+        seeds = [b"poolv1", token_x.key().as_ref(), token_y.key().as_ref(), &pool.load()?.fee.v.to_le_bytes(), &pool.load()?.tick_spacing.to_le_bytes()],
+        bump = pool.load()?.bump
+    )]
+    pub pool: AccountLoader<'info, Pool>,
+    #[account(mut,
+        close = admin,
+        address = pool.load()?.tickmap @ InvalidTickmap
+    )]
+    pub tickmap: AccountLoader<'info, Tickmap>,
+    #[account(mut,
+        close = admin,
+        address = pool.load()?.oracle_address @ InvalidOracle
+    )]
+    pub oracle: Option<AccountLoader<'info, Oracle>>,
+    #[account(constraint = token_x.key() == pool.load()?.token_x @ InvalidTokenAccount, mint::token_program = token_x_program)]
+    pub token_x: Box<InterfaceAccount<'info, Mint>>,
+    #[account(constraint = token_y.key() == pool.load()?.token_y @ InvalidTokenAccount, mint::token_program = token_y_program)]
+    pub token_y: Box<InterfaceAccount<'info, Mint>>,
+    #[account(mut,
+        constraint = reserve_x.key() == pool.load()?.token_x_reserve @ InvalidTokenAccount,
+        token::token_program = token_x_program
+    )]
+    pub reserve_x: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    #[account(mut,
+        constraint = reserve_y.key() == pool.load()?.token_y_reserve @ InvalidTokenAccount,
+        token::token_program = token_y_program
+    )]
+    pub reserve_y: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    #[account(mut, constraint = &state.load()?.admin == admin.key @ InvalidAdmin)]
+    pub admin: Signer<'info>,
+    #[account(mut,
+        constraint = receiver_account_x.mint == token_x.key() @ InvalidMint,
+        constraint = &receiver_account_x.owner == admin.key @ InvalidOwner,
+        token::token_program = token_x_program
+    )]
+    pub receiver_account_x: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    #[account(mut,
+        constraint = receiver_account_y.mint == token_y.key() @ InvalidMint,
+        constraint = &receiver_account_y.owner == admin.key @ InvalidOwner,
+        token::token_program = token_y_program
+    )]
+    pub receiver_account_y: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    #[account(constraint = &state.load()?.authority == program_authority.key @ InvalidAuthority)]
+    /// CHECK: Ignore
+    pub program_authority: AccountInfo<'info>,
+
+    #[account(constraint = token_x_program.key() == token::ID || token_x_program.key() == token_2022::ID)]
+    pub token_x_program: Interface<'info, TokenInterface>,
+    #[account(constraint = token_y_program.key() == token::ID || token_y_program.key() == token_2022::ID)]
+    pub token_y_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> ClosePool<'info> {
+    // rounding dust left after all positions are removed goes to the admin
+    fn close_reserve(
+        &self,
+        reserve: &InterfaceAccount<'info, TokenAccount>,
+        receiver_account: &Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+        mint: &InterfaceAccount<'info, Mint>,
+        token_program: &Interface<'info, TokenInterface>,
+        signer: &[&[&[u8]]],
+    ) -> Result<()> {
+        require!(
+            reserve.amount <= MAX_CLOSE_POOL_DUST,
+            ErrorCode::PoolNotEmpty
+        );
+        if reserve.amount != 0 {
+            let receiver_account = receiver_account
+                .as_ref()
+                .ok_or(ErrorCode::InvalidTokenAccount)?;
+
+            transfer_tokens(
+                token_program.to_account_info(),
+                mint,
+                reserve.to_account_info(),
+                receiver_account.to_account_info(),
+                self.program_authority.clone(),
+                reserve.amount,
+                signer,
+            )?;
+        }
+
+        token_interface::close_account(
+            CpiContext::new(
+                token_program.to_account_info(),
+                token_interface::CloseAccount {
+                    account: reserve.to_account_info(),
+                    destination: self.admin.to_account_info(),
+                    authority: self.program_authority.clone(),
+                },
+            )
+            .with_signer(signer),
+        )
+    }
+
+    pub fn handler(&self) -> Result<()> {
+        msg!("INVARIANT: CLOSE POOL");
+
+        let state = self.state.load()?;
+        let pool = self.pool.load()?;
+        let tickmap = self.tickmap.load()?;
+        let signer: &[&[&[u8]]] = get_signer!(state.nonce);
+
+        // no positions, unclaimed range orders or protocol fees can be left behind
+        require!(
+            { pool.liquidity }.is_zero()
+                && tickmap.is_empty()
+                && pool.open_range_orders == 0
+                && pool.fee_protocol_token_x == 0
+                && pool.fee_protocol_token_y == 0,
+            ErrorCode::PoolNotEmpty
+        );
+        require!(
+            pool.oracle_initialized == self.oracle.is_some(),
+            ErrorCode::OracleNotFound
+        );
+
+        // reserves are passed only if they were initialized
+        match &self.reserve_x {
+            Some(reserve) => self.close_reserve(
+                reserve,
+                &self.receiver_account_x,
+                &self.token_x,
+                &self.token_x_program,
+                signer,
+            )?,
+            None => require!(
+                pool.token_x_reserve == Pubkey::default(),
+                ErrorCode::InvalidTokenAccount
+            ),
+        }
+        match &self.reserve_y {
+            Some(reserve) => self.close_reserve(
+                reserve,
+                &self.receiver_account_y,
+                &self.token_y,
+                &self.token_y_program,
+                signer,
+            )?,
+            None => require!(
+                pool.token_y_reserve == Pubkey::default(),
+                ErrorCode::InvalidTokenAccount
+            ),
+        }
+
+        emit!(ClosePoolEvent {
+            pool: self.pool.key(),
+            admin: self.admin.key(),
+        });
+
+        Ok(())
+    }
+}
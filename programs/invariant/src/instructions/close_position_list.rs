@@ -0,0 +1,31 @@
+use crate::structs::position_list::PositionList;
+use crate::structs::ClosePositionListEvent;
+use crate::ErrorCode;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ClosePositionList<'info> {
+    #[account(mut,
+        close = owner,
+        seeds = [b"positionlistv1", owner.key().as_ref()],
+        bump = position_list.load()?.bump
+    )]
+    pub position_list: AccountLoader<'info, PositionList>,
+    #[account(mut)]
+    pub owner: Signer<'info>,
+}
+
+impl<'info> ClosePositionList<'info> {
+    pub fn handler(&self) -> Result<()> {
+        msg!("INVARIANT: CLOSE POSITION LIST");
+
+        let position_list = self.position_list.load()?;
+        require!(position_list.head == 0, ErrorCode::PositionListNotEmpty);
+
+        emit!(ClosePositionListEvent {
+            owner: self.owner.key(),
+        });
+
+        Ok(())
+    }
+}
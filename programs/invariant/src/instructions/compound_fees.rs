@@ -0,0 +1,135 @@
+use crate::decimals::*;
+use crate::math::get_max_liquidity;
+use crate::structs::pool::Pool;
+use crate::structs::position::Position;
+use crate::structs::position_operator::{PositionOperator, PERMISSION_COMPOUND};
+use crate::structs::state::{State, PAUSE_ADD_LIQUIDITY};
+use crate::structs::tick::Tick;
+use crate::structs::CompoundFeesEvent;
+use crate::util::*;
+use crate::ErrorCode::{self, *};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CompoundFees<'info> {
+    #[account(seeds = [b"statev1".as_ref()], bump = state.load()?.bump)]
+    pub state: AccountLoader<'info, State>,
+    #[account(mut)]
+    pub pool: AccountLoader<'info, Pool>,
+    #[account(mut,
+        constraint = position.load()?.pool == pool.key() @ InvalidPositionIndex
+    )]
+    pub position: AccountLoader<'info, Position>,
+    #[account(mut,
+        seeds = [b"tickv1", pool.key().as_ref(), &position.load()?.lower_tick_index.to_le_bytes()],
+        bump = lower_tick.load()?.bump
+    )]
+    pub lower_tick: AccountLoader<'info, Tick>,
+    #[account(mut,
+        seeds = [b"tickv1", pool.key().as_ref(), &position.load()?.upper_tick_index.to_le_bytes()],
+        bump = upper_tick.load()?.bump
+    )]
+    pub upper_tick: AccountLoader<'info, Tick>,
+    // position owner or an approved operator
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [b"positionoperatorv1",
+        position.load()?.owner.as_ref(),
+        pool.key().as_ref(),
+        &position.load()?.id.to_le_bytes(),
+        authority.key().as_ref()],
+        bump = position_operator.load()?.bump
+    )]
+    pub position_operator: Option<AccountLoader<'info, PositionOperator>>,
+}
+
+impl<'info> CompoundFees<'info> {
+    pub fn handler(&self) -> Result<()> {
+        msg!("INVARIANT: COMPOUND FEES");
+        check_not_paused(
+            &*self.state.load()?,
+            &*self.pool.load()?,
+            PAUSE_ADD_LIQUIDITY,
+        )?;
+
+        let pool = &mut self.pool.load_mut()?;
+        let position = &mut self.position.load_mut()?;
+        let lower_tick = &mut self.lower_tick.load_mut()?;
+        let upper_tick = &mut self.upper_tick.load_mut()?;
+        let authority = self.authority.key();
+        let current_timestamp = get_current_timestamp();
+
+        check_position_authority(
+            position,
+            &authority,
+            &self.position_operator,
+            PERMISSION_COMPOUND,
+        )?;
+
+        check_ticks(lower_tick.index, upper_tick.index, pool.tick_spacing)?;
+
+        // collect fees accrued since the last update
+        position.modify(
+            pool,
+            upper_tick,
+            lower_tick,
+            Liquidity::new(0),
+            true,
+            current_timestamp,
+        )?;
+
+        let available_x = TokenAmount::from_decimal(position.tokens_owed_x);
+        let available_y = TokenAmount::from_decimal(position.tokens_owed_y);
+        let liquidity_delta = get_max_liquidity(
+            available_x,
+            available_y,
+            lower_tick.index,
+            upper_tick.index,
+            pool.sqrt_price,
+            true,
+        )
+        .l;
+        require!(!liquidity_delta.is_zero(), ErrorCode::ZeroAmount);
+
+        // same as in `change_liquidity`, so compounding can't inflate staking rewards
+        position.update_seconds_per_liquidity(
+            pool,
+            **lower_tick,
+            **upper_tick,
+            current_timestamp,
+            get_current_slot(),
+        );
+
+        // tokens stay in reserves, only their accounting moves from owed tokens to liquidity
+        let (amount_x, amount_y) = position.modify(
+            pool,
+            upper_tick,
+            lower_tick,
+            liquidity_delta,
+            true,
+            current_timestamp,
+        )?;
+        require!(
+            amount_x <= available_x && amount_y <= available_y,
+            ErrorCode::AmountInAboveMaximum
+        );
+
+        // trunk-ignore(clippy/assign_op_pattern)
+        position.tokens_owed_x = position.tokens_owed_x - FixedPoint::from_decimal(amount_x);
+        // trunk-ignore(clippy/assign_op_pattern)
+        position.tokens_owed_y = position.tokens_owed_y - FixedPoint::from_decimal(amount_y);
+
+        emit!(CompoundFeesEvent {
+            authority,
+            owner: position.owner,
+            pool: position.pool,
+            id: position.id,
+            liquidity_delta,
+            amount_x: amount_x.0,
+            amount_y: amount_y.0,
+            current_timestamp,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,37 @@
+use crate::structs::fee_split::FeeSplit;
+use crate::structs::{ChangeFeeSplitEvent, Role, State};
+use crate::ErrorCode::*;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::system_program;
+
+#[derive(Accounts)]
+pub struct CreateFeeSplit<'info> {
+    #[account(seeds = [b"statev1".as_ref()], bump = state.load()?.bump)]
+    pub state: AccountLoader<'info, State>,
+    #[account(init,
+        seeds = [b"feesplitv1"],
+        bump, payer = admin,
+        space = FeeSplit::LEN
+    )]
+    pub fee_split: AccountLoader<'info, FeeSplit>,
+    #[account(mut, constraint = state.load()?.has_role(admin.key, Role::ProtocolFeeManager) @ InvalidAdmin)]
+    pub admin: Signer<'info>,
+    pub rent: Sysvar<'info, Rent>,
+    #[account(address = system_program::ID)]
+    /// CHECK: Ignore
+    pub system_program: AccountInfo<'info>,
+}
+
+impl<'info> CreateFeeSplit<'info> {
+    pub fn handler(&self, recipients: Vec<Pubkey>, shares: Vec<u16>, bump: u8) -> Result<()> {
+        msg!("INVARIANT: CREATE FEE SPLIT");
+
+        let fee_split = &mut self.fee_split.load_init()?;
+        fee_split.bump = bump;
+        fee_split.set(&recipients, &shares)?;
+
+        emit!(ChangeFeeSplitEvent { recipients, shares });
+
+        Ok(())
+    }
+}
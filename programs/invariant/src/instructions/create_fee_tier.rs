@@ -1,5 +1,6 @@
 use crate::decimals::*;
 use crate::structs::fee_tier::FeeTier;
+use crate::structs::Role;
 use crate::ErrorCode::{self, *};
 use crate::*;
 use anchor_lang::prelude::*;
@@ -15,7 +16,7 @@ pub struct CreateFeeTier<'info> {
     pub fee_tier: AccountLoader<'info, FeeTier>,
     #[account(seeds = [b"statev1".as_ref()], bump = state.load()?.bump)]
     pub state: AccountLoader<'info, State>,
-    #[account(mut, constraint = &state.load()?.admin == admin.key @ InvalidAdmin)]
+    #[account(mut, constraint = state.load()?.has_role(admin.key, Role::FeeTierManager) @ InvalidAdmin)]
     pub admin: Signer<'info>,
     pub rent: Sysvar<'info, Rent>,
     #[account(address = system_program::ID)]
@@ -35,6 +36,12 @@ impl<'info> CreateFeeTier<'info> {
             fee,
             tick_spacing,
             bump,
+            disabled: false,
+            min_fee: fee,
+            max_fee: fee,
+            protocol_fee_override: FixedPoint::new(0),
+            protocol_fee_overridden: false,
+            fee_receiver_override: Pubkey::default(),
         };
 
         Ok(())
@@ -5,6 +5,7 @@ use crate::math::calculate_price_sqrt;
 use crate::structs::fee_tier::FeeTier;
 use crate::structs::pool::Pool;
 use crate::structs::tickmap::Tickmap;
+use crate::structs::CreatePoolEvent;
 use crate::structs::State;
 use crate::util::check_tick;
 use crate::util::get_current_timestamp;
@@ -28,7 +29,8 @@ pub struct CreatePool<'info> {
     pub pool: AccountLoader<'info, Pool>,
     #[account(
         seeds = [b"feetierv1", __program_id.as_ref(), &fee_tier.load()?.fee.v.to_le_bytes(), &fee_tier.load()?.tick_spacing.to_le_bytes()],
-        bump = fee_tier.load()?.bump
+        bump = fee_tier.load()?.bump,
+        constraint = !fee_tier.load()?.disabled @ ErrorCode::FeeTierDisabled
     )]
     pub fee_tier: AccountLoader<'info, FeeTier>,
     #[account(zero)]
@@ -77,6 +79,7 @@ impl<'info> CreatePool<'info> {
 
         let pool = &mut self.pool.load_init()?;
         let fee_tier = self.fee_tier.load()?;
+        let state = self.state.load()?;
         let current_timestamp = get_current_timestamp();
 
         check_tick(init_tick, fee_tier.tick_spacing)?;
@@ -88,7 +91,7 @@ impl<'info> CreatePool<'info> {
             token_y_reserve: Pubkey::default(),
             tick_spacing: fee_tier.tick_spacing,
             fee: fee_tier.fee,
-            protocol_fee: FixedPoint::from_scale(1, 2),
+            protocol_fee: state.pool_protocol_fee(&fee_tier),
             liquidity: Liquidity::new(0),
             sqrt_price: calculate_price_sqrt(init_tick),
             current_tick_index: init_tick,
@@ -101,12 +104,36 @@ impl<'info> CreatePool<'info> {
             seconds_per_liquidity_global: SecondsPerLiquidity::new(0),
             start_timestamp: current_timestamp,
             last_timestamp: current_timestamp,
-            fee_receiver: self.state.load()?.admin,
+            fee_receiver: state.pool_fee_receiver(&fee_tier),
             oracle_address: Pubkey::default(),
             oracle_initialized: false,
+            dynamic_fee_enabled: false,
+            volatility_fee_per_tick: FixedPoint::new(0),
+            max_volatility_fee: FixedPoint::new(0),
+            volatility_half_life: 0,
+            volatility_accumulator: 0,
+            volatility_last_update: 0,
             bump,
+            paused: 0,
+            fee_override: FixedPoint::new(0),
+            fee_overridden: false,
+            open_range_orders: 0,
         };
 
+        emit!(CreatePoolEvent {
+            pool: self.pool.key(),
+            token_x: pool.token_x,
+            token_y: pool.token_y,
+            fee: pool.fee,
+            tick_spacing: pool.tick_spacing,
+            protocol_fee: pool.protocol_fee,
+            fee_receiver: pool.fee_receiver,
+            sqrt_price: pool.sqrt_price,
+            current_tick: pool.current_tick_index,
+            tickmap: pool.tickmap,
+            current_timestamp,
+        });
+
         Ok(())
     }
 
@@ -130,6 +157,7 @@ impl<'info> CreatePool<'info> {
 
         let pool = &mut self.pool.load_init()?;
         let fee_tier = self.fee_tier.load()?;
+        let state = self.state.load()?;
         let current_timestamp = get_current_timestamp();
 
         let max_tick = get_max_tick(fee_tier.tick_spacing);
@@ -151,7 +179,7 @@ impl<'info> CreatePool<'info> {
             token_y_reserve: Pubkey::default(),
             tick_spacing: fee_tier.tick_spacing,
             fee: fee_tier.fee,
-            protocol_fee: FixedPoint::from_scale(1, 2),
+            protocol_fee: state.pool_protocol_fee(&fee_tier),
             liquidity: Liquidity::new(0),
             sqrt_price: init_sqrt_price,
             current_tick_index: init_tick,
@@ -164,12 +192,36 @@ impl<'info> CreatePool<'info> {
             seconds_per_liquidity_global: SecondsPerLiquidity::new(0),
             start_timestamp: current_timestamp,
             last_timestamp: current_timestamp,
-            fee_receiver: self.state.load()?.admin,
+            fee_receiver: state.pool_fee_receiver(&fee_tier),
             oracle_address: Pubkey::default(),
             oracle_initialized: false,
+            dynamic_fee_enabled: false,
+            volatility_fee_per_tick: FixedPoint::new(0),
+            max_volatility_fee: FixedPoint::new(0),
+            volatility_half_life: 0,
+            volatility_accumulator: 0,
+            volatility_last_update: 0,
             bump,
+            paused: 0,
+            fee_override: FixedPoint::new(0),
+            fee_overridden: false,
+            open_range_orders: 0,
         };
 
+        emit!(CreatePoolEvent {
+            pool: self.pool.key(),
+            token_x: pool.token_x,
+            token_y: pool.token_y,
+            fee: pool.fee,
+            tick_spacing: pool.tick_spacing,
+            protocol_fee: pool.protocol_fee,
+            fee_receiver: pool.fee_receiver,
+            sqrt_price: pool.sqrt_price,
+            current_tick: pool.current_tick_index,
+            tickmap: pool.tickmap,
+            current_timestamp,
+        });
+
         Ok(())
     }
 }
@@ -1,10 +1,13 @@
 use crate::interfaces::take_tokens::TakeTokens;
+use crate::math::get_max_liquidity;
 use crate::structs::pool::Pool;
 use crate::structs::position::Position;
 use crate::structs::position_list::PositionList;
-use crate::structs::tick::Tick;
+use crate::structs::state::PAUSE_ADD_LIQUIDITY;
 use crate::structs::Tickmap;
-use crate::util::check_ticks;
+use crate::util::{
+    check_not_paused, check_ticks, init_tick_if_needed, load_tick_mut, open_position,
+};
 use crate::ErrorCode::{self, *};
 use crate::*;
 use anchor_lang::prelude::*;
@@ -14,7 +17,6 @@ use anchor_spl::token_2022;
 use anchor_spl::token_interface::TokenInterface;
 use anchor_spl::token_interface::{Mint, TokenAccount};
 use decimals::*;
-use structs::CreatePositionEvent;
 
 #[derive(Accounts)]
 #[instruction( lower_tick_index: i32, upper_tick_index: i32)]
@@ -43,14 +45,16 @@ pub struct CreatePosition<'info> {
     pub owner: Signer<'info>,
     #[account(mut,
         seeds = [b"tickv1", pool.key().as_ref(), &lower_tick_index.to_le_bytes()],
-        bump = lower_tick.load()?.bump
+        bump
     )]
-    pub lower_tick: AccountLoader<'info, Tick>,
+    /// CHECK: Created in `init_ticks` when missing
+    pub lower_tick: AccountInfo<'info>,
     #[account(mut,
         seeds = [b"tickv1", pool.key().as_ref(), &upper_tick_index.to_le_bytes()],
-        bump = upper_tick.load()?.bump
+        bump
     )]
-    pub upper_tick: AccountLoader<'info, Tick>,
+    /// CHECK: Created in `init_ticks` when missing
+    pub upper_tick: AccountInfo<'info>,
     #[account(mut,
         constraint = tickmap.key() == pool.load()?.tickmap @ InvalidTickmap,
         constraint = tickmap.to_account_info().owner == __program_id @ InvalidTickmapOwner,
@@ -152,70 +156,138 @@ impl<'info> TakeTokens<'info> for CreatePosition<'info> {
 }
 
 impl<'info> CreatePosition<'info> {
+    // Missing ticks are initialized inline, so no separate `create_tick` is needed
+    pub fn init_ticks(
+        &self,
+        lower_tick_index: i32,
+        upper_tick_index: i32,
+        lower_tick_bump: u8,
+        upper_tick_bump: u8,
+    ) -> Result<()> {
+        let pool = self.pool.load()?;
+        let pool_address = self.pool.key();
+        let current_timestamp = get_current_timestamp();
+
+        for (tick, index, bump) in [
+            (&self.lower_tick, lower_tick_index, lower_tick_bump),
+            (&self.upper_tick, upper_tick_index, upper_tick_bump),
+        ] {
+            init_tick_if_needed(
+                tick,
+                &pool_address,
+                &pool,
+                index,
+                bump,
+                &self.payer.to_account_info(),
+                &self.system_program,
+                current_timestamp,
+            )?;
+        }
+
+        Ok(())
+    }
+
     pub fn handler(
         &self,
         liquidity_delta: Liquidity,
         slippage_limit_lower: Price,
         slippage_limit_upper: Price,
         bump: u8,
+    ) -> Result<()> {
+        self.open_position(
+            liquidity_delta,
+            slippage_limit_lower,
+            slippage_limit_upper,
+            None,
+            bump,
+        )
+    }
+
+    // Liquidity is derived on-chain from the amounts user is willing to deposit
+    pub fn handler_by_amounts(
+        &self,
+        max_amount_x: TokenAmount,
+        max_amount_y: TokenAmount,
+        min_liquidity: Liquidity,
+        slippage_limit_lower: Price,
+        slippage_limit_upper: Price,
+        bump: u8,
+    ) -> Result<()> {
+        let liquidity_delta = {
+            let pool = self.pool.load()?;
+            let lower_tick = load_tick_mut(&self.lower_tick)?;
+            let upper_tick = load_tick_mut(&self.upper_tick)?;
+            check_ticks(lower_tick.index, upper_tick.index, pool.tick_spacing)?;
+
+            get_max_liquidity(
+                max_amount_x,
+                max_amount_y,
+                lower_tick.index,
+                upper_tick.index,
+                pool.sqrt_price,
+                true,
+            )
+            .l
+        };
+        require!(
+            !liquidity_delta.is_zero() && liquidity_delta >= min_liquidity,
+            ErrorCode::LiquidityBelowMinimum
+        );
+
+        self.open_position(
+            liquidity_delta,
+            slippage_limit_lower,
+            slippage_limit_upper,
+            Some((max_amount_x, max_amount_y)),
+            bump,
+        )
+    }
+
+    fn open_position(
+        &self,
+        liquidity_delta: Liquidity,
+        slippage_limit_lower: Price,
+        slippage_limit_upper: Price,
+        amount_limits: Option<(TokenAmount, TokenAmount)>,
+        bump: u8,
     ) -> Result<()> {
         msg!("INVARIANT: CREATE POSITION");
+        check_not_paused(
+            &*self.state.load()?,
+            &*self.pool.load()?,
+            PAUSE_ADD_LIQUIDITY,
+        )?;
 
         let mut position = self.position.load_init()?;
-        let mut pool = &mut self.pool.load_mut()?;
-        let lower_tick = &mut self.lower_tick.load_mut()?;
-        let upper_tick = &mut self.upper_tick.load_mut()?;
+        let pool = &mut self.pool.load_mut()?;
+        let lower_tick = &mut load_tick_mut(&self.lower_tick)?;
+        let upper_tick = &mut load_tick_mut(&self.upper_tick)?;
         let mut position_list = self.position_list.load_mut()?;
-        let current_timestamp = get_current_timestamp();
         let mut tickmap = self.tickmap.load_mut()?;
-        let slot = get_current_slot();
-
-        // validate price
-        let price = pool.sqrt_price;
-        require!(price >= slippage_limit_lower, ErrorCode::PriceLimitReached);
-        require!(price <= slippage_limit_upper, ErrorCode::PriceLimitReached);
-
-        // validate ticks
-        check_ticks(lower_tick.index, upper_tick.index, pool.tick_spacing)?;
-
-        if !tickmap.get(lower_tick.index, pool.tick_spacing) {
-            tickmap.flip(true, lower_tick.index, pool.tick_spacing)
-        }
-        if !tickmap.get(upper_tick.index, pool.tick_spacing) {
-            tickmap.flip(true, upper_tick.index, pool.tick_spacing)
-        }
 
         // update position_list head
         position_list.head = position_list.head.checked_add(1).unwrap();
-        position.initialized_id(&mut pool);
-
-        // init position
-        *position = Position {
-            owner: *self.owner.to_account_info().key,
-            pool: *self.pool.to_account_info().key,
-            id: position.id,
-            liquidity: Liquidity::new(0),
-            lower_tick_index: lower_tick.index,
-            upper_tick_index: upper_tick.index,
-            fee_growth_inside_x: FeeGrowth::new(0),
-            fee_growth_inside_y: FeeGrowth::new(0),
-            seconds_per_liquidity_inside: SecondsPerLiquidity::new(0),
-            last_slot: slot,
-            tokens_owed_x: FixedPoint::new(0),
-            tokens_owed_y: FixedPoint::new(0),
-            bump,
-        };
 
-        let (amount_x, amount_y) = position.modify(
+        let (amount_x, amount_y) = open_position(
+            &mut position,
             pool,
-            upper_tick,
             lower_tick,
+            upper_tick,
+            &mut tickmap,
+            *self.owner.to_account_info().key,
+            *self.pool.to_account_info().key,
             liquidity_delta,
-            true,
-            current_timestamp,
+            slippage_limit_lower,
+            slippage_limit_upper,
+            bump,
         )?;
-        assert!(position.tokens_owed_x.is_zero());
-        assert!(position.tokens_owed_y.is_zero());
+
+        if let Some((max_amount_x, max_amount_y)) = amount_limits {
+            require!(
+                amount_x <= max_amount_x && amount_y <= max_amount_y,
+                ErrorCode::AmountInAboveMaximum
+            );
+        }
 
         match self.token_x_program.key() {
             token_2022::ID => {
@@ -232,22 +304,6 @@ impl<'info> CreatePosition<'info> {
             _ => return Err(ErrorCode::InvalidTokenProgram.into()),
         };
 
-        emit!(CreatePositionEvent {
-            owner: *self.owner.to_account_info().key,
-            pool: *self.pool.to_account_info().key,
-            id: position.id,
-            lower_tick: lower_tick.index,
-            upper_tick: upper_tick.index,
-            liquidity: liquidity_delta,
-            current_timestamp,
-            seconds_per_liquidity_inside_initial: calculate_seconds_per_liquidity_inside(
-                **lower_tick,
-                **upper_tick,
-                pool,
-                current_timestamp
-            )
-        });
-
         Ok(())
     }
 }
@@ -0,0 +1,54 @@
+use crate::decimals::*;
+use crate::structs::referrer::Referrer;
+use crate::structs::ChangeReferrerEvent;
+use crate::ErrorCode::{self, *};
+use crate::*;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::system_program;
+
+#[derive(Accounts)]
+pub struct CreateReferrer<'info> {
+    #[account(init,
+        seeds = [b"referrerv1", owner.key().as_ref()],
+        bump, payer = admin, space = Referrer::LEN
+    )]
+    pub referrer: AccountLoader<'info, Referrer>,
+    #[account(seeds = [b"statev1".as_ref()], bump = state.load()?.bump)]
+    pub state: AccountLoader<'info, State>,
+    #[account(mut, constraint = &state.load()?.admin == admin.key @ InvalidAdmin)]
+    pub admin: Signer<'info>,
+    /// CHECK: Ignore
+    pub owner: AccountInfo<'info>,
+    pub rent: Sysvar<'info, Rent>,
+    #[account(address = system_program::ID)]
+    /// CHECK: Ignore
+    pub system_program: AccountInfo<'info>,
+}
+
+impl<'info> CreateReferrer<'info> {
+    pub fn handler(&self, fee_share: FixedPoint, bump: u8) -> Result<()> {
+        msg!("INVARIANT: CREATE REFERRER");
+
+        require!(
+            fee_share <= FixedPoint::from_integer(1),
+            ErrorCode::InvalidReferralFee
+        );
+        let referrer = &mut self.referrer.load_init()?;
+
+        **referrer = Referrer {
+            owner: self.owner.key(),
+            fee_share,
+            enabled: true,
+            bump,
+        };
+
+        emit!(ChangeReferrerEvent {
+            referrer: self.referrer.key(),
+            owner: self.owner.key(),
+            fee_share,
+            enabled: true,
+        });
+
+        Ok(())
+    }
+}
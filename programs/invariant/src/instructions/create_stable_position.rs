@@ -0,0 +1,257 @@
+use crate::interfaces::take_tokens::TakeTokens;
+use crate::structs::pool::Pool;
+use crate::structs::position::Position;
+use crate::structs::position_list::{PositionEntry, PositionList};
+use crate::structs::state::PAUSE_ADD_LIQUIDITY;
+use crate::structs::Tickmap;
+use crate::util::{check_not_paused, init_tick_if_needed, load_tick_mut, open_position};
+use crate::ErrorCode::{self, *};
+use crate::*;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::system_program;
+use anchor_spl::token;
+use anchor_spl::token_2022;
+use anchor_spl::token_interface::TokenInterface;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+use decimals::*;
+
+#[derive(Accounts)]
+#[instruction( lower_tick_index: i32, upper_tick_index: i32)]
+pub struct CreateStablePosition<'info> {
+    #[account(seeds = [b"statev1".as_ref()], bump = state.load()?.bump)]
+    pub state: AccountLoader<'info, State>,
+    #[account(init,
+        seeds = [b"positionv2",
+        pool.key().as_ref(),
+        &pool.load()?.position_iterator.to_le_bytes()],
+        bump, payer = payer, space = Position::LEN
+    )]
+    pub position: AccountLoader<'info, Position>,
+    #[account(mut,
+        seeds = [b"stablepositionlistv1", owner.key.as_ref()],
+        bump = position_list.load()?.bump
+    )]
+    pub position_list: AccountLoader<'info, PositionList>,
+    #[account(init,
+        seeds = [b"positionentryv1",
+        owner.key.as_ref(),
+        &position_list.load()?.head.to_le_bytes()],
+        bump, payer = payer, space = PositionEntry::LEN
+    )]
+    pub position_entry: AccountLoader<'info, PositionEntry>,
+    #[account(mut,
+        seeds = [b"poolv1", token_x.key().as_ref(), token_y.key().as_ref(), &pool.load()?.fee.v.to_le_bytes(), &pool.load()?.tick_spacing.to_le_bytes()],
+        bump = pool.load()?.bump
+    )]
+    pub pool: AccountLoader<'info, Pool>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub owner: Signer<'info>,
+    #[account(mut,
+        seeds = [b"tickv1", pool.key().as_ref(), &lower_tick_index.to_le_bytes()],
+        bump
+    )]
+    /// CHECK: Created in `init_ticks` when missing
+    pub lower_tick: AccountInfo<'info>,
+    #[account(mut,
+        seeds = [b"tickv1", pool.key().as_ref(), &upper_tick_index.to_le_bytes()],
+        bump
+    )]
+    /// CHECK: Created in `init_ticks` when missing
+    pub upper_tick: AccountInfo<'info>,
+    #[account(mut,
+        constraint = tickmap.key() == pool.load()?.tickmap @ InvalidTickmap,
+        constraint = tickmap.to_account_info().owner == __program_id @ InvalidTickmapOwner,
+    )]
+    pub tickmap: AccountLoader<'info, Tickmap>,
+    #[account(constraint = token_x.key() == pool.load()?.token_x @ InvalidTokenAccount, mint::token_program = token_x_program)]
+    pub token_x: InterfaceAccount<'info, Mint>,
+    #[account(constraint = token_y.key() == pool.load()?.token_y @ InvalidTokenAccount, mint::token_program = token_y_program)]
+    pub token_y: InterfaceAccount<'info, Mint>,
+    #[account(mut,
+        constraint = account_x.mint == token_x.key() @ InvalidMint,
+        constraint = &account_x.owner == owner.key @ InvalidOwner,
+        token::token_program = token_x_program,
+    )]
+    pub account_x: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut,
+        constraint = account_y.mint == token_y.key() @ InvalidMint,
+        constraint = &account_y.owner == owner.key @ InvalidOwner,
+        token::token_program = token_y_program,
+    )]
+    pub account_y: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut,
+        constraint = reserve_x.mint == token_x.key() @ InvalidMint,
+        constraint = &reserve_x.owner == program_authority.key @ InvalidOwner,
+        constraint = reserve_x.key() == pool.load()?.token_x_reserve @ InvalidTokenAccount,
+        token::token_program = token_x_program,
+    )]
+    pub reserve_x: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut,
+        constraint = reserve_y.mint == token_y.key() @ InvalidMint,
+        constraint = &reserve_y.owner == program_authority.key @ InvalidOwner,
+        constraint = reserve_y.key() == pool.load()?.token_y_reserve @ InvalidTokenAccount,
+        token::token_program = token_y_program,
+    )]
+    pub reserve_y: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(constraint = &state.load()?.authority == program_authority.key @ InvalidAuthority)]
+    /// CHECK: ignore
+    pub program_authority: AccountInfo<'info>,
+
+    #[account(constraint = token_x_program.key() == token::ID || token_x_program.key() == token_2022::ID)]
+    pub token_x_program: Interface<'info, TokenInterface>,
+    #[account(constraint = token_y_program.key() == token::ID || token_y_program.key() == token_2022::ID)]
+    pub token_y_program: Interface<'info, TokenInterface>,
+    pub rent: Sysvar<'info, Rent>,
+    #[account(address = system_program::ID)]
+    /// CHECK: ignore
+    pub system_program: AccountInfo<'info>,
+    /// CHECK: Accounts used for RPC calls optimization
+    #[account(address = Pubkey::find_program_address(&[b"eventoptaccv1", pool.key().as_ref()], __program_id).0)]
+    pub event_opt_acc: AccountInfo<'info>,
+}
+
+impl<'info> TakeTokens<'info> for CreateStablePosition<'info> {
+    fn take_x(&self) -> CpiContext<'_, '_, '_, 'info, token::Transfer<'info>> {
+        CpiContext::new(
+            self.token_x_program.to_account_info(),
+            token::Transfer {
+                from: self.account_x.to_account_info(),
+                to: self.reserve_x.to_account_info(),
+                authority: self.owner.to_account_info().clone(),
+            },
+        )
+    }
+
+    fn take_y(&self) -> CpiContext<'_, '_, '_, 'info, token::Transfer<'info>> {
+        CpiContext::new(
+            self.token_y_program.to_account_info(),
+            token::Transfer {
+                from: self.account_y.to_account_info(),
+                to: self.reserve_y.to_account_info(),
+                authority: self.owner.to_account_info().clone(),
+            },
+        )
+    }
+
+    fn take_x_2022(&self) -> CpiContext<'_, '_, '_, 'info, token_2022::TransferChecked<'info>> {
+        CpiContext::new(
+            self.token_x_program.to_account_info(),
+            token_2022::TransferChecked {
+                mint: self.token_x.to_account_info(),
+                from: self.account_x.to_account_info(),
+                to: self.reserve_x.to_account_info(),
+                authority: self.owner.to_account_info().clone(),
+            },
+        )
+    }
+
+    fn take_y_2022(&self) -> CpiContext<'_, '_, '_, 'info, token_2022::TransferChecked<'info>> {
+        CpiContext::new(
+            self.token_y_program.to_account_info(),
+            token_2022::TransferChecked {
+                mint: self.token_y.to_account_info(),
+                from: self.account_y.to_account_info(),
+                to: self.reserve_y.to_account_info(),
+                authority: self.owner.to_account_info().clone(),
+            },
+        )
+    }
+}
+
+impl<'info> CreateStablePosition<'info> {
+    // Missing ticks are initialized inline, so no separate `create_tick` is needed
+    pub fn init_ticks(
+        &self,
+        lower_tick_index: i32,
+        upper_tick_index: i32,
+        lower_tick_bump: u8,
+        upper_tick_bump: u8,
+    ) -> Result<()> {
+        let pool = self.pool.load()?;
+        let pool_address = self.pool.key();
+        let current_timestamp = get_current_timestamp();
+
+        for (tick, index, bump) in [
+            (&self.lower_tick, lower_tick_index, lower_tick_bump),
+            (&self.upper_tick, upper_tick_index, upper_tick_bump),
+        ] {
+            init_tick_if_needed(
+                tick,
+                &pool_address,
+                &pool,
+                index,
+                bump,
+                &self.payer.to_account_info(),
+                &self.system_program,
+                current_timestamp,
+            )?;
+        }
+
+        Ok(())
+    }
+
+    pub fn handler(
+        &self,
+        liquidity_delta: Liquidity,
+        slippage_limit_lower: Price,
+        slippage_limit_upper: Price,
+        bump: u8,
+        entry_bump: u8,
+    ) -> Result<()> {
+        msg!("INVARIANT: CREATE STABLE POSITION");
+        check_not_paused(
+            &*self.state.load()?,
+            &*self.pool.load()?,
+            PAUSE_ADD_LIQUIDITY,
+        )?;
+
+        let mut position = self.position.load_init()?;
+        let pool = &mut self.pool.load_mut()?;
+        let lower_tick = &mut load_tick_mut(&self.lower_tick)?;
+        let upper_tick = &mut load_tick_mut(&self.upper_tick)?;
+        let mut position_list = self.position_list.load_mut()?;
+        let mut position_entry = self.position_entry.load_init()?;
+        let mut tickmap = self.tickmap.load_mut()?;
+
+        // address is derived from the id, so it stays the same for the whole position lifetime
+        let (amount_x, amount_y) = open_position(
+            &mut position,
+            pool,
+            lower_tick,
+            upper_tick,
+            &mut tickmap,
+            *self.owner.to_account_info().key,
+            *self.pool.to_account_info().key,
+            liquidity_delta,
+            slippage_limit_lower,
+            slippage_limit_upper,
+            bump,
+        )?;
+
+        // list only enumerates positions of the owner
+        position_list.head = position_list.head.checked_add(1).unwrap();
+        *position_entry = PositionEntry {
+            pool: position.pool,
+            id: position.id,
+            bump: entry_bump,
+        };
+
+        match self.token_x_program.key() {
+            token_2022::ID => {
+                token_2022::transfer_checked(self.take_x_2022(), amount_x.0, self.token_x.decimals)?
+            }
+            token::ID => token::transfer(self.take_x(), amount_x.0)?,
+            _ => return Err(ErrorCode::InvalidTokenProgram.into()),
+        };
+        match self.token_y_program.key() {
+            token_2022::ID => {
+                token_2022::transfer_checked(self.take_y_2022(), amount_y.0, self.token_y.decimals)?
+            }
+            token::ID => token::transfer(self.take_y(), amount_y.0)?,
+            _ => return Err(ErrorCode::InvalidTokenProgram.into()),
+        };
+
+        Ok(())
+    }
+}
@@ -1,4 +1,5 @@
-use crate::structs::state::State;
+use crate::decimals::*;
+use crate::structs::state::{treasury_address, State};
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::system_program;
 
@@ -27,6 +28,13 @@ pub fn handler(ctx: Context<CreateState>, nonce: u8) -> Result<()> {
         authority: *ctx.accounts.program_authority.key,
         nonce,
         bump: ctx.bumps.state,
+        pending_admin: Pubkey::default(),
+        fee_tier_manager: *ctx.accounts.admin.key,
+        pauser: *ctx.accounts.admin.key,
+        protocol_fee_manager: *ctx.accounts.admin.key,
+        paused: 0,
+        default_protocol_fee: FixedPoint::from_scale(1, 2),
+        default_fee_receiver: treasury_address(),
     };
     Ok(())
 }
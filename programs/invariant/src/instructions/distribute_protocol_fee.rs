@@ -0,0 +1,140 @@
+use crate::structs::fee_split::FeeSplit;
+use crate::structs::pool::Pool;
+use crate::structs::state::State;
+use crate::structs::DistributeProtocolFeeEvent;
+use crate::util::transfer_tokens;
+use crate::ErrorCode::{self, *};
+use crate::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022;
+use anchor_spl::token_interface::TokenInterface;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+
+#[derive(Accounts)]
+pub struct DistributeProtocolFee<'info> {
+    #[account(seeds = [b"statev1".as_ref()], bump = state.load()?.bump)]
+    pub state: AccountLoader<'info, State>,
+    #[account(mut,
+        seeds = [b"poolv1", token_x.key().as_ref(), token_y.key().as_ref(), &pool.load()?.fee.v.to_le_bytes(), &pool.load()?.tick_spacing.to_le_bytes()],
+        bump = pool.load()?.bump
+    )]
+    pub pool: AccountLoader<'info, Pool>,
+    #[account(seeds = [b"feesplitv1"], bump = fee_split.load()?.bump)]
+    pub fee_split: AccountLoader<'info, FeeSplit>,
+    #[account(constraint = token_x.key() == pool.load()?.token_x @ InvalidTokenAccount, mint::token_program = token_x_program)]
+    pub token_x: InterfaceAccount<'info, Mint>,
+    #[account(constraint = token_y.key() == pool.load()?.token_y @ InvalidTokenAccount, mint::token_program = token_y_program)]
+    pub token_y: InterfaceAccount<'info, Mint>,
+    #[account(mut,
+        constraint = reserve_x.mint == token_x.key() @ InvalidMint,
+        constraint = &reserve_x.owner == program_authority.key @ InvalidAuthority,
+        constraint = reserve_x.key() == pool.load()?.token_x_reserve @ InvalidTokenAccount,
+        token::token_program = token_x_program
+    )]
+    pub reserve_x: InterfaceAccount<'info, TokenAccount>,
+    #[account(mut,
+        constraint = reserve_y.mint == token_y.key() @ InvalidMint,
+        constraint = &reserve_y.owner == program_authority.key @ InvalidAuthority,
+        constraint = reserve_y.key() == pool.load()?.token_y_reserve @ InvalidTokenAccount,
+        token::token_program = token_y_program,
+    )]
+    pub reserve_y: InterfaceAccount<'info, TokenAccount>,
+    #[account(constraint = state.load()?.is_fee_receiver(&*pool.load()?, authority.key) @ InvalidAuthority)]
+    pub authority: Signer<'info>,
+    #[account(constraint = &state.load()?.authority == program_authority.key @ InvalidAuthority)]
+    /// CHECK: Ignore
+    pub program_authority: AccountInfo<'info>,
+
+    #[account(constraint = token_x_program.key() == token::ID || token_x_program.key() == token_2022::ID)]
+    pub token_x_program: Interface<'info, TokenInterface>,
+    #[account(constraint = token_y_program.key() == token::ID || token_y_program.key() == token_2022::ID)]
+    pub token_y_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> DistributeProtocolFee<'info> {
+    fn send(
+        &self,
+        in_x: bool,
+        to: &InterfaceAccount<'info, TokenAccount>,
+        amount: u64,
+        signer: &[&[&[u8]]],
+    ) -> Result<()> {
+        let (token_program, mint, from) = match in_x {
+            true => (&self.token_x_program, &self.token_x, &self.reserve_x),
+            false => (&self.token_y_program, &self.token_y, &self.reserve_y),
+        };
+
+        transfer_tokens(
+            token_program.to_account_info(),
+            mint,
+            from.to_account_info(),
+            to.to_account_info(),
+            self.program_authority.clone(),
+            amount,
+            signer,
+        )
+    }
+
+    // Remaining accounts are (account x, account y) pairs of every fee split recipient in order
+    pub fn handler(
+        ctx: Context<'_, '_, 'info, 'info, DistributeProtocolFee<'info>>,
+        amount_x: u64,
+        amount_y: u64,
+    ) -> Result<()> {
+        msg!("INVARIANT: DISTRIBUTE PROTOCOL FEE");
+
+        let accounts = &ctx.accounts;
+        let state = accounts.state.load()?;
+        let mut pool = accounts.pool.load_mut()?;
+        let fee_split = accounts.fee_split.load()?;
+        let signer: &[&[&[u8]]] = get_signer!(state.nonce);
+
+        require!(
+            amount_x <= pool.fee_protocol_token_x && amount_y <= pool.fee_protocol_token_y,
+            ErrorCode::InsufficientProtocolFee
+        );
+        require!(
+            ctx.remaining_accounts.len() == 2 * fee_split.count as usize,
+            ErrorCode::InvalidRemainingAccounts
+        );
+
+        let recipients = fee_split.recipients;
+        let shares = fee_split.shares;
+        let parts_x = fee_split.split(amount_x);
+        let parts_y = fee_split.split(amount_y);
+
+        for (i, pair) in ctx.remaining_accounts.chunks_exact(2).enumerate() {
+            let account_x = InterfaceAccount::<'info, TokenAccount>::try_from(&pair[0])?;
+            let account_y = InterfaceAccount::<'info, TokenAccount>::try_from(&pair[1])?;
+            require!(
+                account_x.mint == accounts.token_x.key()
+                    && account_y.mint == accounts.token_y.key(),
+                ErrorCode::InvalidMint
+            );
+            require!(
+                account_x.owner == recipients[i] && account_y.owner == recipients[i],
+                ErrorCode::InvalidOwner
+            );
+
+            if parts_x[i] != 0 {
+                accounts.send(true, &account_x, parts_x[i], signer)?;
+            }
+            if parts_y[i] != 0 {
+                accounts.send(false, &account_y, parts_y[i], signer)?;
+            }
+
+            emit!(DistributeProtocolFeeEvent {
+                pool: accounts.pool.key(),
+                recipient: recipients[i],
+                share: shares[i],
+                amount_x: parts_x[i],
+                amount_y: parts_y[i],
+            });
+        }
+
+        pool.fee_protocol_token_x = pool.fee_protocol_token_x.checked_sub(amount_x).unwrap();
+        pool.fee_protocol_token_y = pool.fee_protocol_token_y.checked_sub(amount_y).unwrap();
+
+        Ok(())
+    }
+}
@@ -0,0 +1,209 @@
+use crate::interfaces::send_tokens::SendTokens;
+use crate::structs::pool::Pool;
+use crate::structs::state::PAUSE_SWAP;
+use crate::structs::FlashLoanEvent;
+use crate::util::{check_not_paused, invoke_callback};
+use crate::ErrorCode::{self, *};
+use crate::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+use anchor_spl::token_2022;
+use anchor_spl::token_interface::TokenInterface;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+
+// Arguments of `flash_loan_callback` instruction invoked on the callback program
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct FlashLoanCallbackArgs {
+    pub amount_x: u64,
+    pub amount_y: u64,
+    pub fee_x: u64, // has to be transferred to the reserve on top of borrowed amount
+    pub fee_y: u64,
+    pub data: Vec<u8>,
+}
+
+#[derive(Accounts)]
+pub struct FlashLoan<'info> {
+    #[account(seeds = [b"statev1".as_ref()], bump = state.load()?.bump)]
+    pub state: AccountLoader<'info, State>,
+    #[account(mut,
+        seeds = [b"poolv1", token_x.key().as_ref(), token_y.key().as_ref(), &pool.load()?.fee.v.to_le_bytes(), &pool.load()?.tick_spacing.to_le_bytes()],
+        bump = pool.load()?.bump
+    )]
+    pub pool: AccountLoader<'info, Pool>,
+    #[account(constraint = token_x.key() == pool.load()?.token_x @ InvalidTokenAccount, mint::token_program = token_x_program)]
+    pub token_x: Box<InterfaceAccount<'info, Mint>>,
+    #[account(constraint = token_y.key() == pool.load()?.token_y @ InvalidTokenAccount, mint::token_program = token_y_program)]
+    pub token_y: Box<InterfaceAccount<'info, Mint>>,
+    #[account(mut,
+        constraint = account_x.mint == token_x.key() @ InvalidMint,
+        token::token_program = token_x_program,
+    )]
+    pub account_x: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut,
+        constraint = account_y.mint == token_y.key() @ InvalidMint,
+        token::token_program = token_y_program
+    )]
+    pub account_y: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut,
+        constraint = reserve_x.mint == token_x.key() @ InvalidMint,
+        constraint = &reserve_x.owner == program_authority.key @ InvalidAuthority,
+        constraint = reserve_x.to_account_info().key == &pool.load()?.token_x_reserve @ InvalidTokenAccount,
+        token::token_program = token_x_program
+    )]
+    pub reserve_x: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut,
+        constraint = reserve_y.mint == token_y.key() @ InvalidMint,
+        constraint = &reserve_y.owner == program_authority.key @ InvalidAuthority,
+        constraint = reserve_y.to_account_info().key == &pool.load()?.token_y_reserve @ InvalidTokenAccount,
+        token::token_program = token_y_program
+    )]
+    pub reserve_y: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub owner: Signer<'info>,
+    #[account(constraint = &state.load()?.authority == program_authority.key @ InvalidAuthority)]
+    /// CHECK: Ignore
+    pub program_authority: AccountInfo<'info>,
+    /// CHECK: Validated before invocation
+    pub callback_program: AccountInfo<'info>,
+
+    #[account(constraint = token_x_program.key() == token::ID || token_x_program.key() == token_2022::ID)]
+    pub token_x_program: Interface<'info, TokenInterface>,
+    #[account(constraint = token_y_program.key() == token::ID || token_y_program.key() == token_2022::ID)]
+    pub token_y_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> SendTokens<'info> for FlashLoan<'info> {
+    fn send_x(&self) -> CpiContext<'_, '_, '_, 'info, token::Transfer<'info>> {
+        CpiContext::new(
+            self.token_x_program.to_account_info(),
+            token::Transfer {
+                from: self.reserve_x.to_account_info(),
+                to: self.account_x.to_account_info(),
+                authority: self.program_authority.clone(),
+            },
+        )
+    }
+
+    fn send_y(&self) -> CpiContext<'_, '_, '_, 'info, token::Transfer<'info>> {
+        CpiContext::new(
+            self.token_y_program.to_account_info(),
+            token::Transfer {
+                from: self.reserve_y.to_account_info(),
+                to: self.account_y.to_account_info(),
+                authority: self.program_authority.clone(),
+            },
+        )
+    }
+
+    fn send_x_2022(&self) -> CpiContext<'_, '_, '_, 'info, token_2022::TransferChecked<'info>> {
+        CpiContext::new(
+            self.token_x_program.to_account_info(),
+            token_2022::TransferChecked {
+                mint: self.token_x.to_account_info(),
+                from: self.reserve_x.to_account_info(),
+                to: self.account_x.to_account_info(),
+                authority: self.program_authority.clone(),
+            },
+        )
+    }
+
+    fn send_y_2022(&self) -> CpiContext<'_, '_, '_, 'info, token_2022::TransferChecked<'info>> {
+        CpiContext::new(
+            self.token_y_program.to_account_info(),
+            token_2022::TransferChecked {
+                mint: self.token_y.to_account_info(),
+                from: self.reserve_y.to_account_info(),
+                to: self.account_y.to_account_info(),
+                authority: self.program_authority.clone(),
+            },
+        )
+    }
+}
+
+impl<'info> FlashLoan<'info> {
+    pub fn handler(
+        ctx: Context<'_, '_, 'info, 'info, FlashLoan<'info>>,
+        amount_x: u64,
+        amount_y: u64,
+        data: Vec<u8>, // passed to the callback untouched
+    ) -> Result<()> {
+        msg!("INVARIANT: FLASH LOAN");
+        require!(amount_x != 0 || amount_y != 0, ErrorCode::ZeroAmount);
+
+        let nonce = ctx.accounts.state.load()?.nonce;
+        let fee = {
+            let pool = ctx.accounts.pool.load()?;
+            check_not_paused(&*ctx.accounts.state.load()?, &pool, PAUSE_SWAP)?;
+            pool.base_fee()
+        };
+        let fee_x = TokenAmount::from_decimal_up(TokenAmount(amount_x).big_mul_up(fee));
+        let fee_y = TokenAmount::from_decimal_up(TokenAmount(amount_y).big_mul_up(fee));
+
+        let reserve_x_before = ctx.accounts.reserve_x.amount;
+        let reserve_y_before = ctx.accounts.reserve_y.amount;
+
+        let signer: &[&[&[u8]]] = get_signer!(nonce);
+        if amount_x != 0 {
+            match ctx.accounts.token_x_program.key() {
+                token_2022::ID => token_2022::transfer_checked(
+                    ctx.accounts.send_x_2022().with_signer(signer),
+                    amount_x,
+                    ctx.accounts.token_x.decimals,
+                )?,
+                token::ID => token::transfer(ctx.accounts.send_x().with_signer(signer), amount_x)?,
+                _ => return Err(ErrorCode::InvalidTokenProgram.into()),
+            };
+        }
+        if amount_y != 0 {
+            match ctx.accounts.token_y_program.key() {
+                token_2022::ID => token_2022::transfer_checked(
+                    ctx.accounts.send_y_2022().with_signer(signer),
+                    amount_y,
+                    ctx.accounts.token_y.decimals,
+                )?,
+                token::ID => token::transfer(ctx.accounts.send_y().with_signer(signer), amount_y)?,
+                _ => return Err(ErrorCode::InvalidTokenProgram.into()),
+            };
+        }
+
+        invoke_callback(
+            &ctx.accounts.callback_program,
+            ctx.remaining_accounts,
+            "flash_loan_callback",
+            FlashLoanCallbackArgs {
+                amount_x,
+                amount_y,
+                fee_x: fee_x.0,
+                fee_y: fee_y.0,
+                data,
+            },
+        )?;
+
+        // Verify repayment against the reserve balances
+        ctx.accounts.reserve_x.reload()?;
+        ctx.accounts.reserve_y.reload()?;
+        require!(
+            ctx.accounts.reserve_x.amount >= reserve_x_before.checked_add(fee_x.0).unwrap(),
+            ErrorCode::InsufficientRepayment
+        );
+        require!(
+            ctx.accounts.reserve_y.amount >= reserve_y_before.checked_add(fee_y.0).unwrap(),
+            ErrorCode::InsufficientRepayment
+        );
+
+        // Distribute fee between liquidity providers and protocol
+        let mut pool = ctx.accounts.pool.load_mut()?;
+        pool.add_flash_loan_fee(fee_x, true);
+        pool.add_flash_loan_fee(fee_y, false);
+
+        emit!(FlashLoanEvent {
+            borrower: *ctx.accounts.owner.key,
+            pool: ctx.accounts.pool.key(),
+            amount_x,
+            amount_y,
+            fee_x: fee_x.0,
+            fee_y: fee_y.0,
+        });
+
+        Ok(())
+    }
+}
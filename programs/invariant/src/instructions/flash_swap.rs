@@ -0,0 +1,273 @@
+use crate::interfaces::send_tokens::SendTokens;
+use crate::structs::pool::Pool;
+use crate::structs::state::PAUSE_SWAP;
+use crate::structs::tickmap::Tickmap;
+use crate::util::{
+    check_not_paused, cross_tick_from_accounts, invoke_callback, process_swap, record_oracle_price,
+    SwapSummary,
+};
+use crate::ErrorCode::{self, *};
+use crate::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+use anchor_spl::token_2022;
+use anchor_spl::token_interface::TokenInterface;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+use structs::SwapEvent;
+
+// Arguments of `flash_swap_callback` instruction invoked on the callback program
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct FlashSwapCallbackArgs {
+    pub x_to_y: bool,
+    pub amount_in: u64, // amount that has to be transferred to the input reserve (fee included)
+    pub amount_out: u64,
+    pub data: Vec<u8>,
+}
+
+#[derive(Accounts)]
+pub struct FlashSwap<'info> {
+    #[account(seeds = [b"statev1".as_ref()], bump = state.load()?.bump)]
+    pub state: AccountLoader<'info, State>,
+    #[account(mut,
+        seeds = [b"poolv1", token_x.key().as_ref(), token_y.key().as_ref(), &pool.load()?.fee.v.to_le_bytes(), &pool.load()?.tick_spacing.to_le_bytes()],
+        bump = pool.load()?.bump
+    )]
+    pub pool: AccountLoader<'info, Pool>,
+    #[account(mut,
+        constraint = tickmap.to_account_info().key == &pool.load()?.tickmap @ InvalidTickmap,
+        constraint = tickmap.to_account_info().owner == __program_id @ InvalidTickmapOwner
+    )]
+    pub tickmap: AccountLoader<'info, Tickmap>,
+    #[account(constraint = token_x.key() == pool.load()?.token_x @ InvalidTokenAccount, mint::token_program = token_x_program)]
+    pub token_x: Box<InterfaceAccount<'info, Mint>>,
+    #[account(constraint = token_y.key() == pool.load()?.token_y @ InvalidTokenAccount, mint::token_program = token_y_program)]
+    pub token_y: Box<InterfaceAccount<'info, Mint>>,
+    #[account(mut,
+        constraint = account_x.mint == token_x.key() @ InvalidMint,
+        token::token_program = token_x_program,
+    )]
+    pub account_x: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut,
+        constraint = account_y.mint == token_y.key() @ InvalidMint,
+        token::token_program = token_y_program
+    )]
+    pub account_y: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut,
+        constraint = reserve_x.mint == token_x.key() @ InvalidMint,
+        constraint = &reserve_x.owner == program_authority.key @ InvalidAuthority,
+        constraint = reserve_x.to_account_info().key == &pool.load()?.token_x_reserve @ InvalidTokenAccount,
+        token::token_program = token_x_program
+    )]
+    pub reserve_x: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut,
+        constraint = reserve_y.mint == token_y.key() @ InvalidMint,
+        constraint = &reserve_y.owner == program_authority.key @ InvalidAuthority,
+        constraint = reserve_y.to_account_info().key == &pool.load()?.token_y_reserve @ InvalidTokenAccount,
+        token::token_program = token_y_program
+    )]
+    pub reserve_y: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub owner: Signer<'info>,
+    #[account(constraint = &state.load()?.authority == program_authority.key @ InvalidAuthority)]
+    /// CHECK: Ignore
+    pub program_authority: AccountInfo<'info>,
+    /// CHECK: Validated before invocation
+    pub callback_program: AccountInfo<'info>,
+
+    #[account(constraint = token_x_program.key() == token::ID || token_x_program.key() == token_2022::ID)]
+    pub token_x_program: Interface<'info, TokenInterface>,
+    #[account(constraint = token_y_program.key() == token::ID || token_y_program.key() == token_2022::ID)]
+    pub token_y_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> SendTokens<'info> for FlashSwap<'info> {
+    fn send_x(&self) -> CpiContext<'_, '_, '_, 'info, token::Transfer<'info>> {
+        CpiContext::new(
+            self.token_x_program.to_account_info(),
+            token::Transfer {
+                from: self.reserve_x.to_account_info(),
+                to: self.account_x.to_account_info(),
+                authority: self.program_authority.clone(),
+            },
+        )
+    }
+
+    fn send_y(&self) -> CpiContext<'_, '_, '_, 'info, token::Transfer<'info>> {
+        CpiContext::new(
+            self.token_y_program.to_account_info(),
+            token::Transfer {
+                from: self.reserve_y.to_account_info(),
+                to: self.account_y.to_account_info(),
+                authority: self.program_authority.clone(),
+            },
+        )
+    }
+
+    fn send_x_2022(&self) -> CpiContext<'_, '_, '_, 'info, token_2022::TransferChecked<'info>> {
+        CpiContext::new(
+            self.token_x_program.to_account_info(),
+            token_2022::TransferChecked {
+                mint: self.token_x.to_account_info(),
+                from: self.reserve_x.to_account_info(),
+                to: self.account_x.to_account_info(),
+                authority: self.program_authority.clone(),
+            },
+        )
+    }
+
+    fn send_y_2022(&self) -> CpiContext<'_, '_, '_, 'info, token_2022::TransferChecked<'info>> {
+        CpiContext::new(
+            self.token_y_program.to_account_info(),
+            token_2022::TransferChecked {
+                mint: self.token_y.to_account_info(),
+                from: self.reserve_y.to_account_info(),
+                to: self.account_y.to_account_info(),
+                authority: self.program_authority.clone(),
+            },
+        )
+    }
+}
+
+impl<'info> FlashSwap<'info> {
+    pub fn handler(
+        ctx: Context<'_, '_, 'info, 'info, FlashSwap<'info>>,
+        x_to_y: bool,
+        amount: u64,
+        by_amount_in: bool, // whether amount specifies input or output
+        sqrt_price_limit: u128,
+        data: Vec<u8>, // passed to the callback untouched
+    ) -> Result<()> {
+        msg!("INVARIANT: FLASH SWAP");
+        require!(amount != 0, ErrorCode::ZeroAmount);
+
+        let sqrt_price_limit = Price::new(sqrt_price_limit);
+        let pool_address = ctx.accounts.pool.key();
+        let nonce = ctx.accounts.state.load()?.nonce;
+
+        // Pool is released before the callback so it can not observe a borrowed account
+        let (total_amount_in, total_amount_out) = {
+            let mut pool = ctx.accounts.pool.load_mut()?;
+            check_not_paused(&*ctx.accounts.state.load()?, &pool, PAUSE_SWAP)?;
+            let tickmap = ctx.accounts.tickmap.load()?;
+            let pool_sqrt_price_before = pool.sqrt_price;
+
+            let SwapSummary {
+                amount_in,
+                amount_out,
+                fee,
+                crossed_ticks,
+                ..
+            } = process_swap(
+                &mut pool,
+                &tickmap,
+                x_to_y,
+                TokenAmount(amount),
+                by_amount_in,
+                sqrt_price_limit,
+                false,
+                FixedPoint::from_integer(0),
+                get_current_timestamp(),
+                |pool, tick_index| {
+                    cross_tick_from_accounts(
+                        ctx.remaining_accounts,
+                        ctx.program_id,
+                        &pool_address,
+                        pool,
+                        tick_index,
+                    )
+                },
+            )?;
+
+            if amount_out.0 == 0 {
+                return Err(ErrorCode::NoGainSwap.into());
+            }
+
+            if pool.oracle_initialized {
+                record_oracle_price(ctx.remaining_accounts, &pool, pool_sqrt_price_before)?;
+            }
+
+            emit!(SwapEvent {
+                swapper: *ctx.accounts.owner.key,
+                pool: pool_address,
+                token_x: ctx.accounts.token_x.key(),
+                token_y: ctx.accounts.token_y.key(),
+                x_to_y,
+                amount_in: amount_in.0,
+                amount_out: amount_out.0,
+                fee: fee.0,
+                referral_fee: 0,
+                price_before_swap: pool_sqrt_price_before,
+                price_after_swap: pool.sqrt_price,
+                liquidity: pool.liquidity,
+                current_tick: pool.current_tick_index,
+                crossed_ticks,
+                remaining_amount: 0,
+            });
+
+            (amount_in, amount_out)
+        };
+
+        // Send output first
+        let signer: &[&[&[u8]]] = get_signer!(nonce);
+        match x_to_y {
+            true => match ctx.accounts.token_y_program.key() {
+                token_2022::ID => token_2022::transfer_checked(
+                    ctx.accounts.send_y_2022().with_signer(signer),
+                    total_amount_out.0,
+                    ctx.accounts.token_y.decimals,
+                )?,
+                token::ID => token::transfer(
+                    ctx.accounts.send_y().with_signer(signer),
+                    total_amount_out.0,
+                )?,
+                _ => return Err(ErrorCode::InvalidTokenProgram.into()),
+            },
+            false => match ctx.accounts.token_x_program.key() {
+                token_2022::ID => token_2022::transfer_checked(
+                    ctx.accounts.send_x_2022().with_signer(signer),
+                    total_amount_out.0,
+                    ctx.accounts.token_x.decimals,
+                )?,
+                token::ID => token::transfer(
+                    ctx.accounts.send_x().with_signer(signer),
+                    total_amount_out.0,
+                )?,
+                _ => return Err(ErrorCode::InvalidTokenProgram.into()),
+            },
+        };
+
+        let reserve_in_before = match x_to_y {
+            true => ctx.accounts.reserve_x.amount,
+            false => ctx.accounts.reserve_y.amount,
+        };
+
+        invoke_callback(
+            &ctx.accounts.callback_program,
+            ctx.remaining_accounts,
+            "flash_swap_callback",
+            FlashSwapCallbackArgs {
+                x_to_y,
+                amount_in: total_amount_in.0,
+                amount_out: total_amount_out.0,
+                data,
+            },
+        )?;
+
+        // Verify repayment (fee included) against the reserve balance
+        let reserve_in_after = match x_to_y {
+            true => {
+                ctx.accounts.reserve_x.reload()?;
+                ctx.accounts.reserve_x.amount
+            }
+            false => {
+                ctx.accounts.reserve_y.reload()?;
+                ctx.accounts.reserve_y.amount
+            }
+        };
+        require!(
+            reserve_in_after >= reserve_in_before.checked_add(total_amount_in.0).unwrap(),
+            ErrorCode::InsufficientRepayment
+        );
+
+        Ok(())
+    }
+}
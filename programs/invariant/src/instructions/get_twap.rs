@@ -0,0 +1,22 @@
+use crate::decimals::*;
+use crate::structs::oracle::Oracle;
+use crate::structs::pool::Pool;
+use crate::util::get_current_timestamp;
+use crate::ErrorCode::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct GetTwap<'info> {
+    pub pool: AccountLoader<'info, Pool>,
+    #[account(constraint = oracle.key() == pool.load()?.oracle_address @ InvalidOracle)]
+    pub oracle: AccountLoader<'info, Oracle>,
+}
+
+impl<'info> GetTwap<'info> {
+    pub fn handler(&self, window: u64) -> Result<Price> {
+        msg!("INVARIANT: GET TWAP");
+
+        let oracle = self.oracle.load()?;
+        oracle.get_twap(window, get_current_timestamp())
+    }
+}
@@ -1,4 +1,5 @@
 use crate::structs::pool::Pool;
+use crate::structs::InitReservesEvent;
 use crate::structs::State;
 use crate::util::get_current_timestamp;
 use crate::ErrorCode::*;
@@ -79,6 +80,12 @@ impl<'info> InitReserves<'info> {
         pool.token_y_reserve = *self.token_y_reserve.to_account_info().key;
         pool.last_timestamp = current_timestamp;
 
+        emit!(InitReservesEvent {
+            pool: self.pool.key(),
+            token_x_reserve: pool.token_x_reserve,
+            token_y_reserve: pool.token_y_reserve,
+        });
+
         Ok(())
     }
 }
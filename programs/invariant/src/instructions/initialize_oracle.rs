@@ -1,6 +1,7 @@
 use crate::errors::ErrorCode;
 use crate::structs::oracle::Oracle;
 use crate::structs::pool::Pool;
+use crate::structs::InitializeOracleEvent;
 use crate::ErrorCode::*;
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::system_program;
@@ -41,6 +42,11 @@ impl<'info> InitializeOracle<'info> {
         pool.set_oracle(self.oracle.key());
         oracle.init();
 
+        emit!(InitializeOracleEvent {
+            pool: self.pool.key(),
+            oracle: self.oracle.key(),
+        });
+
         Ok(())
     }
 }
@@ -0,0 +1,57 @@
+use crate::structs::fee_tier::FeeTier;
+use crate::structs::MigrateFeeTierEvent;
+use crate::ErrorCode;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::system_program;
+
+#[derive(Accounts)]
+pub struct MigrateFeeTier<'info> {
+    // fee tier is not loaded, accounts created before the layout was extended are shorter than `FeeTier`
+    #[account(mut)]
+    pub fee_tier: AccountLoader<'info, FeeTier>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(address = system_program::ID)]
+    /// CHECK: Ignore
+    pub system_program: AccountInfo<'info>,
+}
+
+impl<'info> MigrateFeeTier<'info> {
+    pub fn handler(&self) -> Result<()> {
+        msg!("INVARIANT: MIGRATE FEE TIER");
+        let fee_tier_info = self.fee_tier.to_account_info();
+
+        require!(
+            fee_tier_info.data_len() < FeeTier::LEN,
+            ErrorCode::AlreadyMigrated
+        );
+
+        let lamports = Rent::get()?
+            .minimum_balance(FeeTier::LEN)
+            .saturating_sub(fee_tier_info.lamports());
+        if lamports > 0 {
+            anchor_lang::system_program::transfer(
+                CpiContext::new(
+                    self.system_program.clone(),
+                    anchor_lang::system_program::Transfer {
+                        from: self.payer.to_account_info(),
+                        to: fee_tier_info.clone(),
+                    },
+                ),
+                lamports,
+            )?;
+        }
+        fee_tier_info.realloc(FeeTier::LEN, true)?;
+
+        // existing pools keep their fee until the bounds are widened by `change_fee_tier`
+        let mut fee_tier = self.fee_tier.load_mut()?;
+        fee_tier.min_fee = fee_tier.fee;
+        fee_tier.max_fee = fee_tier.fee;
+
+        emit!(MigrateFeeTierEvent {
+            fee_tier: self.fee_tier.key(),
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,33 @@
+use crate::structs::pool::Pool;
+use crate::structs::MigratePoolEvent;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::system_program;
+
+#[derive(Accounts)]
+pub struct MigratePool<'info> {
+    // pool is not loaded, accounts created before the layout was extended are shorter than `Pool`
+    #[account(mut,
+        realloc = Pool::LEN,
+        realloc::payer = payer,
+        realloc::zero = true
+    )]
+    pub pool: AccountLoader<'info, Pool>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(address = system_program::ID)]
+    /// CHECK: Ignore
+    pub system_program: AccountInfo<'info>,
+}
+
+impl<'info> MigratePool<'info> {
+    pub fn handler(&self) -> Result<()> {
+        msg!("INVARIANT: MIGRATE POOL");
+        // appended fields are zeroed, which leaves dynamic fee, pause and fee override disabled
+
+        emit!(MigratePoolEvent {
+            pool: self.pool.key(),
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,107 @@
+use crate::structs::position::Position;
+use crate::structs::position_list::{PositionEntry, PositionList};
+use crate::structs::MigratePositionEvent;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::system_program;
+
+#[derive(Accounts)]
+#[instruction(index: u32)]
+pub struct MigratePosition<'info> {
+    #[account(mut,
+        seeds = [b"positionlistv1", owner.key().as_ref()],
+        bump = position_list.load()?.bump
+    )]
+    pub position_list: AccountLoader<'info, PositionList>,
+    #[account(init,
+        seeds = [b"positionv2",
+        removed_position.load()?.pool.as_ref(),
+        &removed_position.load()?.id.to_le_bytes()],
+        bump, payer = payer,
+        space = Position::LEN
+    )]
+    pub stable_position: AccountLoader<'info, Position>,
+    #[account(mut,
+        seeds = [b"stablepositionlistv1", owner.key().as_ref()],
+        bump = stable_position_list.load()?.bump
+    )]
+    pub stable_position_list: AccountLoader<'info, PositionList>,
+    #[account(init,
+        seeds = [b"positionentryv1",
+        owner.key().as_ref(),
+        &stable_position_list.load()?.head.to_le_bytes()],
+        bump, payer = payer,
+        space = PositionEntry::LEN
+    )]
+    pub position_entry: AccountLoader<'info, PositionEntry>,
+    #[account(mut,
+        seeds = [b"positionv1",
+        owner.key().as_ref(),
+        &index.to_le_bytes()],
+        bump = removed_position.load()?.bump,
+    )]
+    pub removed_position: AccountLoader<'info, Position>,
+    #[account(mut,
+        close = payer,
+        seeds = [b"positionv1",
+        owner.key().as_ref(),
+        &(position_list.load()?.head - 1).to_le_bytes()],
+        bump = last_position.load()?.bump
+    )]
+    pub last_position: AccountLoader<'info, Position>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub owner: Signer<'info>,
+    pub rent: Sysvar<'info, Rent>,
+    #[account(address = system_program::ID)]
+    /// CHECK: Ignore
+    pub system_program: AccountInfo<'info>,
+}
+
+impl<'info> MigratePosition<'info> {
+    pub fn handler(&self, index: u32, bump: u8, entry_bump: u8) -> Result<()> {
+        msg!("INVARIANT: MIGRATE POSITION");
+
+        let mut position_list = self.position_list.load_mut()?;
+        let stable_position = &mut self.stable_position.load_init()?;
+        let removed_position = &mut self.removed_position.load_mut()?;
+        let mut stable_position_list = self.stable_position_list.load_mut()?;
+        let mut position_entry = self.position_entry.load_init()?;
+
+        **stable_position = Position {
+            bump, // assign new bump
+            ..**removed_position
+        };
+
+        // migrated position is enumerated by the stable list from now on
+        *position_entry = PositionEntry {
+            pool: stable_position.pool,
+            id: stable_position.id,
+            bump: entry_bump,
+        };
+        stable_position_list.head = stable_position_list.head.checked_add(1).unwrap();
+
+        emit!(MigratePositionEvent {
+            owner: stable_position.owner,
+            pool: stable_position.pool,
+            id: stable_position.id,
+            index,
+        });
+
+        position_list.head = position_list.head.checked_sub(1).unwrap();
+
+        // when removed position is not the last one
+        if position_list.head != index {
+            let mut last_position = self.last_position.load_mut()?;
+
+            **removed_position = Position {
+                bump: removed_position.bump, // stay with the same bump
+                ..*last_position
+            };
+            *last_position = Default::default();
+        } else {
+            **removed_position = Default::default();
+        }
+
+        Ok(())
+    }
+}
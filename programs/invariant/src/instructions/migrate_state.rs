@@ -0,0 +1,63 @@
+use crate::decimals::*;
+use crate::structs::state::{treasury_address, State};
+use crate::structs::MigrateStateEvent;
+use crate::ErrorCode;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::system_program;
+
+#[derive(Accounts)]
+pub struct MigrateState<'info> {
+    // state is not loaded, the account created before the layout was extended is shorter than `State`
+    #[account(mut, seeds = [b"statev1".as_ref()], bump)]
+    pub state: AccountLoader<'info, State>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(address = system_program::ID)]
+    /// CHECK: Ignore
+    pub system_program: AccountInfo<'info>,
+}
+
+impl<'info> MigrateState<'info> {
+    pub fn handler(&self) -> Result<()> {
+        msg!("INVARIANT: MIGRATE STATE");
+        let state_info = self.state.to_account_info();
+
+        require!(
+            state_info.data_len() < State::LEN,
+            ErrorCode::AlreadyMigrated
+        );
+        // admin is the first field of every layout
+        let admin = Pubkey::try_from_slice(&state_info.try_borrow_data()?[8..40])?;
+        require_keys_eq!(admin, self.admin.key(), ErrorCode::InvalidAdmin);
+
+        let lamports = Rent::get()?
+            .minimum_balance(State::LEN)
+            .saturating_sub(state_info.lamports());
+        if lamports > 0 {
+            anchor_lang::system_program::transfer(
+                CpiContext::new(
+                    self.system_program.clone(),
+                    anchor_lang::system_program::Transfer {
+                        from: self.admin.to_account_info(),
+                        to: state_info.clone(),
+                    },
+                ),
+                lamports,
+            )?;
+        }
+        state_info.realloc(State::LEN, true)?;
+
+        // appended fields get the values `create_state` would set
+        let mut state = self.state.load_mut()?;
+        state.pending_admin = Pubkey::default();
+        state.fee_tier_manager = admin;
+        state.pauser = admin;
+        state.protocol_fee_manager = admin;
+        state.default_protocol_fee = FixedPoint::from_scale(1, 2);
+        state.default_fee_receiver = treasury_address();
+
+        emit!(MigrateStateEvent { admin });
+
+        Ok(())
+    }
+}
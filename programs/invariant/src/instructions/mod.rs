@@ -1,35 +1,115 @@
+pub mod accept_admin;
+pub mod approve_position_operator;
+pub mod change_dynamic_fee;
 pub mod change_fee_receiver;
-// pub mod change_liquidity;
+pub mod change_fee_split;
+pub mod change_fee_tier;
+pub mod change_fee_tier_pool_defaults;
+pub mod change_liquidity;
+pub mod change_pause;
+pub mod change_pool_defaults;
+pub mod change_pool_fee;
+pub mod change_pool_pause;
 pub mod change_protocol_fee;
+pub mod change_referrer;
 pub mod claim_fee;
+pub mod claim_fee_batch;
+pub mod claim_range_order;
+pub mod claim_tokenized_fee;
+pub mod close_pool;
+pub mod close_position_list;
+pub mod compound_fees;
+pub mod create_fee_split;
 pub mod create_fee_tier;
 pub mod create_pool;
 pub mod create_position;
 pub mod create_position_list;
+pub mod create_range_order;
+pub mod create_referrer;
+pub mod create_stable_position;
+pub mod create_stable_position_list;
 pub mod create_state;
 pub mod create_tick;
+pub mod distribute_protocol_fee;
+pub mod flash_loan;
+pub mod flash_swap;
+pub mod get_twap;
 pub mod init_reserves;
 pub mod initialize_oracle;
+pub mod migrate_fee_tier;
+pub mod migrate_pool;
+pub mod migrate_position;
+pub mod migrate_state;
+pub mod propose_admin;
+pub mod quote_swap;
+pub mod redeem_position;
 pub mod remove_position;
+pub mod remove_stable_position;
+pub mod revoke_position_operator;
+pub mod set_role;
+pub mod settle_range_order;
 pub mod swap;
+pub mod swap_route;
+pub mod tokenize_position;
 pub mod transfer_position_ownership;
+pub mod transfer_stable_position;
 pub mod update_seconds_per_liquidity;
 pub mod withdraw_protocol_fee;
 
+pub use accept_admin::*;
+pub use approve_position_operator::*;
+pub use change_dynamic_fee::*;
 pub use change_fee_receiver::*;
-// pub use change_liquidity::*;
+pub use change_fee_split::*;
+pub use change_fee_tier::*;
+pub use change_fee_tier_pool_defaults::*;
+pub use change_liquidity::*;
+pub use change_pause::*;
+pub use change_pool_defaults::*;
+pub use change_pool_fee::*;
+pub use change_pool_pause::*;
 pub use change_protocol_fee::*;
+pub use change_referrer::*;
 pub use claim_fee::*;
+pub use claim_fee_batch::*;
+pub use claim_range_order::*;
+pub use claim_tokenized_fee::*;
+pub use close_pool::*;
+pub use close_position_list::*;
+pub use compound_fees::*;
+pub use create_fee_split::*;
 pub use create_fee_tier::*;
 pub use create_pool::*;
 pub use create_position::*;
 pub use create_position_list::*;
+pub use create_range_order::*;
+pub use create_referrer::*;
+pub use create_stable_position::*;
+pub use create_stable_position_list::*;
 pub use create_state::*;
 pub use create_tick::*;
+pub use distribute_protocol_fee::*;
+pub use flash_loan::*;
+pub use flash_swap::*;
+pub use get_twap::*;
 pub use init_reserves::*;
 pub use initialize_oracle::*;
+pub use migrate_fee_tier::*;
+pub use migrate_pool::*;
+pub use migrate_position::*;
+pub use migrate_state::*;
+pub use propose_admin::*;
+pub use quote_swap::*;
+pub use redeem_position::*;
 pub use remove_position::*;
+pub use remove_stable_position::*;
+pub use revoke_position_operator::*;
+pub use set_role::*;
+pub use settle_range_order::*;
 pub use swap::*;
+pub use swap_route::*;
+pub use tokenize_position::*;
 pub use transfer_position_ownership::*;
+pub use transfer_stable_position::*;
 pub use update_seconds_per_liquidity::*;
 pub use withdraw_protocol_fee::*;
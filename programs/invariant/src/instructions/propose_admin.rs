@@ -0,0 +1,29 @@
+use crate::structs::{ProposeAdminEvent, State};
+use crate::ErrorCode::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ProposeAdmin<'info> {
+    #[account(mut, seeds = [b"statev1".as_ref()], bump = state.load()?.bump)]
+    pub state: AccountLoader<'info, State>,
+    #[account(constraint = &state.load()?.admin == admin.key @ InvalidAdmin)]
+    pub admin: Signer<'info>,
+    /// CHECK: Has to accept the transfer before becoming admin
+    pub pending_admin: AccountInfo<'info>,
+}
+
+impl<'info> ProposeAdmin<'info> {
+    pub fn handler(&self) -> Result<()> {
+        msg!("INVARIANT: PROPOSE ADMIN");
+
+        let mut state = self.state.load_mut()?;
+        state.pending_admin = self.pending_admin.key();
+
+        emit!(ProposeAdminEvent {
+            admin: state.admin,
+            pending_admin: state.pending_admin,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,90 @@
+use crate::decimals::*;
+use crate::referral::find_referral;
+use crate::structs::pool::Pool;
+use crate::structs::tickmap::Tickmap;
+use crate::util::{cross_tick, find_tick_account, get_current_timestamp, process_swap};
+use crate::ErrorCode::{self, *};
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct QuoteResult {
+    pub amount_in: u64,
+    pub amount_out: u64,
+    pub fee: u64,
+    pub referral_fee: u64,
+    pub remaining_amount: u64,
+    pub sqrt_price: Price,
+    pub crossed_ticks: Vec<i32>,
+}
+
+#[derive(Accounts)]
+pub struct QuoteSwap<'info> {
+    pub pool: AccountLoader<'info, Pool>,
+    #[account(
+        constraint = tickmap.to_account_info().key == &pool.load()?.tickmap @ InvalidTickmap,
+        constraint = tickmap.to_account_info().owner == __program_id @ InvalidTickmapOwner
+    )]
+    pub tickmap: AccountLoader<'info, Tickmap>,
+}
+
+impl<'info> QuoteSwap<'info> {
+    pub fn handler(
+        ctx: Context<'_, '_, 'info, 'info, QuoteSwap<'info>>,
+        x_to_y: bool,
+        amount: u64,
+        by_amount_in: bool, // whether amount specifies input or output
+        sqrt_price_limit: u128,
+        partial_fill: bool,
+    ) -> Result<QuoteResult> {
+        msg!("INVARIANT: QUOTE SWAP");
+        require!(amount != 0, ErrorCode::ZeroAmount);
+
+        // Swap is simulated on copies, so no account is modified
+        let mut pool = *ctx.accounts.pool.load()?;
+        let tickmap = ctx.accounts.tickmap.load()?;
+        let pool_address = ctx.accounts.pool.key();
+        let current_timestamp = get_current_timestamp();
+
+        let input_mint = match x_to_y {
+            true => pool.token_x,
+            false => pool.token_y,
+        };
+        let ref_percentage = match find_referral(ctx.remaining_accounts, input_mint) {
+            Some((_, fee_share)) => fee_share,
+            None => FixedPoint::from_integer(0),
+        };
+
+        let summary = process_swap(
+            &mut pool,
+            &tickmap,
+            x_to_y,
+            TokenAmount(amount),
+            by_amount_in,
+            Price::new(sqrt_price_limit),
+            partial_fill,
+            ref_percentage,
+            current_timestamp,
+            |pool, tick_index| {
+                let loader = find_tick_account(
+                    ctx.remaining_accounts,
+                    ctx.program_id,
+                    &pool_address,
+                    tick_index,
+                )?;
+                // crossing is applied to a copy, quoted tick accounts stay untouched
+                let mut tick = *loader.load()?;
+                cross_tick(&mut tick, pool, current_timestamp)
+            },
+        )?;
+
+        Ok(QuoteResult {
+            amount_in: summary.amount_in.0,
+            amount_out: summary.amount_out.0,
+            fee: summary.fee.0,
+            referral_fee: summary.referral_fee.0,
+            remaining_amount: summary.remaining_amount.0,
+            sqrt_price: pool.sqrt_price,
+            crossed_ticks: summary.crossed_ticks,
+        })
+    }
+}
@@ -0,0 +1,111 @@
+use crate::structs::position::Position;
+use crate::structs::position_list::PositionList;
+use crate::structs::RedeemPositionEvent;
+use crate::ErrorCode::*;
+use crate::*;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::system_program;
+use anchor_spl::token_2022::{self, Token2022};
+use anchor_spl::token_interface::{Mint, TokenAccount};
+
+#[derive(Accounts)]
+pub struct RedeemPosition<'info> {
+    #[account(seeds = [b"statev1".as_ref()], bump = state.load()?.bump)]
+    pub state: AccountLoader<'info, State>,
+    #[account(mut,
+        seeds = [b"positionlistv1", holder.key().as_ref()],
+        bump = position_list.load()?.bump
+    )]
+    pub position_list: AccountLoader<'info, PositionList>,
+    #[account(init,
+        seeds = [b"positionv1",
+        holder.key().as_ref(),
+        &position_list.load()?.head.to_le_bytes()],
+        bump, payer = holder,
+        space = Position::LEN
+    )]
+    pub position: AccountLoader<'info, Position>,
+    #[account(mut,
+        close = holder,
+        seeds = [b"tokenizedpositionv1", position_mint.key().as_ref()],
+        bump = tokenized_position.load()?.bump,
+        constraint = tokenized_position.load()?.owner == position_mint.key() @ InvalidPositionToken
+    )]
+    pub tokenized_position: AccountLoader<'info, Position>,
+    #[account(mut,
+        seeds = [b"positionmintv1", tokenized_position.load()?.pool.as_ref(), &tokenized_position.load()?.id.to_le_bytes()],
+        bump,
+        mint::token_program = token_program
+    )]
+    pub position_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(mut,
+        token::mint = position_mint,
+        token::authority = holder,
+        token::token_program = token_program,
+        constraint = holder_token_account.amount == 1 @ InvalidPositionToken
+    )]
+    pub holder_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut)]
+    pub holder: Signer<'info>,
+    #[account(constraint = &state.load()?.authority == program_authority.key @ InvalidAuthority)]
+    /// CHECK: Ignore
+    pub program_authority: AccountInfo<'info>,
+    pub token_program: Program<'info, Token2022>,
+    pub rent: Sysvar<'info, Rent>,
+    #[account(address = system_program::ID)]
+    /// CHECK: Ignore
+    pub system_program: AccountInfo<'info>,
+}
+
+impl<'info> RedeemPosition<'info> {
+    pub fn handler(&self, bump: u8) -> Result<()> {
+        msg!("INVARIANT: REDEEM POSITION");
+
+        let state = self.state.load()?;
+        let mut position_list = self.position_list.load_mut()?;
+        let position = &mut self.position.load_init()?;
+        let tokenized_position = self.tokenized_position.load()?;
+        let signer: &[&[&[u8]]] = get_signer!(state.nonce);
+
+        token_2022::burn(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                token_2022::Burn {
+                    mint: self.position_mint.to_account_info(),
+                    from: self.holder_token_account.to_account_info(),
+                    authority: self.holder.to_account_info(),
+                },
+            ),
+            1,
+        )?;
+        // rent of the position mint returns to the holder
+        token_2022::close_account(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                token_2022::CloseAccount {
+                    account: self.position_mint.to_account_info(),
+                    destination: self.holder.to_account_info(),
+                    authority: self.program_authority.clone(),
+                },
+            )
+            .with_signer(signer),
+        )?;
+
+        position_list.head = position_list.head.checked_add(1).unwrap();
+
+        **position = Position {
+            owner: *self.holder.key,
+            bump,
+            ..*tokenized_position
+        };
+
+        emit!(RedeemPositionEvent {
+            owner: position.owner,
+            pool: position.pool,
+            id: position.id,
+            mint: self.position_mint.key(),
+        });
+
+        Ok(())
+    }
+}
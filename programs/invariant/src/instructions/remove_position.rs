@@ -1,11 +1,12 @@
-use crate::decimals::*;
 use crate::interfaces::send_tokens::SendTokens;
 use crate::structs::pool::Pool;
 use crate::structs::position::Position;
 use crate::structs::position_list::PositionList;
+use crate::structs::position_operator::{PositionOperator, PERMISSION_REMOVE};
+use crate::structs::state::PAUSE_REMOVE_LIQUIDITY;
 use crate::structs::tick::Tick;
 use crate::structs::tickmap::Tickmap;
-use crate::util::{check_ticks, close};
+use crate::util::{check_not_paused, check_position_authority, close_position};
 use crate::ErrorCode::{self, *};
 use crate::*;
 use anchor_lang::prelude::*;
@@ -13,7 +14,6 @@ use anchor_spl::token_interface::TokenInterface;
 use anchor_spl::token_interface::{Mint, TokenAccount};
 
 use anchor_spl::{token, token_2022};
-use structs::RemovePositionEvent;
 
 #[derive(Accounts)]
 #[instruction(index: i32, lower_tick_index: i32, upper_tick_index: i32)]
@@ -22,20 +22,20 @@ pub struct RemovePosition<'info> {
     pub state: AccountLoader<'info, State>,
     #[account(mut,
         seeds = [b"positionv1",
-        owner.key().as_ref(),
+        removed_position.load()?.owner.as_ref(),
         &index.to_le_bytes()],
         bump = removed_position.load()?.bump
     )]
     pub removed_position: AccountLoader<'info, Position>,
     #[account(mut,
-        seeds = [b"positionlistv1", owner.key().as_ref()],
+        seeds = [b"positionlistv1", removed_position.load()?.owner.as_ref()],
         bump = position_list.load()?.bump
     )]
     pub position_list: AccountLoader<'info, PositionList>,
     #[account(mut,
         close = payer,
         seeds = [b"positionv1",
-        owner.key().as_ref(),
+        removed_position.load()?.owner.as_ref(),
         &(position_list.load()?.head - 1).to_le_bytes()],
         bump = last_position.load()?.bump
     )]
@@ -64,6 +64,7 @@ pub struct RemovePosition<'info> {
     pub upper_tick: AccountLoader<'info, Tick>,
     #[account(mut)]
     pub payer: Signer<'info>,
+    // position owner or an operator approved to remove the position
     pub owner: Signer<'info>,
     #[account(constraint = token_x.key() == pool.load()?.token_x @ InvalidTokenAccount, mint::token_program = token_x_program)]
     pub token_x: InterfaceAccount<'info, Mint>,
@@ -71,13 +72,13 @@ pub struct RemovePosition<'info> {
     pub token_y: InterfaceAccount<'info, Mint>,
     #[account(mut,
         constraint = account_x.mint == token_x.key() @ InvalidMint,
-        constraint = &account_x.owner == owner.key @ InvalidOwner,
+        constraint = account_x.owner == removed_position.load()?.owner @ InvalidOwner,
         token::token_program = token_x_program
     )]
     pub account_x: Box<InterfaceAccount<'info, TokenAccount>>,
     #[account(mut,
         constraint = account_y.mint == token_y.key() @ InvalidMint,
-        constraint = &account_y.owner == owner.key @ InvalidOwner,
+        constraint = account_y.owner == removed_position.load()?.owner @ InvalidOwner,
         token::token_program = token_y_program
     )]
     pub account_y: Box<InterfaceAccount<'info, TokenAccount>>,
@@ -106,6 +107,16 @@ pub struct RemovePosition<'info> {
     /// CHECK: Accounts used for RPC calls optimization
     #[account(address = Pubkey::find_program_address(&[b"eventoptaccv1", pool.key().as_ref()], __program_id).0)]
     pub event_opt_acc: AccountInfo<'info>,
+    // optional accounts are trailing to keep the layout of existing clients
+    #[account(
+        seeds = [b"positionoperatorv1",
+        removed_position.load()?.owner.as_ref(),
+        pool.key().as_ref(),
+        &removed_position.load()?.id.to_le_bytes(),
+        owner.key().as_ref()],
+        bump = position_operator.load()?.bump
+    )]
+    pub position_operator: Option<AccountLoader<'info, PositionOperator>>,
 }
 
 impl<'info> SendTokens<'info> for RemovePosition<'info> {
@@ -157,92 +168,40 @@ impl<'info> SendTokens<'info> for RemovePosition<'info> {
 }
 
 impl<'info> RemovePosition<'info> {
-    pub fn handler(&self, index: u32, lower_tick_index: i32, upper_tick_index: i32) -> Result<()> {
+    pub fn handler(
+        &self,
+        index: u32,
+        _lower_tick_index: i32,
+        _upper_tick_index: i32,
+    ) -> Result<()> {
         msg!("INVARIANT: REMOVE POSITION");
+        check_not_paused(
+            &*self.state.load()?,
+            &*self.pool.load()?,
+            PAUSE_REMOVE_LIQUIDITY,
+        )?;
 
         let state = self.state.load()?;
         let mut position_list = self.position_list.load_mut()?;
         let removed_position = &mut self.removed_position.load_mut()?;
         let pool = &mut self.pool.load_mut()?;
         let tickmap = &mut self.tickmap.load_mut()?;
-        let current_timestamp = get_current_timestamp();
-
-        // closing tick can't be in the same scope as loaded tick
-        let close_lower;
-        let close_upper;
-
-        let mut remove_position_event = RemovePositionEvent {
-            owner: removed_position.owner,
-            pool: removed_position.pool,
-            id: removed_position.id,
-            liquidity: removed_position.liquidity,
-            upper_tick: removed_position.upper_tick_index,
-            current_tick: pool.current_tick_index,
-            lower_tick: removed_position.lower_tick_index,
-            upper_tick_seconds_per_liquidity_outside: SecondsPerLiquidity::default(),
-            lower_tick_seconds_per_liquidity_outside: SecondsPerLiquidity::default(),
-            pool_seconds_per_liquidity_global: SecondsPerLiquidity::default(),
-            current_timestamp,
-        };
-
-        let (amount_x, amount_y) = {
-            let lower_tick = &mut self.lower_tick.load_mut()?;
-            let upper_tick = &mut self.upper_tick.load_mut()?;
-
-            // validate ticks
-            check_ticks(lower_tick.index, upper_tick.index, pool.tick_spacing)?;
-            let liquidity_delta = removed_position.liquidity;
-            let (amount_x, amount_y) = removed_position.modify(
-                pool,
-                upper_tick,
-                lower_tick,
-                liquidity_delta,
-                false,
-                current_timestamp,
-            )?;
-
-            let amount_x = amount_x + TokenAmount::from_decimal(removed_position.tokens_owed_x);
-            let amount_y = amount_y + TokenAmount::from_decimal(removed_position.tokens_owed_y);
 
-            close_lower = lower_tick.liquidity_gross.is_zero();
-            close_upper = upper_tick.liquidity_gross.is_zero();
+        check_position_authority(
+            removed_position,
+            self.owner.key,
+            &self.position_operator,
+            PERMISSION_REMOVE,
+        )?;
 
-            remove_position_event.lower_tick_seconds_per_liquidity_outside =
-                lower_tick.seconds_per_liquidity_outside;
-            remove_position_event.upper_tick_seconds_per_liquidity_outside =
-                upper_tick.seconds_per_liquidity_outside;
-            remove_position_event.pool_seconds_per_liquidity_global =
-                pool.seconds_per_liquidity_global;
-
-            (amount_x, amount_y)
-        };
-
-        if close_lower {
-            {
-                let lower_tick = &mut self.lower_tick.load_mut()?;
-                **lower_tick = Default::default();
-            }
-            close(
-                self.lower_tick.to_account_info(),
-                self.payer.to_account_info(),
-            )
-            .unwrap();
-
-            tickmap.flip(false, lower_tick_index, pool.tick_spacing);
-        }
-        if close_upper {
-            {
-                let upper_tick = &mut self.upper_tick.load_mut()?;
-                **upper_tick = Default::default();
-            }
-            close(
-                self.upper_tick.to_account_info(),
-                self.payer.to_account_info(),
-            )
-            .unwrap();
-
-            tickmap.flip(false, upper_tick_index, pool.tick_spacing);
-        }
+        let (amount_x, amount_y, remove_position_event) = close_position(
+            removed_position,
+            pool,
+            &self.lower_tick,
+            &self.upper_tick,
+            tickmap,
+            self.payer.to_account_info(),
+        )?;
 
         // Remove empty position
         position_list.head = position_list.head.checked_sub(1).unwrap();
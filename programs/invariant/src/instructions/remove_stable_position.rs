@@ -0,0 +1,263 @@
+use crate::interfaces::send_tokens::SendTokens;
+use crate::structs::pool::Pool;
+use crate::structs::position::Position;
+use crate::structs::position_list::{PositionEntry, PositionList};
+use crate::structs::position_operator::{PositionOperator, PERMISSION_REMOVE};
+use crate::structs::state::PAUSE_REMOVE_LIQUIDITY;
+use crate::structs::tick::Tick;
+use crate::structs::tickmap::Tickmap;
+use crate::util::{check_not_paused, check_position_authority, close_position};
+use crate::ErrorCode::{self, *};
+use crate::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenInterface;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+
+use anchor_spl::{token, token_2022};
+
+#[derive(Accounts)]
+#[instruction(index: u32, lower_tick_index: i32, upper_tick_index: i32)]
+pub struct RemoveStablePosition<'info> {
+    #[account(seeds = [b"statev1".as_ref()], bump = state.load()?.bump)]
+    pub state: AccountLoader<'info, State>,
+    #[account(mut,
+        close = position_owner,
+        seeds = [b"positionv2",
+        pool.key().as_ref(),
+        &removed_position.load()?.id.to_le_bytes()],
+        bump = removed_position.load()?.bump
+    )]
+    pub removed_position: AccountLoader<'info, Position>,
+    #[account(mut,
+        seeds = [b"stablepositionlistv1", removed_position.load()?.owner.as_ref()],
+        bump = position_list.load()?.bump
+    )]
+    pub position_list: AccountLoader<'info, PositionList>,
+    #[account(mut,
+        seeds = [b"positionentryv1",
+        removed_position.load()?.owner.as_ref(),
+        &index.to_le_bytes()],
+        bump = removed_entry.load()?.bump,
+        constraint = removed_entry.load()?.pool == removed_position.load()?.pool @ InvalidPositionIndex,
+        constraint = removed_entry.load()?.id == removed_position.load()?.id @ InvalidPositionIndex
+    )]
+    pub removed_entry: AccountLoader<'info, PositionEntry>,
+    #[account(mut,
+        close = position_owner,
+        seeds = [b"positionentryv1",
+        removed_position.load()?.owner.as_ref(),
+        &(position_list.load()?.head - 1).to_le_bytes()],
+        bump = last_entry.load()?.bump
+    )]
+    pub last_entry: AccountLoader<'info, PositionEntry>,
+    #[account(mut,
+        seeds = [b"poolv1", token_x.key().as_ref(), token_y.key().as_ref(), &pool.load()?.fee.v.to_le_bytes(), &pool.load()?.tick_spacing.to_le_bytes()],
+        bump = pool.load()?.bump
+    )]
+    pub pool: AccountLoader<'info, Pool>,
+    #[account(mut,
+        constraint = tickmap.key() == pool.load()?.tickmap @ InvalidTickmap,
+        constraint = tickmap.to_account_info().owner == __program_id @ InvalidTickmapOwner,
+    )]
+    pub tickmap: AccountLoader<'info, Tickmap>,
+    #[account(mut,
+        seeds = [b"tickv1", pool.key().as_ref(), &lower_tick_index.to_le_bytes()],
+        bump = lower_tick.load()?.bump,
+        constraint = lower_tick_index == removed_position.load()?.lower_tick_index @ WrongTick
+    )]
+    pub lower_tick: AccountLoader<'info, Tick>,
+    #[account(mut,
+        seeds = [b"tickv1", pool.key().as_ref(), &upper_tick_index.to_le_bytes()],
+        bump = upper_tick.load()?.bump,
+        constraint = upper_tick_index == removed_position.load()?.upper_tick_index @ WrongTick
+    )]
+    pub upper_tick: AccountLoader<'info, Tick>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    // position owner or an operator approved to remove the position
+    pub owner: Signer<'info>,
+    #[account(mut, address = removed_position.load()?.owner @ InvalidOwner)]
+    /// CHECK: Receives rent of the position
+    pub position_owner: AccountInfo<'info>,
+    #[account(constraint = token_x.key() == pool.load()?.token_x @ InvalidTokenAccount, mint::token_program = token_x_program)]
+    pub token_x: InterfaceAccount<'info, Mint>,
+    #[account(constraint = token_y.key() == pool.load()?.token_y @ InvalidTokenAccount, mint::token_program = token_y_program)]
+    pub token_y: InterfaceAccount<'info, Mint>,
+    #[account(mut,
+        constraint = account_x.mint == token_x.key() @ InvalidMint,
+        constraint = account_x.owner == removed_position.load()?.owner @ InvalidOwner,
+        token::token_program = token_x_program
+    )]
+    pub account_x: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut,
+        constraint = account_y.mint == token_y.key() @ InvalidMint,
+        constraint = account_y.owner == removed_position.load()?.owner @ InvalidOwner,
+        token::token_program = token_y_program
+    )]
+    pub account_y: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut,
+        constraint = reserve_x.mint == token_x.key() @ InvalidMint,
+        constraint = &reserve_x.owner == program_authority.key @ InvalidAuthority,
+        constraint = reserve_x.key() == pool.load()?.token_x_reserve @ InvalidTokenAccount,
+        token::token_program = token_x_program
+    )]
+    pub reserve_x: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut,
+        constraint = reserve_y.mint == token_y.key() @ InvalidMint,
+        constraint = &reserve_y.owner == program_authority.key @ InvalidAuthority,
+        constraint = reserve_y.key() == pool.load()?.token_y_reserve @ InvalidTokenAccount,
+        token::token_program = token_y_program
+    )]
+    pub reserve_y: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(constraint = &state.load()?.authority == program_authority.key @ InvalidAuthority)]
+    /// CHECK: Ignore
+    pub program_authority: AccountInfo<'info>,
+
+    #[account(constraint = token_x_program.key() == token::ID || token_x_program.key() == token_2022::ID)]
+    pub token_x_program: Interface<'info, TokenInterface>,
+    #[account(constraint = token_y_program.key() == token::ID || token_y_program.key() == token_2022::ID)]
+    pub token_y_program: Interface<'info, TokenInterface>,
+    #[account(
+        seeds = [b"positionoperatorv1",
+        removed_position.load()?.owner.as_ref(),
+        pool.key().as_ref(),
+        &removed_position.load()?.id.to_le_bytes(),
+        owner.key().as_ref()],
+        bump = position_operator.load()?.bump
+    )]
+    pub position_operator: Option<AccountLoader<'info, PositionOperator>>,
+    /// CHECK: Accounts used for RPC calls optimization
+    #[account(address = Pubkey::find_program_address(&[b"eventoptaccv1", pool.key().as_ref()], __program_id).0)]
+    pub event_opt_acc: AccountInfo<'info>,
+}
+
+impl<'info> SendTokens<'info> for RemoveStablePosition<'info> {
+    fn send_x(&self) -> CpiContext<'_, '_, '_, 'info, token::Transfer<'info>> {
+        CpiContext::new(
+            self.token_x_program.to_account_info(),
+            token::Transfer {
+                from: self.reserve_x.to_account_info(),
+                to: self.account_x.to_account_info(),
+                authority: self.program_authority.clone(),
+            },
+        )
+    }
+
+    fn send_y(&self) -> CpiContext<'_, '_, '_, 'info, token::Transfer<'info>> {
+        CpiContext::new(
+            self.token_y_program.to_account_info(),
+            token::Transfer {
+                from: self.reserve_y.to_account_info(),
+                to: self.account_y.to_account_info(),
+                authority: self.program_authority.clone(),
+            },
+        )
+    }
+
+    fn send_x_2022(&self) -> CpiContext<'_, '_, '_, 'info, token_2022::TransferChecked<'info>> {
+        CpiContext::new(
+            self.token_x_program.to_account_info(),
+            token_2022::TransferChecked {
+                mint: self.token_x.to_account_info(),
+                from: self.reserve_x.to_account_info(),
+                to: self.account_x.to_account_info(),
+                authority: self.program_authority.clone(),
+            },
+        )
+    }
+
+    fn send_y_2022(&self) -> CpiContext<'_, '_, '_, 'info, token_2022::TransferChecked<'info>> {
+        CpiContext::new(
+            self.token_y_program.to_account_info(),
+            token_2022::TransferChecked {
+                mint: self.token_y.to_account_info(),
+                from: self.reserve_y.to_account_info(),
+                to: self.account_y.to_account_info(),
+                authority: self.program_authority.clone(),
+            },
+        )
+    }
+}
+
+impl<'info> RemoveStablePosition<'info> {
+    pub fn handler(
+        &self,
+        index: u32,
+        _lower_tick_index: i32,
+        _upper_tick_index: i32,
+    ) -> Result<()> {
+        msg!("INVARIANT: REMOVE STABLE POSITION");
+        check_not_paused(
+            &*self.state.load()?,
+            &*self.pool.load()?,
+            PAUSE_REMOVE_LIQUIDITY,
+        )?;
+
+        let state = self.state.load()?;
+        let removed_position = &mut self.removed_position.load_mut()?;
+        let pool = &mut self.pool.load_mut()?;
+        let tickmap = &mut self.tickmap.load_mut()?;
+
+        check_position_authority(
+            removed_position,
+            self.owner.key,
+            &self.position_operator,
+            PERMISSION_REMOVE,
+        )?;
+
+        let (amount_x, amount_y, remove_position_event) = close_position(
+            removed_position,
+            pool,
+            &self.lower_tick,
+            &self.upper_tick,
+            tickmap,
+            self.payer.to_account_info(),
+        )?;
+
+        // Position account is closed, no other position is moved
+        **removed_position = Default::default();
+
+        // only the entry pointing to the position is replaced in the list
+        let mut position_list = self.position_list.load_mut()?;
+        position_list.head = position_list.head.checked_sub(1).unwrap();
+
+        if position_list.head != index {
+            let mut removed_entry = self.removed_entry.load_mut()?;
+            let mut last_entry = self.last_entry.load_mut()?;
+
+            *removed_entry = PositionEntry {
+                bump: removed_entry.bump,
+                ..*last_entry
+            };
+            *last_entry = Default::default();
+        } else {
+            *self.removed_entry.load_mut()? = Default::default();
+        }
+
+        let signer: &[&[&[u8]]] = get_signer!(state.nonce);
+
+        match self.token_x_program.key() {
+            token_2022::ID => token_2022::transfer_checked(
+                self.send_x_2022().with_signer(signer),
+                amount_x.0,
+                self.token_x.decimals,
+            )?,
+            token::ID => token::transfer(self.send_x().with_signer(signer), amount_x.0)?,
+            _ => return Err(ErrorCode::InvalidTokenProgram.into()),
+        };
+
+        match self.token_y_program.key() {
+            token_2022::ID => token_2022::transfer_checked(
+                self.send_y_2022().with_signer(signer),
+                amount_y.0,
+                self.token_y.decimals,
+            )?,
+            token::ID => token::transfer(self.send_y().with_signer(signer), amount_y.0)?,
+            _ => return Err(ErrorCode::InvalidTokenProgram.into()),
+        };
+
+        emit!(remove_position_event);
+
+        Ok(())
+    }
+}
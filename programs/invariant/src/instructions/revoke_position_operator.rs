@@ -0,0 +1,38 @@
+use crate::structs::position_operator::PositionOperator;
+use crate::structs::RevokePositionOperatorEvent;
+use crate::ErrorCode::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct RevokePositionOperator<'info> {
+    #[account(mut,
+        close = owner,
+        seeds = [b"positionoperatorv1",
+        owner.key().as_ref(),
+        position_operator.load()?.pool.as_ref(),
+        &position_operator.load()?.position_id.to_le_bytes(),
+        position_operator.load()?.operator.as_ref()],
+        bump = position_operator.load()?.bump,
+        constraint = position_operator.load()?.owner == owner.key() @ InvalidOwner
+    )]
+    pub position_operator: AccountLoader<'info, PositionOperator>,
+    #[account(mut)]
+    pub owner: Signer<'info>,
+}
+
+impl<'info> RevokePositionOperator<'info> {
+    pub fn handler(&self) -> Result<()> {
+        msg!("INVARIANT: REVOKE POSITION OPERATOR");
+
+        let position_operator = self.position_operator.load()?;
+
+        emit!(RevokePositionOperatorEvent {
+            owner: position_operator.owner,
+            pool: position_operator.pool,
+            id: position_operator.position_id,
+            operator: position_operator.operator,
+        });
+
+        Ok(())
+    }
+}
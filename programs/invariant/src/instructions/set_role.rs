@@ -0,0 +1,29 @@
+use crate::structs::{Role, SetRoleEvent, State};
+use crate::ErrorCode::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetRole<'info> {
+    #[account(mut, seeds = [b"statev1".as_ref()], bump = state.load()?.bump)]
+    pub state: AccountLoader<'info, State>,
+    #[account(constraint = &state.load()?.admin == admin.key @ InvalidAdmin)]
+    pub admin: Signer<'info>,
+    /// CHECK: Ignore
+    pub holder: AccountInfo<'info>,
+}
+
+impl<'info> SetRole<'info> {
+    pub fn handler(&self, role: Role) -> Result<()> {
+        msg!("INVARIANT: SET ROLE");
+
+        let mut state = self.state.load_mut()?;
+        state.set_role(role, self.holder.key());
+
+        emit!(SetRoleEvent {
+            role,
+            holder: self.holder.key(),
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,148 @@
+use crate::structs::pool::Pool;
+use crate::structs::range_order::RangeOrder;
+use crate::structs::state::PAUSE_REMOVE_LIQUIDITY;
+use crate::structs::tick::Tick;
+use crate::structs::Tickmap;
+use crate::util::{check_not_paused, check_ticks, close};
+use crate::ErrorCode::{self, *};
+use crate::*;
+use anchor_lang::prelude::*;
+use decimals::*;
+use structs::RemovePositionEvent;
+
+#[derive(Accounts)]
+pub struct SettleRangeOrder<'info> {
+    #[account(seeds = [b"statev1".as_ref()], bump = state.load()?.bump)]
+    pub state: AccountLoader<'info, State>,
+    #[account(mut,
+        seeds = [b"rangeorderv1", range_order.load()?.position.owner.as_ref(), pool.key().as_ref(), &range_order.load()?.position.lower_tick_index.to_le_bytes()],
+        bump = range_order.load()?.bump
+    )]
+    pub range_order: AccountLoader<'info, RangeOrder>,
+    #[account(mut)]
+    pub pool: AccountLoader<'info, Pool>,
+    #[account(mut,
+        seeds = [b"tickv1", pool.key().as_ref(), &range_order.load()?.position.lower_tick_index.to_le_bytes()],
+        bump = lower_tick.load()?.bump
+    )]
+    pub lower_tick: AccountLoader<'info, Tick>,
+    #[account(mut,
+        seeds = [b"tickv1", pool.key().as_ref(), &range_order.load()?.position.upper_tick_index.to_le_bytes()],
+        bump = upper_tick.load()?.bump
+    )]
+    pub upper_tick: AccountLoader<'info, Tick>,
+    #[account(mut,
+        constraint = tickmap.key() == pool.load()?.tickmap @ InvalidTickmap,
+        constraint = tickmap.to_account_info().owner == __program_id @ InvalidTickmapOwner,
+    )]
+    pub tickmap: AccountLoader<'info, Tickmap>,
+    // anyone can settle filled order
+    pub settler: Signer<'info>,
+    // rent of emptied ticks goes back to the order owner who paid for them
+    #[account(mut, constraint = owner.key() == range_order.load()?.position.owner @ InvalidOwner)]
+    /// CHECK: ignore
+    pub owner: AccountInfo<'info>,
+}
+
+impl<'info> SettleRangeOrder<'info> {
+    pub fn handler(&self) -> Result<()> {
+        msg!("INVARIANT: SETTLE RANGE ORDER");
+
+        let range_order = &mut self.range_order.load_mut()?;
+        let pool = &mut self.pool.load_mut()?;
+        check_not_paused(&*self.state.load()?, pool, PAUSE_REMOVE_LIQUIDITY)?;
+        let tickmap = &mut self.tickmap.load_mut()?;
+        let current_timestamp = get_current_timestamp();
+
+        require!(!range_order.settled, ErrorCode::RangeOrderSettled);
+        // owner can cancel the order before it is filled
+        require!(
+            range_order.is_filled(pool.current_tick_index)
+                || *self.settler.key == range_order.position.owner,
+            ErrorCode::RangeOrderNotFilled
+        );
+
+        // closing tick can't be in the same scope as loaded tick
+        let (close_lower, close_upper) = {
+            let lower_tick = &mut self.lower_tick.load_mut()?;
+            let upper_tick = &mut self.upper_tick.load_mut()?;
+
+            check_ticks(lower_tick.index, upper_tick.index, pool.tick_spacing)?;
+            let position = &mut range_order.position;
+            let liquidity_delta = position.liquidity;
+            let current_tick = pool.current_tick_index;
+            let (amount_x, amount_y) = position.modify(
+                pool,
+                upper_tick,
+                lower_tick,
+                liquidity_delta,
+                false,
+                current_timestamp,
+            )?;
+
+            // converted tokens are kept in reserves until claimed by the owner
+            // trunk-ignore(clippy/assign_op_pattern)
+            position.tokens_owed_x = position.tokens_owed_x + FixedPoint::from_decimal(amount_x);
+            // trunk-ignore(clippy/assign_op_pattern)
+            position.tokens_owed_y = position.tokens_owed_y + FixedPoint::from_decimal(amount_y);
+
+            emit!(RemovePositionEvent {
+                owner: position.owner,
+                pool: position.pool,
+                id: position.id,
+                liquidity: liquidity_delta,
+                upper_tick: upper_tick.index,
+                current_tick,
+                lower_tick: lower_tick.index,
+                upper_tick_seconds_per_liquidity_outside: upper_tick.seconds_per_liquidity_outside,
+                lower_tick_seconds_per_liquidity_outside: lower_tick.seconds_per_liquidity_outside,
+                pool_seconds_per_liquidity_global: pool.seconds_per_liquidity_global,
+                current_timestamp,
+            });
+
+            (
+                lower_tick.liquidity_gross.is_zero(),
+                upper_tick.liquidity_gross.is_zero(),
+            )
+        };
+
+        if close_lower {
+            {
+                let lower_tick = &mut self.lower_tick.load_mut()?;
+                **lower_tick = Default::default();
+            }
+            close(
+                self.lower_tick.to_account_info(),
+                self.owner.to_account_info(),
+            )
+            .unwrap();
+
+            tickmap.flip(
+                false,
+                range_order.position.lower_tick_index,
+                pool.tick_spacing,
+            );
+        }
+        if close_upper {
+            {
+                let upper_tick = &mut self.upper_tick.load_mut()?;
+                **upper_tick = Default::default();
+            }
+            close(
+                self.upper_tick.to_account_info(),
+                self.owner.to_account_info(),
+            )
+            .unwrap();
+
+            tickmap.flip(
+                false,
+                range_order.position.upper_tick_index,
+                pool.tick_spacing,
+            );
+        }
+
+        range_order.settled = true;
+
+        Ok(())
+    }
+}
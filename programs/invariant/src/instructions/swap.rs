@@ -1,15 +1,15 @@
 use crate::interfaces::send_tokens::SendTokens;
 use crate::interfaces::take_ref_tokens::TakeRefTokens;
 use crate::interfaces::take_tokens::TakeTokens;
-use crate::log::get_tick_at_sqrt_price;
-use crate::math::compute_swap_step;
 use crate::structs::pool::Pool;
-use crate::structs::tick::Tick;
+use crate::structs::state::PAUSE_SWAP;
 use crate::structs::tickmap::Tickmap;
-use crate::util::get_closer_limit;
+use crate::util::{
+    check_not_paused, cross_tick_from_accounts, process_swap, record_oracle_price, SwapSummary,
+};
 use crate::ErrorCode::{self, *};
 use crate::*;
-use crate::{decimals::*, referral::whitelist::contains_owner};
+use crate::{decimals::*, referral::find_referral};
 use anchor_lang::prelude::*;
 use anchor_spl::token;
 use anchor_spl::token_2022;
@@ -225,13 +225,17 @@ impl<'info> TakeRefTokens<'info> for Swap<'info> {
 }
 
 impl<'info> Swap<'info> {
+    #[allow(clippy::too_many_arguments)]
     pub fn handler(
         ctx: Context<'_, '_, 'info, 'info, Swap<'info>>,
         x_to_y: bool,
         amount: u64,
         by_amount_in: bool, // whether amount specifies input or output
         sqrt_price_limit: u128,
-    ) -> Result<()> {
+        min_amount_out: Option<u64>,
+        max_amount_in: Option<u64>,
+        partial_fill: bool, // whether to stop at price limit instead of failing
+    ) -> Result<u64> {
         msg!("INVARIANT: SWAP");
         require!(amount != 0, ErrorCode::ZeroAmount);
 
@@ -239,172 +243,85 @@ impl<'info> Swap<'info> {
         let mut pool = ctx.accounts.pool.load_mut()?;
         let tickmap = ctx.accounts.tickmap.load()?;
         let state = ctx.accounts.state.load()?;
+        check_not_paused(&state, &pool, PAUSE_SWAP)?;
 
-        let ref_account = match ctx
-            .remaining_accounts
-            .iter()
-            .find(|account| *account.owner == token::ID)
+        let input_mint = match x_to_y {
+            true => ctx.accounts.account_x.mint,
+            false => ctx.accounts.account_y.mint,
+        };
+        let (ref_account, ref_percentage) = match find_referral(ctx.remaining_accounts, input_mint)
         {
-            Some(account) => match InterfaceAccount::<'info, TokenAccount>::try_from(account) {
-                Ok(token) => {
-                    let is_valid_mint = token.mint
-                        == match x_to_y {
-                            true => ctx.accounts.account_x.mint,
-                            false => ctx.accounts.account_y.mint,
-                        };
-                    let is_on_whitelist = contains_owner(token.owner);
-                    match is_valid_mint && is_on_whitelist {
-                        true => Some(account),
-                        false => None,
-                    }
-                }
-                Err(_) => None,
-            },
-            None => None,
+            Some((account, fee_share)) => (Some(account), fee_share),
+            None => (None, FixedPoint::from_integer(0)),
         };
+        let pool_sqrt_price_before = pool.sqrt_price;
+        let pool_address = ctx.accounts.pool.key();
+
+        let SwapSummary {
+            amount_in: total_amount_in,
+            amount_out: total_amount_out,
+            fee: total_fee,
+            referral_fee: total_amount_referral,
+            remaining_amount,
+            crossed_ticks,
+        } = process_swap(
+            &mut pool,
+            &tickmap,
+            x_to_y,
+            TokenAmount(amount),
+            by_amount_in,
+            sqrt_price_limit,
+            partial_fill,
+            ref_percentage,
+            get_current_timestamp(),
+            |pool, tick_index| {
+                cross_tick_from_accounts(
+                    ctx.remaining_accounts,
+                    ctx.program_id,
+                    &pool_address,
+                    pool,
+                    tick_index,
+                )
+            },
+        )?;
 
-        // limit is on the right side of price
-        if x_to_y {
-            require!(
-                { pool.sqrt_price } > sqrt_price_limit
-                    && sqrt_price_limit <= Price::new(MAX_SQRT_PRICE),
-                ErrorCode::WrongLimit
-            );
-        } else {
+        if total_amount_out.0 == 0 {
+            return Err(ErrorCode::NoGainSwap.into());
+        }
+
+        if let Some(min_amount_out) = min_amount_out {
             require!(
-                { pool.sqrt_price } < sqrt_price_limit
-                    && sqrt_price_limit >= Price::new(MIN_SQRT_PRICE),
-                ErrorCode::WrongLimit
+                total_amount_out >= TokenAmount(min_amount_out),
+                ErrorCode::AmountOutBelowMinimum
             );
         }
-
-        let mut remaining_amount = TokenAmount(amount);
-
-        let mut total_amount_in = TokenAmount(0);
-        let mut total_amount_out = TokenAmount(0);
-        let mut total_fee = TokenAmount(0);
-        let mut total_amount_referral = TokenAmount(0);
-        let pool_sqrt_price_before = pool.sqrt_price;
-
-        while !remaining_amount.is_zero() {
-            let (swap_limit, limiting_tick) = get_closer_limit(
-                sqrt_price_limit,
-                x_to_y,
-                pool.current_tick_index,
-                pool.tick_spacing,
-                &tickmap,
-            )?;
-
-            let result = compute_swap_step(
-                pool.sqrt_price,
-                swap_limit,
-                pool.liquidity,
-                remaining_amount,
-                by_amount_in,
-                pool.fee,
+        if let Some(max_amount_in) = max_amount_in {
+            require!(
+                total_amount_in <= TokenAmount(max_amount_in),
+                ErrorCode::AmountInAboveMaximum
             );
-            // make remaining amount smaller
-            if by_amount_in {
-                remaining_amount -= result.amount_in + result.fee_amount;
-            } else {
-                remaining_amount -= result.amount_out;
-            }
-
-            total_amount_referral += match ref_account.is_some() {
-                true => pool.add_fee(result.fee_amount, FixedPoint::from_scale(2, 1), x_to_y),
-                false => pool.add_fee(result.fee_amount, FixedPoint::from_integer(0), x_to_y),
-            };
-
-            pool.sqrt_price = result.next_price_sqrt;
-
-            total_amount_in += result.amount_in + result.fee_amount;
-            total_fee += result.fee_amount;
-            total_amount_out += result.amount_out;
-
-            // Fail if price would go over swap limit
-            if { pool.sqrt_price } == sqrt_price_limit && !remaining_amount.is_zero() {
-                return Err(ErrorCode::PriceLimitReached.into());
-            }
-
-            // crossing tick
-            // trunk-ignore(clippy/unnecessary_unwrap)
-            if result.next_price_sqrt == swap_limit && limiting_tick.is_some() {
-                let (tick_index, initialized) = limiting_tick.unwrap();
-
-                let is_enough_amount_to_cross = is_enough_amount_to_push_price(
-                    remaining_amount,
-                    result.next_price_sqrt,
-                    pool.liquidity,
-                    pool.fee,
-                    by_amount_in,
-                    x_to_y,
-                );
-
-                if initialized {
-                    // Calculating address of the crossed tick
-                    let (tick_address, _) = Pubkey::find_program_address(
-                        &[
-                            b"tickv1",
-                            ctx.accounts.pool.to_account_info().key.as_ref(),
-                            &tick_index.to_le_bytes(),
-                        ],
-                        ctx.program_id,
-                    );
-
-                    // Finding the correct tick in remaining accounts
-                    let loader = match ctx
-                        .remaining_accounts
-                        .iter()
-                        .find(|account| *account.key == tick_address)
-                    {
-                        Some(account) => AccountLoader::<'_, Tick>::try_from(account).unwrap(),
-                        None => return Err(ErrorCode::TickNotFound.into()),
-                    };
-                    let mut tick = loader.load_mut().unwrap();
-
-                    // crossing tick
-                    if !x_to_y || is_enough_amount_to_cross {
-                        msg!("INVARIANT: CROSSING TICK {} ", { tick.index });
-                        cross_tick(&mut tick, &mut pool, get_current_timestamp())?;
-                    } else if !remaining_amount.is_zero() {
-                        if by_amount_in {
-                            pool.add_fee(remaining_amount, FixedPoint::from_integer(0), x_to_y);
-                            total_amount_in += remaining_amount;
-                        }
-                        remaining_amount = TokenAmount(0);
-                    }
-                }
-                // set tick to limit (below if price is going down, because current tick should always be below price)
-                pool.current_tick_index = if x_to_y && is_enough_amount_to_cross {
-                    tick_index.checked_sub(pool.tick_spacing as i32).unwrap()
-                } else {
-                    tick_index
-                };
-            } else {
-                assert!(
-                    pool.current_tick_index
-                        .checked_rem(pool.tick_spacing.into())
-                        .unwrap()
-                        == 0,
-                    "tick not divisible by spacing"
-                );
-                pool.current_tick_index =
-                    get_tick_at_sqrt_price(result.next_price_sqrt, pool.tick_spacing);
-            }
         }
 
-        if total_amount_out.0 == 0 {
-            return Err(ErrorCode::NoGainSwap.into());
+        if pool.oracle_initialized {
+            record_oracle_price(ctx.remaining_accounts, &pool, pool_sqrt_price_before)?;
         }
 
         emit!(SwapEvent {
             swapper: *ctx.accounts.owner.key,
+            pool: pool_address,
             token_x: ctx.accounts.token_x.key(),
             token_y: ctx.accounts.token_y.key(),
             x_to_y,
+            amount_in: total_amount_in.0,
+            amount_out: total_amount_out.0,
             fee: total_fee.0,
+            referral_fee: total_amount_referral.0,
             price_before_swap: pool_sqrt_price_before,
             price_after_swap: pool.sqrt_price,
+            liquidity: pool.liquidity,
+            current_tick: pool.current_tick_index,
+            crossed_ticks,
+            remaining_amount: remaining_amount.0,
         });
 
         // Execute swap
@@ -599,6 +516,6 @@ impl<'info> Swap<'info> {
             return Err(ErrorCode::InvalidTokenProgram.into());
         }
 
-        Ok(())
+        Ok(remaining_amount.0)
     }
 }
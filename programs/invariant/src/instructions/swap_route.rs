@@ -0,0 +1,276 @@
+use crate::decimals::*;
+use crate::math::{MAX_SQRT_PRICE, MIN_SQRT_PRICE};
+use crate::structs::pool::Pool;
+use crate::structs::state::PAUSE_SWAP;
+use crate::structs::tickmap::Tickmap;
+use crate::util::{
+    check_not_paused, cross_tick_from_accounts, process_swap, record_oracle_price, transfer_tokens,
+    SwapSummary,
+};
+use crate::ErrorCode::{self, *};
+use crate::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+use anchor_spl::token_2022;
+use anchor_spl::token_interface::TokenInterface;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+use structs::SwapEvent;
+
+// pool, tickmap, reserve_in, reserve_out, token_out, token_out_program
+const HOP_ACCOUNTS: usize = 6;
+
+#[derive(Accounts)]
+pub struct SwapRoute<'info> {
+    #[account(seeds = [b"statev1".as_ref()], bump = state.load()?.bump)]
+    pub state: AccountLoader<'info, State>,
+    #[account(mint::token_program = token_in_program)]
+    pub token_in: Box<InterfaceAccount<'info, Mint>>,
+    #[account(mut,
+        constraint = account_in.mint == token_in.key() @ InvalidMint,
+        constraint = &account_in.owner == owner.key @ InvalidOwner,
+        token::token_program = token_in_program,
+    )]
+    pub account_in: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut,
+        constraint = &account_out.owner == owner.key @ InvalidOwner,
+    )]
+    pub account_out: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub owner: Signer<'info>,
+    #[account(constraint = &state.load()?.authority == program_authority.key @ InvalidAuthority)]
+    /// CHECK: Ignore
+    pub program_authority: AccountInfo<'info>,
+    #[account(constraint = token_in_program.key() == token::ID || token_in_program.key() == token_2022::ID)]
+    pub token_in_program: Interface<'info, TokenInterface>,
+}
+
+struct RouteHop<'info> {
+    pool: AccountLoader<'info, Pool>,
+    tickmap: AccountLoader<'info, Tickmap>,
+    reserve_in: AccountInfo<'info>,
+    reserve_out: AccountInfo<'info>,
+    token_out: InterfaceAccount<'info, Mint>,
+    token_out_program: AccountInfo<'info>,
+    x_to_y: bool,
+}
+
+impl<'info> SwapRoute<'info> {
+    fn load_route(
+        &self,
+        remaining_accounts: &'info [AccountInfo<'info>],
+        hops: usize,
+    ) -> Result<Vec<RouteHop<'info>>> {
+        require!(
+            hops > 0 && remaining_accounts.len() >= hops * HOP_ACCOUNTS,
+            ErrorCode::InvalidRoute
+        );
+
+        let mut route = Vec::with_capacity(hops);
+        let mut token_in = self.token_in.key();
+
+        for accounts in remaining_accounts.chunks(HOP_ACCOUNTS).take(hops) {
+            let pool_loader = AccountLoader::<'info, Pool>::try_from(&accounts[0])?;
+            let tickmap = AccountLoader::<'info, Tickmap>::try_from(&accounts[1])?;
+            let token_out = InterfaceAccount::<'info, Mint>::try_from(&accounts[4])?;
+            let token_out_program = accounts[5].clone();
+
+            let x_to_y = {
+                let pool = pool_loader.load()?;
+                require!(tickmap.key() == pool.tickmap, ErrorCode::InvalidTickmap);
+
+                let x_to_y = token_in == pool.token_x;
+                let (expected_in, expected_out, reserve_in, reserve_out) = match x_to_y {
+                    true => (
+                        pool.token_x,
+                        pool.token_y,
+                        pool.token_x_reserve,
+                        pool.token_y_reserve,
+                    ),
+                    false => (
+                        pool.token_y,
+                        pool.token_x,
+                        pool.token_y_reserve,
+                        pool.token_x_reserve,
+                    ),
+                };
+                require!(token_in == expected_in, ErrorCode::InvalidRoute);
+                require!(token_out.key() == expected_out, ErrorCode::InvalidMint);
+                require!(
+                    accounts[2].key() == reserve_in && accounts[3].key() == reserve_out,
+                    ErrorCode::InvalidTokenAccount
+                );
+                require!(
+                    token_out_program.key() == *token_out.to_account_info().owner
+                        && (token_out_program.key() == token::ID
+                            || token_out_program.key() == token_2022::ID),
+                    ErrorCode::InvalidTokenProgram
+                );
+                x_to_y
+            };
+
+            token_in = token_out.key();
+            route.push(RouteHop {
+                pool: pool_loader,
+                tickmap,
+                reserve_in: accounts[2].clone(),
+                reserve_out: accounts[3].clone(),
+                token_out,
+                token_out_program,
+                x_to_y,
+            });
+        }
+
+        require!(self.account_out.mint == token_in, ErrorCode::InvalidMint);
+
+        Ok(route)
+    }
+
+    pub fn handler(
+        ctx: Context<'_, '_, 'info, 'info, SwapRoute<'info>>,
+        hops: u8,
+        amount: u64,
+        by_amount_in: bool, // whether amount specifies input of the first hop or output of the last one
+        amount_limit: u64,  // minimum amount out or maximum amount in
+    ) -> Result<()> {
+        msg!("INVARIANT: SWAP ROUTE");
+        require!(amount != 0, ErrorCode::ZeroAmount);
+
+        let state = ctx.accounts.state.load()?;
+        let route = ctx
+            .accounts
+            .load_route(ctx.remaining_accounts, hops as usize)?;
+
+        // exact output has to be propagated from the last pool backwards
+        let order: Vec<usize> = match by_amount_in {
+            true => (0..route.len()).collect(),
+            false => (0..route.len()).rev().collect(),
+        };
+
+        let mut summaries: Vec<SwapSummary> =
+            route.iter().map(|_| SwapSummary::default()).collect();
+        let mut hop_amount = TokenAmount(amount);
+
+        for i in order {
+            let hop = &route[i];
+            let pool_address = hop.pool.key();
+            let mut pool = hop.pool.load_mut()?;
+            check_not_paused(&state, &pool, PAUSE_SWAP)?;
+            let tickmap = hop.tickmap.load()?;
+            let pool_sqrt_price_before = pool.sqrt_price;
+
+            let sqrt_price_limit = match hop.x_to_y {
+                true => Price::new(MIN_SQRT_PRICE),
+                false => Price::new(MAX_SQRT_PRICE),
+            };
+
+            let summary = process_swap(
+                &mut pool,
+                &tickmap,
+                hop.x_to_y,
+                hop_amount,
+                by_amount_in,
+                sqrt_price_limit,
+                false,
+                FixedPoint::from_integer(0),
+                get_current_timestamp(),
+                |pool, tick_index| {
+                    cross_tick_from_accounts(
+                        ctx.remaining_accounts,
+                        ctx.program_id,
+                        &pool_address,
+                        pool,
+                        tick_index,
+                    )
+                },
+            )?;
+
+            if summary.amount_out.is_zero() {
+                return Err(ErrorCode::NoGainSwap.into());
+            }
+
+            if pool.oracle_initialized {
+                record_oracle_price(ctx.remaining_accounts, &pool, pool_sqrt_price_before)?;
+            }
+
+            emit!(SwapEvent {
+                swapper: *ctx.accounts.owner.key,
+                pool: pool_address,
+                token_x: pool.token_x,
+                token_y: pool.token_y,
+                x_to_y: hop.x_to_y,
+                amount_in: summary.amount_in.0,
+                amount_out: summary.amount_out.0,
+                fee: summary.fee.0,
+                referral_fee: summary.referral_fee.0,
+                price_before_swap: pool_sqrt_price_before,
+                price_after_swap: pool.sqrt_price,
+                liquidity: pool.liquidity,
+                current_tick: pool.current_tick_index,
+                crossed_ticks: summary.crossed_ticks.clone(),
+                remaining_amount: summary.remaining_amount.0,
+            });
+
+            hop_amount = match by_amount_in {
+                true => summary.amount_out,
+                false => summary.amount_in,
+            };
+            summaries[i] = summary;
+        }
+
+        // every intermediate reserve has to receive exactly what the next pool accounted for,
+        // exact output swap can deliver less than requested when it stops in front of a tick
+        for (summary, next_summary) in summaries.iter().zip(summaries.iter().skip(1)) {
+            require!(
+                summary.amount_out == next_summary.amount_in,
+                ErrorCode::RouteAmountMismatch
+            );
+        }
+
+        let total_amount_in = summaries[0].amount_in;
+        let total_amount_out = summaries[summaries.len() - 1].amount_out;
+
+        if by_amount_in {
+            require!(
+                total_amount_out >= TokenAmount(amount_limit),
+                ErrorCode::AmountOutBelowMinimum
+            );
+        } else {
+            require!(
+                total_amount_in <= TokenAmount(amount_limit),
+                ErrorCode::AmountInAboveMaximum
+            );
+        }
+
+        // Execute swap
+        let signer: &[&[&[u8]]] = get_signer!(state.nonce);
+
+        transfer_tokens(
+            ctx.accounts.token_in_program.to_account_info(),
+            &ctx.accounts.token_in,
+            ctx.accounts.account_in.to_account_info(),
+            route[0].reserve_in.clone(),
+            ctx.accounts.owner.to_account_info(),
+            total_amount_in.0,
+            &[],
+        )?;
+
+        for (i, hop) in route.iter().enumerate() {
+            // intermediate tokens go straight to the reserve of the next pool
+            let to = match route.get(i + 1) {
+                Some(next_hop) => next_hop.reserve_in.clone(),
+                None => ctx.accounts.account_out.to_account_info(),
+            };
+
+            transfer_tokens(
+                hop.token_out_program.clone(),
+                &hop.token_out,
+                hop.reserve_out.clone(),
+                to,
+                ctx.accounts.program_authority.clone(),
+                summaries[i].amount_out.0,
+                signer,
+            )?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,276 @@
+use crate::structs::position::Position;
+use crate::structs::position_list::PositionList;
+use crate::structs::TokenizePositionEvent;
+use crate::ErrorCode::*;
+use crate::*;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::{invoke, invoke_signed};
+use anchor_lang::solana_program::system_program;
+use anchor_spl::associated_token::{self, AssociatedToken};
+use anchor_spl::token_2022::{
+    self,
+    spl_token_2022::{
+        extension::{metadata_pointer, ExtensionType},
+        instruction::AuthorityType,
+        state::Mint,
+    },
+    Token2022,
+};
+use spl_token_metadata_interface::state::{Field, TokenMetadata};
+
+pub const POSITION_TOKEN_NAME: &str = "Invariant Position";
+pub const POSITION_TOKEN_SYMBOL: &str = "INVP";
+
+// Space of position mint before token metadata is appended
+pub fn position_mint_space() -> usize {
+    ExtensionType::try_calculate_account_len::<Mint>(&[
+        ExtensionType::MetadataPointer,
+        ExtensionType::MintCloseAuthority,
+    ])
+    .unwrap()
+}
+
+// Holder of the token acts as the position owner: fees are claimed with `claim_tokenized_fee`,
+// liquidity is changed or removed after `redeem_position` moves the position to the holder list
+#[derive(Accounts)]
+#[instruction(index: u32)]
+pub struct TokenizePosition<'info> {
+    #[account(seeds = [b"statev1".as_ref()], bump = state.load()?.bump)]
+    pub state: AccountLoader<'info, State>,
+    #[account(mut,
+        seeds = [b"positionlistv1", owner.key().as_ref()],
+        bump = position_list.load()?.bump
+    )]
+    pub position_list: AccountLoader<'info, PositionList>,
+    #[account(mut,
+        seeds = [b"positionv1",
+        owner.key().as_ref(),
+        &index.to_le_bytes()],
+        bump = removed_position.load()?.bump,
+    )]
+    pub removed_position: AccountLoader<'info, Position>,
+    #[account(mut,
+        close = payer,
+        seeds = [b"positionv1",
+        owner.key().as_ref(),
+        &(position_list.load()?.head - 1).to_le_bytes()],
+        bump = last_position.load()?.bump
+    )]
+    pub last_position: AccountLoader<'info, Position>,
+    #[account(init,
+        seeds = [b"tokenizedpositionv1", position_mint.key().as_ref()],
+        bump, payer = payer,
+        space = Position::LEN
+    )]
+    pub tokenized_position: AccountLoader<'info, Position>,
+    #[account(init,
+        seeds = [b"positionmintv1", removed_position.load()?.pool.as_ref(), &removed_position.load()?.id.to_le_bytes()],
+        bump, payer = payer,
+        space = position_mint_space(),
+        owner = token_2022::ID
+    )]
+    /// CHECK: Initialized as a mint in the handler
+    pub position_mint: AccountInfo<'info>,
+    #[account(mut)]
+    /// CHECK: Created as associated token account of the owner
+    pub owner_token_account: AccountInfo<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub owner: Signer<'info>,
+    #[account(constraint = &state.load()?.authority == program_authority.key @ InvalidAuthority)]
+    /// CHECK: Ignore
+    pub program_authority: AccountInfo<'info>,
+    pub token_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub rent: Sysvar<'info, Rent>,
+    #[account(address = system_program::ID)]
+    /// CHECK: Ignore
+    pub system_program: AccountInfo<'info>,
+}
+
+impl<'info> TokenizePosition<'info> {
+    // Creates non-fungible mint with metadata describing the position
+    fn init_position_mint(&self, position: &Position, signer: &[&[&[u8]]]) -> Result<()> {
+        let mint = self.position_mint.key;
+        let authority = self.program_authority.key;
+
+        invoke(
+            &metadata_pointer::instruction::initialize(
+                &token_2022::ID,
+                mint,
+                Some(*authority),
+                Some(*mint),
+            )?,
+            std::slice::from_ref(&self.position_mint),
+        )?;
+        token_2022::initialize_mint_close_authority(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                token_2022::InitializeMintCloseAuthority {
+                    mint: self.position_mint.clone(),
+                },
+            ),
+            Some(authority),
+        )?;
+        token_2022::initialize_mint2(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                token_2022::InitializeMint2 {
+                    mint: self.position_mint.clone(),
+                },
+            ),
+            0,
+            authority,
+            None,
+        )?;
+
+        let additional_metadata = vec![
+            ("pool".to_string(), position.pool.to_string()),
+            ("id".to_string(), { position.id }.to_string()),
+            (
+                "lower_tick".to_string(),
+                { position.lower_tick_index }.to_string(),
+            ),
+            (
+                "upper_tick".to_string(),
+                { position.upper_tick_index }.to_string(),
+            ),
+        ];
+
+        // metadata is appended by the token program, so the rent has to be covered upfront
+        let metadata = TokenMetadata {
+            mint: *mint,
+            name: POSITION_TOKEN_NAME.to_string(),
+            symbol: POSITION_TOKEN_SYMBOL.to_string(),
+            additional_metadata: additional_metadata.clone(),
+            ..Default::default()
+        };
+        let space = position_mint_space() + metadata.tlv_size_of()?;
+        let lamports = self
+            .rent
+            .minimum_balance(space)
+            .saturating_sub(self.position_mint.lamports());
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                self.system_program.clone(),
+                anchor_lang::system_program::Transfer {
+                    from: self.payer.to_account_info(),
+                    to: self.position_mint.clone(),
+                },
+            ),
+            lamports,
+        )?;
+
+        let accounts = [self.position_mint.clone(), self.program_authority.clone()];
+        invoke_signed(
+            &spl_token_metadata_interface::instruction::initialize(
+                &token_2022::ID,
+                mint,
+                authority,
+                mint,
+                authority,
+                metadata.name,
+                metadata.symbol,
+                metadata.uri,
+            ),
+            &accounts,
+            signer,
+        )?;
+        for (key, value) in additional_metadata {
+            invoke_signed(
+                &spl_token_metadata_interface::instruction::update_field(
+                    &token_2022::ID,
+                    mint,
+                    authority,
+                    Field::Key(key),
+                    value,
+                ),
+                &accounts,
+                signer,
+            )?;
+        }
+
+        Ok(())
+    }
+
+    pub fn handler(&self, index: u32, bump: u8) -> Result<()> {
+        msg!("INVARIANT: TOKENIZE POSITION");
+
+        let state = self.state.load()?;
+        let mut position_list = self.position_list.load_mut()?;
+        let tokenized_position = &mut self.tokenized_position.load_init()?;
+        let removed_position = &mut self.removed_position.load_mut()?;
+        let signer: &[&[&[u8]]] = get_signer!(state.nonce);
+
+        // mint address marks the position as owned by the token holder
+        **tokenized_position = Position {
+            owner: self.position_mint.key(),
+            bump,
+            ..**removed_position
+        };
+
+        self.init_position_mint(tokenized_position, signer)?;
+
+        associated_token::create(CpiContext::new(
+            self.associated_token_program.to_account_info(),
+            associated_token::Create {
+                payer: self.payer.to_account_info(),
+                associated_token: self.owner_token_account.clone(),
+                authority: self.owner.to_account_info(),
+                mint: self.position_mint.clone(),
+                system_program: self.system_program.clone(),
+                token_program: self.token_program.to_account_info(),
+            },
+        ))?;
+        token_2022::mint_to(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                token_2022::MintTo {
+                    mint: self.position_mint.clone(),
+                    to: self.owner_token_account.clone(),
+                    authority: self.program_authority.clone(),
+                },
+            )
+            .with_signer(signer),
+            1,
+        )?;
+        // supply is fixed to a single token
+        token_2022::set_authority(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                token_2022::SetAuthority {
+                    account_or_mint: self.position_mint.clone(),
+                    current_authority: self.program_authority.clone(),
+                },
+            )
+            .with_signer(signer),
+            AuthorityType::MintTokens,
+            None,
+        )?;
+
+        emit!(TokenizePositionEvent {
+            owner: removed_position.owner,
+            pool: tokenized_position.pool,
+            id: tokenized_position.id,
+            mint: self.position_mint.key(),
+        });
+
+        // Remove tokenized position from the owner list
+        position_list.head = position_list.head.checked_sub(1).unwrap();
+
+        // when removed position is not the last one
+        if position_list.head != index {
+            let mut last_position = self.last_position.load_mut()?;
+
+            **removed_position = Position {
+                bump: removed_position.bump, // stay with the same bump
+                ..*last_position
+            };
+            *last_position = Default::default();
+        } else {
+            **removed_position = Default::default();
+        }
+
+        Ok(())
+    }
+}
@@ -1,5 +1,6 @@
 use crate::structs::position::Position;
 use crate::structs::position_list::PositionList;
+use crate::structs::TransferPositionOwnershipEvent;
 use crate::ErrorCode::{self, *};
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::system_program;
@@ -94,6 +95,13 @@ impl<'info> TransferPositionOwnership<'info> {
             };
         }
 
+        emit!(TransferPositionOwnershipEvent {
+            pool: new_position.pool,
+            id: new_position.id,
+            owner: removed_position.owner,
+            recipient: new_position.owner,
+        });
+
         // when removed position is not the last one
         if owner_list.head != index {
             let mut last_position = self.last_position.load_mut()?;
@@ -0,0 +1,109 @@
+use crate::structs::position::Position;
+use crate::structs::position_list::{PositionEntry, PositionList};
+use crate::structs::TransferPositionOwnershipEvent;
+use crate::ErrorCode::*;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::system_program;
+
+#[derive(Accounts)]
+#[instruction(index: u32)]
+pub struct TransferStablePosition<'info> {
+    #[account(mut,
+        seeds = [b"positionv2",
+        position.load()?.pool.as_ref(),
+        &position.load()?.id.to_le_bytes()],
+        bump = position.load()?.bump,
+        constraint = position.load()?.owner == owner.key() @ InvalidOwner
+    )]
+    pub position: AccountLoader<'info, Position>,
+    #[account(mut,
+        seeds = [b"stablepositionlistv1", owner.key().as_ref()],
+        bump = owner_list.load()?.bump
+    )]
+    pub owner_list: AccountLoader<'info, PositionList>,
+    #[account(mut,
+        seeds = [b"stablepositionlistv1", recipient.key().as_ref()],
+        bump = recipient_list.load()?.bump,
+        constraint = recipient_list.key() != owner_list.key() @ InvalidListOwner
+    )]
+    pub recipient_list: AccountLoader<'info, PositionList>,
+    #[account(mut,
+        seeds = [b"positionentryv1",
+        owner.key().as_ref(),
+        &index.to_le_bytes()],
+        bump = removed_entry.load()?.bump,
+        constraint = removed_entry.load()?.pool == position.load()?.pool @ InvalidPositionIndex,
+        constraint = removed_entry.load()?.id == position.load()?.id @ InvalidPositionIndex
+    )]
+    pub removed_entry: AccountLoader<'info, PositionEntry>,
+    #[account(mut,
+        close = payer,
+        seeds = [b"positionentryv1",
+        owner.key().as_ref(),
+        &(owner_list.load()?.head - 1).to_le_bytes()],
+        bump = last_entry.load()?.bump
+    )]
+    pub last_entry: AccountLoader<'info, PositionEntry>,
+    #[account(init,
+        seeds = [b"positionentryv1",
+        recipient.key().as_ref(),
+        &recipient_list.load()?.head.to_le_bytes()],
+        bump, payer = payer,
+        space = PositionEntry::LEN
+    )]
+    pub new_entry: AccountLoader<'info, PositionEntry>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub owner: Signer<'info>,
+    /// CHECK: Ignore
+    pub recipient: AccountInfo<'info>,
+    pub rent: Sysvar<'info, Rent>,
+    #[account(address = system_program::ID)]
+    /// CHECK: Ignore
+    pub system_program: AccountInfo<'info>,
+}
+
+impl<'info> TransferStablePosition<'info> {
+    pub fn handler(&self, index: u32, entry_bump: u8) -> Result<()> {
+        msg!("INVARIANT: TRANSFER STABLE POSITION");
+
+        let mut position = self.position.load_mut()?;
+        let mut owner_list = self.owner_list.load_mut()?;
+        let mut recipient_list = self.recipient_list.load_mut()?;
+        let mut new_entry = self.new_entry.load_init()?;
+
+        // address does not depend on the owner, so the account is reassigned in place
+        position.owner = *self.recipient.key;
+
+        // entry is moved from the owner list to the recipient list
+        *new_entry = PositionEntry {
+            pool: position.pool,
+            id: position.id,
+            bump: entry_bump,
+        };
+        recipient_list.head = recipient_list.head.checked_add(1).unwrap();
+        owner_list.head = owner_list.head.checked_sub(1).unwrap();
+
+        if owner_list.head != index {
+            let mut removed_entry = self.removed_entry.load_mut()?;
+            let mut last_entry = self.last_entry.load_mut()?;
+
+            *removed_entry = PositionEntry {
+                bump: removed_entry.bump,
+                ..*last_entry
+            };
+            *last_entry = Default::default();
+        } else {
+            *self.removed_entry.load_mut()? = Default::default();
+        }
+
+        emit!(TransferPositionOwnershipEvent {
+            pool: position.pool,
+            id: position.id,
+            owner: *self.owner.key,
+            recipient: position.owner,
+        });
+
+        Ok(())
+    }
+}
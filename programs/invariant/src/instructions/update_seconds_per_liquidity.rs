@@ -1,4 +1,3 @@
-use crate::math;
 use crate::structs::pool::Pool;
 use crate::structs::position::Position;
 use crate::structs::tick::Tick;
@@ -7,7 +6,6 @@ use crate::ErrorCode::*;
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::system_program;
 use anchor_spl::token_interface::Mint;
-use math::*;
 
 #[derive(Accounts)]
 #[instruction(lower_tick_index: i32, upper_tick_index: i32, index: i32)]
@@ -59,9 +57,13 @@ impl<'info> UpdateSecondsPerLiquidity<'info> {
         let upper_tick = *self.upper_tick.load()?;
         let current_time = get_current_timestamp();
         let position = &mut self.position.load_mut()?;
-        position.seconds_per_liquidity_inside =
-            calculate_seconds_per_liquidity_inside(lower_tick, upper_tick, pool, current_time);
-        position.last_slot = get_current_slot();
+        position.update_seconds_per_liquidity(
+            pool,
+            lower_tick,
+            upper_tick,
+            current_time,
+            get_current_slot(),
+        );
 
         Ok(())
     }
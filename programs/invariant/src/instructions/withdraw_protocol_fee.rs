@@ -1,6 +1,7 @@
 use crate::interfaces::send_tokens::SendTokens;
 use crate::structs::pool::Pool;
 use crate::structs::state::State;
+use crate::structs::WithdrawProtocolFeeEvent;
 use crate::ErrorCode::{self, *};
 use crate::SEED;
 use crate::*;
@@ -46,7 +47,7 @@ pub struct WithdrawProtocolFee<'info> {
         token::token_program = token_y_program,
     )]
     pub reserve_y: InterfaceAccount<'info, TokenAccount>,
-    #[account(constraint = &pool.load()?.fee_receiver == authority.key @ InvalidAuthority)]
+    #[account(constraint = state.load()?.is_fee_receiver(&*pool.load()?, authority.key) @ InvalidAuthority)]
     pub authority: Signer<'info>,
     #[account(constraint = &state.load()?.authority == program_authority.key @ InvalidAuthority)]
     /// CHECK: Ignore
@@ -107,40 +108,47 @@ impl<'info> SendTokens<'info> for WithdrawProtocolFee<'info> {
 }
 
 impl<'info> WithdrawProtocolFee<'info> {
-    pub fn handler(&self) -> Result<()> {
+    pub fn handler(&self, amount_x: u64, amount_y: u64) -> Result<()> {
         msg!("INVARIANT: WITHDRAW PROTOCOL FEE");
 
         let state = self.state.load()?;
         let mut pool = self.pool.load_mut()?;
+        require!(
+            amount_x <= pool.fee_protocol_token_x && amount_y <= pool.fee_protocol_token_y,
+            ErrorCode::InsufficientProtocolFee
+        );
 
         let signer: &[&[&[u8]]] = get_signer!(state.nonce);
 
         match self.token_x_program.key() {
             token_2022::ID => token_2022::transfer_checked(
                 self.send_x_2022().with_signer(signer),
-                pool.fee_protocol_token_x,
+                amount_x,
                 self.token_x.decimals,
             )?,
-            token::ID => {
-                token::transfer(self.send_x().with_signer(signer), pool.fee_protocol_token_x)?
-            }
+            token::ID => token::transfer(self.send_x().with_signer(signer), amount_x)?,
             _ => return Err(ErrorCode::InvalidTokenProgram.into()),
         };
 
         match self.token_y_program.key() {
             token_2022::ID => token_2022::transfer_checked(
                 self.send_y_2022().with_signer(signer),
-                pool.fee_protocol_token_y,
+                amount_y,
                 self.token_y.decimals,
             )?,
-            token::ID => {
-                token::transfer(self.send_y().with_signer(signer), pool.fee_protocol_token_y)?
-            }
+            token::ID => token::transfer(self.send_y().with_signer(signer), amount_y)?,
             _ => return Err(ErrorCode::InvalidTokenProgram.into()),
         };
 
-        pool.fee_protocol_token_x = 0;
-        pool.fee_protocol_token_y = 0;
+        emit!(WithdrawProtocolFeeEvent {
+            pool: self.pool.key(),
+            fee_receiver: pool.fee_receiver,
+            amount_x,
+            amount_y,
+        });
+
+        pool.fee_protocol_token_x = pool.fee_protocol_token_x.checked_sub(amount_x).unwrap();
+        pool.fee_protocol_token_y = pool.fee_protocol_token_y.checked_sub(amount_y).unwrap();
 
         Ok(())
     }
@@ -17,7 +17,7 @@ use crate::decimals::*;
 use errors::ErrorCode;
 use instructions::*;
 use math::*;
-use structs::{Pool, State};
+use structs::{Role, State};
 use util::*;
 
 declare_id!("iNvTyprs4TX8m6UeUEkeqDFjAL9zRCRWcexK9Sd4WEU");
@@ -31,7 +31,20 @@ pub mod invariant {
     pub fn create_state(ctx: Context<CreateState>, nonce: u8) -> Result<()> {
         instructions::create_state::handler(ctx, nonce)
     }
+    pub fn propose_admin(ctx: Context<ProposeAdmin>) -> Result<()> {
+        ctx.accounts.handler()
+    }
+
+    pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+        ctx.accounts.handler()
+    }
+
     #[access_control(admin(&ctx.accounts.state, &ctx.accounts.admin))]
+    pub fn set_role(ctx: Context<SetRole>, role: Role) -> Result<()> {
+        ctx.accounts.handler(role)
+    }
+
+    #[access_control(role(&ctx.accounts.state, &ctx.accounts.admin, Role::FeeTierManager))]
     pub fn create_fee_tier(
         ctx: Context<CreateFeeTier>,
         fee: u128,
@@ -40,6 +53,21 @@ pub mod invariant {
         ctx.accounts.handler(fee, tick_spacing, ctx.bumps.fee_tier)
     }
 
+    #[access_control(role(&ctx.accounts.state, &ctx.accounts.admin, Role::FeeTierManager))]
+    pub fn change_fee_tier(
+        ctx: Context<ChangeFeeTier>,
+        disabled: bool,
+        min_fee: FixedPoint,
+        max_fee: FixedPoint,
+    ) -> Result<()> {
+        ctx.accounts.handler(disabled, min_fee, max_fee)
+    }
+
+    #[access_control(role(&ctx.accounts.state, &ctx.accounts.admin, Role::FeeTierManager))]
+    pub fn change_pool_fee(ctx: Context<ChangePoolFee>, fee: FixedPoint) -> Result<()> {
+        ctx.accounts.handler(fee)
+    }
+
     pub fn create_pool(ctx: Context<CreatePool>, init_tick: i32) -> Result<()> {
         ctx.accounts.handler_by_tick(init_tick, ctx.bumps.pool)
     }
@@ -53,20 +81,89 @@ pub mod invariant {
         ctx.accounts.handler()
     }
 
+    pub fn close_pool(ctx: Context<ClosePool>) -> Result<()> {
+        ctx.accounts.handler()
+    }
+
+    #[allow(clippy::too_many_arguments)]
     pub fn swap<'info>(
         ctx: Context<'_, '_, 'info, 'info, Swap<'info>>,
         x_to_y: bool,
         amount: u64,
         by_amount_in: bool, // whether amount specifies input or output
         sqrt_price_limit: u128,
+        min_amount_out: Option<u64>,
+        max_amount_in: Option<u64>,
+        partial_fill: bool,
+    ) -> Result<u64> {
+        Swap::handler(
+            ctx,
+            x_to_y,
+            amount,
+            by_amount_in,
+            sqrt_price_limit,
+            min_amount_out,
+            max_amount_in,
+            partial_fill,
+        )
+    }
+
+    pub fn swap_route<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SwapRoute<'info>>,
+        hops: u8,
+        amount: u64,
+        by_amount_in: bool,
+        amount_limit: u64,
+    ) -> Result<()> {
+        SwapRoute::handler(ctx, hops, amount, by_amount_in, amount_limit)
+    }
+
+    pub fn quote_swap<'info>(
+        ctx: Context<'_, '_, 'info, 'info, QuoteSwap<'info>>,
+        x_to_y: bool,
+        amount: u64,
+        by_amount_in: bool,
+        sqrt_price_limit: u128,
+        partial_fill: bool,
+    ) -> Result<QuoteResult> {
+        QuoteSwap::handler(
+            ctx,
+            x_to_y,
+            amount,
+            by_amount_in,
+            sqrt_price_limit,
+            partial_fill,
+        )
+    }
+
+    pub fn flash_swap<'info>(
+        ctx: Context<'_, '_, 'info, 'info, FlashSwap<'info>>,
+        x_to_y: bool,
+        amount: u64,
+        by_amount_in: bool,
+        sqrt_price_limit: u128,
+        data: Vec<u8>,
+    ) -> Result<()> {
+        FlashSwap::handler(ctx, x_to_y, amount, by_amount_in, sqrt_price_limit, data)
+    }
+
+    pub fn flash_loan<'info>(
+        ctx: Context<'_, '_, 'info, 'info, FlashLoan<'info>>,
+        amount_x: u64,
+        amount_y: u64,
+        data: Vec<u8>,
     ) -> Result<()> {
-        Swap::handler(ctx, x_to_y, amount, by_amount_in, sqrt_price_limit)
+        FlashLoan::handler(ctx, amount_x, amount_y, data)
     }
 
     pub fn initialize_oracle(ctx: Context<InitializeOracle>) -> Result<()> {
         ctx.accounts.handler()
     }
 
+    pub fn get_twap(ctx: Context<GetTwap>, window: u64) -> Result<Price> {
+        ctx.accounts.handler(window)
+    }
+
     pub fn create_tick(ctx: Context<CreateTick>, index: i32) -> Result<()> {
         ctx.accounts.handler(index, ctx.bumps.tick)
     }
@@ -75,14 +172,28 @@ pub mod invariant {
         ctx.accounts.handler(ctx.bumps.position_list)
     }
 
+    pub fn close_position_list(ctx: Context<ClosePositionList>) -> Result<()> {
+        ctx.accounts.handler()
+    }
+
+    pub fn create_stable_position_list(ctx: Context<CreateStablePositionList>) -> Result<()> {
+        ctx.accounts.handler(ctx.bumps.position_list)
+    }
+
     pub fn create_position(
         ctx: Context<CreatePosition>,
-        _lower_tick_index: i32,
-        _upper_tick_index: i32,
+        lower_tick_index: i32,
+        upper_tick_index: i32,
         liquidity_delta: Liquidity,
         slippage_limit_lower: Price,
         slippage_limit_upper: Price,
     ) -> Result<()> {
+        ctx.accounts.init_ticks(
+            lower_tick_index,
+            upper_tick_index,
+            ctx.bumps.lower_tick,
+            ctx.bumps.upper_tick,
+        )?;
         ctx.accounts.handler(
             liquidity_delta,
             slippage_limit_lower,
@@ -91,6 +202,33 @@ pub mod invariant {
         )
     }
 
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_position_by_amounts(
+        ctx: Context<CreatePosition>,
+        lower_tick_index: i32,
+        upper_tick_index: i32,
+        max_amount_x: u64,
+        max_amount_y: u64,
+        min_liquidity: Liquidity,
+        slippage_limit_lower: Price,
+        slippage_limit_upper: Price,
+    ) -> Result<()> {
+        ctx.accounts.init_ticks(
+            lower_tick_index,
+            upper_tick_index,
+            ctx.bumps.lower_tick,
+            ctx.bumps.upper_tick,
+        )?;
+        ctx.accounts.handler_by_amounts(
+            TokenAmount(max_amount_x),
+            TokenAmount(max_amount_y),
+            min_liquidity,
+            slippage_limit_lower,
+            slippage_limit_upper,
+            ctx.bumps.position,
+        )
+    }
+
     pub fn remove_position(
         ctx: Context<RemovePosition>,
         index: u32,
@@ -101,21 +239,114 @@ pub mod invariant {
             .handler(index, lower_tick_index, upper_tick_index)
     }
 
-    // pub fn change_liquidity(
-    //     ctx: Context<ChangeLiquidity>,
-    //     _index: u32,
-    //     liquidity_delta: Liquidity,
-    //     add_liquidity: bool,
-    //     slippage_limit_lower: Price,
-    //     slippage_limit_upper: Price,
-    // ) -> Result<()> {
-    //     ctx.accounts.handler(
-    //         liquidity_delta,
-    //         add_liquidity,
-    //         slippage_limit_lower,
-    //         slippage_limit_upper,
-    //     )
-    // }
+    pub fn create_stable_position(
+        ctx: Context<CreateStablePosition>,
+        lower_tick_index: i32,
+        upper_tick_index: i32,
+        liquidity_delta: Liquidity,
+        slippage_limit_lower: Price,
+        slippage_limit_upper: Price,
+    ) -> Result<()> {
+        ctx.accounts.init_ticks(
+            lower_tick_index,
+            upper_tick_index,
+            ctx.bumps.lower_tick,
+            ctx.bumps.upper_tick,
+        )?;
+        ctx.accounts.handler(
+            liquidity_delta,
+            slippage_limit_lower,
+            slippage_limit_upper,
+            ctx.bumps.position,
+            ctx.bumps.position_entry,
+        )
+    }
+
+    pub fn remove_stable_position(
+        ctx: Context<RemoveStablePosition>,
+        index: u32,
+        lower_tick_index: i32,
+        upper_tick_index: i32,
+    ) -> Result<()> {
+        ctx.accounts
+            .handler(index, lower_tick_index, upper_tick_index)
+    }
+
+    pub fn transfer_stable_position(
+        ctx: Context<TransferStablePosition>,
+        index: u32,
+    ) -> Result<()> {
+        ctx.accounts.handler(index, ctx.bumps.new_entry)
+    }
+
+    pub fn migrate_position(ctx: Context<MigratePosition>, index: u32) -> Result<()> {
+        ctx.accounts
+            .handler(index, ctx.bumps.stable_position, ctx.bumps.position_entry)
+    }
+
+    pub fn migrate_pool(ctx: Context<MigratePool>) -> Result<()> {
+        ctx.accounts.handler()
+    }
+
+    pub fn migrate_state(ctx: Context<MigrateState>) -> Result<()> {
+        ctx.accounts.handler()
+    }
+
+    pub fn migrate_fee_tier(ctx: Context<MigrateFeeTier>) -> Result<()> {
+        ctx.accounts.handler()
+    }
+
+    pub fn create_range_order(
+        ctx: Context<CreateRangeOrder>,
+        _lower_tick_index: i32,
+        liquidity_delta: Liquidity,
+    ) -> Result<()> {
+        ctx.accounts.handler(liquidity_delta, ctx.bumps.range_order)
+    }
+
+    pub fn settle_range_order(ctx: Context<SettleRangeOrder>) -> Result<()> {
+        ctx.accounts.handler()
+    }
+
+    pub fn claim_range_order(ctx: Context<ClaimRangeOrder>) -> Result<()> {
+        ctx.accounts.handler()
+    }
+
+    pub fn increase_liquidity(
+        ctx: Context<ChangeLiquidity>,
+        liquidity_delta: Liquidity,
+        slippage_limit_lower: Price,
+        slippage_limit_upper: Price,
+        max_amount_x: u64,
+        max_amount_y: u64,
+    ) -> Result<()> {
+        ctx.accounts.handler(
+            liquidity_delta,
+            true,
+            slippage_limit_lower,
+            slippage_limit_upper,
+            max_amount_x,
+            max_amount_y,
+        )
+    }
+
+    pub fn decrease_liquidity(
+        ctx: Context<ChangeLiquidity>,
+        liquidity_delta: Liquidity,
+        slippage_limit_lower: Price,
+        slippage_limit_upper: Price,
+        min_amount_x: u64,
+        min_amount_y: u64,
+    ) -> Result<()> {
+        ctx.accounts.handler(
+            liquidity_delta,
+            false,
+            slippage_limit_lower,
+            slippage_limit_upper,
+            min_amount_x,
+            min_amount_y,
+        )
+    }
 
     pub fn transfer_position_ownership(
         ctx: Context<TransferPositionOwnership>,
@@ -124,6 +355,22 @@ pub mod invariant {
         ctx.accounts.handler(index, ctx.bumps.new_position)
     }
 
+    pub fn tokenize_position(ctx: Context<TokenizePosition>, index: u32) -> Result<()> {
+        ctx.accounts.handler(index, ctx.bumps.tokenized_position)
+    }
+
+    pub fn redeem_position(ctx: Context<RedeemPosition>) -> Result<()> {
+        ctx.accounts.handler(ctx.bumps.position)
+    }
+
+    pub fn claim_tokenized_fee(
+        ctx: Context<ClaimTokenizedFee>,
+        _lower_tick_index: i32,
+        _upper_tick_index: i32,
+    ) -> Result<()> {
+        ctx.accounts.handler()
+    }
+
     pub fn claim_fee(
         ctx: Context<ClaimFee>,
         _index: u32,
@@ -133,6 +380,28 @@ pub mod invariant {
         ctx.accounts.handler()
     }
 
+    pub fn compound_fees(ctx: Context<CompoundFees>) -> Result<()> {
+        ctx.accounts.handler()
+    }
+
+    pub fn approve_position_operator(
+        ctx: Context<ApprovePositionOperator>,
+        permissions: u8,
+    ) -> Result<()> {
+        ctx.accounts
+            .handler(permissions, ctx.bumps.position_operator)
+    }
+
+    pub fn revoke_position_operator(ctx: Context<RevokePositionOperator>) -> Result<()> {
+        ctx.accounts.handler()
+    }
+
+    pub fn claim_fee_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ClaimFeeBatch<'info>>,
+    ) -> Result<()> {
+        ClaimFeeBatch::handler(ctx)
+    }
+
     pub fn update_seconds_per_liquidity(
         ctx: Context<UpdateSecondsPerLiquidity>,
         _lower_tick_index: i32,
@@ -142,12 +411,42 @@ pub mod invariant {
         ctx.accounts.handler()
     }
 
-    #[access_control(receiver(&ctx.accounts.pool, &ctx.accounts.authority))]
-    pub fn withdraw_protocol_fee(ctx: Context<WithdrawProtocolFee>) -> Result<()> {
-        ctx.accounts.handler()
+    pub fn withdraw_protocol_fee(
+        ctx: Context<WithdrawProtocolFee>,
+        amount_x: u64,
+        amount_y: u64,
+    ) -> Result<()> {
+        ctx.accounts.handler(amount_x, amount_y)
     }
 
-    #[access_control(receiver(&ctx.accounts.pool, &ctx.accounts.admin))]
+    pub fn distribute_protocol_fee<'info>(
+        ctx: Context<'_, '_, 'info, 'info, DistributeProtocolFee<'info>>,
+        amount_x: u64,
+        amount_y: u64,
+    ) -> Result<()> {
+        DistributeProtocolFee::handler(ctx, amount_x, amount_y)
+    }
+
+    #[access_control(role(&ctx.accounts.state, &ctx.accounts.admin, Role::ProtocolFeeManager))]
+    pub fn create_fee_split(
+        ctx: Context<CreateFeeSplit>,
+        recipients: Vec<Pubkey>,
+        shares: Vec<u16>,
+    ) -> Result<()> {
+        ctx.accounts
+            .handler(recipients, shares, ctx.bumps.fee_split)
+    }
+
+    #[access_control(role(&ctx.accounts.state, &ctx.accounts.admin, Role::ProtocolFeeManager))]
+    pub fn change_fee_split(
+        ctx: Context<ChangeFeeSplit>,
+        recipients: Vec<Pubkey>,
+        shares: Vec<u16>,
+    ) -> Result<()> {
+        ctx.accounts.handler(recipients, shares)
+    }
+
+    #[access_control(role(&ctx.accounts.state, &ctx.accounts.admin, Role::ProtocolFeeManager))]
     pub fn change_protocol_fee(
         ctx: Context<ChangeProtocolFee>,
         protocol_fee: FixedPoint,
@@ -155,10 +454,67 @@ pub mod invariant {
         ctx.accounts.handler(protocol_fee)
     }
 
+    #[access_control(role(&ctx.accounts.state, &ctx.accounts.pauser, Role::Pauser))]
+    pub fn change_pause(ctx: Context<ChangePause>, paused: u8) -> Result<()> {
+        ctx.accounts.handler(paused)
+    }
+
+    #[access_control(role(&ctx.accounts.state, &ctx.accounts.pauser, Role::Pauser))]
+    pub fn change_pool_pause(ctx: Context<ChangePoolPause>, paused: u8) -> Result<()> {
+        ctx.accounts.handler(paused)
+    }
+
     #[access_control(admin(&ctx.accounts.state, &ctx.accounts.admin))]
+    pub fn change_dynamic_fee(
+        ctx: Context<ChangeDynamicFee>,
+        enabled: bool,
+        volatility_fee_per_tick: FixedPoint,
+        max_volatility_fee: FixedPoint,
+        volatility_half_life: u64,
+    ) -> Result<()> {
+        ctx.accounts.handler(
+            enabled,
+            volatility_fee_per_tick,
+            max_volatility_fee,
+            volatility_half_life,
+        )
+    }
+
+    #[access_control(role(&ctx.accounts.state, &ctx.accounts.admin, Role::ProtocolFeeManager))]
+    pub fn change_pool_defaults(
+        ctx: Context<ChangePoolDefaults>,
+        protocol_fee: FixedPoint,
+    ) -> Result<()> {
+        ctx.accounts.handler(protocol_fee)
+    }
+
+    #[access_control(role(&ctx.accounts.state, &ctx.accounts.admin, Role::ProtocolFeeManager))]
+    pub fn change_fee_tier_pool_defaults(
+        ctx: Context<ChangeFeeTierPoolDefaults>,
+        protocol_fee: Option<FixedPoint>,
+        fee_receiver: Option<Pubkey>,
+    ) -> Result<()> {
+        ctx.accounts.handler(protocol_fee, fee_receiver)
+    }
+
+    #[access_control(role(&ctx.accounts.state, &ctx.accounts.admin, Role::ProtocolFeeManager))]
     pub fn change_fee_receiver(ctx: Context<ChangeFeeReceiver>) -> Result<()> {
         ctx.accounts.handler()
     }
+
+    #[access_control(admin(&ctx.accounts.state, &ctx.accounts.admin))]
+    pub fn create_referrer(ctx: Context<CreateReferrer>, fee_share: FixedPoint) -> Result<()> {
+        ctx.accounts.handler(fee_share, ctx.bumps.referrer)
+    }
+
+    #[access_control(admin(&ctx.accounts.state, &ctx.accounts.admin))]
+    pub fn change_referrer(
+        ctx: Context<ChangeReferrer>,
+        fee_share: FixedPoint,
+        enabled: bool,
+    ) -> Result<()> {
+        ctx.accounts.handler(fee_share, enabled)
+    }
 }
 
 fn admin(state_loader: &AccountLoader<State>, signer: &AccountInfo) -> Result<()> {
@@ -167,8 +523,8 @@ fn admin(state_loader: &AccountLoader<State>, signer: &AccountInfo) -> Result<()
     Ok(())
 }
 
-fn receiver(pool_loader: &AccountLoader<Pool>, signer: &AccountInfo) -> Result<()> {
-    let pool = pool_loader.load()?;
-    require!(signer.key.eq(&pool.fee_receiver), ErrorCode::Unauthorized);
+fn role(state_loader: &AccountLoader<State>, signer: &AccountInfo, role: Role) -> Result<()> {
+    let state = state_loader.load()?;
+    require!(state.has_role(signer.key, role), ErrorCode::Unauthorized);
     Ok(())
 }
@@ -1,54 +1,162 @@
-pub mod whitelist {
-    use anchor_lang::prelude::Pubkey;
-    #[cfg(feature = "jupiter")]
-    use std::str::FromStr;
-
-    #[allow(unreachable_code)]
-    pub fn contains_owner(_ref_owner: Pubkey) -> bool {
-        #[cfg(feature = "jupiter")]
-        {
-            let jup2 = Pubkey::from_str(&"BUX7s2ef2htTGb2KKoPHWkmzxPj4nTWMWRgs5CSbQxf9").unwrap();
-            return jup2 == _ref_owner;
-        }
+use crate::decimals::FixedPoint;
+use crate::structs::Referrer;
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+use anchor_spl::token_2022;
+use anchor_spl::token_interface::TokenAccount;
 
-        #[cfg(feature = "all")]
-        return true;
+// Finds a referral token account of the given mint whose owner has an enabled referrer
+pub fn find_referral<'info>(
+    remaining_accounts: &'info [AccountInfo<'info>],
+    mint: Pubkey,
+) -> Option<(&'info AccountInfo<'info>, FixedPoint)> {
+    remaining_accounts
+        .iter()
+        .filter(|account| *account.owner == token::ID || *account.owner == token_2022::ID)
+        .find_map(|account| {
+            let token = InterfaceAccount::<'info, TokenAccount>::try_from(account).ok()?;
+            if token.mint != mint {
+                return None;
+            }
 
-        #[cfg(feature = "none")]
-        return false;
-    }
+            let referrer = find_referrer(remaining_accounts, token.owner)?;
+            match referrer.enabled {
+                true => Some((account, referrer.fee_share)),
+                false => None,
+            }
+        })
+}
+
+// Referrer accounts can only be created by admin, so owner field is enough to identify them
+fn find_referrer<'info>(
+    remaining_accounts: &'info [AccountInfo<'info>],
+    owner: Pubkey,
+) -> Option<Referrer> {
+    remaining_accounts.iter().find_map(|account| {
+        let loader = AccountLoader::<'info, Referrer>::try_from(account).ok()?;
+        let referrer = *loader.load().ok()?;
+        match referrer.owner == owner {
+            true => Some(referrer),
+            false => None,
+        }
+    })
 }
 
 #[cfg(test)]
 mod tests {
-    use std::str::FromStr;
+    use super::*;
+    use crate::decimals::*;
+    use anchor_lang::solana_program::program_pack::Pack;
+    use anchor_lang::Discriminator;
+    use anchor_spl::token::spl_token;
 
-    use anchor_lang::prelude::Pubkey;
+    fn leak_account(owner: Pubkey, data: Vec<u8>) -> AccountInfo<'static> {
+        AccountInfo::new(
+            Box::leak(Box::new(Pubkey::new_unique())),
+            false,
+            false,
+            Box::leak(Box::new(0)),
+            Box::leak(data.into_boxed_slice()),
+            Box::leak(Box::new(owner)),
+            false,
+            0,
+        )
+    }
 
-    use super::whitelist::contains_owner;
+    fn token_account(mint: Pubkey, owner: Pubkey) -> AccountInfo<'static> {
+        let mut data = vec![0u8; spl_token::state::Account::LEN];
+        spl_token::state::Account {
+            mint,
+            owner,
+            state: spl_token::state::AccountState::Initialized,
+            ..Default::default()
+        }
+        .pack_into_slice(&mut data);
+        leak_account(token::ID, data)
+    }
 
-    #[test]
-    #[cfg_attr(not(feature = "all"), ignore)]
-    fn test_is_referral_for_all() {
-        let is_contains = contains_owner(Pubkey::default());
-        assert!(is_contains);
+    fn referrer_account(
+        owner: Pubkey,
+        fee_share: FixedPoint,
+        enabled: bool,
+    ) -> AccountInfo<'static> {
+        let referrer = Referrer {
+            owner,
+            fee_share,
+            enabled,
+            bump: 0,
+        };
+        let mut data = Referrer::discriminator().to_vec();
+        data.extend_from_slice(bytemuck::bytes_of(&referrer));
+        leak_account(crate::ID, data)
     }
 
-    #[test]
-    #[cfg_attr(any(feature = "jupiter", feature = "all"), ignore)]
-    fn test_is_referral_for_none() {
-        let pubkey = Pubkey::from_str(&"7CKCpJWFRu1WAWfCvDkwyniP6JGpSyMf4Bkxk2U6v2Ej").unwrap();
-        assert!(!contains_owner(pubkey));
-        let jup2 = Pubkey::from_str(&"BUX7s2ef2htTGb2KKoPHWkmzxPj4nTWMWRgs5CSbQxf9").unwrap();
-        assert!(!contains_owner(jup2));
+    fn leak_accounts(accounts: Vec<AccountInfo<'static>>) -> &'static [AccountInfo<'static>] {
+        Box::leak(accounts.into_boxed_slice())
     }
 
     #[test]
-    #[cfg_attr(not(feature = "jupiter"), ignore)]
-    fn test_is_referral_for_jupiter() {
-        let pubkey = Pubkey::from_str(&"7CKCpJWFRu1WAWfCvDkwyniP6JGpSyMf4Bkxk2U6v2Ej").unwrap();
-        assert!(!contains_owner(pubkey));
-        let jup2 = Pubkey::from_str(&"BUX7s2ef2htTGb2KKoPHWkmzxPj4nTWMWRgs5CSbQxf9").unwrap();
-        assert!(contains_owner(jup2));
+    fn test_find_referral() {
+        let mint = Pubkey::new_unique();
+        let ref_owner = Pubkey::new_unique();
+        let fee_share = FixedPoint::from_scale(2, 1);
+
+        // enabled referrer
+        {
+            let accounts = leak_accounts(vec![
+                token_account(mint, ref_owner),
+                referrer_account(ref_owner, fee_share, true),
+            ]);
+            let (account, share) = find_referral(accounts, mint).unwrap();
+            assert_eq!(account.key, accounts[0].key);
+            assert_eq!(share, fee_share);
+        }
+        // referrer passed before the token account
+        {
+            let accounts = leak_accounts(vec![
+                referrer_account(ref_owner, fee_share, true),
+                token_account(mint, ref_owner),
+            ]);
+            let (account, _) = find_referral(accounts, mint).unwrap();
+            assert_eq!(account.key, accounts[1].key);
+        }
+        // disabled referrer
+        {
+            let accounts = leak_accounts(vec![
+                token_account(mint, ref_owner),
+                referrer_account(ref_owner, fee_share, false),
+            ]);
+            assert!(find_referral(accounts, mint).is_none());
+        }
+        // missing referrer
+        {
+            let accounts = leak_accounts(vec![token_account(mint, ref_owner)]);
+            assert!(find_referral(accounts, mint).is_none());
+        }
+        // referrer of a different owner
+        {
+            let accounts = leak_accounts(vec![
+                token_account(mint, ref_owner),
+                referrer_account(Pubkey::new_unique(), fee_share, true),
+            ]);
+            assert!(find_referral(accounts, mint).is_none());
+        }
+        // token account of a different mint
+        {
+            let accounts = leak_accounts(vec![
+                token_account(Pubkey::new_unique(), ref_owner),
+                referrer_account(ref_owner, fee_share, true),
+            ]);
+            assert!(find_referral(accounts, mint).is_none());
+        }
+        // referrer data owned by another program
+        {
+            let forged = referrer_account(ref_owner, fee_share, true);
+            let accounts = leak_accounts(vec![
+                token_account(mint, ref_owner),
+                leak_account(Pubkey::new_unique(), forged.data.borrow().to_vec()),
+            ]);
+            assert!(find_referral(accounts, mint).is_none());
+        }
     }
 }
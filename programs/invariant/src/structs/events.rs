@@ -1,4 +1,5 @@
-use crate::{Liquidity, Price, SecondsPerLiquidity};
+use crate::structs::Role;
+use crate::{FixedPoint, Liquidity, Price, SecondsPerLiquidity};
 use anchor_lang::prelude::*;
 
 #[event]
@@ -28,6 +29,18 @@ pub struct RemovePositionEvent {
     pub current_timestamp: u64,
 }
 
+#[event]
+pub struct ChangeLiquidityEvent {
+    pub owner: Pubkey,
+    pub pool: Pubkey,
+    pub id: u128,
+    pub liquidity_delta: Liquidity,
+    pub add_liquidity: bool,
+    pub amount_x: u64,
+    pub amount_y: u64,
+    pub current_timestamp: u64,
+}
+
 #[event]
 pub struct SwapEvent {
     pub swapper: Pubkey,
@@ -37,4 +50,286 @@ pub struct SwapEvent {
     pub fee: u64,
     pub price_before_swap: Price,
     pub price_after_swap: Price,
+    // appended after the original fields so existing decoders keep working
+    pub pool: Pubkey,
+    pub amount_in: u64,
+    pub amount_out: u64,
+    pub referral_fee: u64,
+    pub liquidity: Liquidity,
+    pub current_tick: i32,
+    pub crossed_ticks: Vec<i32>,
+    pub remaining_amount: u64,
+}
+
+#[event]
+pub struct CrossTickEvent {
+    pub pool: Pubkey,
+    pub index: i32,
+    pub liquidity: Liquidity, // pool liquidity after crossing
+    pub fee_growth_outside_x: u128,
+    pub fee_growth_outside_y: u128,
+    pub seconds_per_liquidity_outside: SecondsPerLiquidity,
+    pub current_timestamp: u64,
+}
+
+#[event]
+pub struct ClaimFeeEvent {
+    pub owner: Pubkey,
+    pub pool: Pubkey,
+    pub id: u128,
+    pub amount_x: u64,
+    pub amount_y: u64,
+    pub current_timestamp: u64,
+}
+
+#[event]
+pub struct ClaimRangeOrderEvent {
+    pub owner: Pubkey,
+    pub pool: Pubkey,
+    pub id: u128,
+    pub amount_x: u64,
+    pub amount_y: u64,
+}
+
+#[event]
+pub struct WithdrawProtocolFeeEvent {
+    pub pool: Pubkey,
+    pub fee_receiver: Pubkey,
+    pub amount_x: u64,
+    pub amount_y: u64,
+}
+
+#[event]
+pub struct DistributeProtocolFeeEvent {
+    pub pool: Pubkey,
+    pub recipient: Pubkey,
+    pub share: u16,
+    pub amount_x: u64,
+    pub amount_y: u64,
+}
+
+#[event]
+pub struct ChangeFeeSplitEvent {
+    pub recipients: Vec<Pubkey>,
+    pub shares: Vec<u16>,
+}
+
+#[event]
+pub struct CreatePoolEvent {
+    pub pool: Pubkey,
+    pub token_x: Pubkey,
+    pub token_y: Pubkey,
+    pub fee: FixedPoint,
+    pub tick_spacing: u16,
+    pub protocol_fee: FixedPoint,
+    pub fee_receiver: Pubkey,
+    pub sqrt_price: Price,
+    pub current_tick: i32,
+    pub tickmap: Pubkey,
+    pub current_timestamp: u64,
+}
+
+#[event]
+pub struct ClosePoolEvent {
+    pub pool: Pubkey,
+    pub admin: Pubkey,
+}
+
+#[event]
+pub struct ClosePositionListEvent {
+    pub owner: Pubkey,
+}
+
+#[event]
+pub struct InitReservesEvent {
+    pub pool: Pubkey,
+    pub token_x_reserve: Pubkey,
+    pub token_y_reserve: Pubkey,
+}
+
+#[event]
+pub struct CreateTickEvent {
+    pub pool: Pubkey,
+    pub index: i32,
+    pub fee_growth_outside_x: u128,
+    pub fee_growth_outside_y: u128,
+    pub seconds_outside: u64,
+    pub seconds_per_liquidity_outside: SecondsPerLiquidity,
+}
+
+#[event]
+pub struct ChangeProtocolFeeEvent {
+    pub pool: Pubkey,
+    pub protocol_fee: FixedPoint,
+}
+
+#[event]
+pub struct ChangePauseEvent {
+    pub paused: u8,
+}
+
+#[event]
+pub struct ChangePoolPauseEvent {
+    pub pool: Pubkey,
+    pub paused: u8,
+}
+
+#[event]
+pub struct ChangeFeeTierEvent {
+    pub fee_tier: Pubkey,
+    pub disabled: bool,
+    pub min_fee: FixedPoint,
+    pub max_fee: FixedPoint,
+}
+
+#[event]
+pub struct ChangePoolFeeEvent {
+    pub pool: Pubkey,
+    pub fee: FixedPoint,
+}
+
+#[event]
+pub struct ChangeDynamicFeeEvent {
+    pub pool: Pubkey,
+    pub enabled: bool,
+    pub volatility_fee_per_tick: FixedPoint,
+    pub max_volatility_fee: FixedPoint,
+    pub volatility_half_life: u64,
+}
+
+#[event]
+pub struct ChangePoolDefaultsEvent {
+    pub protocol_fee: FixedPoint,
+    pub fee_receiver: Pubkey,
+}
+
+#[event]
+pub struct ChangeFeeTierPoolDefaultsEvent {
+    pub fee_tier: Pubkey,
+    pub protocol_fee: Option<FixedPoint>,
+    pub fee_receiver: Option<Pubkey>,
+}
+
+#[event]
+pub struct ChangeFeeReceiverEvent {
+    pub pool: Pubkey,
+    pub fee_receiver: Pubkey,
+}
+
+#[event]
+pub struct TransferPositionOwnershipEvent {
+    pub pool: Pubkey,
+    pub id: u128,
+    pub owner: Pubkey,
+    pub recipient: Pubkey,
+}
+
+#[event]
+pub struct TokenizePositionEvent {
+    pub owner: Pubkey,
+    pub pool: Pubkey,
+    pub id: u128,
+    pub mint: Pubkey,
+}
+
+#[event]
+pub struct RedeemPositionEvent {
+    pub owner: Pubkey,
+    pub pool: Pubkey,
+    pub id: u128,
+    pub mint: Pubkey,
+}
+
+#[event]
+pub struct InitializeOracleEvent {
+    pub pool: Pubkey,
+    pub oracle: Pubkey,
+}
+
+#[event]
+pub struct FlashLoanEvent {
+    pub borrower: Pubkey,
+    pub pool: Pubkey,
+    pub amount_x: u64,
+    pub amount_y: u64,
+    pub fee_x: u64,
+    pub fee_y: u64,
+}
+
+#[event]
+pub struct MigratePositionEvent {
+    pub owner: Pubkey,
+    pub pool: Pubkey,
+    pub id: u128,
+    pub index: u32,
+}
+
+#[event]
+pub struct MigratePoolEvent {
+    pub pool: Pubkey,
+}
+
+#[event]
+pub struct MigrateStateEvent {
+    pub admin: Pubkey,
+}
+
+#[event]
+pub struct MigrateFeeTierEvent {
+    pub fee_tier: Pubkey,
+}
+
+#[event]
+pub struct ApprovePositionOperatorEvent {
+    pub owner: Pubkey,
+    pub pool: Pubkey,
+    pub id: u128,
+    pub operator: Pubkey,
+    pub permissions: u8,
+}
+
+#[event]
+pub struct RevokePositionOperatorEvent {
+    pub owner: Pubkey,
+    pub pool: Pubkey,
+    pub id: u128,
+    pub operator: Pubkey,
+}
+
+#[event]
+pub struct CompoundFeesEvent {
+    pub authority: Pubkey,
+    pub owner: Pubkey,
+    pub pool: Pubkey,
+    pub id: u128,
+    pub liquidity_delta: Liquidity,
+    pub amount_x: u64,
+    pub amount_y: u64,
+    pub current_timestamp: u64,
+}
+
+#[event]
+pub struct ProposeAdminEvent {
+    pub admin: Pubkey,
+    pub pending_admin: Pubkey,
+}
+
+#[event]
+pub struct AcceptAdminEvent {
+    pub previous_admin: Pubkey,
+    pub admin: Pubkey,
+}
+
+#[event]
+pub struct SetRoleEvent {
+    pub role: Role,
+    pub holder: Pubkey,
+}
+
+#[event]
+pub struct ChangeReferrerEvent {
+    pub referrer: Pubkey,
+    pub owner: Pubkey,
+    pub fee_share: FixedPoint,
+    pub enabled: bool,
 }
@@ -0,0 +1,112 @@
+use crate::account_size;
+use crate::ErrorCode;
+use anchor_lang::prelude::*;
+
+pub const MAX_FEE_SPLIT_RECIPIENTS: usize = 4;
+pub const BASIS_POINTS: u16 = 10_000;
+
+#[account(zero_copy(unsafe))]
+#[repr(packed)]
+#[derive(PartialEq, Default, Debug, InitSpace)]
+pub struct FeeSplit {
+    pub recipients: [Pubkey; MAX_FEE_SPLIT_RECIPIENTS],
+    pub shares: [u16; MAX_FEE_SPLIT_RECIPIENTS], // basis points, summing up to BASIS_POINTS
+    pub count: u8,
+    pub bump: u8,
+}
+
+account_size!(FeeSplit);
+
+impl FeeSplit {
+    pub fn set(&mut self, recipients: &[Pubkey], shares: &[u16]) -> Result<()> {
+        require!(
+            !recipients.is_empty()
+                && recipients.len() <= MAX_FEE_SPLIT_RECIPIENTS
+                && recipients.len() == shares.len(),
+            ErrorCode::InvalidFeeSplit
+        );
+        require!(
+            shares.iter().all(|share| *share != 0)
+                && shares.iter().map(|share| *share as u32).sum::<u32>() == BASIS_POINTS as u32,
+            ErrorCode::InvalidFeeSplit
+        );
+
+        let mut new_recipients = [Pubkey::default(); MAX_FEE_SPLIT_RECIPIENTS];
+        let mut new_shares = [0; MAX_FEE_SPLIT_RECIPIENTS];
+        new_recipients[..recipients.len()].copy_from_slice(recipients);
+        new_shares[..shares.len()].copy_from_slice(shares);
+
+        self.recipients = new_recipients;
+        self.shares = new_shares;
+        self.count = recipients.len() as u8;
+
+        Ok(())
+    }
+
+    // Rounding dust goes to the last recipient, so the whole amount is always distributed
+    pub fn split(&self, amount: u64) -> Vec<u64> {
+        let count = self.count as usize;
+        let shares = self.shares;
+        let mut remaining = amount;
+
+        (0..count)
+            .map(|i| {
+                let part = match i == count - 1 {
+                    true => remaining,
+                    false => (amount as u128 * shares[i] as u128 / BASIS_POINTS as u128) as u64,
+                };
+                remaining -= part;
+                part
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set() {
+        let recipients = [Pubkey::new_unique(), Pubkey::new_unique()];
+        let mut fee_split = FeeSplit::default();
+
+        // shares have to sum up to 100%
+        assert!(fee_split.set(&recipients, &[5000, 4000]).is_err());
+        // every recipient needs a share
+        assert!(fee_split.set(&recipients, &[10000, 0]).is_err());
+        assert!(fee_split.set(&recipients, &[10000]).is_err());
+        assert!(fee_split.set(&[], &[]).is_err());
+        assert!(fee_split
+            .set(&[Pubkey::new_unique(); 5], &[2000; 5])
+            .is_err());
+
+        fee_split.set(&recipients, &[6000, 4000]).unwrap();
+        assert_eq!(fee_split.count, 2);
+        assert_eq!({ fee_split.recipients }[1], recipients[1]);
+        assert_eq!({ fee_split.shares }, [6000, 4000, 0, 0]);
+
+        // shrinking clears unused slots
+        fee_split.set(&recipients[..1], &[10000]).unwrap();
+        assert_eq!(fee_split.count, 1);
+        assert_eq!({ fee_split.recipients }[1], Pubkey::default());
+        assert_eq!({ fee_split.shares }, [10000, 0, 0, 0]);
+    }
+
+    #[test]
+    fn test_split() {
+        let recipients = [
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        ];
+        let mut fee_split = FeeSplit::default();
+        fee_split.set(&recipients, &[5000, 3333, 1667]).unwrap();
+
+        assert_eq!(fee_split.split(10000), vec![5000, 3333, 1667]);
+        assert_eq!(fee_split.split(0), vec![0, 0, 0]);
+        // dust goes to the last recipient
+        assert_eq!(fee_split.split(7), vec![3, 2, 2]);
+        assert_eq!(fee_split.split(u64::MAX).iter().sum::<u64>(), u64::MAX);
+    }
+}
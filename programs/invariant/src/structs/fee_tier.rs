@@ -8,6 +8,13 @@ pub struct FeeTier {
     pub fee: FixedPoint,
     pub tick_spacing: u16,
     pub bump: u8,
+    // fields below extend the original layout, existing fee tiers are resized by `migrate_fee_tier`
+    pub disabled: bool,      // disabled tiers can not be used for new pools
+    pub min_fee: FixedPoint, // bounds for changing fee of pools created with this tier
+    pub max_fee: FixedPoint,
+    pub protocol_fee_override: FixedPoint, // used for new pools instead of the State default
+    pub protocol_fee_overridden: bool,
+    pub fee_receiver_override: Pubkey, // default key falls back to the State default
 }
 
 account_size!(FeeTier);
@@ -1,19 +1,27 @@
 pub mod events;
+pub mod fee_split;
 pub mod fee_tier;
 pub mod oracle;
 pub mod pool;
 pub mod position;
 pub mod position_list;
+pub mod position_operator;
+pub mod range_order;
+pub mod referrer;
 pub mod state;
 pub mod tick;
 pub mod tickmap;
 
 pub use events::*;
+pub use fee_split::*;
 pub use fee_tier::*;
 pub use oracle::*;
 pub use pool::*;
 pub use position::*;
 pub use position_list::*;
+pub use position_operator::*;
+pub use range_order::*;
+pub use referrer::*;
 pub use state::*;
 pub use tick::*;
 pub use tickmap::*;
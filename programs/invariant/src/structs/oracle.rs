@@ -1,5 +1,7 @@
 use crate::decimals::*;
+use crate::ErrorCode;
 use anchor_lang::prelude::*;
+use std::convert::TryInto;
 
 const SIZE: u16 = 256; // UPDATE IN ARRAYS AS WELL!
 
@@ -31,10 +33,58 @@ impl Oracle {
         }
     }
 
+    pub fn update(&mut self, timestamp: u64, price: Price) {
+        // only the last price within the same second is relevant for the average
+        if self.amount > 0 && { self.data[self.head as usize].timestamp } == timestamp {
+            self.data[self.head as usize].price = price;
+            return;
+        }
+
+        self.add_record(timestamp, price);
+    }
+
     pub fn init(&mut self) {
         self.size = SIZE;
         self.head = SIZE - 1;
     }
+
+    // every record holds its price until the next one (or current timestamp for the newest)
+    pub fn get_twap(&self, window: u64, current_timestamp: u64) -> Result<Price> {
+        require!(window > 0, ErrorCode::InvalidTwapWindow);
+        let window_start = current_timestamp
+            .checked_sub(window)
+            .ok_or(ErrorCode::InsufficientOracleData)?;
+
+        let mut weighted_sum = U256::from(0);
+        let mut end = current_timestamp;
+
+        for i in 0..self.amount {
+            let index = (self.head + self.size - i) % self.size;
+            let record = self.data[index as usize];
+            let start = { record.timestamp }.max(window_start);
+
+            weighted_sum = weighted_sum
+                .checked_add(
+                    U256::from(record.price.v)
+                        .checked_mul(U256::from(end.checked_sub(start).unwrap()))
+                        .unwrap(),
+                )
+                .unwrap();
+
+            if { record.timestamp } <= window_start {
+                return Ok(Price::new(
+                    weighted_sum
+                        .checked_div(U256::from(window))
+                        .unwrap()
+                        .try_into()
+                        .unwrap(),
+                ));
+            }
+            end = record.timestamp;
+        }
+
+        Err(ErrorCode::InsufficientOracleData.into())
+    }
 }
 
 #[cfg(test)]
@@ -81,4 +131,83 @@ mod tests {
             index += 1;
         }
     }
+
+    #[test]
+    fn test_update() {
+        let mut oracle = Oracle {
+            data: [Record {
+                price: Price::from_integer(0),
+                timestamp: 0,
+            }; 256],
+            head: SIZE - 1,
+            amount: 0,
+            size: SIZE,
+        };
+
+        oracle.update(10, Price::from_integer(1));
+        oracle.update(10, Price::from_integer(2));
+        assert_eq!({ oracle.amount }, 1);
+        assert_eq!(
+            { oracle.data[oracle.head as usize].price },
+            Price::from_integer(2)
+        );
+
+        oracle.update(11, Price::from_integer(3));
+        assert_eq!({ oracle.amount }, 2);
+        assert_eq!({ oracle.data[oracle.head as usize].timestamp }, 11);
+        assert_eq!(
+            { oracle.data[oracle.head as usize].price },
+            Price::from_integer(3)
+        );
+    }
+
+    #[test]
+    fn test_get_twap() {
+        let mut oracle = Oracle {
+            data: [Record {
+                price: Price::from_integer(0),
+                timestamp: 0,
+            }; 256],
+            head: SIZE - 1,
+            amount: 0,
+            size: SIZE,
+        };
+        // empty oracle
+        {
+            let result = oracle.get_twap(10, 100);
+            assert!(result.is_err());
+        }
+
+        oracle.add_record(100, Price::from_integer(1));
+        oracle.add_record(110, Price::from_integer(3));
+        oracle.add_record(130, Price::from_integer(2));
+
+        // window covered by the newest record only
+        {
+            let twap = oracle.get_twap(20, 150).unwrap();
+            assert_eq!(twap, Price::from_integer(2));
+        }
+        // window spanning all records
+        {
+            // (1 * 10 + 3 * 20 + 2 * 20) / 50
+            let twap = oracle.get_twap(50, 150).unwrap();
+            assert_eq!(twap, Price::from_scale(22, 1));
+        }
+        // window starting in the middle of a record
+        {
+            // (1 * 5 + 3 * 20 + 2 * 20) / 45
+            let twap = oracle.get_twap(45, 150).unwrap();
+            assert_eq!(twap, Price::new(2333333333333333333333333));
+        }
+        // window older than the oldest record
+        {
+            let result = oracle.get_twap(51, 150);
+            assert!(result.is_err());
+        }
+        // zero window
+        {
+            let result = oracle.get_twap(0, 150);
+            assert!(result.is_err());
+        }
+    }
 }
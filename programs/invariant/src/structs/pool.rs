@@ -12,7 +12,7 @@ pub struct Pool {
     pub token_y_reserve: Pubkey,
     pub position_iterator: u128,
     pub tick_spacing: u16,
-    pub fee: FixedPoint,
+    pub fee: FixedPoint, // fee the pool was created with, part of the pool address
     pub protocol_fee: FixedPoint,
     pub liquidity: Liquidity,
     pub sqrt_price: Price,
@@ -29,6 +29,17 @@ pub struct Pool {
     pub oracle_address: Pubkey,
     pub oracle_initialized: bool,
     pub bump: u8,
+    // fields below extend the original layout, existing pools are resized by `migrate_pool`
+    pub dynamic_fee_enabled: bool,
+    pub volatility_fee_per_tick: FixedPoint, // fee added for every tick spacing of accumulated volatility
+    pub max_volatility_fee: FixedPoint,
+    pub volatility_half_life: u64, // seconds after which accumulator is halved
+    pub volatility_accumulator: u64,
+    pub volatility_last_update: u64,
+    pub paused: u8,               // PAUSE_* bits applied on top of the global ones
+    pub fee_override: FixedPoint, // replaces the creation fee once set by governance
+    pub fee_overridden: bool,
+    pub open_range_orders: u32, // created and not yet claimed, the pool can not be closed before
 }
 
 account_size!(Pool);
@@ -41,9 +52,12 @@ impl Pool {
         in_x: bool,
     ) -> TokenAmount {
         let protocol_fee = TokenAmount::from_decimal_up(amount.big_mul_up(self.protocol_fee));
+        // referral share cannot take more than what is left after protocol fee
         let ref_fee = match ref_percentage.is_zero() {
             true => TokenAmount(0),
-            false => TokenAmount::from_decimal(amount.big_mul(ref_percentage)),
+            false => {
+                TokenAmount::from_decimal(amount.big_mul(ref_percentage)).min(amount - protocol_fee)
+            }
         };
         let pool_fee = amount - protocol_fee - ref_fee;
 
@@ -68,6 +82,73 @@ impl Pool {
         ref_fee
     }
 
+    // Flash loan fee is not tied to a price range, with no liquidity in range it is kept by the protocol
+    pub fn add_flash_loan_fee(&mut self, amount: TokenAmount, in_x: bool) {
+        if !self.liquidity.is_zero() {
+            self.add_fee(amount, FixedPoint::from_integer(0), in_x);
+            return;
+        }
+
+        match in_x {
+            true => {
+                self.fee_protocol_token_x = self.fee_protocol_token_x.checked_add(amount.0).unwrap()
+            }
+            false => {
+                self.fee_protocol_token_y = self.fee_protocol_token_y.checked_add(amount.0).unwrap()
+            }
+        }
+    }
+
+    // Swap fee before the volatility component is added
+    pub fn base_fee(&self) -> FixedPoint {
+        match self.fee_overridden {
+            true => self.fee_override,
+            false => self.fee,
+        }
+    }
+
+    // Base fee increased by the volatility component in dynamic fee mode
+    pub fn effective_fee(&self) -> FixedPoint {
+        if !self.dynamic_fee_enabled {
+            return self.base_fee();
+        }
+        let volatility_fee = FixedPoint::new(
+            { self.volatility_fee_per_tick }
+                .v
+                .saturating_mul(self.volatility_accumulator as u128),
+        )
+        .min(self.max_volatility_fee);
+
+        self.base_fee() + volatility_fee
+    }
+
+    pub fn decay_volatility(&mut self, current_timestamp: u64) {
+        if !self.dynamic_fee_enabled {
+            return;
+        }
+        let elapsed = current_timestamp.saturating_sub(self.volatility_last_update);
+        let periods = elapsed / self.volatility_half_life;
+
+        self.volatility_accumulator = match periods >= u64::BITS as u64 {
+            true => 0,
+            false => self.volatility_accumulator >> periods,
+        };
+        self.volatility_last_update += periods * self.volatility_half_life;
+    }
+
+    // Accumulates price movement in tick spacings since `tick_before`
+    pub fn accumulate_volatility(&mut self, tick_before: i32) {
+        if !self.dynamic_fee_enabled {
+            return;
+        }
+        let ticks_moved =
+            (self.current_tick_index - tick_before).unsigned_abs() / self.tick_spacing as u32;
+
+        self.volatility_accumulator = self
+            .volatility_accumulator
+            .saturating_add(ticks_moved as u64);
+    }
+
     pub fn update_liquidity_safely(&mut self, liquidity_delta: Liquidity, add: bool) -> Result<()> {
         // validate in decrease liquidity case
         if !add && { self.liquidity } < liquidity_delta {
@@ -188,6 +269,134 @@ mod tests {
             assert_eq!({ pool.fee_protocol_token_y }, 0);
             assert_eq!(ref_fee, TokenAmount(0));
         }
+        // referral takes everything left after protocol fee
+        {
+            let mut pool = pool.clone();
+            let amount = TokenAmount::from_integer(10);
+            let ref_fee = pool.add_fee(amount, FixedPoint::from_integer(1), true);
+
+            assert_eq!({ pool.fee_growth_global_x }, FeeGrowth::new(0));
+            assert_eq!({ pool.fee_protocol_token_x }, 2);
+            assert_eq!(ref_fee, TokenAmount(8));
+        }
+    }
+
+    #[test]
+    fn test_add_flash_loan_fee() {
+        let pool = Pool {
+            protocol_fee: FixedPoint::from_scale(2, 1),
+            liquidity: Liquidity::from_integer(10),
+            ..Default::default()
+        };
+        // shared with liquidity providers
+        {
+            let mut pool = pool.clone();
+            pool.add_flash_loan_fee(TokenAmount::from_integer(6), true);
+            assert_eq!({ pool.fee_growth_global_x }, FeeGrowth::from_scale(4, 1));
+            assert_eq!({ pool.fee_protocol_token_x }, 2);
+            assert_eq!({ pool.fee_protocol_token_y }, 0);
+        }
+        // no liquidity in range
+        {
+            let mut pool = Pool {
+                liquidity: Liquidity::new(0),
+                ..pool
+            };
+            pool.add_flash_loan_fee(TokenAmount::from_integer(6), true);
+            pool.add_flash_loan_fee(TokenAmount::from_integer(3), false);
+            assert_eq!({ pool.fee_growth_global_x }, FeeGrowth::new(0));
+            assert_eq!({ pool.fee_growth_global_y }, FeeGrowth::new(0));
+            assert_eq!({ pool.fee_protocol_token_x }, 6);
+            assert_eq!({ pool.fee_protocol_token_y }, 3);
+        }
+    }
+
+    #[test]
+    fn test_dynamic_fee() {
+        let base_pool = Pool {
+            tick_spacing: 10,
+            fee: FixedPoint::from_scale(3, 3),
+            dynamic_fee_enabled: true,
+            volatility_fee_per_tick: FixedPoint::from_scale(1, 4),
+            max_volatility_fee: FixedPoint::from_scale(5, 3),
+            volatility_half_life: 60,
+            volatility_last_update: 100,
+            ..Default::default()
+        };
+        // disabled
+        {
+            let mut pool = Pool {
+                dynamic_fee_enabled: false,
+                current_tick_index: 100,
+                ..base_pool
+            };
+            pool.accumulate_volatility(0);
+            assert_eq!({ pool.volatility_accumulator }, 0);
+            assert_eq!(pool.effective_fee(), FixedPoint::from_scale(3, 3));
+        }
+        // accumulating in both directions
+        {
+            let mut pool = Pool {
+                current_tick_index: 100,
+                ..base_pool
+            };
+            pool.accumulate_volatility(0);
+            assert_eq!({ pool.volatility_accumulator }, 10);
+
+            pool.current_tick_index = 50;
+            pool.accumulate_volatility(100);
+            assert_eq!({ pool.volatility_accumulator }, 15);
+            assert_eq!(pool.effective_fee(), FixedPoint::from_scale(45, 4));
+        }
+        // capped volatility fee
+        {
+            let pool = Pool {
+                volatility_accumulator: 1000,
+                ..base_pool
+            };
+            assert_eq!(pool.effective_fee(), FixedPoint::from_scale(8, 3));
+        }
+        // decay
+        {
+            let mut pool = Pool {
+                volatility_accumulator: 16,
+                ..base_pool
+            };
+            pool.decay_volatility(159);
+            assert_eq!({ pool.volatility_accumulator }, 16);
+            assert_eq!({ pool.volatility_last_update }, 100);
+
+            pool.decay_volatility(230);
+            assert_eq!({ pool.volatility_accumulator }, 4);
+            assert_eq!({ pool.volatility_last_update }, 220);
+
+            pool.decay_volatility(u64::MAX);
+            assert_eq!({ pool.volatility_accumulator }, 0);
+        }
+    }
+
+    #[test]
+    fn test_base_fee() {
+        let mut pool = Pool {
+            fee: FixedPoint::from_scale(3, 3),
+            ..Default::default()
+        };
+        assert_eq!(pool.base_fee(), FixedPoint::from_scale(3, 3));
+
+        // override can lower the fee to zero as well
+        pool.fee_overridden = true;
+        assert_eq!(pool.base_fee(), FixedPoint::new(0));
+
+        pool.fee_override = FixedPoint::from_scale(1, 3);
+        assert_eq!(pool.base_fee(), FixedPoint::from_scale(1, 3));
+        assert_eq!(pool.effective_fee(), FixedPoint::from_scale(1, 3));
+        assert_eq!({ pool.fee }, FixedPoint::from_scale(3, 3));
+
+        pool.dynamic_fee_enabled = true;
+        pool.volatility_fee_per_tick = FixedPoint::from_scale(1, 4);
+        pool.max_volatility_fee = FixedPoint::from_scale(5, 3);
+        pool.volatility_accumulator = 10;
+        assert_eq!(pool.effective_fee(), FixedPoint::from_scale(2, 3));
     }
 
     #[test]
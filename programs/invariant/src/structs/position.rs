@@ -108,6 +108,20 @@ impl Position {
         Ok(())
     }
 
+    // Refreshes staking snapshot of the position, done before every change of its liquidity
+    pub fn update_seconds_per_liquidity(
+        &mut self,
+        pool: &mut Pool,
+        lower_tick: Tick,
+        upper_tick: Tick,
+        current_timestamp: u64,
+        slot: u64,
+    ) {
+        self.seconds_per_liquidity_inside =
+            calculate_seconds_per_liquidity_inside(lower_tick, upper_tick, pool, current_timestamp);
+        self.last_slot = slot;
+    }
+
     pub fn initialized_id(&mut self, pool: &mut Pool) {
         self.id = pool.position_iterator;
         pool.position_iterator = pool.position_iterator.checked_add(1).unwrap();
@@ -362,4 +376,49 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_update_seconds_per_liquidity() {
+        let lower_tick = Tick {
+            index: 0,
+            seconds_per_liquidity_outside: SecondsPerLiquidity::new(3012300000),
+            ..Default::default()
+        };
+        let upper_tick = Tick {
+            index: 10,
+            seconds_per_liquidity_outside: SecondsPerLiquidity::new(2030400000),
+            ..Default::default()
+        };
+        let mut pool = Pool {
+            liquidity: Liquidity::from_integer(1000),
+            current_tick_index: -10,
+            ..Default::default()
+        };
+        let mut position = Position {
+            seconds_per_liquidity_inside: SecondsPerLiquidity::new(7),
+            last_slot: 1,
+            ..Default::default()
+        };
+        let current_timestamp = 100;
+        let slot = 20;
+
+        let expected = calculate_seconds_per_liquidity_inside(
+            lower_tick,
+            upper_tick,
+            &mut { pool },
+            current_timestamp,
+        );
+        position.update_seconds_per_liquidity(
+            &mut pool,
+            lower_tick,
+            upper_tick,
+            current_timestamp,
+            slot,
+        );
+
+        assert_eq!({ position.seconds_per_liquidity_inside }, expected);
+        assert_eq!(expected.get(), 981900000);
+        assert_eq!({ position.last_slot }, slot);
+        assert_eq!({ pool.last_timestamp }, current_timestamp);
+    }
 }
@@ -11,3 +11,16 @@ pub struct PositionList {
 }
 
 account_size!(PositionList);
+
+// Entry of the owner's stable position list, points to the position by its pool and id
+// Entries stay dense, so the last entry is moved into the slot of a removed one
+#[account(zero_copy(unsafe))]
+#[repr(packed)]
+#[derive(PartialEq, Default, Debug, InitSpace)]
+pub struct PositionEntry {
+    pub pool: Pubkey,
+    pub id: u128,
+    pub bump: u8,
+}
+
+account_size!(PositionEntry);
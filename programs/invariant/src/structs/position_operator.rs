@@ -0,0 +1,81 @@
+use crate::account_size;
+use crate::structs::position::Position;
+use anchor_lang::prelude::*;
+
+#[account(zero_copy(unsafe))]
+#[repr(packed)]
+#[derive(PartialEq, Default, Debug, InitSpace)]
+pub struct PositionOperator {
+    pub owner: Pubkey, // approval is void once the position changes hands
+    pub pool: Pubkey,
+    pub position_id: u128,
+    pub operator: Pubkey,
+    pub permissions: u8, // bitmask of PERMISSION_* flags
+    pub bump: u8,
+}
+
+account_size!(PositionOperator);
+
+pub const PERMISSION_CLAIM_FEE: u8 = 1;
+pub const PERMISSION_CHANGE_LIQUIDITY: u8 = 1 << 1;
+pub const PERMISSION_REMOVE: u8 = 1 << 2;
+pub const PERMISSION_COMPOUND: u8 = 1 << 3;
+pub const PERMISSION_ALL: u8 =
+    PERMISSION_CLAIM_FEE | PERMISSION_CHANGE_LIQUIDITY | PERMISSION_REMOVE | PERMISSION_COMPOUND;
+
+impl PositionOperator {
+    pub fn is_approved(&self, position: &Position, operator: &Pubkey, permission: u8) -> bool {
+        self.owner == position.owner
+            && self.pool == position.pool
+            && self.position_id == position.id
+            && self.operator == *operator
+            && self.permissions & permission == permission
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_approved() {
+        let owner = Pubkey::new_unique();
+        let pool = Pubkey::new_unique();
+        let operator = Pubkey::new_unique();
+        let position = Position {
+            owner,
+            pool,
+            id: 3,
+            ..Default::default()
+        };
+        let approval = PositionOperator {
+            owner,
+            pool,
+            position_id: 3,
+            operator,
+            permissions: PERMISSION_CLAIM_FEE | PERMISSION_COMPOUND,
+            bump: 0,
+        };
+
+        assert!(approval.is_approved(&position, &operator, PERMISSION_CLAIM_FEE));
+        assert!(approval.is_approved(&position, &operator, PERMISSION_COMPOUND));
+        // permission outside of the scope
+        assert!(!approval.is_approved(&position, &operator, PERMISSION_REMOVE));
+        assert!(!approval.is_approved(&position, &operator, PERMISSION_CHANGE_LIQUIDITY));
+        // other operator
+        assert!(!approval.is_approved(&position, &Pubkey::new_unique(), PERMISSION_CLAIM_FEE));
+        // other position of the pool
+        {
+            let position = Position { id: 4, ..position };
+            assert!(!approval.is_approved(&position, &operator, PERMISSION_CLAIM_FEE));
+        }
+        // position transferred to another owner
+        {
+            let position = Position {
+                owner: Pubkey::new_unique(),
+                ..position
+            };
+            assert!(!approval.is_approved(&position, &operator, PERMISSION_CLAIM_FEE));
+        }
+    }
+}
@@ -0,0 +1,63 @@
+use crate::account_size;
+use crate::structs::position::Position;
+use anchor_lang::prelude::*;
+
+#[account(zero_copy(unsafe))]
+#[repr(packed)]
+#[derive(PartialEq, Default, Debug, InitSpace)]
+pub struct RangeOrder {
+    pub position: Position, // single tick spacing wide position backing the order
+    pub x_to_y: bool,       // order sells token x when placed above the price
+    pub settled: bool,
+    pub bump: u8,
+}
+
+account_size!(RangeOrder);
+
+impl RangeOrder {
+    // Order is fully converted once the price passes its far boundary
+    pub fn is_filled(&self, current_tick_index: i32) -> bool {
+        match self.x_to_y {
+            true => current_tick_index >= self.position.upper_tick_index,
+            false => current_tick_index < self.position.lower_tick_index,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_filled() {
+        let position = Position {
+            lower_tick_index: 10,
+            upper_tick_index: 20,
+            ..Default::default()
+        };
+        // selling x
+        {
+            let order = RangeOrder {
+                position,
+                x_to_y: true,
+                ..Default::default()
+            };
+            assert!(!order.is_filled(0));
+            assert!(!order.is_filled(10));
+            assert!(order.is_filled(20));
+            assert!(order.is_filled(30));
+        }
+        // selling y
+        {
+            let order = RangeOrder {
+                position,
+                x_to_y: false,
+                ..Default::default()
+            };
+            assert!(!order.is_filled(20));
+            assert!(!order.is_filled(10));
+            assert!(order.is_filled(9));
+            assert!(order.is_filled(0));
+        }
+    }
+}
@@ -0,0 +1,14 @@
+use crate::{account_size, decimals::FixedPoint};
+use anchor_lang::prelude::*;
+
+#[account(zero_copy(unsafe))]
+#[repr(packed)]
+#[derive(PartialEq, Default, Debug, InitSpace)]
+pub struct Referrer {
+    pub owner: Pubkey,
+    pub fee_share: FixedPoint,
+    pub enabled: bool,
+    pub bump: u8,
+}
+
+account_size!(Referrer);
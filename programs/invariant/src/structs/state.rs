@@ -1,6 +1,28 @@
 use anchor_lang::prelude::*;
 
 use crate::account_size;
+use crate::decimals::FixedPoint;
+use crate::structs::fee_tier::FeeTier;
+use crate::structs::pool::Pool;
+
+pub const PAUSE_SWAP: u8 = 1;
+pub const PAUSE_ADD_LIQUIDITY: u8 = 1 << 1;
+pub const PAUSE_REMOVE_LIQUIDITY: u8 = 1 << 2;
+pub const PAUSE_ALL: u8 = PAUSE_SWAP | PAUSE_ADD_LIQUIDITY | PAUSE_REMOVE_LIQUIDITY;
+
+pub const TREASURY_SEED: &[u8] = b"treasuryv1";
+
+// Default fee receiver of new pools, the PDA holds no data and never signs
+pub fn treasury_address() -> Pubkey {
+    Pubkey::find_program_address(&[TREASURY_SEED], &crate::ID).0
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Role {
+    FeeTierManager,
+    Pauser,
+    ProtocolFeeManager,
+}
 
 #[account(zero_copy(unsafe))]
 #[repr(packed)]
@@ -10,6 +32,160 @@ pub struct State {
     pub nonce: u8,
     pub authority: Pubkey,
     pub bump: u8,
+    // fields below extend the original layout, the deployed state is resized by `migrate_state`
+    pub pending_admin: Pubkey, // default key when no transfer is proposed
+    pub fee_tier_manager: Pubkey,
+    pub pauser: Pubkey,
+    pub protocol_fee_manager: Pubkey,
+    pub paused: u8, // PAUSE_* bits applied to every pool
+    pub default_protocol_fee: FixedPoint,
+    pub default_fee_receiver: Pubkey, // treasury unless changed by the protocol fee manager
 }
 
 account_size!(State);
+
+impl State {
+    pub fn get_role(&self, role: Role) -> Pubkey {
+        match role {
+            Role::FeeTierManager => self.fee_tier_manager,
+            Role::Pauser => self.pauser,
+            Role::ProtocolFeeManager => self.protocol_fee_manager,
+        }
+    }
+
+    pub fn set_role(&mut self, role: Role, holder: Pubkey) {
+        match role {
+            Role::FeeTierManager => self.fee_tier_manager = holder,
+            Role::Pauser => self.pauser = holder,
+            Role::ProtocolFeeManager => self.protocol_fee_manager = holder,
+        }
+    }
+
+    // Protocol fee of a new pool, the fee tier override takes precedence
+    pub fn pool_protocol_fee(&self, fee_tier: &FeeTier) -> FixedPoint {
+        match fee_tier.protocol_fee_overridden {
+            true => fee_tier.protocol_fee_override,
+            false => self.default_protocol_fee,
+        }
+    }
+
+    pub fn pool_fee_receiver(&self, fee_tier: &FeeTier) -> Pubkey {
+        match fee_tier.fee_receiver_override == Pubkey::default() {
+            true => self.default_fee_receiver,
+            false => fee_tier.fee_receiver_override,
+        }
+    }
+
+    // admin keeps every permission of the roles it hands out
+    pub fn has_role(&self, key: &Pubkey, role: Role) -> bool {
+        *key == self.admin || *key == self.get_role(role)
+    }
+
+    // protocol fee manager acts for the treasury, which can not sign withdrawals itself
+    pub fn is_fee_receiver(&self, pool: &Pool, key: &Pubkey) -> bool {
+        pool.fee_receiver == *key
+            || (pool.fee_receiver == treasury_address()
+                && self.has_role(key, Role::ProtocolFeeManager))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::decimals::*;
+    use crate::structs::Pool;
+    use crate::util::check_not_paused;
+
+    #[test]
+    fn test_has_role() {
+        let admin = Pubkey::new_unique();
+        let manager = Pubkey::new_unique();
+        let mut state = State {
+            admin,
+            fee_tier_manager: admin,
+            pauser: admin,
+            protocol_fee_manager: admin,
+            ..Default::default()
+        };
+
+        assert!(state.has_role(&admin, Role::FeeTierManager));
+        assert!(!state.has_role(&manager, Role::FeeTierManager));
+
+        state.set_role(Role::FeeTierManager, manager);
+        assert_eq!(state.get_role(Role::FeeTierManager), manager);
+        assert!(state.has_role(&manager, Role::FeeTierManager));
+        assert!(state.has_role(&admin, Role::FeeTierManager));
+        assert!(!state.has_role(&manager, Role::Pauser));
+        assert!(!state.has_role(&manager, Role::ProtocolFeeManager));
+    }
+
+    #[test]
+    fn test_check_not_paused() {
+        let mut state = State::default();
+        let mut pool = Pool::default();
+
+        assert!(check_not_paused(&state, &pool, PAUSE_SWAP).is_ok());
+
+        // global flags block every pool
+        state.paused = PAUSE_SWAP | PAUSE_ADD_LIQUIDITY;
+        assert!(check_not_paused(&state, &pool, PAUSE_SWAP).is_err());
+        assert!(check_not_paused(&state, &pool, PAUSE_ADD_LIQUIDITY).is_err());
+        assert!(check_not_paused(&state, &pool, PAUSE_REMOVE_LIQUIDITY).is_ok());
+
+        // pool flags are added on top
+        state.paused = 0;
+        pool.paused = PAUSE_REMOVE_LIQUIDITY;
+        assert!(check_not_paused(&state, &pool, PAUSE_SWAP).is_ok());
+        assert!(check_not_paused(&state, &pool, PAUSE_REMOVE_LIQUIDITY).is_err());
+    }
+
+    #[test]
+    fn test_is_fee_receiver() {
+        let admin = Pubkey::new_unique();
+        let manager = Pubkey::new_unique();
+        let receiver = Pubkey::new_unique();
+        let state = State {
+            admin,
+            protocol_fee_manager: manager,
+            ..Default::default()
+        };
+        let mut pool = Pool {
+            fee_receiver: receiver,
+            ..Default::default()
+        };
+
+        assert!(state.is_fee_receiver(&pool, &receiver));
+        assert!(!state.is_fee_receiver(&pool, &manager));
+        assert!(!state.is_fee_receiver(&pool, &admin));
+
+        // fees of the treasury are withdrawn by the manager
+        pool.fee_receiver = treasury_address();
+        assert!(state.is_fee_receiver(&pool, &manager));
+        assert!(state.is_fee_receiver(&pool, &admin));
+        assert!(!state.is_fee_receiver(&pool, &receiver));
+    }
+
+    #[test]
+    fn test_pool_defaults() {
+        let treasury = Pubkey::new_unique();
+        let tier_receiver = Pubkey::new_unique();
+        let state = State {
+            default_protocol_fee: FixedPoint::from_scale(1, 2),
+            default_fee_receiver: treasury,
+            ..Default::default()
+        };
+        let mut fee_tier = FeeTier::default();
+
+        assert_eq!(
+            state.pool_protocol_fee(&fee_tier),
+            FixedPoint::from_scale(1, 2)
+        );
+        assert_eq!(state.pool_fee_receiver(&fee_tier), treasury);
+
+        // overrides take precedence, even a zero protocol fee
+        fee_tier.protocol_fee_overridden = true;
+        fee_tier.fee_receiver_override = tier_receiver;
+        assert_eq!(state.pool_protocol_fee(&fee_tier), FixedPoint::new(0));
+        assert_eq!(state.pool_fee_receiver(&fee_tier), tier_receiver);
+    }
+}
@@ -1,3 +1,5 @@
+use crate::math::calculate_price_sqrt;
+use crate::structs::pool::Pool;
 use crate::ErrorCode;
 use crate::*;
 use anchor_lang::prelude::*;
@@ -23,6 +25,42 @@ pub struct Tick {
 account_size!(Tick);
 
 impl Tick {
+    // Everything accumulated so far is assumed to happen below a tick initialized at or below the current one
+    pub fn create(
+        pool_address: Pubkey,
+        pool: &Pool,
+        index: i32,
+        current_timestamp: u64,
+        bump: u8,
+    ) -> Self {
+        let below_current_tick = index <= pool.current_tick_index;
+        Tick {
+            pool: pool_address,
+            index,
+            sign: true,
+            liquidity_change: Liquidity::new(0),
+            liquidity_gross: Liquidity::new(0),
+            sqrt_price: calculate_price_sqrt(index),
+            fee_growth_outside_x: match below_current_tick {
+                true => pool.fee_growth_global_x,
+                false => FeeGrowth::new(0),
+            },
+            fee_growth_outside_y: match below_current_tick {
+                true => pool.fee_growth_global_y,
+                false => FeeGrowth::new(0),
+            },
+            seconds_outside: match below_current_tick {
+                true => current_timestamp.checked_sub(pool.start_timestamp).unwrap(),
+                false => 0,
+            },
+            seconds_per_liquidity_outside: match below_current_tick {
+                true => pool.seconds_per_liquidity_global,
+                false => SecondsPerLiquidity::new(0),
+            },
+            bump,
+        }
+    }
+
     pub fn update(
         &mut self,
         liquidity_delta: Liquidity,
@@ -205,4 +243,46 @@ mod tests {
             assert!(result.is_err());
         }
     }
+
+    #[test]
+    fn test_create() {
+        let pool_address = Pubkey::new_unique();
+        let pool = Pool {
+            current_tick_index: 10,
+            fee_growth_global_x: FeeGrowth::from_integer(4),
+            fee_growth_global_y: FeeGrowth::from_integer(7),
+            seconds_per_liquidity_global: SecondsPerLiquidity::from_integer(3),
+            start_timestamp: 100,
+            ..Default::default()
+        };
+        // at or below current tick
+        {
+            let tick = Tick::create(pool_address, &pool, 10, 150, 1);
+
+            assert_eq!(tick.pool, pool_address);
+            assert_eq!({ tick.index }, 10);
+            assert!(tick.sign);
+            assert_eq!({ tick.sqrt_price }, calculate_price_sqrt(10));
+            assert_eq!({ tick.fee_growth_outside_x }, FeeGrowth::from_integer(4));
+            assert_eq!({ tick.fee_growth_outside_y }, FeeGrowth::from_integer(7));
+            assert_eq!({ tick.seconds_outside }, 50);
+            assert_eq!(
+                { tick.seconds_per_liquidity_outside },
+                SecondsPerLiquidity::from_integer(3)
+            );
+            assert_eq!(tick.bump, 1);
+        }
+        // above current tick
+        {
+            let tick = Tick::create(pool_address, &pool, 20, 150, 1);
+
+            assert_eq!({ tick.fee_growth_outside_x }, FeeGrowth::new(0));
+            assert_eq!({ tick.fee_growth_outside_y }, FeeGrowth::new(0));
+            assert_eq!({ tick.seconds_outside }, 0);
+            assert_eq!(
+                { tick.seconds_per_liquidity_outside },
+                SecondsPerLiquidity::new(0)
+            );
+        }
+    }
 }
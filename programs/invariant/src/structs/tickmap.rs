@@ -59,6 +59,10 @@ pub fn get_search_limit(tick: i32, tick_spacing: u16, up: bool) -> i32 {
 }
 
 impl Tickmap {
+    pub fn is_empty(&self) -> bool {
+        self.bitmap.iter().all(|byte| *byte == 0)
+    }
+
     pub fn flip(&mut self, value: bool, tick: i32, tick_spacing: u16) {
         assert!(
             self.get(tick, tick_spacing) != value,
@@ -174,6 +178,18 @@ impl Tickmap {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_is_empty() {
+        let mut map = Tickmap::default();
+        assert!(map.is_empty());
+
+        map.flip(true, -TICK_LIMIT + 1, 1);
+        assert!(!map.is_empty());
+
+        map.flip(false, -TICK_LIMIT + 1, 1);
+        assert!(map.is_empty());
+    }
+
     #[test]
     fn test_price_limit() {
         let map = Tickmap::default();
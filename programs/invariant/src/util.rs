@@ -1,17 +1,30 @@
-use anchor_lang::solana_program::system_program;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke;
+use anchor_lang::solana_program::{hash, system_program};
+use anchor_lang::Discriminator;
 use anchor_spl::{
-    token::Token,
-    token_2022::spl_token_2022::{
+    token::{self, Token},
+    token_2022::{
         self,
-        extension::{BaseStateWithExtensions, ExtensionType, StateWithExtensions},
+        spl_token_2022::{
+            self,
+            extension::{BaseStateWithExtensions, ExtensionType, StateWithExtensions},
+        },
     },
     token_interface::Mint,
 };
 use std::cell::RefMut;
 use std::convert::TryInto;
 
-use crate::math::calculate_price_sqrt;
+use crate::log::get_tick_at_sqrt_price;
+use crate::math::{calculate_price_sqrt, compute_swap_step};
+use crate::structs::events::{
+    CreatePositionEvent, CreateTickEvent, CrossTickEvent, RemovePositionEvent,
+};
+use crate::structs::oracle::Oracle;
 use crate::structs::pool::Pool;
+use crate::structs::position::Position;
+use crate::structs::position_operator::PositionOperator;
 use crate::structs::tick::Tick;
 use crate::structs::tickmap::Tickmap;
 use crate::structs::tickmap::{get_search_limit, MAX_TICK, TICK_LIMIT};
@@ -99,7 +112,7 @@ pub fn get_closer_limit(
     }
 }
 
-pub fn cross_tick(tick: &mut RefMut<Tick>, pool: &mut Pool, current_timestamp: u64) -> Result<()> {
+pub fn cross_tick(tick: &mut Tick, pool: &mut Pool, current_timestamp: u64) -> Result<()> {
     tick.fee_growth_outside_x = pool
         .fee_growth_global_x
         .unchecked_sub(tick.fee_growth_outside_x);
@@ -131,6 +144,541 @@ pub fn cross_tick(tick: &mut RefMut<Tick>, pool: &mut Pool, current_timestamp: u
     Ok(())
 }
 
+#[derive(PartialEq, Debug, Default)]
+pub struct SwapSummary {
+    pub amount_in: TokenAmount,
+    pub amount_out: TokenAmount,
+    pub fee: TokenAmount,
+    pub referral_fee: TokenAmount,
+    pub remaining_amount: TokenAmount,
+    pub crossed_ticks: Vec<i32>,
+}
+
+// Runs the swap against the pool, crossing initialized ticks with the provided callback
+// with partial fill the swap stops at the price limit instead of failing
+#[allow(clippy::too_many_arguments)]
+pub fn process_swap(
+    pool: &mut Pool,
+    tickmap: &Tickmap,
+    x_to_y: bool,
+    amount: TokenAmount,
+    by_amount_in: bool,
+    sqrt_price_limit: Price,
+    partial_fill: bool,
+    ref_percentage: FixedPoint,
+    current_timestamp: u64,
+    mut cross: impl FnMut(&mut Pool, i32) -> Result<()>,
+) -> Result<SwapSummary> {
+    // limit is on the right side of price
+    if x_to_y {
+        require!(
+            { pool.sqrt_price } > sqrt_price_limit
+                && sqrt_price_limit <= Price::new(MAX_SQRT_PRICE),
+            ErrorCode::WrongLimit
+        );
+    } else {
+        require!(
+            { pool.sqrt_price } < sqrt_price_limit
+                && sqrt_price_limit >= Price::new(MIN_SQRT_PRICE),
+            ErrorCode::WrongLimit
+        );
+    }
+
+    pool.decay_volatility(current_timestamp);
+    let fee = pool.effective_fee();
+    let tick_before = pool.current_tick_index;
+
+    let mut remaining_amount = amount;
+    let mut summary = SwapSummary::default();
+
+    while !remaining_amount.is_zero() {
+        let (swap_limit, limiting_tick) = get_closer_limit(
+            sqrt_price_limit,
+            x_to_y,
+            pool.current_tick_index,
+            pool.tick_spacing,
+            tickmap,
+        )?;
+
+        let result = compute_swap_step(
+            pool.sqrt_price,
+            swap_limit,
+            pool.liquidity,
+            remaining_amount,
+            by_amount_in,
+            fee,
+        );
+        // make remaining amount smaller
+        if by_amount_in {
+            remaining_amount -= result.amount_in + result.fee_amount;
+        } else {
+            remaining_amount -= result.amount_out;
+        }
+
+        summary.referral_fee += pool.add_fee(result.fee_amount, ref_percentage, x_to_y);
+
+        pool.sqrt_price = result.next_price_sqrt;
+
+        summary.amount_in += result.amount_in + result.fee_amount;
+        summary.fee += result.fee_amount;
+        summary.amount_out += result.amount_out;
+
+        // Fail if price would go over swap limit
+        let limit_reached = { pool.sqrt_price } == sqrt_price_limit && !remaining_amount.is_zero();
+        if limit_reached && !partial_fill {
+            return Err(ErrorCode::PriceLimitReached.into());
+        }
+
+        // crossing tick
+        if let Some((tick_index, initialized)) =
+            limiting_tick.filter(|_| result.next_price_sqrt == swap_limit)
+        {
+            let is_enough_amount_to_cross = is_enough_amount_to_push_price(
+                remaining_amount,
+                result.next_price_sqrt,
+                pool.liquidity,
+                fee,
+                by_amount_in,
+                x_to_y,
+            );
+
+            if initialized {
+                // crossing tick
+                if !x_to_y || is_enough_amount_to_cross {
+                    cross(pool, tick_index)?;
+                    summary.crossed_ticks.push(tick_index);
+                } else if !remaining_amount.is_zero() {
+                    if by_amount_in {
+                        pool.add_fee(remaining_amount, FixedPoint::from_integer(0), x_to_y);
+                        summary.amount_in += remaining_amount;
+                    }
+                    remaining_amount = TokenAmount(0);
+                }
+            }
+            // set tick to limit (below if price is going down, because current tick should always be below price)
+            pool.current_tick_index = if x_to_y && is_enough_amount_to_cross {
+                tick_index.checked_sub(pool.tick_spacing as i32).unwrap()
+            } else {
+                tick_index
+            };
+        } else {
+            assert!(
+                pool.current_tick_index
+                    .checked_rem(pool.tick_spacing.into())
+                    .unwrap()
+                    == 0,
+                "tick not divisible by spacing"
+            );
+            pool.current_tick_index =
+                get_tick_at_sqrt_price(result.next_price_sqrt, pool.tick_spacing);
+        }
+
+        if limit_reached {
+            break;
+        }
+    }
+
+    pool.accumulate_volatility(tick_before);
+
+    summary.remaining_amount = remaining_amount;
+    Ok(summary)
+}
+
+// Finds the tick account among remaining accounts by its address and crosses it
+pub fn cross_tick_from_accounts<'info>(
+    remaining_accounts: &'info [AccountInfo<'info>],
+    program_id: &Pubkey,
+    pool_address: &Pubkey,
+    pool: &mut Pool,
+    tick_index: i32,
+) -> Result<()> {
+    let loader = find_tick_account(remaining_accounts, program_id, pool_address, tick_index)?;
+    let mut tick = loader.load_mut()?;
+    let current_timestamp = get_current_timestamp();
+
+    msg!("INVARIANT: CROSSING TICK {} ", { tick.index });
+    cross_tick(&mut tick, pool, current_timestamp)?;
+
+    emit!(CrossTickEvent {
+        pool: *pool_address,
+        index: tick.index,
+        liquidity: pool.liquidity,
+        fee_growth_outside_x: tick.fee_growth_outside_x.v,
+        fee_growth_outside_y: tick.fee_growth_outside_y.v,
+        seconds_per_liquidity_outside: tick.seconds_per_liquidity_outside,
+        current_timestamp,
+    });
+
+    Ok(())
+}
+
+// Creates tick account at its address unless it already exists, initialized the same way as in `create_tick`
+#[allow(clippy::too_many_arguments)]
+pub fn init_tick_if_needed<'info>(
+    tick_info: &AccountInfo<'info>,
+    pool_address: &Pubkey,
+    pool: &Pool,
+    index: i32,
+    bump: u8,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    current_timestamp: u64,
+) -> Result<()> {
+    if *tick_info.owner == crate::ID {
+        return Ok(());
+    }
+    check_tick(index, pool.tick_spacing)?;
+
+    let index_bytes = index.to_le_bytes();
+    let seeds: &[&[u8]] = &[b"tickv1", pool_address.as_ref(), &index_bytes, &[bump]];
+
+    // funding and allocation are separated, so lamports sent to the address upfront do not block creation
+    let lamports = Rent::get()?
+        .minimum_balance(Tick::LEN)
+        .saturating_sub(tick_info.lamports());
+    if lamports > 0 {
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                anchor_lang::system_program::Transfer {
+                    from: payer.clone(),
+                    to: tick_info.clone(),
+                },
+            ),
+            lamports,
+        )?;
+    }
+    anchor_lang::system_program::allocate(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            anchor_lang::system_program::Allocate {
+                account_to_allocate: tick_info.clone(),
+            },
+            &[seeds],
+        ),
+        Tick::LEN as u64,
+    )?;
+    anchor_lang::system_program::assign(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            anchor_lang::system_program::Assign {
+                account_to_assign: tick_info.clone(),
+            },
+            &[seeds],
+        ),
+        &crate::ID,
+    )?;
+
+    tick_info.try_borrow_mut_data()?[..8].copy_from_slice(&Tick::discriminator());
+    let mut tick = load_tick_mut(tick_info)?;
+    *tick = Tick::create(*pool_address, pool, index, current_timestamp, bump);
+
+    emit!(CreateTickEvent {
+        pool: tick.pool,
+        index,
+        fee_growth_outside_x: tick.fee_growth_outside_x.v,
+        fee_growth_outside_y: tick.fee_growth_outside_y.v,
+        seconds_outside: tick.seconds_outside,
+        seconds_per_liquidity_outside: tick.seconds_per_liquidity_outside,
+    });
+
+    Ok(())
+}
+
+// Loads tick passed as unchecked account, address has to be validated by the caller
+pub fn load_tick_mut<'a>(tick_info: &'a AccountInfo) -> Result<RefMut<'a, Tick>> {
+    if *tick_info.owner != crate::ID {
+        return Err(anchor_lang::error::ErrorCode::AccountOwnedByWrongProgram.into());
+    }
+    let data = tick_info.try_borrow_mut_data()?;
+    if data.len() < Tick::LEN || data[..8] != Tick::discriminator() {
+        return Err(anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch.into());
+    }
+
+    Ok(RefMut::map(data, |data| {
+        bytemuck::from_bytes_mut(&mut data[8..Tick::LEN])
+    }))
+}
+
+// Signer has to be the position owner or an operator approved for the action
+pub fn check_position_authority(
+    position: &Position,
+    authority: &Pubkey,
+    position_operator: &Option<AccountLoader<PositionOperator>>,
+    permission: u8,
+) -> Result<()> {
+    if position.owner == *authority {
+        return Ok(());
+    }
+    let approved = match position_operator {
+        Some(position_operator) => position_operator
+            .load()?
+            .is_approved(position, authority, permission),
+        None => false,
+    };
+    require!(approved, ErrorCode::Unauthorized);
+
+    Ok(())
+}
+
+// Initializes position and deposits its liquidity, shared by legacy and stable positions
+#[allow(clippy::too_many_arguments)]
+pub fn open_position(
+    position: &mut Position,
+    pool: &mut Pool,
+    lower_tick: &mut Tick,
+    upper_tick: &mut Tick,
+    tickmap: &mut Tickmap,
+    owner: Pubkey,
+    pool_address: Pubkey,
+    liquidity_delta: Liquidity,
+    slippage_limit_lower: Price,
+    slippage_limit_upper: Price,
+    bump: u8,
+) -> Result<(TokenAmount, TokenAmount)> {
+    let current_timestamp = get_current_timestamp();
+    let slot = get_current_slot();
+
+    // validate price
+    let price = pool.sqrt_price;
+    require!(price >= slippage_limit_lower, ErrorCode::PriceLimitReached);
+    require!(price <= slippage_limit_upper, ErrorCode::PriceLimitReached);
+
+    // validate ticks
+    check_ticks(lower_tick.index, upper_tick.index, pool.tick_spacing)?;
+
+    if !tickmap.get(lower_tick.index, pool.tick_spacing) {
+        tickmap.flip(true, lower_tick.index, pool.tick_spacing)
+    }
+    if !tickmap.get(upper_tick.index, pool.tick_spacing) {
+        tickmap.flip(true, upper_tick.index, pool.tick_spacing)
+    }
+
+    position.initialized_id(pool);
+
+    // init position
+    *position = Position {
+        owner,
+        pool: pool_address,
+        id: position.id,
+        liquidity: Liquidity::new(0),
+        lower_tick_index: lower_tick.index,
+        upper_tick_index: upper_tick.index,
+        fee_growth_inside_x: FeeGrowth::new(0),
+        fee_growth_inside_y: FeeGrowth::new(0),
+        seconds_per_liquidity_inside: SecondsPerLiquidity::new(0),
+        last_slot: slot,
+        tokens_owed_x: FixedPoint::new(0),
+        tokens_owed_y: FixedPoint::new(0),
+        bump,
+    };
+
+    let (amount_x, amount_y) = position.modify(
+        pool,
+        upper_tick,
+        lower_tick,
+        liquidity_delta,
+        true,
+        current_timestamp,
+    )?;
+    assert!(position.tokens_owed_x.is_zero());
+    assert!(position.tokens_owed_y.is_zero());
+
+    emit!(CreatePositionEvent {
+        owner,
+        pool: pool_address,
+        id: position.id,
+        lower_tick: lower_tick.index,
+        upper_tick: upper_tick.index,
+        liquidity: liquidity_delta,
+        current_timestamp,
+        seconds_per_liquidity_inside_initial: calculate_seconds_per_liquidity_inside(
+            *lower_tick,
+            *upper_tick,
+            pool,
+            current_timestamp
+        )
+    });
+
+    Ok((amount_x, amount_y))
+}
+
+// Withdraws whole liquidity with owed tokens and closes ticks left empty, the position account is handled by the caller
+pub fn close_position<'info>(
+    position: &mut Position,
+    pool: &mut Pool,
+    lower_tick: &AccountLoader<'info, Tick>,
+    upper_tick: &AccountLoader<'info, Tick>,
+    tickmap: &mut Tickmap,
+    payer: AccountInfo<'info>,
+) -> Result<(TokenAmount, TokenAmount, RemovePositionEvent)> {
+    let current_timestamp = get_current_timestamp();
+    let lower_tick_index = position.lower_tick_index;
+    let upper_tick_index = position.upper_tick_index;
+
+    // closing tick can't be in the same scope as loaded tick
+    let close_lower;
+    let close_upper;
+
+    let mut remove_position_event = RemovePositionEvent {
+        owner: position.owner,
+        pool: position.pool,
+        id: position.id,
+        liquidity: position.liquidity,
+        upper_tick: upper_tick_index,
+        current_tick: pool.current_tick_index,
+        lower_tick: lower_tick_index,
+        upper_tick_seconds_per_liquidity_outside: SecondsPerLiquidity::default(),
+        lower_tick_seconds_per_liquidity_outside: SecondsPerLiquidity::default(),
+        pool_seconds_per_liquidity_global: SecondsPerLiquidity::default(),
+        current_timestamp,
+    };
+
+    let (amount_x, amount_y) = {
+        let lower_tick = &mut lower_tick.load_mut()?;
+        let upper_tick = &mut upper_tick.load_mut()?;
+
+        // validate ticks
+        check_ticks(lower_tick.index, upper_tick.index, pool.tick_spacing)?;
+        let liquidity_delta = position.liquidity;
+        let (amount_x, amount_y) = position.modify(
+            pool,
+            upper_tick,
+            lower_tick,
+            liquidity_delta,
+            false,
+            current_timestamp,
+        )?;
+
+        let amount_x = amount_x + TokenAmount::from_decimal(position.tokens_owed_x);
+        let amount_y = amount_y + TokenAmount::from_decimal(position.tokens_owed_y);
+
+        close_lower = lower_tick.liquidity_gross.is_zero();
+        close_upper = upper_tick.liquidity_gross.is_zero();
+
+        remove_position_event.lower_tick_seconds_per_liquidity_outside =
+            lower_tick.seconds_per_liquidity_outside;
+        remove_position_event.upper_tick_seconds_per_liquidity_outside =
+            upper_tick.seconds_per_liquidity_outside;
+        remove_position_event.pool_seconds_per_liquidity_global = pool.seconds_per_liquidity_global;
+
+        (amount_x, amount_y)
+    };
+
+    if close_lower {
+        {
+            let lower_tick = &mut lower_tick.load_mut()?;
+            **lower_tick = Default::default();
+        }
+        close(lower_tick.to_account_info(), payer.clone()).unwrap();
+
+        tickmap.flip(false, lower_tick_index, pool.tick_spacing);
+    }
+    if close_upper {
+        {
+            let upper_tick = &mut upper_tick.load_mut()?;
+            **upper_tick = Default::default();
+        }
+        close(upper_tick.to_account_info(), payer).unwrap();
+
+        tickmap.flip(false, upper_tick_index, pool.tick_spacing);
+    }
+
+    Ok((amount_x, amount_y, remove_position_event))
+}
+
+// Operation is blocked if its bit is set either globally or on the pool
+pub fn check_not_paused(state: &State, pool: &Pool, operation: u8) -> Result<()> {
+    require!(
+        (state.paused | pool.paused) & operation == 0,
+        ErrorCode::OperationPaused
+    );
+
+    Ok(())
+}
+
+pub fn find_tick_account<'info>(
+    remaining_accounts: &'info [AccountInfo<'info>],
+    program_id: &Pubkey,
+    pool_address: &Pubkey,
+    tick_index: i32,
+) -> Result<AccountLoader<'info, Tick>> {
+    // Calculating address of the crossed tick
+    let (tick_address, _) = Pubkey::find_program_address(
+        &[b"tickv1", pool_address.as_ref(), &tick_index.to_le_bytes()],
+        program_id,
+    );
+
+    // Finding the correct tick in remaining accounts
+    match remaining_accounts
+        .iter()
+        .find(|account| *account.key == tick_address)
+    {
+        Some(account) => AccountLoader::<'info, Tick>::try_from(account),
+        None => Err(ErrorCode::TickNotFound.into()),
+    }
+}
+
+// Appends the pool price to its oracle, which is required among remaining accounts once initialized
+// so no swap moving the price can skip the record
+pub fn record_oracle_price<'info>(
+    remaining_accounts: &'info [AccountInfo<'info>],
+    pool: &Pool,
+    sqrt_price_before: Price,
+) -> Result<()> {
+    let oracle_address = pool.oracle_address;
+    let loader = match remaining_accounts
+        .iter()
+        .find(|account| *account.key == oracle_address)
+    {
+        Some(account) => AccountLoader::<'_, Oracle>::try_from(account)?,
+        None => return Err(ErrorCode::OracleNotFound.into()),
+    };
+    if { pool.sqrt_price } == sqrt_price_before {
+        return Ok(());
+    }
+    let mut oracle = loader.load_mut()?;
+    oracle.update(get_current_timestamp(), pool.sqrt_price);
+
+    Ok(())
+}
+
+// Calls `name` instruction of the callback program using anchor discriminator
+// accounts are passed through without program authority signature
+pub fn invoke_callback<'info>(
+    callback_program: &AccountInfo<'info>,
+    accounts: &[AccountInfo<'info>],
+    name: &str,
+    args: impl AnchorSerialize,
+) -> Result<()> {
+    require!(
+        callback_program.executable && *callback_program.key != crate::ID,
+        ErrorCode::InvalidCallbackProgram
+    );
+
+    let preimage = format!("global:{}", name);
+    let mut data = hash::hash(preimage.as_bytes()).to_bytes()[..8].to_vec();
+    args.serialize(&mut data)?;
+
+    let instruction = Instruction {
+        program_id: *callback_program.key,
+        accounts: accounts
+            .iter()
+            .map(|account| AccountMeta {
+                pubkey: *account.key,
+                is_signer: account.is_signer,
+                is_writable: account.is_writable,
+            })
+            .collect(),
+        data,
+    };
+    let mut account_infos = accounts.to_vec();
+    account_infos.push(callback_program.clone());
+    invoke(&instruction, &account_infos)?;
+
+    Ok(())
+}
+
 pub fn get_current_timestamp() -> u64 {
     Clock::get().unwrap().unix_timestamp.try_into().unwrap()
 }
@@ -170,6 +718,47 @@ pub fn is_supported_mint(mint_account: &InterfaceAccount<Mint>) -> Result<bool>
     Ok(true)
 }
 
+// Transfer between token accounts of either token program, `signer` is empty for user authorities
+pub fn transfer_tokens<'info>(
+    token_program: AccountInfo<'info>,
+    mint: &InterfaceAccount<'info, Mint>,
+    from: AccountInfo<'info>,
+    to: AccountInfo<'info>,
+    authority: AccountInfo<'info>,
+    amount: u64,
+    signer: &[&[&[u8]]],
+) -> Result<()> {
+    match token_program.key() {
+        token_2022::ID => token_2022::transfer_checked(
+            CpiContext::new_with_signer(
+                token_program,
+                token_2022::TransferChecked {
+                    mint: mint.to_account_info(),
+                    from,
+                    to,
+                    authority,
+                },
+                signer,
+            ),
+            amount,
+            mint.decimals,
+        ),
+        token::ID => token::transfer(
+            CpiContext::new_with_signer(
+                token_program,
+                token::Transfer {
+                    from,
+                    to,
+                    authority,
+                },
+                signer,
+            ),
+            amount,
+        ),
+        _ => Err(ErrorCode::InvalidTokenProgram.into()),
+    }
+}
+
 #[cfg(test)]
 mod test {
     use std::cell::RefCell;
@@ -216,6 +805,129 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_process_swap() -> Result<()> {
+        let tickmap = &mut Tickmap::default();
+        tickmap.flip(true, -10, 1);
+        let pool = Pool {
+            liquidity: Liquidity::from_integer(1_000_000),
+            sqrt_price: Price::from_integer(1),
+            current_tick_index: 0,
+            tick_spacing: 1,
+            fee: FixedPoint::from_scale(3, 3),
+            ..Default::default()
+        };
+        // swap stays between ticks
+        {
+            let mut pool = pool;
+            let mut crossed = vec![];
+            let summary = process_swap(
+                &mut pool,
+                tickmap,
+                true,
+                TokenAmount(100),
+                true,
+                Price::new(MIN_SQRT_PRICE),
+                false,
+                FixedPoint::from_integer(0),
+                0,
+                |_, index| {
+                    crossed.push(index);
+                    Ok(())
+                },
+            )?;
+            assert_eq!(summary.amount_in, TokenAmount(100));
+            assert_eq!(summary.amount_out, TokenAmount(98));
+            assert_eq!(summary.fee, TokenAmount(1));
+            assert_eq!(summary.referral_fee, TokenAmount(0));
+            assert!(crossed.is_empty());
+            assert!(summary.crossed_ticks.is_empty());
+            assert_eq!({ pool.current_tick_index }, -2);
+        }
+        // swap crosses initialized tick
+        {
+            let mut pool = pool;
+            let mut crossed = vec![];
+            let summary = process_swap(
+                &mut pool,
+                tickmap,
+                true,
+                TokenAmount(10_000),
+                true,
+                Price::new(MIN_SQRT_PRICE),
+                false,
+                FixedPoint::from_integer(0),
+                0,
+                |_, index| {
+                    crossed.push(index);
+                    Ok(())
+                },
+            )?;
+            assert_eq!(summary.amount_in, TokenAmount(10_000));
+            assert_eq!(crossed, vec![-10]);
+            assert_eq!(summary.crossed_ticks, vec![-10]);
+            assert!({ pool.current_tick_index } < -10);
+        }
+        // price limit reached
+        {
+            let mut pool = pool;
+            let result = process_swap(
+                &mut pool,
+                tickmap,
+                true,
+                TokenAmount(10_000),
+                true,
+                calculate_price_sqrt(-5),
+                false,
+                FixedPoint::from_integer(0),
+                0,
+                |_, _| Ok(()),
+            );
+            assert!(result.is_err());
+        }
+        // price limit reached with partial fill
+        {
+            let mut pool = pool;
+            let summary = process_swap(
+                &mut pool,
+                tickmap,
+                true,
+                TokenAmount(10_000),
+                true,
+                calculate_price_sqrt(-5),
+                true,
+                FixedPoint::from_integer(0),
+                0,
+                |_, _| Ok(()),
+            )?;
+            assert_eq!({ pool.sqrt_price }, calculate_price_sqrt(-5));
+            assert_eq!({ pool.current_tick_index }, -5);
+            assert!(!summary.remaining_amount.is_zero());
+            assert_eq!(
+                summary.amount_in + summary.remaining_amount,
+                TokenAmount(10_000)
+            );
+        }
+        // limit on the wrong side of price
+        {
+            let mut pool = pool;
+            let result = process_swap(
+                &mut pool,
+                tickmap,
+                false,
+                TokenAmount(100),
+                true,
+                calculate_price_sqrt(-5),
+                false,
+                FixedPoint::from_integer(0),
+                0,
+                |_, _| Ok(()),
+            );
+            assert!(result.is_err());
+        }
+        Ok(())
+    }
+
     #[test]
     fn test_cross_tick() -> Result<()> {
         let max_time_range = 10 * 365 * 24 * 3600;
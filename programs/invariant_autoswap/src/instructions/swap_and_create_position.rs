@@ -244,6 +244,9 @@ impl<'info> SwapAndCreatePosition<'info> {
             amount,
             by_amount_in,
             sqrt_price_limit.v,
+            None,
+            None,
+            false,
         )?;
 
         ctx.accounts.account_x.reload()?;
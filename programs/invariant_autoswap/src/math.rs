@@ -1,283 +1,10 @@
-use decimal::U192;
-use invariant::{decimals::*, math::calculate_price_sqrt};
-use std::convert::TryInto;
-
-#[derive(Debug)]
-pub struct LiquidityResult {
-    #[allow(dead_code)]
-    pub x: TokenAmount,
-    #[allow(dead_code)]
-    pub y: TokenAmount,
-    pub l: Liquidity,
-}
-
-#[derive(Debug)]
-pub struct SingleTokenLiquidity {
-    pub l: Liquidity,
-    pub amount: TokenAmount,
-}
-
-pub fn get_liquidity_by_y_sqrt_price(
-    y: TokenAmount,
-    lower_sqrt_price: Price,
-    upper_sqrt_price: Price,
-    current_sqrt_price: Price,
-    rounding_up: bool,
-) -> SingleTokenLiquidity {
-    assert!(
-        current_sqrt_price > lower_sqrt_price,
-        "Current sqrt price is less than or equal to lower sqrt price"
-    );
-
-    if upper_sqrt_price <= current_sqrt_price {
-        let sqrt_price_diff = upper_sqrt_price.checked_sub(lower_sqrt_price).unwrap();
-        let liquidity = Liquidity::new(
-            U192::from(y.get())
-                .checked_mul(U192::from(Price::from_integer(1).get()))
-                .unwrap()
-                .checked_mul(U192::from(Liquidity::from_integer(1).get()))
-                .unwrap()
-                .checked_div(U192::from(sqrt_price_diff.get()))
-                .unwrap()
-                .try_into()
-                .unwrap(),
-        );
-        return SingleTokenLiquidity {
-            l: liquidity,
-            amount: TokenAmount(0),
-        };
-    }
-
-    let sqrt_price_diff = current_sqrt_price.checked_sub(lower_sqrt_price).unwrap();
-    let liquidity = Liquidity::new(
-        U192::from(y.get())
-            .checked_mul(U192::from(Price::from_integer(1).get()))
-            .unwrap()
-            .checked_mul(U192::from(Liquidity::from_integer(1).get()))
-            .unwrap()
-            .checked_div(U192::from(sqrt_price_diff.get()))
-            .unwrap()
-            .try_into()
-            .unwrap(),
-    );
-    let denominator = current_sqrt_price.big_mul(upper_sqrt_price);
-    let nominator = upper_sqrt_price.checked_sub(current_sqrt_price).unwrap();
-
-    let x = calculate_x(nominator, denominator, liquidity, rounding_up);
-
-    SingleTokenLiquidity {
-        l: liquidity,
-        amount: x,
-    }
-}
-
-pub fn get_liquidity_by_x_sqrt_price(
-    x: TokenAmount,
-    lower_sqrt_price: Price,
-    upper_sqrt_price: Price,
-    current_sqrt_price: Price,
-    rounding_up: bool,
-) -> SingleTokenLiquidity {
-    assert!(
-        upper_sqrt_price > current_sqrt_price,
-        "Upper sqrt price is less than current sqrt price"
-    );
-
-    if current_sqrt_price < lower_sqrt_price {
-        let nominator = lower_sqrt_price.big_mul(upper_sqrt_price);
-        let denominator = upper_sqrt_price.checked_sub(lower_sqrt_price).unwrap();
-        let liquidity = Liquidity::new(
-            U256::from(x.get())
-                .checked_mul(U256::from(nominator.get()))
-                .unwrap()
-                .checked_mul(U256::from(Liquidity::from_integer(1).get()))
-                .unwrap()
-                .checked_div(U256::from(denominator.get()))
-                .unwrap()
-                .try_into()
-                .unwrap(),
-        );
-        return SingleTokenLiquidity {
-            l: liquidity,
-            amount: TokenAmount(0),
-        };
-    }
-
-    let nominator = current_sqrt_price.big_mul(upper_sqrt_price);
-    let denominator = upper_sqrt_price.checked_sub(current_sqrt_price).unwrap();
-    let liquidity = Liquidity::new(
-        U256::from(x.get())
-            .checked_mul(U256::from(nominator.get()))
-            .unwrap()
-            .checked_mul(U256::from(Liquidity::from_integer(1).get()))
-            .unwrap()
-            .checked_div(U256::from(denominator.get()))
-            .unwrap()
-            .try_into()
-            .unwrap(),
-    );
-
-    let sqrt_price_diff = current_sqrt_price.checked_sub(lower_sqrt_price).unwrap();
-    let y = calculate_y(sqrt_price_diff, liquidity, rounding_up);
-    SingleTokenLiquidity {
-        l: liquidity,
-        amount: y,
-    }
-}
-
-pub fn calculate_x(
-    nominator: Price,
-    denominator: Price,
-    liquidity: Liquidity,
-    rounding_up: bool,
-) -> TokenAmount {
-    let common = liquidity.big_mul(nominator).big_div(denominator).get();
-
-    if rounding_up {
-        TokenAmount::new(
-            (U192::from(common)
-                .checked_add(U192::from(Liquidity::from_integer(1).get()))
-                .unwrap()
-                .checked_sub(U192::from(1))
-                .unwrap()
-                .checked_div(U192::from(Liquidity::from_integer(1).get())))
-            .unwrap()
-            .try_into()
-            .unwrap(),
-        )
-    } else {
-        TokenAmount::new(
-            (common.checked_div(Liquidity::from_integer(1).get()))
-                .unwrap()
-                .try_into()
-                .unwrap(),
-        )
-    }
-}
-
-pub fn calculate_y(sqrt_price_diff: Price, liquidity: Liquidity, rounding_up: bool) -> TokenAmount {
-    let shifted_liquidity = liquidity
-        .get()
-        .checked_div(Liquidity::from_integer(1).get())
-        .unwrap();
-    if rounding_up {
-        TokenAmount::new(
-            (U256::from(sqrt_price_diff.get()).checked_mul(U256::from(shifted_liquidity)))
-                .unwrap()
-                .checked_add(U256::from(
-                    Price::from_integer(1).get().checked_sub(1).unwrap(),
-                ))
-                .unwrap()
-                .checked_div(U256::from(Price::from_integer(1).get()))
-                .unwrap()
-                .try_into()
-                .unwrap(),
-        )
-    } else {
-        TokenAmount::new(
-            U256::from(sqrt_price_diff.get())
-                .checked_mul(U256::from(shifted_liquidity))
-                .unwrap()
-                .checked_div(U256::from(Price::from_integer(1).get()))
-                .unwrap()
-                .try_into()
-                .unwrap(),
-        )
-    }
-}
-
-pub fn get_max_liquidity(
-    x: TokenAmount,
-    y: TokenAmount,
-    lower_tick: i32,
-    upper_tick: i32,
-    current_sqrt_price: Price,
-    rounding_up: bool,
-) -> LiquidityResult {
-    let lower_sqrt_price = calculate_price_sqrt(lower_tick);
-    let upper_sqrt_price = calculate_price_sqrt(upper_tick);
-
-    if upper_sqrt_price <= current_sqrt_price {
-        let liquidity = get_liquidity_by_y_sqrt_price(
-            y,
-            lower_sqrt_price,
-            upper_sqrt_price,
-            current_sqrt_price,
-            rounding_up,
-        );
-        return LiquidityResult {
-            l: liquidity.l,
-            x: liquidity.amount,
-            y,
-        };
-    }
-
-    if current_sqrt_price <= lower_sqrt_price {
-        let liquidity = get_liquidity_by_x_sqrt_price(
-            x,
-            lower_sqrt_price,
-            upper_sqrt_price,
-            current_sqrt_price,
-            rounding_up,
-        );
-        return LiquidityResult {
-            l: liquidity.l,
-            x,
-            y: liquidity.amount,
-        };
-    }
-
-    let result_by_y = get_liquidity_by_y_sqrt_price(
-        y,
-        lower_sqrt_price,
-        upper_sqrt_price,
-        current_sqrt_price,
-        rounding_up,
-    );
-    let result_by_x = get_liquidity_by_x_sqrt_price(
-        x,
-        lower_sqrt_price,
-        upper_sqrt_price,
-        current_sqrt_price,
-        rounding_up,
-    );
-
-    let result = if result_by_x.l > result_by_y.l {
-        if result_by_x.amount <= y {
-            LiquidityResult {
-                x,
-                y: result_by_x.amount,
-                l: result_by_x.l,
-            }
-        } else {
-            LiquidityResult {
-                x: result_by_y.amount,
-                y,
-                l: result_by_y.l,
-            }
-        }
-    } else {
-        if result_by_y.amount <= x {
-            LiquidityResult {
-                x: result_by_y.amount,
-                y,
-                l: result_by_y.l,
-            }
-        } else {
-            LiquidityResult {
-                x,
-                y: result_by_x.amount,
-                l: result_by_x.l,
-            }
-        }
-    };
-
-    result
-}
+// Liquidity math is shared with the invariant program
+pub use invariant::math::{get_max_liquidity, LiquidityResult};
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use invariant::decimals::*;
     use invariant::math::*;
     use invariant::structs::*;
     use invariant::util::*;
@@ -146,6 +146,7 @@ impl<'info> ClaimFee<'info> {
                 program_authority: self.inv_program_authority.to_account_info(),
                 token_x_program: self.token_x_program.to_account_info(),
                 token_y_program: self.token_y_program.to_account_info(),
+                position_operator: None,
             },
         )
     }
@@ -38,6 +38,71 @@ export type Invariant = {
         }
       ]
     },
+    {
+      "name": "proposeAdmin",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "pendingAdmin",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "acceptAdmin",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pendingAdmin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setRole",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "holder",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "role",
+          "type": {
+            "defined": "Role"
+          }
+        }
+      ]
+    },
     {
       "name": "createFeeTier",
       "accounts": [
@@ -78,6 +143,87 @@ export type Invariant = {
         }
       ]
     },
+    {
+      "name": "changeFeeTier",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "feeTier",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "disabled",
+          "type": "bool"
+        },
+        {
+          "name": "minFee",
+          "type": {
+            "defined": "FixedPoint"
+          }
+        },
+        {
+          "name": "maxFee",
+          "type": {
+            "defined": "FixedPoint"
+          }
+        }
+      ]
+    },
+    {
+      "name": "changePoolFee",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeTier",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "fee",
+          "type": {
+            "defined": "FixedPoint"
+          }
+        }
+      ]
+    },
     {
       "name": "createPool",
       "accounts": [
@@ -273,6 +419,87 @@ export type Invariant = {
       ],
       "args": []
     },
+    {
+      "name": "closePool",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tickmap",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "oracle",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "reserveY",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "receiverAccountX",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "receiverAccountY",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "programAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenXProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenYProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
     {
       "name": "swap",
       "accounts": [
@@ -358,58 +585,87 @@ export type Invariant = {
         {
           "name": "sqrtPriceLimit",
           "type": "u128"
+        },
+        {
+          "name": "minAmountOut",
+          "type": {
+            "option": "u64"
+          }
+        },
+        {
+          "name": "maxAmountIn",
+          "type": {
+            "option": "u64"
+          }
+        },
+        {
+          "name": "partialFill",
+          "type": "bool"
         }
-      ]
+      ],
+      "returns": "u64"
     },
     {
-      "name": "initializeOracle",
+      "name": "swapRoute",
       "accounts": [
         {
-          "name": "pool",
-          "isMut": true,
+          "name": "state",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "oracle",
-          "isMut": true,
+          "name": "tokenIn",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenX",
-          "isMut": false,
+          "name": "accountIn",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenY",
-          "isMut": false,
+          "name": "accountOut",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "payer",
+          "name": "owner",
           "isMut": false,
           "isSigner": true
         },
         {
-          "name": "rent",
+          "name": "programAuthority",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
+          "name": "tokenInProgram",
           "isMut": false,
           "isSigner": false
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "hops",
+          "type": "u8"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "byAmountIn",
+          "type": "bool"
+        },
+        {
+          "name": "amountLimit",
+          "type": "u64"
+        }
+      ]
     },
     {
-      "name": "createTick",
+      "name": "quoteSwap",
       "accounts": [
-        {
-          "name": "tick",
-          "isMut": true,
-          "isSigner": false
-        },
         {
           "name": "pool",
           "isMut": false,
@@ -417,126 +673,49 @@ export type Invariant = {
         },
         {
           "name": "tickmap",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "payer",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "tokenX",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "tokenY",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "rent",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "tokenXProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "tokenYProgram",
           "isMut": false,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "index",
-          "type": "i32"
-        }
-      ]
-    },
-    {
-      "name": "createPositionList",
-      "accounts": [
-        {
-          "name": "positionList",
-          "isMut": true,
-          "isSigner": false
+          "name": "xToY",
+          "type": "bool"
         },
         {
-          "name": "owner",
-          "isMut": false,
-          "isSigner": false
+          "name": "amount",
+          "type": "u64"
         },
         {
-          "name": "signer",
-          "isMut": true,
-          "isSigner": true
+          "name": "byAmountIn",
+          "type": "bool"
         },
         {
-          "name": "rent",
-          "isMut": false,
-          "isSigner": false
+          "name": "sqrtPriceLimit",
+          "type": "u128"
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
+          "name": "partialFill",
+          "type": "bool"
         }
       ],
-      "args": []
+      "returns": {
+        "defined": "QuoteResult"
+      }
     },
     {
-      "name": "createPosition",
+      "name": "flashSwap",
       "accounts": [
         {
           "name": "state",
           "isMut": false,
           "isSigner": false
         },
-        {
-          "name": "position",
-          "isMut": true,
-          "isSigner": false
-        },
         {
           "name": "pool",
           "isMut": true,
           "isSigner": false
         },
-        {
-          "name": "positionList",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "payer",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "owner",
-          "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "lowerTick",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "upperTick",
-          "isMut": true,
-          "isSigner": false
-        },
         {
           "name": "tickmap",
           "isMut": true,
@@ -573,118 +752,67 @@ export type Invariant = {
           "isSigner": false
         },
         {
-          "name": "programAuthority",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "tokenXProgram",
+          "name": "owner",
           "isMut": false,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "tokenYProgram",
+          "name": "programAuthority",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "rent",
+          "name": "callbackProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
+          "name": "tokenXProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "eventOptAcc",
+          "name": "tokenYProgram",
           "isMut": false,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "lowerTickIndex",
-          "type": "i32"
+          "name": "xToY",
+          "type": "bool"
         },
         {
-          "name": "upperTickIndex",
-          "type": "i32"
+          "name": "amount",
+          "type": "u64"
         },
         {
-          "name": "liquidityDelta",
-          "type": {
-            "defined": "Liquidity"
-          }
+          "name": "byAmountIn",
+          "type": "bool"
         },
         {
-          "name": "slippageLimitLower",
-          "type": {
-            "defined": "Price"
-          }
+          "name": "sqrtPriceLimit",
+          "type": "u128"
         },
         {
-          "name": "slippageLimitUpper",
-          "type": {
-            "defined": "Price"
-          }
+          "name": "data",
+          "type": "bytes"
         }
       ]
     },
     {
-      "name": "removePosition",
+      "name": "flashLoan",
       "accounts": [
         {
           "name": "state",
           "isMut": false,
           "isSigner": false
         },
-        {
-          "name": "removedPosition",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "positionList",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "lastPosition",
-          "isMut": true,
-          "isSigner": false
-        },
         {
           "name": "pool",
           "isMut": true,
           "isSigner": false
         },
-        {
-          "name": "tickmap",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "lowerTick",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "upperTick",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "payer",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "owner",
-          "isMut": false,
-          "isSigner": true
-        },
         {
           "name": "tokenX",
           "isMut": false,
@@ -715,134 +843,133 @@ export type Invariant = {
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
         {
           "name": "programAuthority",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenXProgram",
+          "name": "callbackProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenYProgram",
+          "name": "tokenXProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "eventOptAcc",
+          "name": "tokenYProgram",
           "isMut": false,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "index",
-          "type": "u32"
+          "name": "amountX",
+          "type": "u64"
         },
         {
-          "name": "lowerTickIndex",
-          "type": "i32"
+          "name": "amountY",
+          "type": "u64"
         },
         {
-          "name": "upperTickIndex",
-          "type": "i32"
+          "name": "data",
+          "type": "bytes"
         }
       ]
     },
     {
-      "name": "transferPositionOwnership",
+      "name": "initializeOracle",
       "accounts": [
         {
-          "name": "ownerList",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "recipientList",
+          "name": "pool",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "newPosition",
+          "name": "oracle",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "removedPosition",
-          "isMut": true,
+          "name": "tokenX",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "lastPosition",
-          "isMut": true,
+          "name": "tokenY",
+          "isMut": false,
           "isSigner": false
         },
         {
           "name": "payer",
-          "isMut": true,
+          "isMut": false,
           "isSigner": true
         },
         {
-          "name": "owner",
+          "name": "rent",
           "isMut": false,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "recipient",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
-        },
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "getTwap",
+      "accounts": [
         {
-          "name": "rent",
+          "name": "pool",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
+          "name": "oracle",
           "isMut": false,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "index",
-          "type": "u32"
+          "name": "window",
+          "type": "u64"
         }
-      ]
+      ],
+      "returns": {
+        "defined": "Price"
+      }
     },
     {
-      "name": "claimFee",
+      "name": "createTick",
       "accounts": [
         {
-          "name": "state",
-          "isMut": false,
+          "name": "tick",
+          "isMut": true,
           "isSigner": false
         },
         {
           "name": "pool",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "position",
+          "name": "tickmap",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "lowerTick",
+          "name": "payer",
           "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "upperTick",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "owner",
-          "isMut": false,
           "isSigner": true
         },
         {
@@ -856,27 +983,12 @@ export type Invariant = {
           "isSigner": false
         },
         {
-          "name": "accountX",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "accountY",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "reserveX",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "reserveY",
-          "isMut": true,
+          "name": "rent",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "programAuthority",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         },
@@ -894,49 +1006,63 @@ export type Invariant = {
       "args": [
         {
           "name": "index",
-          "type": "u32"
-        },
-        {
-          "name": "lowerTickIndex",
-          "type": "i32"
-        },
-        {
-          "name": "upperTickIndex",
           "type": "i32"
         }
       ]
     },
     {
-      "name": "updateSecondsPerLiquidity",
+      "name": "createPositionList",
       "accounts": [
         {
-          "name": "pool",
+          "name": "positionList",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "lowerTick",
+          "name": "owner",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "upperTick",
-          "isMut": false,
-          "isSigner": false
+          "name": "signer",
+          "isMut": true,
+          "isSigner": true
         },
         {
-          "name": "position",
-          "isMut": true,
+          "name": "rent",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenX",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closePositionList",
+      "accounts": [
+        {
+          "name": "positionList",
+          "isMut": true,
+          "isSigner": false
         },
         {
-          "name": "tokenY",
-          "isMut": false,
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createStablePositionList",
+      "accounts": [
+        {
+          "name": "positionList",
+          "isMut": true,
           "isSigner": false
         },
         {
@@ -960,34 +1086,56 @@ export type Invariant = {
           "isSigner": false
         }
       ],
-      "args": [
-        {
-          "name": "lowerTickIndex",
-          "type": "i32"
-        },
-        {
-          "name": "upperTickIndex",
-          "type": "i32"
-        },
-        {
-          "name": "index",
-          "type": "i32"
-        }
-      ]
+      "args": []
     },
     {
-      "name": "withdrawProtocolFee",
+      "name": "createPosition",
       "accounts": [
         {
           "name": "state",
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "position",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "pool",
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "positionList",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "lowerTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "upperTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tickmap",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "tokenX",
           "isMut": false,
@@ -1018,11 +1166,6 @@ export type Invariant = {
           "isMut": true,
           "isSigner": false
         },
-        {
-          "name": "authority",
-          "isMut": false,
-          "isSigner": true
-        },
         {
           "name": "programAuthority",
           "isMut": false,
@@ -1037,66 +1180,100 @@ export type Invariant = {
           "name": "tokenYProgram",
           "isMut": false,
           "isSigner": false
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "changeProtocolFee",
-      "accounts": [
+        },
         {
-          "name": "state",
+          "name": "rent",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "pool",
-          "isMut": true,
+          "name": "systemProgram",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenX",
+          "name": "eventOptAcc",
           "isMut": false,
           "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "lowerTickIndex",
+          "type": "i32"
         },
         {
-          "name": "tokenY",
-          "isMut": false,
-          "isSigner": false
+          "name": "upperTickIndex",
+          "type": "i32"
         },
         {
-          "name": "admin",
-          "isMut": false,
-          "isSigner": true
+          "name": "liquidityDelta",
+          "type": {
+            "defined": "Liquidity"
+          }
         },
         {
-          "name": "programAuthority",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
+          "name": "slippageLimitLower",
+          "type": {
+            "defined": "Price"
+          }
+        },
         {
-          "name": "protocolFee",
+          "name": "slippageLimitUpper",
           "type": {
-            "defined": "FixedPoint"
+            "defined": "Price"
           }
         }
       ]
     },
     {
-      "name": "changeFeeReceiver",
+      "name": "createPositionByAmounts",
       "accounts": [
         {
           "name": "state",
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "position",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "pool",
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "positionList",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "lowerTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "upperTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tickmap",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "tokenX",
           "isMut": false,
@@ -1108,921 +1285,459 @@ export type Invariant = {
           "isSigner": false
         },
         {
-          "name": "admin",
+          "name": "accountX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "accountY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programAuthority",
           "isMut": false,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "feeReceiver",
+          "name": "tokenXProgram",
           "isMut": false,
           "isSigner": false
-        }
-      ],
-      "args": []
-    }
-  ],
-  "accounts": [
-    {
-      "name": "feeTier",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "fee",
-            "type": {
-              "defined": "FixedPoint"
-            }
-          },
-          {
-            "name": "tickSpacing",
-            "type": "u16"
-          },
-          {
-            "name": "bump",
-            "type": "u8"
-          }
-        ]
-      }
-    },
-    {
-      "name": "oracle",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "data",
-            "type": {
-              "array": [
-                {
-                  "defined": "Record"
-                },
-                256
-              ]
-            }
-          },
-          {
-            "name": "head",
-            "type": "u16"
-          },
-          {
-            "name": "amount",
-            "type": "u16"
-          },
-          {
-            "name": "size",
-            "type": "u16"
+        },
+        {
+          "name": "tokenYProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventOptAcc",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "lowerTickIndex",
+          "type": "i32"
+        },
+        {
+          "name": "upperTickIndex",
+          "type": "i32"
+        },
+        {
+          "name": "maxAmountX",
+          "type": "u64"
+        },
+        {
+          "name": "maxAmountY",
+          "type": "u64"
+        },
+        {
+          "name": "minLiquidity",
+          "type": {
+            "defined": "Liquidity"
           }
-        ]
-      }
-    },
-    {
-      "name": "pool",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "tokenX",
-            "type": "publicKey"
-          },
-          {
-            "name": "tokenY",
-            "type": "publicKey"
-          },
-          {
-            "name": "tokenXReserve",
-            "type": "publicKey"
-          },
-          {
-            "name": "tokenYReserve",
-            "type": "publicKey"
-          },
-          {
-            "name": "positionIterator",
-            "type": "u128"
-          },
-          {
-            "name": "tickSpacing",
-            "type": "u16"
-          },
-          {
-            "name": "fee",
-            "type": {
-              "defined": "FixedPoint"
-            }
-          },
-          {
-            "name": "protocolFee",
-            "type": {
-              "defined": "FixedPoint"
-            }
-          },
-          {
-            "name": "liquidity",
-            "type": {
-              "defined": "Liquidity"
-            }
-          },
-          {
-            "name": "sqrtPrice",
-            "type": {
-              "defined": "Price"
-            }
-          },
-          {
-            "name": "currentTickIndex",
-            "type": "i32"
-          },
-          {
-            "name": "tickmap",
-            "type": "publicKey"
-          },
-          {
-            "name": "feeGrowthGlobalX",
-            "type": {
-              "defined": "FeeGrowth"
-            }
-          },
-          {
-            "name": "feeGrowthGlobalY",
-            "type": {
-              "defined": "FeeGrowth"
-            }
-          },
-          {
-            "name": "feeProtocolTokenX",
-            "type": "u64"
-          },
-          {
-            "name": "feeProtocolTokenY",
-            "type": "u64"
-          },
-          {
-            "name": "secondsPerLiquidityGlobal",
-            "type": {
-              "defined": "SecondsPerLiquidity"
-            }
-          },
-          {
-            "name": "startTimestamp",
-            "type": "u64"
-          },
-          {
-            "name": "lastTimestamp",
-            "type": "u64"
-          },
-          {
-            "name": "feeReceiver",
-            "type": "publicKey"
-          },
-          {
-            "name": "oracleAddress",
-            "type": "publicKey"
-          },
-          {
-            "name": "oracleInitialized",
-            "type": "bool"
-          },
-          {
-            "name": "bump",
-            "type": "u8"
+        },
+        {
+          "name": "slippageLimitLower",
+          "type": {
+            "defined": "Price"
           }
-        ]
-      }
-    },
-    {
-      "name": "positionList",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "head",
-            "type": "u32"
-          },
-          {
-            "name": "bump",
-            "type": "u8"
+        },
+        {
+          "name": "slippageLimitUpper",
+          "type": {
+            "defined": "Price"
           }
-        ]
-      }
+        }
+      ]
     },
     {
-      "name": "position",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "owner",
-            "type": "publicKey"
-          },
-          {
-            "name": "pool",
-            "type": "publicKey"
-          },
-          {
-            "name": "id",
-            "type": "u128"
-          },
-          {
-            "name": "liquidity",
-            "type": {
-              "defined": "Liquidity"
-            }
-          },
-          {
-            "name": "lowerTickIndex",
-            "type": "i32"
-          },
-          {
-            "name": "upperTickIndex",
-            "type": "i32"
-          },
-          {
-            "name": "feeGrowthInsideX",
-            "type": {
-              "defined": "FeeGrowth"
-            }
-          },
-          {
-            "name": "feeGrowthInsideY",
-            "type": {
-              "defined": "FeeGrowth"
-            }
-          },
-          {
-            "name": "secondsPerLiquidityInside",
-            "type": {
-              "defined": "SecondsPerLiquidity"
-            }
-          },
-          {
-            "name": "lastSlot",
-            "type": "u64"
-          },
-          {
-            "name": "tokensOwedX",
-            "type": {
-              "defined": "FixedPoint"
-            }
-          },
-          {
-            "name": "tokensOwedY",
-            "type": {
-              "defined": "FixedPoint"
-            }
-          },
-          {
-            "name": "bump",
-            "type": "u8"
-          }
-        ]
-      }
-    },
-    {
-      "name": "state",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "admin",
-            "type": "publicKey"
-          },
-          {
-            "name": "nonce",
-            "type": "u8"
-          },
-          {
-            "name": "authority",
-            "type": "publicKey"
-          },
-          {
-            "name": "bump",
-            "type": "u8"
-          }
-        ]
-      }
-    },
-    {
-      "name": "tickmap",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "bitmap",
-            "type": {
-              "array": [
-                "u8",
-                11091
-              ]
-            }
-          }
-        ]
-      }
-    },
-    {
-      "name": "tick",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "pool",
-            "type": "publicKey"
-          },
-          {
-            "name": "index",
-            "type": "i32"
-          },
-          {
-            "name": "sign",
-            "type": "bool"
-          },
-          {
-            "name": "liquidityChange",
-            "type": {
-              "defined": "Liquidity"
-            }
-          },
-          {
-            "name": "liquidityGross",
-            "type": {
-              "defined": "Liquidity"
-            }
-          },
-          {
-            "name": "sqrtPrice",
-            "type": {
-              "defined": "Price"
-            }
-          },
-          {
-            "name": "feeGrowthOutsideX",
-            "type": {
-              "defined": "FeeGrowth"
-            }
-          },
-          {
-            "name": "feeGrowthOutsideY",
-            "type": {
-              "defined": "FeeGrowth"
-            }
-          },
-          {
-            "name": "secondsPerLiquidityOutside",
-            "type": {
-              "defined": "SecondsPerLiquidity"
-            }
-          },
-          {
-            "name": "secondsOutside",
-            "type": "u64"
-          },
-          {
-            "name": "bump",
-            "type": "u8"
-          }
-        ]
-      }
-    }
-  ],
-  "types": [
-    {
-      "name": "Price",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "v",
-            "type": "u128"
-          }
-        ]
-      }
-    },
-    {
-      "name": "Liquidity",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "v",
-            "type": "u128"
-          }
-        ]
-      }
-    },
-    {
-      "name": "FeeGrowth",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "v",
-            "type": "u128"
-          }
-        ]
-      }
-    },
-    {
-      "name": "FixedPoint",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "v",
-            "type": "u128"
-          }
-        ]
-      }
-    },
-    {
-      "name": "SecondsPerLiquidity",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "v",
-            "type": "u128"
-          }
-        ]
-      }
-    },
-    {
-      "name": "Record",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "timestamp",
-            "type": "u64"
-          },
-          {
-            "name": "price",
-            "type": {
-              "defined": "Price"
-            }
-          }
-        ]
-      }
-    }
-  ],
-  "events": [
-    {
-      "name": "CreatePositionEvent",
-      "fields": [
+      "name": "removePosition",
+      "accounts": [
         {
-          "name": "owner",
-          "type": "publicKey",
-          "index": false
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "pool",
-          "type": "publicKey",
-          "index": false
+          "name": "removedPosition",
+          "isMut": true,
+          "isSigner": false
         },
         {
-          "name": "id",
-          "type": "u128",
-          "index": false
+          "name": "positionList",
+          "isMut": true,
+          "isSigner": false
         },
         {
-          "name": "liquidity",
-          "type": {
-            "defined": "Liquidity"
-          },
-          "index": false
+          "name": "lastPosition",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tickmap",
+          "isMut": true,
+          "isSigner": false
         },
         {
           "name": "lowerTick",
-          "type": "i32",
-          "index": false
+          "isMut": true,
+          "isSigner": false
         },
         {
           "name": "upperTick",
-          "type": "i32",
-          "index": false
+          "isMut": true,
+          "isSigner": false
         },
         {
-          "name": "currentTimestamp",
-          "type": "u64",
-          "index": false
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
         },
-        {
-          "name": "secondsPerLiquidityInsideInitial",
-          "type": {
-            "defined": "SecondsPerLiquidity"
-          },
-          "index": false
-        }
-      ]
-    },
-    {
-      "name": "RemovePositionEvent",
-      "fields": [
         {
           "name": "owner",
-          "type": "publicKey",
-          "index": false
+          "isMut": false,
+          "isSigner": true
         },
         {
-          "name": "pool",
-          "type": "publicKey",
-          "index": false
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "id",
-          "type": "u128",
-          "index": false
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "liquidity",
-          "type": {
-            "defined": "Liquidity"
-          },
-          "index": false
+          "name": "accountX",
+          "isMut": true,
+          "isSigner": false
         },
         {
-          "name": "upperTick",
-          "type": "i32",
-          "index": false
+          "name": "accountY",
+          "isMut": true,
+          "isSigner": false
         },
         {
-          "name": "currentTick",
-          "type": "i32",
-          "index": false
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false
         },
         {
-          "name": "lowerTick",
-          "type": "i32",
-          "index": false
+          "name": "reserveY",
+          "isMut": true,
+          "isSigner": false
         },
         {
-          "name": "upperTickSecondsPerLiquidityOutside",
-          "type": {
-            "defined": "SecondsPerLiquidity"
-          },
-          "index": false
+          "name": "programAuthority",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "lowerTickSecondsPerLiquidityOutside",
-          "type": {
-            "defined": "SecondsPerLiquidity"
-          },
-          "index": false
+          "name": "tokenXProgram",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "poolSecondsPerLiquidityGlobal",
-          "type": {
-            "defined": "SecondsPerLiquidity"
-          },
-          "index": false
+          "name": "tokenYProgram",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "currentTimestamp",
-          "type": "u64",
-          "index": false
+          "name": "eventOptAcc",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "positionOperator",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "index",
+          "type": "u32"
+        },
+        {
+          "name": "lowerTickIndex",
+          "type": "i32"
+        },
+        {
+          "name": "upperTickIndex",
+          "type": "i32"
         }
       ]
     },
     {
-      "name": "SwapEvent",
-      "fields": [
+      "name": "createStablePosition",
+      "accounts": [
         {
-          "name": "swapper",
-          "type": "publicKey",
-          "index": false
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "position",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "positionList",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "positionEntry",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "lowerTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "upperTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tickmap",
+          "isMut": true,
+          "isSigner": false
         },
         {
           "name": "tokenX",
-          "type": "publicKey",
-          "index": false
+          "isMut": false,
+          "isSigner": false
         },
         {
           "name": "tokenY",
-          "type": "publicKey",
-          "index": false
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "xToY",
-          "type": "bool",
-          "index": false
+          "name": "accountX",
+          "isMut": true,
+          "isSigner": false
         },
         {
-          "name": "fee",
-          "type": "u64",
-          "index": false
+          "name": "accountY",
+          "isMut": true,
+          "isSigner": false
         },
         {
-          "name": "priceBeforeSwap",
-          "type": {
-            "defined": "Price"
-          },
-          "index": false
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false
         },
         {
-          "name": "priceAfterSwap",
-          "type": {
-            "defined": "Price"
-          },
-          "index": false
+          "name": "reserveY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenXProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenYProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventOptAcc",
+          "isMut": false,
+          "isSigner": false
         }
-      ]
-    }
-  ],
-  "errors": [
-    {
-      "code": 6000,
-      "name": "ZeroAmount",
-      "msg": "Amount is zero"
-    },
-    {
-      "code": 6001,
-      "name": "ZeroOutput",
-      "msg": "Output would be zero"
-    },
-    {
-      "code": 6002,
-      "name": "WrongTick",
-      "msg": "Not the expected tick"
-    },
-    {
-      "code": 6003,
-      "name": "WrongLimit",
-      "msg": "Price limit is on the wrong side of price"
-    },
-    {
-      "code": 6004,
-      "name": "InvalidTickIndex",
-      "msg": "Tick index not divisible by spacing or over limit"
-    },
-    {
-      "code": 6005,
-      "name": "InvalidTickInterval",
-      "msg": "Invalid tick_lower or tick_upper"
-    },
-    {
-      "code": 6006,
-      "name": "NoMoreTicks",
-      "msg": "There is no more tick in that direction"
-    },
-    {
-      "code": 6007,
-      "name": "TickNotFound",
-      "msg": "Correct tick not found in context"
-    },
-    {
-      "code": 6008,
-      "name": "PriceLimitReached",
-      "msg": "Price would cross swap limit"
-    },
-    {
-      "code": 6009,
-      "name": "InvalidTickLiquidity",
-      "msg": "Invalid tick liquidity"
-    },
-    {
-      "code": 6010,
-      "name": "EmptyPositionPokes",
-      "msg": "Disable empty position pokes"
-    },
-    {
-      "code": 6011,
-      "name": "InvalidPositionLiquidity",
-      "msg": "Invalid position liquidity"
-    },
-    {
-      "code": 6012,
-      "name": "InvalidPoolLiquidity",
-      "msg": "Invalid pool liquidity"
-    },
-    {
-      "code": 6013,
-      "name": "InvalidPositionIndex",
-      "msg": "Invalid position index"
-    },
-    {
-      "code": 6014,
-      "name": "PositionWithoutLiquidity",
-      "msg": "Position liquidity would be zero"
-    },
-    {
-      "code": 6015,
-      "name": "Unauthorized",
-      "msg": "You are not admin"
-    },
-    {
-      "code": 6016,
-      "name": "InvalidPoolTokenAddresses",
-      "msg": "Invalid pool token addresses"
-    },
-    {
-      "code": 6017,
-      "name": "NegativeTime",
-      "msg": "Time cannot be negative"
-    },
-    {
-      "code": 6018,
-      "name": "OracleAlreadyInitialized",
-      "msg": "Oracle is already initialized"
-    },
-    {
-      "code": 6019,
-      "name": "LimitReached",
-      "msg": "Absolute price limit was reached"
-    },
-    {
-      "code": 6020,
-      "name": "InvalidProtocolFee",
-      "msg": "Invalid protocol fee"
-    },
-    {
-      "code": 6021,
-      "name": "NoGainSwap",
-      "msg": "Swap amount out is 0"
-    },
-    {
-      "code": 6022,
-      "name": "InvalidTokenAccount",
-      "msg": "Provided token account is different than expected"
-    },
-    {
-      "code": 6023,
-      "name": "InvalidAdmin",
-      "msg": "Admin address is different than expected"
-    },
-    {
-      "code": 6024,
-      "name": "InvalidAuthority",
-      "msg": "Provided authority is different than expected"
-    },
-    {
-      "code": 6025,
-      "name": "InvalidOwner",
-      "msg": "Provided token owner is different than expected"
-    },
-    {
-      "code": 6026,
-      "name": "InvalidMint",
-      "msg": "Provided token account mint is different than expected mint token"
-    },
-    {
-      "code": 6027,
-      "name": "InvalidTickmap",
-      "msg": "Provided tickmap is different than expected"
-    },
-    {
-      "code": 6028,
-      "name": "InvalidTickmapOwner",
-      "msg": "Provided tickmap owner is different than program ID"
-    },
-    {
-      "code": 6029,
-      "name": "InvalidListOwner",
-      "msg": "Recipient list address and owner list address should be different"
-    },
-    {
-      "code": 6030,
-      "name": "InvalidTickSpacing",
-      "msg": "Invalid tick spacing"
-    },
-    {
-      "code": 6031,
-      "name": "InvalidTokenProgram",
-      "msg": "Invalid token program"
-    },
-    {
-      "code": 6032,
-      "name": "InvalidInitSqrtPrice",
-      "msg": "Invalid init sqrt price"
-    },
-    {
-      "code": 6033,
-      "name": "DisabledPool",
-      "msg": "Transfer position is disabled for this pool"
+      ],
+      "args": [
+        {
+          "name": "lowerTickIndex",
+          "type": "i32"
+        },
+        {
+          "name": "upperTickIndex",
+          "type": "i32"
+        },
+        {
+          "name": "liquidityDelta",
+          "type": {
+            "defined": "Liquidity"
+          }
+        },
+        {
+          "name": "slippageLimitLower",
+          "type": {
+            "defined": "Price"
+          }
+        },
+        {
+          "name": "slippageLimitUpper",
+          "type": {
+            "defined": "Price"
+          }
+        }
+      ]
     },
     {
-      "code": 6034,
-      "name": "UnsupportedExtension",
-      "msg": "Not supported Token2022 extension for mint account"
-    }
-  ]
-};
-
-export const IDL: Invariant = {
-  "version": "0.1.0",
-  "name": "invariant",
-  "instructions": [
-    {
-      "name": "createState",
+      "name": "removeStablePosition",
       "accounts": [
         {
           "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "removedPosition",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "admin",
+          "name": "positionList",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "programAuthority",
-          "isMut": false,
+          "name": "removedEntry",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "rent",
-          "isMut": false,
+          "name": "lastEntry",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
+          "name": "pool",
+          "isMut": true,
           "isSigner": false
-        }
-      ],
-      "args": [
+        },
         {
-          "name": "nonce",
-          "type": "u8"
-        }
-      ]
-    },
-    {
-      "name": "createFeeTier",
-      "accounts": [
+          "name": "tickmap",
+          "isMut": true,
+          "isSigner": false
+        },
         {
-          "name": "feeTier",
+          "name": "lowerTick",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "state",
-          "isMut": false,
+          "name": "upperTick",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "admin",
+          "name": "payer",
           "isMut": true,
           "isSigner": true
         },
         {
-          "name": "rent",
+          "name": "owner",
           "isMut": false,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
+          "name": "positionOwner",
+          "isMut": true,
           "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "fee",
-          "type": "u128"
         },
         {
-          "name": "tickSpacing",
-          "type": "u16"
-        }
-      ]
-    },
-    {
-      "name": "createPool",
-      "accounts": [
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
         {
-          "name": "state",
+          "name": "tokenY",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "pool",
+          "name": "accountX",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "feeTier",
-          "isMut": false,
+          "name": "accountY",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tickmap",
+          "name": "reserveX",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenX",
-          "isMut": false,
+          "name": "reserveY",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenY",
+          "name": "programAuthority",
           "isMut": false,
           "isSigner": false
         },
-        {
-          "name": "payer",
-          "isMut": true,
-          "isSigner": true
-        },
         {
           "name": "tokenXProgram",
           "isMut": false,
@@ -2034,54 +1749,63 @@ export const IDL: Invariant = {
           "isSigner": false
         },
         {
-          "name": "rent",
+          "name": "positionOperator",
           "isMut": false,
-          "isSigner": false
+          "isSigner": false,
+          "isOptional": true
         },
         {
-          "name": "systemProgram",
+          "name": "eventOptAcc",
           "isMut": false,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "initTick",
+          "name": "index",
+          "type": "u32"
+        },
+        {
+          "name": "lowerTickIndex",
+          "type": "i32"
+        },
+        {
+          "name": "upperTickIndex",
           "type": "i32"
         }
       ]
     },
     {
-      "name": "createPoolWithSqrtPrice",
+      "name": "transferStablePosition",
       "accounts": [
         {
-          "name": "state",
-          "isMut": false,
+          "name": "position",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "pool",
+          "name": "ownerList",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "feeTier",
-          "isMut": false,
+          "name": "recipientList",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tickmap",
+          "name": "removedEntry",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenX",
-          "isMut": false,
+          "name": "lastEntry",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenY",
-          "isMut": false,
+          "name": "newEntry",
+          "isMut": true,
           "isSigner": false
         },
         {
@@ -2090,12 +1814,12 @@ export const IDL: Invariant = {
           "isSigner": true
         },
         {
-          "name": "tokenXProgram",
+          "name": "owner",
           "isMut": false,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "tokenYProgram",
+          "name": "recipient",
           "isMut": false,
           "isSigner": false
         },
@@ -2112,45 +1836,43 @@ export const IDL: Invariant = {
       ],
       "args": [
         {
-          "name": "initPrice",
-          "type": {
-            "defined": "Price"
-          }
+          "name": "index",
+          "type": "u32"
         }
       ]
     },
     {
-      "name": "initReserves",
+      "name": "migratePosition",
       "accounts": [
         {
-          "name": "state",
-          "isMut": false,
+          "name": "positionList",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "pool",
+          "name": "stablePosition",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenX",
-          "isMut": false,
+          "name": "stablePositionList",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenY",
-          "isMut": false,
+          "name": "positionEntry",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenXReserve",
+          "name": "removedPosition",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "tokenYReserve",
+          "name": "lastPosition",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
         },
         {
           "name": "payer",
@@ -2158,19 +1880,82 @@ export const IDL: Invariant = {
           "isSigner": true
         },
         {
-          "name": "authority",
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "rent",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenXProgram",
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "index",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "migratePool",
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateState",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false
         },
         {
-          "name": "tokenYProgram",
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateFeeTier",
+      "accounts": [
+        {
+          "name": "feeTier",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
         },
         {
           "name": "systemProgram",
@@ -2181,18 +1966,43 @@ export const IDL: Invariant = {
       "args": []
     },
     {
-      "name": "swap",
+      "name": "createRangeOrder",
       "accounts": [
         {
           "name": "state",
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "rangeOrder",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "pool",
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "lowerTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "upperTick",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "tickmap",
           "isMut": true,
@@ -2228,11 +2038,6 @@ export const IDL: Invariant = {
           "isMut": true,
           "isSigner": false
         },
-        {
-          "name": "owner",
-          "isMut": false,
-          "isSigner": true
-        },
         {
           "name": "programAuthority",
           "isMut": false,
@@ -2247,62 +2052,142 @@ export const IDL: Invariant = {
           "name": "tokenYProgram",
           "isMut": false,
           "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "xToY",
-          "type": "bool"
         },
         {
-          "name": "amount",
-          "type": "u64"
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "byAmountIn",
-          "type": "bool"
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "lowerTickIndex",
+          "type": "i32"
         },
         {
-          "name": "sqrtPriceLimit",
-          "type": "u128"
+          "name": "liquidityDelta",
+          "type": {
+            "defined": "Liquidity"
+          }
         }
       ]
     },
     {
-      "name": "initializeOracle",
+      "name": "settleRangeOrder",
       "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rangeOrder",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "pool",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "oracle",
+          "name": "lowerTick",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenX",
-          "isMut": false,
+          "name": "upperTick",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenY",
-          "isMut": false,
+          "name": "tickmap",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "payer",
+          "name": "settler",
           "isMut": false,
           "isSigner": true
         },
         {
-          "name": "rent",
-          "isMut": false,
+          "name": "owner",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "claimRangeOrder",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
+          "name": "rangeOrder",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "accountX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "accountY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenXProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenYProgram",
           "isMut": false,
           "isSigner": false
         }
@@ -2310,28 +2195,152 @@ export const IDL: Invariant = {
       "args": []
     },
     {
-      "name": "createTick",
+      "name": "increaseLiquidity",
       "accounts": [
         {
-          "name": "tick",
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "position",
           "isMut": true,
           "isSigner": false
         },
         {
           "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
           "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "lowerTick",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tickmap",
+          "name": "upperTick",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "payer",
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "accountX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "accountY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenXProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenYProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "positionOperator",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "liquidityDelta",
+          "type": {
+            "defined": "Liquidity"
+          }
+        },
+        {
+          "name": "slippageLimitLower",
+          "type": {
+            "defined": "Price"
+          }
+        },
+        {
+          "name": "slippageLimitUpper",
+          "type": {
+            "defined": "Price"
+          }
+        },
+        {
+          "name": "maxAmountX",
+          "type": "u64"
+        },
+        {
+          "name": "maxAmountY",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "decreaseLiquidity",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "position",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
           "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
           "isSigner": true
         },
+        {
+          "name": "lowerTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "upperTick",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "tokenX",
           "isMut": false,
@@ -2343,12 +2352,27 @@ export const IDL: Invariant = {
           "isSigner": false
         },
         {
-          "name": "rent",
-          "isMut": false,
+          "name": "accountX",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
+          "name": "accountY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programAuthority",
           "isMut": false,
           "isSigner": false
         },
@@ -2361,1118 +2385,8779 @@ export const IDL: Invariant = {
           "name": "tokenYProgram",
           "isMut": false,
           "isSigner": false
+        },
+        {
+          "name": "positionOperator",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
         }
       ],
       "args": [
         {
-          "name": "index",
-          "type": "i32"
+          "name": "liquidityDelta",
+          "type": {
+            "defined": "Liquidity"
+          }
+        },
+        {
+          "name": "slippageLimitLower",
+          "type": {
+            "defined": "Price"
+          }
+        },
+        {
+          "name": "slippageLimitUpper",
+          "type": {
+            "defined": "Price"
+          }
+        },
+        {
+          "name": "minAmountX",
+          "type": "u64"
+        },
+        {
+          "name": "minAmountY",
+          "type": "u64"
         }
       ]
     },
     {
-      "name": "createPositionList",
-      "accounts": [
-        {
-          "name": "positionList",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "owner",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "signer",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "rent",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": []
+      "name": "transferPositionOwnership",
+      "accounts": [
+        {
+          "name": "ownerList",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "recipientList",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "newPosition",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "removedPosition",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lastPosition",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "recipient",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "index",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "tokenizePosition",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "positionList",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "removedPosition",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lastPosition",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenizedPosition",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "positionMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ownerTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "programAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "index",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "redeemPosition",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "positionList",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "position",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenizedPosition",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "positionMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "holderTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "holder",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "programAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "claimTokenizedFee",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "position",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "positionMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ownerTokenAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "lowerTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "upperTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "accountX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "accountY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenXProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenYProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "positionTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "lowerTickIndex",
+          "type": "i32"
+        },
+        {
+          "name": "upperTickIndex",
+          "type": "i32"
+        }
+      ]
+    },
+    {
+      "name": "claimFee",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "position",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lowerTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "upperTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "accountX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "accountY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenXProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenYProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "positionOperator",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "index",
+          "type": "u32"
+        },
+        {
+          "name": "lowerTickIndex",
+          "type": "i32"
+        },
+        {
+          "name": "upperTickIndex",
+          "type": "i32"
+        }
+      ]
+    },
+    {
+      "name": "compoundFees",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "position",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lowerTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "upperTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "positionOperator",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "approvePositionOperator",
+      "accounts": [
+        {
+          "name": "positionOperator",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "position",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "operator",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "permissions",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "revokePositionOperator",
+      "accounts": [
+        {
+          "name": "positionOperator",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "claimFeeBatch",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "accountX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "accountY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenXProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenYProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "updateSecondsPerLiquidity",
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lowerTick",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "upperTick",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "position",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "signer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "lowerTickIndex",
+          "type": "i32"
+        },
+        {
+          "name": "upperTickIndex",
+          "type": "i32"
+        },
+        {
+          "name": "index",
+          "type": "i32"
+        }
+      ]
+    },
+    {
+      "name": "withdrawProtocolFee",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "accountX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "accountY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "programAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenXProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenYProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "amountX",
+          "type": "u64"
+        },
+        {
+          "name": "amountY",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "distributeProtocolFee",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeSplit",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "programAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenXProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenYProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "amountX",
+          "type": "u64"
+        },
+        {
+          "name": "amountY",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "createFeeSplit",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "feeSplit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "recipients",
+          "type": {
+            "vec": "publicKey"
+          }
+        },
+        {
+          "name": "shares",
+          "type": {
+            "vec": "u16"
+          }
+        }
+      ]
+    },
+    {
+      "name": "changeFeeSplit",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "feeSplit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "recipients",
+          "type": {
+            "vec": "publicKey"
+          }
+        },
+        {
+          "name": "shares",
+          "type": {
+            "vec": "u16"
+          }
+        }
+      ]
+    },
+    {
+      "name": "changeProtocolFee",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "programAuthority",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "protocolFee",
+          "type": {
+            "defined": "FixedPoint"
+          }
+        }
+      ]
+    },
+    {
+      "name": "changePause",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pauser",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "paused",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "changePoolPause",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pauser",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "paused",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "changeDynamicFee",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "enabled",
+          "type": "bool"
+        },
+        {
+          "name": "volatilityFeePerTick",
+          "type": {
+            "defined": "FixedPoint"
+          }
+        },
+        {
+          "name": "maxVolatilityFee",
+          "type": {
+            "defined": "FixedPoint"
+          }
+        },
+        {
+          "name": "volatilityHalfLife",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "changePoolDefaults",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "feeReceiver",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "protocolFee",
+          "type": {
+            "defined": "FixedPoint"
+          }
+        }
+      ]
+    },
+    {
+      "name": "changeFeeTierPoolDefaults",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "feeTier",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "protocolFee",
+          "type": {
+            "option": {
+              "defined": "FixedPoint"
+            }
+          }
+        },
+        {
+          "name": "feeReceiver",
+          "type": {
+            "option": "publicKey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "changeFeeReceiver",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "feeReceiver",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createReferrer",
+      "accounts": [
+        {
+          "name": "referrer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "feeShare",
+          "type": {
+            "defined": "FixedPoint"
+          }
+        }
+      ]
+    },
+    {
+      "name": "changeReferrer",
+      "accounts": [
+        {
+          "name": "referrer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "feeShare",
+          "type": {
+            "defined": "FixedPoint"
+          }
+        },
+        {
+          "name": "enabled",
+          "type": "bool"
+        }
+      ]
+    }
+  ],
+  "accounts": [
+    {
+      "name": "feeSplit",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "recipients",
+            "type": {
+              "array": [
+                "publicKey",
+                4
+              ]
+            }
+          },
+          {
+            "name": "shares",
+            "type": {
+              "array": [
+                "u16",
+                4
+              ]
+            }
+          },
+          {
+            "name": "count",
+            "type": "u8"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "feeTier",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "fee",
+            "type": {
+              "defined": "FixedPoint"
+            }
+          },
+          {
+            "name": "tickSpacing",
+            "type": "u16"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "disabled",
+            "type": "bool"
+          },
+          {
+            "name": "minFee",
+            "type": {
+              "defined": "FixedPoint"
+            }
+          },
+          {
+            "name": "maxFee",
+            "type": {
+              "defined": "FixedPoint"
+            }
+          },
+          {
+            "name": "protocolFeeOverride",
+            "type": {
+              "defined": "FixedPoint"
+            }
+          },
+          {
+            "name": "protocolFeeOverridden",
+            "type": "bool"
+          },
+          {
+            "name": "feeReceiverOverride",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "oracle",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "data",
+            "type": {
+              "array": [
+                {
+                  "defined": "Record"
+                },
+                256
+              ]
+            }
+          },
+          {
+            "name": "head",
+            "type": "u16"
+          },
+          {
+            "name": "amount",
+            "type": "u16"
+          },
+          {
+            "name": "size",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "pool",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "tokenX",
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenY",
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenXReserve",
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenYReserve",
+            "type": "publicKey"
+          },
+          {
+            "name": "positionIterator",
+            "type": "u128"
+          },
+          {
+            "name": "tickSpacing",
+            "type": "u16"
+          },
+          {
+            "name": "fee",
+            "type": {
+              "defined": "FixedPoint"
+            }
+          },
+          {
+            "name": "protocolFee",
+            "type": {
+              "defined": "FixedPoint"
+            }
+          },
+          {
+            "name": "liquidity",
+            "type": {
+              "defined": "Liquidity"
+            }
+          },
+          {
+            "name": "sqrtPrice",
+            "type": {
+              "defined": "Price"
+            }
+          },
+          {
+            "name": "currentTickIndex",
+            "type": "i32"
+          },
+          {
+            "name": "tickmap",
+            "type": "publicKey"
+          },
+          {
+            "name": "feeGrowthGlobalX",
+            "type": {
+              "defined": "FeeGrowth"
+            }
+          },
+          {
+            "name": "feeGrowthGlobalY",
+            "type": {
+              "defined": "FeeGrowth"
+            }
+          },
+          {
+            "name": "feeProtocolTokenX",
+            "type": "u64"
+          },
+          {
+            "name": "feeProtocolTokenY",
+            "type": "u64"
+          },
+          {
+            "name": "secondsPerLiquidityGlobal",
+            "type": {
+              "defined": "SecondsPerLiquidity"
+            }
+          },
+          {
+            "name": "startTimestamp",
+            "type": "u64"
+          },
+          {
+            "name": "lastTimestamp",
+            "type": "u64"
+          },
+          {
+            "name": "feeReceiver",
+            "type": "publicKey"
+          },
+          {
+            "name": "oracleAddress",
+            "type": "publicKey"
+          },
+          {
+            "name": "oracleInitialized",
+            "type": "bool"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "dynamicFeeEnabled",
+            "type": "bool"
+          },
+          {
+            "name": "volatilityFeePerTick",
+            "type": {
+              "defined": "FixedPoint"
+            }
+          },
+          {
+            "name": "maxVolatilityFee",
+            "type": {
+              "defined": "FixedPoint"
+            }
+          },
+          {
+            "name": "volatilityHalfLife",
+            "type": "u64"
+          },
+          {
+            "name": "volatilityAccumulator",
+            "type": "u64"
+          },
+          {
+            "name": "volatilityLastUpdate",
+            "type": "u64"
+          },
+          {
+            "name": "paused",
+            "type": "u8"
+          },
+          {
+            "name": "feeOverride",
+            "type": {
+              "defined": "FixedPoint"
+            }
+          },
+          {
+            "name": "feeOverridden",
+            "type": "bool"
+          },
+          {
+            "name": "openRangeOrders",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "positionList",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "head",
+            "type": "u32"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "positionEntry",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pool",
+            "type": "publicKey"
+          },
+          {
+            "name": "id",
+            "type": "u128"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "positionOperator",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "owner",
+            "type": "publicKey"
+          },
+          {
+            "name": "pool",
+            "type": "publicKey"
+          },
+          {
+            "name": "positionId",
+            "type": "u128"
+          },
+          {
+            "name": "operator",
+            "type": "publicKey"
+          },
+          {
+            "name": "permissions",
+            "type": "u8"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "position",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "owner",
+            "type": "publicKey"
+          },
+          {
+            "name": "pool",
+            "type": "publicKey"
+          },
+          {
+            "name": "id",
+            "type": "u128"
+          },
+          {
+            "name": "liquidity",
+            "type": {
+              "defined": "Liquidity"
+            }
+          },
+          {
+            "name": "lowerTickIndex",
+            "type": "i32"
+          },
+          {
+            "name": "upperTickIndex",
+            "type": "i32"
+          },
+          {
+            "name": "feeGrowthInsideX",
+            "type": {
+              "defined": "FeeGrowth"
+            }
+          },
+          {
+            "name": "feeGrowthInsideY",
+            "type": {
+              "defined": "FeeGrowth"
+            }
+          },
+          {
+            "name": "secondsPerLiquidityInside",
+            "type": {
+              "defined": "SecondsPerLiquidity"
+            }
+          },
+          {
+            "name": "lastSlot",
+            "type": "u64"
+          },
+          {
+            "name": "tokensOwedX",
+            "type": {
+              "defined": "FixedPoint"
+            }
+          },
+          {
+            "name": "tokensOwedY",
+            "type": {
+              "defined": "FixedPoint"
+            }
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "rangeOrder",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "position",
+            "type": {
+              "defined": "Position"
+            }
+          },
+          {
+            "name": "xToY",
+            "type": "bool"
+          },
+          {
+            "name": "settled",
+            "type": "bool"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "referrer",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "owner",
+            "type": "publicKey"
+          },
+          {
+            "name": "feeShare",
+            "type": {
+              "defined": "FixedPoint"
+            }
+          },
+          {
+            "name": "enabled",
+            "type": "bool"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "state",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "admin",
+            "type": "publicKey"
+          },
+          {
+            "name": "nonce",
+            "type": "u8"
+          },
+          {
+            "name": "authority",
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "pendingAdmin",
+            "type": "publicKey"
+          },
+          {
+            "name": "feeTierManager",
+            "type": "publicKey"
+          },
+          {
+            "name": "pauser",
+            "type": "publicKey"
+          },
+          {
+            "name": "protocolFeeManager",
+            "type": "publicKey"
+          },
+          {
+            "name": "paused",
+            "type": "u8"
+          },
+          {
+            "name": "defaultProtocolFee",
+            "type": {
+              "defined": "FixedPoint"
+            }
+          },
+          {
+            "name": "defaultFeeReceiver",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "tickmap",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bitmap",
+            "type": {
+              "array": [
+                "u8",
+                11091
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "tick",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pool",
+            "type": "publicKey"
+          },
+          {
+            "name": "index",
+            "type": "i32"
+          },
+          {
+            "name": "sign",
+            "type": "bool"
+          },
+          {
+            "name": "liquidityChange",
+            "type": {
+              "defined": "Liquidity"
+            }
+          },
+          {
+            "name": "liquidityGross",
+            "type": {
+              "defined": "Liquidity"
+            }
+          },
+          {
+            "name": "sqrtPrice",
+            "type": {
+              "defined": "Price"
+            }
+          },
+          {
+            "name": "feeGrowthOutsideX",
+            "type": {
+              "defined": "FeeGrowth"
+            }
+          },
+          {
+            "name": "feeGrowthOutsideY",
+            "type": {
+              "defined": "FeeGrowth"
+            }
+          },
+          {
+            "name": "secondsPerLiquidityOutside",
+            "type": {
+              "defined": "SecondsPerLiquidity"
+            }
+          },
+          {
+            "name": "secondsOutside",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    }
+  ],
+  "types": [
+    {
+      "name": "Price",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "v",
+            "type": "u128"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Liquidity",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "v",
+            "type": "u128"
+          }
+        ]
+      }
+    },
+    {
+      "name": "FeeGrowth",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "v",
+            "type": "u128"
+          }
+        ]
+      }
+    },
+    {
+      "name": "FixedPoint",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "v",
+            "type": "u128"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SecondsPerLiquidity",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "v",
+            "type": "u128"
+          }
+        ]
+      }
+    },
+    {
+      "name": "FlashLoanCallbackArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amountX",
+            "type": "u64"
+          },
+          {
+            "name": "amountY",
+            "type": "u64"
+          },
+          {
+            "name": "feeX",
+            "type": "u64"
+          },
+          {
+            "name": "feeY",
+            "type": "u64"
+          },
+          {
+            "name": "data",
+            "type": "bytes"
+          }
+        ]
+      }
+    },
+    {
+      "name": "FlashSwapCallbackArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "xToY",
+            "type": "bool"
+          },
+          {
+            "name": "amountIn",
+            "type": "u64"
+          },
+          {
+            "name": "amountOut",
+            "type": "u64"
+          },
+          {
+            "name": "data",
+            "type": "bytes"
+          }
+        ]
+      }
+    },
+    {
+      "name": "QuoteResult",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amountIn",
+            "type": "u64"
+          },
+          {
+            "name": "amountOut",
+            "type": "u64"
+          },
+          {
+            "name": "fee",
+            "type": "u64"
+          },
+          {
+            "name": "referralFee",
+            "type": "u64"
+          },
+          {
+            "name": "remainingAmount",
+            "type": "u64"
+          },
+          {
+            "name": "sqrtPrice",
+            "type": {
+              "defined": "Price"
+            }
+          },
+          {
+            "name": "crossedTicks",
+            "type": {
+              "vec": "i32"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "Record",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "timestamp",
+            "type": "u64"
+          },
+          {
+            "name": "price",
+            "type": {
+              "defined": "Price"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "Role",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "FeeTierManager"
+          },
+          {
+            "name": "Pauser"
+          },
+          {
+            "name": "ProtocolFeeManager"
+          }
+        ]
+      }
+    }
+  ],
+  "events": [
+    {
+      "name": "CreatePositionEvent",
+      "fields": [
+        {
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "id",
+          "type": "u128",
+          "index": false
+        },
+        {
+          "name": "liquidity",
+          "type": {
+            "defined": "Liquidity"
+          },
+          "index": false
+        },
+        {
+          "name": "lowerTick",
+          "type": "i32",
+          "index": false
+        },
+        {
+          "name": "upperTick",
+          "type": "i32",
+          "index": false
+        },
+        {
+          "name": "currentTimestamp",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "secondsPerLiquidityInsideInitial",
+          "type": {
+            "defined": "SecondsPerLiquidity"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "RemovePositionEvent",
+      "fields": [
+        {
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "id",
+          "type": "u128",
+          "index": false
+        },
+        {
+          "name": "liquidity",
+          "type": {
+            "defined": "Liquidity"
+          },
+          "index": false
+        },
+        {
+          "name": "upperTick",
+          "type": "i32",
+          "index": false
+        },
+        {
+          "name": "currentTick",
+          "type": "i32",
+          "index": false
+        },
+        {
+          "name": "lowerTick",
+          "type": "i32",
+          "index": false
+        },
+        {
+          "name": "upperTickSecondsPerLiquidityOutside",
+          "type": {
+            "defined": "SecondsPerLiquidity"
+          },
+          "index": false
+        },
+        {
+          "name": "lowerTickSecondsPerLiquidityOutside",
+          "type": {
+            "defined": "SecondsPerLiquidity"
+          },
+          "index": false
+        },
+        {
+          "name": "poolSecondsPerLiquidityGlobal",
+          "type": {
+            "defined": "SecondsPerLiquidity"
+          },
+          "index": false
+        },
+        {
+          "name": "currentTimestamp",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ChangeLiquidityEvent",
+      "fields": [
+        {
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "id",
+          "type": "u128",
+          "index": false
+        },
+        {
+          "name": "liquidityDelta",
+          "type": {
+            "defined": "Liquidity"
+          },
+          "index": false
+        },
+        {
+          "name": "addLiquidity",
+          "type": "bool",
+          "index": false
+        },
+        {
+          "name": "amountX",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "amountY",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "currentTimestamp",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "SwapEvent",
+      "fields": [
+        {
+          "name": "swapper",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "tokenX",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "tokenY",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "xToY",
+          "type": "bool",
+          "index": false
+        },
+        {
+          "name": "fee",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "priceBeforeSwap",
+          "type": {
+            "defined": "Price"
+          },
+          "index": false
+        },
+        {
+          "name": "priceAfterSwap",
+          "type": {
+            "defined": "Price"
+          },
+          "index": false
+        },
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amountIn",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "amountOut",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "referralFee",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "liquidity",
+          "type": {
+            "defined": "Liquidity"
+          },
+          "index": false
+        },
+        {
+          "name": "currentTick",
+          "type": "i32",
+          "index": false
+        },
+        {
+          "name": "crossedTicks",
+          "type": {
+            "vec": "i32"
+          },
+          "index": false
+        },
+        {
+          "name": "remainingAmount",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "CrossTickEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "index",
+          "type": "i32",
+          "index": false
+        },
+        {
+          "name": "liquidity",
+          "type": {
+            "defined": "Liquidity"
+          },
+          "index": false
+        },
+        {
+          "name": "feeGrowthOutsideX",
+          "type": "u128",
+          "index": false
+        },
+        {
+          "name": "feeGrowthOutsideY",
+          "type": "u128",
+          "index": false
+        },
+        {
+          "name": "secondsPerLiquidityOutside",
+          "type": {
+            "defined": "SecondsPerLiquidity"
+          },
+          "index": false
+        },
+        {
+          "name": "currentTimestamp",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ClaimFeeEvent",
+      "fields": [
+        {
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "id",
+          "type": "u128",
+          "index": false
+        },
+        {
+          "name": "amountX",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "amountY",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "currentTimestamp",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ClaimRangeOrderEvent",
+      "fields": [
+        {
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "id",
+          "type": "u128",
+          "index": false
+        },
+        {
+          "name": "amountX",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "amountY",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "WithdrawProtocolFeeEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "feeReceiver",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amountX",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "amountY",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "DistributeProtocolFeeEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "recipient",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "share",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "amountX",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "amountY",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ChangeFeeSplitEvent",
+      "fields": [
+        {
+          "name": "recipients",
+          "type": {
+            "vec": "publicKey"
+          },
+          "index": false
+        },
+        {
+          "name": "shares",
+          "type": {
+            "vec": "u16"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "CreatePoolEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "tokenX",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "tokenY",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "fee",
+          "type": {
+            "defined": "FixedPoint"
+          },
+          "index": false
+        },
+        {
+          "name": "tickSpacing",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "protocolFee",
+          "type": {
+            "defined": "FixedPoint"
+          },
+          "index": false
+        },
+        {
+          "name": "feeReceiver",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "sqrtPrice",
+          "type": {
+            "defined": "Price"
+          },
+          "index": false
+        },
+        {
+          "name": "currentTick",
+          "type": "i32",
+          "index": false
+        },
+        {
+          "name": "tickmap",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "currentTimestamp",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ClosePoolEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "admin",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ClosePositionListEvent",
+      "fields": [
+        {
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "InitReservesEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "tokenXReserve",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "tokenYReserve",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "CreateTickEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "index",
+          "type": "i32",
+          "index": false
+        },
+        {
+          "name": "feeGrowthOutsideX",
+          "type": "u128",
+          "index": false
+        },
+        {
+          "name": "feeGrowthOutsideY",
+          "type": "u128",
+          "index": false
+        },
+        {
+          "name": "secondsOutside",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "secondsPerLiquidityOutside",
+          "type": {
+            "defined": "SecondsPerLiquidity"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ChangeProtocolFeeEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "protocolFee",
+          "type": {
+            "defined": "FixedPoint"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ChangePauseEvent",
+      "fields": [
+        {
+          "name": "paused",
+          "type": "u8",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ChangePoolPauseEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "paused",
+          "type": "u8",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ChangeFeeTierEvent",
+      "fields": [
+        {
+          "name": "feeTier",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "disabled",
+          "type": "bool",
+          "index": false
+        },
+        {
+          "name": "minFee",
+          "type": {
+            "defined": "FixedPoint"
+          },
+          "index": false
+        },
+        {
+          "name": "maxFee",
+          "type": {
+            "defined": "FixedPoint"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ChangePoolFeeEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "fee",
+          "type": {
+            "defined": "FixedPoint"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ChangeDynamicFeeEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "enabled",
+          "type": "bool",
+          "index": false
+        },
+        {
+          "name": "volatilityFeePerTick",
+          "type": {
+            "defined": "FixedPoint"
+          },
+          "index": false
+        },
+        {
+          "name": "maxVolatilityFee",
+          "type": {
+            "defined": "FixedPoint"
+          },
+          "index": false
+        },
+        {
+          "name": "volatilityHalfLife",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ChangePoolDefaultsEvent",
+      "fields": [
+        {
+          "name": "protocolFee",
+          "type": {
+            "defined": "FixedPoint"
+          },
+          "index": false
+        },
+        {
+          "name": "feeReceiver",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ChangeFeeTierPoolDefaultsEvent",
+      "fields": [
+        {
+          "name": "feeTier",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "protocolFee",
+          "type": {
+            "option": {
+              "defined": "FixedPoint"
+            }
+          },
+          "index": false
+        },
+        {
+          "name": "feeReceiver",
+          "type": {
+            "option": "publicKey"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ChangeFeeReceiverEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "feeReceiver",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TransferPositionOwnershipEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "id",
+          "type": "u128",
+          "index": false
+        },
+        {
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "recipient",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TokenizePositionEvent",
+      "fields": [
+        {
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "id",
+          "type": "u128",
+          "index": false
+        },
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "RedeemPositionEvent",
+      "fields": [
+        {
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "id",
+          "type": "u128",
+          "index": false
+        },
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "InitializeOracleEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "oracle",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "FlashLoanEvent",
+      "fields": [
+        {
+          "name": "borrower",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amountX",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "amountY",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "feeX",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "feeY",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "MigratePositionEvent",
+      "fields": [
+        {
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "id",
+          "type": "u128",
+          "index": false
+        },
+        {
+          "name": "index",
+          "type": "u32",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "MigratePoolEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "MigrateStateEvent",
+      "fields": [
+        {
+          "name": "admin",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "MigrateFeeTierEvent",
+      "fields": [
+        {
+          "name": "feeTier",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ApprovePositionOperatorEvent",
+      "fields": [
+        {
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "id",
+          "type": "u128",
+          "index": false
+        },
+        {
+          "name": "operator",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "permissions",
+          "type": "u8",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "RevokePositionOperatorEvent",
+      "fields": [
+        {
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "id",
+          "type": "u128",
+          "index": false
+        },
+        {
+          "name": "operator",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "CompoundFeesEvent",
+      "fields": [
+        {
+          "name": "authority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "id",
+          "type": "u128",
+          "index": false
+        },
+        {
+          "name": "liquidityDelta",
+          "type": {
+            "defined": "Liquidity"
+          },
+          "index": false
+        },
+        {
+          "name": "amountX",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "amountY",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "currentTimestamp",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ProposeAdminEvent",
+      "fields": [
+        {
+          "name": "admin",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "pendingAdmin",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "AcceptAdminEvent",
+      "fields": [
+        {
+          "name": "previousAdmin",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "admin",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "SetRoleEvent",
+      "fields": [
+        {
+          "name": "role",
+          "type": {
+            "defined": "Role"
+          },
+          "index": false
+        },
+        {
+          "name": "holder",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ChangeReferrerEvent",
+      "fields": [
+        {
+          "name": "referrer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "feeShare",
+          "type": {
+            "defined": "FixedPoint"
+          },
+          "index": false
+        },
+        {
+          "name": "enabled",
+          "type": "bool",
+          "index": false
+        }
+      ]
+    }
+  ],
+  "errors": [
+    {
+      "code": 6000,
+      "name": "ZeroAmount",
+      "msg": "Amount is zero"
+    },
+    {
+      "code": 6001,
+      "name": "ZeroOutput",
+      "msg": "Output would be zero"
+    },
+    {
+      "code": 6002,
+      "name": "WrongTick",
+      "msg": "Not the expected tick"
+    },
+    {
+      "code": 6003,
+      "name": "WrongLimit",
+      "msg": "Price limit is on the wrong side of price"
+    },
+    {
+      "code": 6004,
+      "name": "InvalidTickIndex",
+      "msg": "Tick index not divisible by spacing or over limit"
+    },
+    {
+      "code": 6005,
+      "name": "InvalidTickInterval",
+      "msg": "Invalid tick_lower or tick_upper"
+    },
+    {
+      "code": 6006,
+      "name": "NoMoreTicks",
+      "msg": "There is no more tick in that direction"
+    },
+    {
+      "code": 6007,
+      "name": "TickNotFound",
+      "msg": "Correct tick not found in context"
+    },
+    {
+      "code": 6008,
+      "name": "PriceLimitReached",
+      "msg": "Price would cross swap limit"
+    },
+    {
+      "code": 6009,
+      "name": "InvalidTickLiquidity",
+      "msg": "Invalid tick liquidity"
+    },
+    {
+      "code": 6010,
+      "name": "EmptyPositionPokes",
+      "msg": "Disable empty position pokes"
+    },
+    {
+      "code": 6011,
+      "name": "InvalidPositionLiquidity",
+      "msg": "Invalid position liquidity"
+    },
+    {
+      "code": 6012,
+      "name": "InvalidPoolLiquidity",
+      "msg": "Invalid pool liquidity"
+    },
+    {
+      "code": 6013,
+      "name": "InvalidPositionIndex",
+      "msg": "Invalid position index"
+    },
+    {
+      "code": 6014,
+      "name": "PositionWithoutLiquidity",
+      "msg": "Position liquidity would be zero"
+    },
+    {
+      "code": 6015,
+      "name": "Unauthorized",
+      "msg": "You are not admin"
+    },
+    {
+      "code": 6016,
+      "name": "InvalidPoolTokenAddresses",
+      "msg": "Invalid pool token addresses"
+    },
+    {
+      "code": 6017,
+      "name": "NegativeTime",
+      "msg": "Time cannot be negative"
+    },
+    {
+      "code": 6018,
+      "name": "OracleAlreadyInitialized",
+      "msg": "Oracle is already initialized"
+    },
+    {
+      "code": 6019,
+      "name": "LimitReached",
+      "msg": "Absolute price limit was reached"
+    },
+    {
+      "code": 6020,
+      "name": "InvalidProtocolFee",
+      "msg": "Invalid protocol fee"
+    },
+    {
+      "code": 6021,
+      "name": "NoGainSwap",
+      "msg": "Swap amount out is 0"
+    },
+    {
+      "code": 6022,
+      "name": "InvalidTokenAccount",
+      "msg": "Provided token account is different than expected"
+    },
+    {
+      "code": 6023,
+      "name": "InvalidAdmin",
+      "msg": "Admin address is different than expected"
+    },
+    {
+      "code": 6024,
+      "name": "InvalidAuthority",
+      "msg": "Provided authority is different than expected"
+    },
+    {
+      "code": 6025,
+      "name": "InvalidOwner",
+      "msg": "Provided token owner is different than expected"
+    },
+    {
+      "code": 6026,
+      "name": "InvalidMint",
+      "msg": "Provided token account mint is different than expected mint token"
+    },
+    {
+      "code": 6027,
+      "name": "InvalidTickmap",
+      "msg": "Provided tickmap is different than expected"
+    },
+    {
+      "code": 6028,
+      "name": "InvalidTickmapOwner",
+      "msg": "Provided tickmap owner is different than program ID"
+    },
+    {
+      "code": 6029,
+      "name": "InvalidListOwner",
+      "msg": "Recipient list address and owner list address should be different"
+    },
+    {
+      "code": 6030,
+      "name": "InvalidTickSpacing",
+      "msg": "Invalid tick spacing"
+    },
+    {
+      "code": 6031,
+      "name": "InvalidTokenProgram",
+      "msg": "Invalid token program"
+    },
+    {
+      "code": 6032,
+      "name": "InvalidInitSqrtPrice",
+      "msg": "Invalid init sqrt price"
+    },
+    {
+      "code": 6033,
+      "name": "DisabledPool",
+      "msg": "Transfer position is disabled for this pool"
+    },
+    {
+      "code": 6034,
+      "name": "UnsupportedExtension",
+      "msg": "Not supported Token2022 extension for mint account"
+    },
+    {
+      "code": 6035,
+      "name": "InvalidOracle",
+      "msg": "Provided oracle is different than expected"
+    },
+    {
+      "code": 6036,
+      "name": "OracleNotFound",
+      "msg": "Oracle not found in context"
+    },
+    {
+      "code": 6037,
+      "name": "InsufficientOracleData",
+      "msg": "Oracle does not contain enough records for requested window"
+    },
+    {
+      "code": 6038,
+      "name": "InvalidTwapWindow",
+      "msg": "TWAP window cannot be zero"
+    },
+    {
+      "code": 6039,
+      "name": "InvalidRoute",
+      "msg": "Provided route is invalid"
+    },
+    {
+      "code": 6040,
+      "name": "AmountOutBelowMinimum",
+      "msg": "Amount out is lower than provided minimum"
+    },
+    {
+      "code": 6041,
+      "name": "AmountInAboveMaximum",
+      "msg": "Amount in is higher than provided maximum"
+    },
+    {
+      "code": 6042,
+      "name": "InvalidReferralFee",
+      "msg": "Invalid referral fee"
+    },
+    {
+      "code": 6043,
+      "name": "InvalidCallbackProgram",
+      "msg": "Invalid callback program"
+    },
+    {
+      "code": 6044,
+      "name": "InsufficientRepayment",
+      "msg": "Borrowed tokens were not repaid"
+    },
+    {
+      "code": 6045,
+      "name": "InvalidDynamicFee",
+      "msg": "Invalid dynamic fee parameters"
+    },
+    {
+      "code": 6046,
+      "name": "InvalidRangeOrder",
+      "msg": "Range order has to be placed entirely on one side of the price"
+    },
+    {
+      "code": 6047,
+      "name": "RangeOrderNotFilled",
+      "msg": "Range order is not filled yet"
+    },
+    {
+      "code": 6048,
+      "name": "RangeOrderSettled",
+      "msg": "Range order is already settled"
+    },
+    {
+      "code": 6049,
+      "name": "RangeOrderNotSettled",
+      "msg": "Range order is not settled yet"
+    },
+    {
+      "code": 6050,
+      "name": "InvalidPositionToken",
+      "msg": "Provided token account does not hold the position token"
+    },
+    {
+      "code": 6051,
+      "name": "InvalidRemainingAccounts",
+      "msg": "Remaining accounts are not (position, lower tick, upper tick) triples"
+    },
+    {
+      "code": 6052,
+      "name": "LiquidityBelowMinimum",
+      "msg": "Liquidity supported by the amounts is below the minimum"
+    },
+    {
+      "code": 6053,
+      "name": "InvalidPermissions",
+      "msg": "Invalid operator permissions"
+    },
+    {
+      "code": 6054,
+      "name": "PoolNotEmpty",
+      "msg": "Pool still holds liquidity, ticks or fees"
+    },
+    {
+      "code": 6055,
+      "name": "PositionListNotEmpty",
+      "msg": "Position list still contains positions"
+    },
+    {
+      "code": 6056,
+      "name": "OperationPaused",
+      "msg": "Operation is paused"
+    },
+    {
+      "code": 6057,
+      "name": "InvalidPauseFlags",
+      "msg": "Invalid pause flags"
+    },
+    {
+      "code": 6058,
+      "name": "FeeTierDisabled",
+      "msg": "Fee tier is disabled"
+    },
+    {
+      "code": 6059,
+      "name": "FeeOutOfBounds",
+      "msg": "Fee is outside of the fee tier bounds"
+    },
+    {
+      "code": 6060,
+      "name": "InvalidFeeSplit",
+      "msg": "Fee split needs up to 4 recipients with shares summing up to 10000 bps"
+    },
+    {
+      "code": 6061,
+      "name": "InsufficientProtocolFee",
+      "msg": "Requested amount exceeds collected protocol fee"
+    },
+    {
+      "code": 6062,
+      "name": "RouteAmountMismatch",
+      "msg": "Amount out of a route hop does not match amount in of the next one"
+    },
+    {
+      "code": 6063,
+      "name": "AlreadyMigrated",
+      "msg": "Account already has the current layout"
+    }
+  ]
+};
+
+export const IDL: Invariant = {
+  "version": "0.1.0",
+  "name": "invariant",
+  "instructions": [
+    {
+      "name": "createState",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "programAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "nonce",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "proposeAdmin",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "pendingAdmin",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "acceptAdmin",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pendingAdmin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setRole",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "holder",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "role",
+          "type": {
+            "defined": "Role"
+          }
+        }
+      ]
+    },
+    {
+      "name": "createFeeTier",
+      "accounts": [
+        {
+          "name": "feeTier",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "fee",
+          "type": "u128"
+        },
+        {
+          "name": "tickSpacing",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "changeFeeTier",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "feeTier",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "disabled",
+          "type": "bool"
+        },
+        {
+          "name": "minFee",
+          "type": {
+            "defined": "FixedPoint"
+          }
+        },
+        {
+          "name": "maxFee",
+          "type": {
+            "defined": "FixedPoint"
+          }
+        }
+      ]
+    },
+    {
+      "name": "changePoolFee",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeTier",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "fee",
+          "type": {
+            "defined": "FixedPoint"
+          }
+        }
+      ]
+    },
+    {
+      "name": "createPool",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeTier",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tickmap",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "tokenXProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenYProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "initTick",
+          "type": "i32"
+        }
+      ]
+    },
+    {
+      "name": "createPoolWithSqrtPrice",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeTier",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tickmap",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "tokenXProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenYProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "initPrice",
+          "type": {
+            "defined": "Price"
+          }
+        }
+      ]
+    },
+    {
+      "name": "initReserves",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenXReserve",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "tokenYReserve",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenXProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenYProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closePool",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tickmap",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "oracle",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "reserveY",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "receiverAccountX",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "receiverAccountY",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "programAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenXProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenYProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "swap",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tickmap",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "accountX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "accountY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "programAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenXProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenYProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "xToY",
+          "type": "bool"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "byAmountIn",
+          "type": "bool"
+        },
+        {
+          "name": "sqrtPriceLimit",
+          "type": "u128"
+        },
+        {
+          "name": "minAmountOut",
+          "type": {
+            "option": "u64"
+          }
+        },
+        {
+          "name": "maxAmountIn",
+          "type": {
+            "option": "u64"
+          }
+        },
+        {
+          "name": "partialFill",
+          "type": "bool"
+        }
+      ],
+      "returns": "u64"
+    },
+    {
+      "name": "swapRoute",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenIn",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "accountIn",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "accountOut",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "programAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenInProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "hops",
+          "type": "u8"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "byAmountIn",
+          "type": "bool"
+        },
+        {
+          "name": "amountLimit",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "quoteSwap",
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tickmap",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "xToY",
+          "type": "bool"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "byAmountIn",
+          "type": "bool"
+        },
+        {
+          "name": "sqrtPriceLimit",
+          "type": "u128"
+        },
+        {
+          "name": "partialFill",
+          "type": "bool"
+        }
+      ],
+      "returns": {
+        "defined": "QuoteResult"
+      }
+    },
+    {
+      "name": "flashSwap",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tickmap",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "accountX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "accountY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "programAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "callbackProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenXProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenYProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "xToY",
+          "type": "bool"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "byAmountIn",
+          "type": "bool"
+        },
+        {
+          "name": "sqrtPriceLimit",
+          "type": "u128"
+        },
+        {
+          "name": "data",
+          "type": "bytes"
+        }
+      ]
+    },
+    {
+      "name": "flashLoan",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "accountX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "accountY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "programAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "callbackProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenXProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenYProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "amountX",
+          "type": "u64"
+        },
+        {
+          "name": "amountY",
+          "type": "u64"
+        },
+        {
+          "name": "data",
+          "type": "bytes"
+        }
+      ]
+    },
+    {
+      "name": "initializeOracle",
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "oracle",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "getTwap",
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "oracle",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "window",
+          "type": "u64"
+        }
+      ],
+      "returns": {
+        "defined": "Price"
+      }
+    },
+    {
+      "name": "createTick",
+      "accounts": [
+        {
+          "name": "tick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tickmap",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenXProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenYProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "index",
+          "type": "i32"
+        }
+      ]
+    },
+    {
+      "name": "createPositionList",
+      "accounts": [
+        {
+          "name": "positionList",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "signer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closePositionList",
+      "accounts": [
+        {
+          "name": "positionList",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createStablePositionList",
+      "accounts": [
+        {
+          "name": "positionList",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "signer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createPosition",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "position",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "positionList",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "lowerTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "upperTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tickmap",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "accountX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "accountY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenXProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenYProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventOptAcc",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "lowerTickIndex",
+          "type": "i32"
+        },
+        {
+          "name": "upperTickIndex",
+          "type": "i32"
+        },
+        {
+          "name": "liquidityDelta",
+          "type": {
+            "defined": "Liquidity"
+          }
+        },
+        {
+          "name": "slippageLimitLower",
+          "type": {
+            "defined": "Price"
+          }
+        },
+        {
+          "name": "slippageLimitUpper",
+          "type": {
+            "defined": "Price"
+          }
+        }
+      ]
+    },
+    {
+      "name": "createPositionByAmounts",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "position",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "positionList",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "lowerTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "upperTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tickmap",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "accountX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "accountY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenXProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenYProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventOptAcc",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "lowerTickIndex",
+          "type": "i32"
+        },
+        {
+          "name": "upperTickIndex",
+          "type": "i32"
+        },
+        {
+          "name": "maxAmountX",
+          "type": "u64"
+        },
+        {
+          "name": "maxAmountY",
+          "type": "u64"
+        },
+        {
+          "name": "minLiquidity",
+          "type": {
+            "defined": "Liquidity"
+          }
+        },
+        {
+          "name": "slippageLimitLower",
+          "type": {
+            "defined": "Price"
+          }
+        },
+        {
+          "name": "slippageLimitUpper",
+          "type": {
+            "defined": "Price"
+          }
+        }
+      ]
+    },
+    {
+      "name": "removePosition",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "removedPosition",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "positionList",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lastPosition",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tickmap",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lowerTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "upperTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "accountX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "accountY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenXProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenYProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventOptAcc",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "positionOperator",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "index",
+          "type": "u32"
+        },
+        {
+          "name": "lowerTickIndex",
+          "type": "i32"
+        },
+        {
+          "name": "upperTickIndex",
+          "type": "i32"
+        }
+      ]
+    },
+    {
+      "name": "createStablePosition",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "position",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "positionList",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "positionEntry",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "lowerTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "upperTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tickmap",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "accountX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "accountY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenXProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenYProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventOptAcc",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "lowerTickIndex",
+          "type": "i32"
+        },
+        {
+          "name": "upperTickIndex",
+          "type": "i32"
+        },
+        {
+          "name": "liquidityDelta",
+          "type": {
+            "defined": "Liquidity"
+          }
+        },
+        {
+          "name": "slippageLimitLower",
+          "type": {
+            "defined": "Price"
+          }
+        },
+        {
+          "name": "slippageLimitUpper",
+          "type": {
+            "defined": "Price"
+          }
+        }
+      ]
+    },
+    {
+      "name": "removeStablePosition",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "removedPosition",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "positionList",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "removedEntry",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lastEntry",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tickmap",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lowerTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "upperTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "positionOwner",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "accountX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "accountY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenXProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenYProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "positionOperator",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "eventOptAcc",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "index",
+          "type": "u32"
+        },
+        {
+          "name": "lowerTickIndex",
+          "type": "i32"
+        },
+        {
+          "name": "upperTickIndex",
+          "type": "i32"
+        }
+      ]
+    },
+    {
+      "name": "transferStablePosition",
+      "accounts": [
+        {
+          "name": "position",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ownerList",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "recipientList",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "removedEntry",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lastEntry",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "newEntry",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "recipient",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "index",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "migratePosition",
+      "accounts": [
+        {
+          "name": "positionList",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "stablePosition",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "stablePositionList",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "positionEntry",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "removedPosition",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lastPosition",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "index",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "migratePool",
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateState",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateFeeTier",
+      "accounts": [
+        {
+          "name": "feeTier",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createRangeOrder",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rangeOrder",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "lowerTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "upperTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tickmap",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "accountX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "accountY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenXProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenYProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "lowerTickIndex",
+          "type": "i32"
+        },
+        {
+          "name": "liquidityDelta",
+          "type": {
+            "defined": "Liquidity"
+          }
+        }
+      ]
+    },
+    {
+      "name": "settleRangeOrder",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rangeOrder",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lowerTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "upperTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tickmap",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "settler",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "claimRangeOrder",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rangeOrder",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "accountX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "accountY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenXProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenYProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "increaseLiquidity",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "position",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "lowerTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "upperTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "accountX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "accountY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenXProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenYProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "positionOperator",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "liquidityDelta",
+          "type": {
+            "defined": "Liquidity"
+          }
+        },
+        {
+          "name": "slippageLimitLower",
+          "type": {
+            "defined": "Price"
+          }
+        },
+        {
+          "name": "slippageLimitUpper",
+          "type": {
+            "defined": "Price"
+          }
+        },
+        {
+          "name": "maxAmountX",
+          "type": "u64"
+        },
+        {
+          "name": "maxAmountY",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "decreaseLiquidity",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "position",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "lowerTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "upperTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "accountX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "accountY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenXProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenYProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "positionOperator",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "liquidityDelta",
+          "type": {
+            "defined": "Liquidity"
+          }
+        },
+        {
+          "name": "slippageLimitLower",
+          "type": {
+            "defined": "Price"
+          }
+        },
+        {
+          "name": "slippageLimitUpper",
+          "type": {
+            "defined": "Price"
+          }
+        },
+        {
+          "name": "minAmountX",
+          "type": "u64"
+        },
+        {
+          "name": "minAmountY",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "transferPositionOwnership",
+      "accounts": [
+        {
+          "name": "ownerList",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "recipientList",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "newPosition",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "removedPosition",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lastPosition",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "recipient",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "index",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "tokenizePosition",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "positionList",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "removedPosition",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lastPosition",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenizedPosition",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "positionMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ownerTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "programAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "index",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "redeemPosition",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "positionList",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "position",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenizedPosition",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "positionMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "holderTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "holder",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "programAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "claimTokenizedFee",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "position",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "positionMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ownerTokenAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "lowerTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "upperTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "accountX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "accountY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenXProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenYProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "positionTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "lowerTickIndex",
+          "type": "i32"
+        },
+        {
+          "name": "upperTickIndex",
+          "type": "i32"
+        }
+      ]
+    },
+    {
+      "name": "claimFee",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "position",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lowerTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "upperTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "accountX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "accountY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenXProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenYProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "positionOperator",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "index",
+          "type": "u32"
+        },
+        {
+          "name": "lowerTickIndex",
+          "type": "i32"
+        },
+        {
+          "name": "upperTickIndex",
+          "type": "i32"
+        }
+      ]
+    },
+    {
+      "name": "compoundFees",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "position",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lowerTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "upperTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "positionOperator",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "approvePositionOperator",
+      "accounts": [
+        {
+          "name": "positionOperator",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "position",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "operator",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "permissions",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "revokePositionOperator",
+      "accounts": [
+        {
+          "name": "positionOperator",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "claimFeeBatch",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "accountX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "accountY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenXProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenYProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "updateSecondsPerLiquidity",
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lowerTick",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "upperTick",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "position",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "signer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "lowerTickIndex",
+          "type": "i32"
+        },
+        {
+          "name": "upperTickIndex",
+          "type": "i32"
+        },
+        {
+          "name": "index",
+          "type": "i32"
+        }
+      ]
+    },
+    {
+      "name": "withdrawProtocolFee",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "accountX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "accountY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "programAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenXProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenYProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "amountX",
+          "type": "u64"
+        },
+        {
+          "name": "amountY",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "distributeProtocolFee",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeSplit",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "programAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenXProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenYProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "amountX",
+          "type": "u64"
+        },
+        {
+          "name": "amountY",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "createFeeSplit",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "feeSplit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "recipients",
+          "type": {
+            "vec": "publicKey"
+          }
+        },
+        {
+          "name": "shares",
+          "type": {
+            "vec": "u16"
+          }
+        }
+      ]
+    },
+    {
+      "name": "changeFeeSplit",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "feeSplit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "recipients",
+          "type": {
+            "vec": "publicKey"
+          }
+        },
+        {
+          "name": "shares",
+          "type": {
+            "vec": "u16"
+          }
+        }
+      ]
+    },
+    {
+      "name": "changeProtocolFee",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "programAuthority",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "protocolFee",
+          "type": {
+            "defined": "FixedPoint"
+          }
+        }
+      ]
+    },
+    {
+      "name": "changePause",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pauser",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "paused",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "changePoolPause",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pauser",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "paused",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "changeDynamicFee",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "enabled",
+          "type": "bool"
+        },
+        {
+          "name": "volatilityFeePerTick",
+          "type": {
+            "defined": "FixedPoint"
+          }
+        },
+        {
+          "name": "maxVolatilityFee",
+          "type": {
+            "defined": "FixedPoint"
+          }
+        },
+        {
+          "name": "volatilityHalfLife",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "changePoolDefaults",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "feeReceiver",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "protocolFee",
+          "type": {
+            "defined": "FixedPoint"
+          }
+        }
+      ]
+    },
+    {
+      "name": "changeFeeTierPoolDefaults",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "feeTier",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "protocolFee",
+          "type": {
+            "option": {
+              "defined": "FixedPoint"
+            }
+          }
+        },
+        {
+          "name": "feeReceiver",
+          "type": {
+            "option": "publicKey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "changeFeeReceiver",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "feeReceiver",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createReferrer",
+      "accounts": [
+        {
+          "name": "referrer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "feeShare",
+          "type": {
+            "defined": "FixedPoint"
+          }
+        }
+      ]
+    },
+    {
+      "name": "changeReferrer",
+      "accounts": [
+        {
+          "name": "referrer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "feeShare",
+          "type": {
+            "defined": "FixedPoint"
+          }
+        },
+        {
+          "name": "enabled",
+          "type": "bool"
+        }
+      ]
+    }
+  ],
+  "accounts": [
+    {
+      "name": "feeSplit",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "recipients",
+            "type": {
+              "array": [
+                "publicKey",
+                4
+              ]
+            }
+          },
+          {
+            "name": "shares",
+            "type": {
+              "array": [
+                "u16",
+                4
+              ]
+            }
+          },
+          {
+            "name": "count",
+            "type": "u8"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "feeTier",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "fee",
+            "type": {
+              "defined": "FixedPoint"
+            }
+          },
+          {
+            "name": "tickSpacing",
+            "type": "u16"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "disabled",
+            "type": "bool"
+          },
+          {
+            "name": "minFee",
+            "type": {
+              "defined": "FixedPoint"
+            }
+          },
+          {
+            "name": "maxFee",
+            "type": {
+              "defined": "FixedPoint"
+            }
+          },
+          {
+            "name": "protocolFeeOverride",
+            "type": {
+              "defined": "FixedPoint"
+            }
+          },
+          {
+            "name": "protocolFeeOverridden",
+            "type": "bool"
+          },
+          {
+            "name": "feeReceiverOverride",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "oracle",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "data",
+            "type": {
+              "array": [
+                {
+                  "defined": "Record"
+                },
+                256
+              ]
+            }
+          },
+          {
+            "name": "head",
+            "type": "u16"
+          },
+          {
+            "name": "amount",
+            "type": "u16"
+          },
+          {
+            "name": "size",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "pool",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "tokenX",
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenY",
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenXReserve",
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenYReserve",
+            "type": "publicKey"
+          },
+          {
+            "name": "positionIterator",
+            "type": "u128"
+          },
+          {
+            "name": "tickSpacing",
+            "type": "u16"
+          },
+          {
+            "name": "fee",
+            "type": {
+              "defined": "FixedPoint"
+            }
+          },
+          {
+            "name": "protocolFee",
+            "type": {
+              "defined": "FixedPoint"
+            }
+          },
+          {
+            "name": "liquidity",
+            "type": {
+              "defined": "Liquidity"
+            }
+          },
+          {
+            "name": "sqrtPrice",
+            "type": {
+              "defined": "Price"
+            }
+          },
+          {
+            "name": "currentTickIndex",
+            "type": "i32"
+          },
+          {
+            "name": "tickmap",
+            "type": "publicKey"
+          },
+          {
+            "name": "feeGrowthGlobalX",
+            "type": {
+              "defined": "FeeGrowth"
+            }
+          },
+          {
+            "name": "feeGrowthGlobalY",
+            "type": {
+              "defined": "FeeGrowth"
+            }
+          },
+          {
+            "name": "feeProtocolTokenX",
+            "type": "u64"
+          },
+          {
+            "name": "feeProtocolTokenY",
+            "type": "u64"
+          },
+          {
+            "name": "secondsPerLiquidityGlobal",
+            "type": {
+              "defined": "SecondsPerLiquidity"
+            }
+          },
+          {
+            "name": "startTimestamp",
+            "type": "u64"
+          },
+          {
+            "name": "lastTimestamp",
+            "type": "u64"
+          },
+          {
+            "name": "feeReceiver",
+            "type": "publicKey"
+          },
+          {
+            "name": "oracleAddress",
+            "type": "publicKey"
+          },
+          {
+            "name": "oracleInitialized",
+            "type": "bool"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "dynamicFeeEnabled",
+            "type": "bool"
+          },
+          {
+            "name": "volatilityFeePerTick",
+            "type": {
+              "defined": "FixedPoint"
+            }
+          },
+          {
+            "name": "maxVolatilityFee",
+            "type": {
+              "defined": "FixedPoint"
+            }
+          },
+          {
+            "name": "volatilityHalfLife",
+            "type": "u64"
+          },
+          {
+            "name": "volatilityAccumulator",
+            "type": "u64"
+          },
+          {
+            "name": "volatilityLastUpdate",
+            "type": "u64"
+          },
+          {
+            "name": "paused",
+            "type": "u8"
+          },
+          {
+            "name": "feeOverride",
+            "type": {
+              "defined": "FixedPoint"
+            }
+          },
+          {
+            "name": "feeOverridden",
+            "type": "bool"
+          },
+          {
+            "name": "openRangeOrders",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "positionList",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "head",
+            "type": "u32"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "positionEntry",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pool",
+            "type": "publicKey"
+          },
+          {
+            "name": "id",
+            "type": "u128"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "positionOperator",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "owner",
+            "type": "publicKey"
+          },
+          {
+            "name": "pool",
+            "type": "publicKey"
+          },
+          {
+            "name": "positionId",
+            "type": "u128"
+          },
+          {
+            "name": "operator",
+            "type": "publicKey"
+          },
+          {
+            "name": "permissions",
+            "type": "u8"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "position",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "owner",
+            "type": "publicKey"
+          },
+          {
+            "name": "pool",
+            "type": "publicKey"
+          },
+          {
+            "name": "id",
+            "type": "u128"
+          },
+          {
+            "name": "liquidity",
+            "type": {
+              "defined": "Liquidity"
+            }
+          },
+          {
+            "name": "lowerTickIndex",
+            "type": "i32"
+          },
+          {
+            "name": "upperTickIndex",
+            "type": "i32"
+          },
+          {
+            "name": "feeGrowthInsideX",
+            "type": {
+              "defined": "FeeGrowth"
+            }
+          },
+          {
+            "name": "feeGrowthInsideY",
+            "type": {
+              "defined": "FeeGrowth"
+            }
+          },
+          {
+            "name": "secondsPerLiquidityInside",
+            "type": {
+              "defined": "SecondsPerLiquidity"
+            }
+          },
+          {
+            "name": "lastSlot",
+            "type": "u64"
+          },
+          {
+            "name": "tokensOwedX",
+            "type": {
+              "defined": "FixedPoint"
+            }
+          },
+          {
+            "name": "tokensOwedY",
+            "type": {
+              "defined": "FixedPoint"
+            }
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "rangeOrder",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "position",
+            "type": {
+              "defined": "Position"
+            }
+          },
+          {
+            "name": "xToY",
+            "type": "bool"
+          },
+          {
+            "name": "settled",
+            "type": "bool"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "referrer",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "owner",
+            "type": "publicKey"
+          },
+          {
+            "name": "feeShare",
+            "type": {
+              "defined": "FixedPoint"
+            }
+          },
+          {
+            "name": "enabled",
+            "type": "bool"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "state",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "admin",
+            "type": "publicKey"
+          },
+          {
+            "name": "nonce",
+            "type": "u8"
+          },
+          {
+            "name": "authority",
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "pendingAdmin",
+            "type": "publicKey"
+          },
+          {
+            "name": "feeTierManager",
+            "type": "publicKey"
+          },
+          {
+            "name": "pauser",
+            "type": "publicKey"
+          },
+          {
+            "name": "protocolFeeManager",
+            "type": "publicKey"
+          },
+          {
+            "name": "paused",
+            "type": "u8"
+          },
+          {
+            "name": "defaultProtocolFee",
+            "type": {
+              "defined": "FixedPoint"
+            }
+          },
+          {
+            "name": "defaultFeeReceiver",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "tickmap",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bitmap",
+            "type": {
+              "array": [
+                "u8",
+                11091
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "tick",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pool",
+            "type": "publicKey"
+          },
+          {
+            "name": "index",
+            "type": "i32"
+          },
+          {
+            "name": "sign",
+            "type": "bool"
+          },
+          {
+            "name": "liquidityChange",
+            "type": {
+              "defined": "Liquidity"
+            }
+          },
+          {
+            "name": "liquidityGross",
+            "type": {
+              "defined": "Liquidity"
+            }
+          },
+          {
+            "name": "sqrtPrice",
+            "type": {
+              "defined": "Price"
+            }
+          },
+          {
+            "name": "feeGrowthOutsideX",
+            "type": {
+              "defined": "FeeGrowth"
+            }
+          },
+          {
+            "name": "feeGrowthOutsideY",
+            "type": {
+              "defined": "FeeGrowth"
+            }
+          },
+          {
+            "name": "secondsPerLiquidityOutside",
+            "type": {
+              "defined": "SecondsPerLiquidity"
+            }
+          },
+          {
+            "name": "secondsOutside",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    }
+  ],
+  "types": [
+    {
+      "name": "Price",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "v",
+            "type": "u128"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Liquidity",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "v",
+            "type": "u128"
+          }
+        ]
+      }
     },
     {
-      "name": "createPosition",
-      "accounts": [
-        {
-          "name": "state",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "position",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "pool",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "positionList",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "payer",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "owner",
-          "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "lowerTick",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "upperTick",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "tickmap",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "tokenX",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "tokenY",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "accountX",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "accountY",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "reserveX",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "reserveY",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "programAuthority",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "tokenXProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "tokenYProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "rent",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "eventOptAcc",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "lowerTickIndex",
-          "type": "i32"
-        },
-        {
-          "name": "upperTickIndex",
-          "type": "i32"
-        },
-        {
-          "name": "liquidityDelta",
-          "type": {
-            "defined": "Liquidity"
+      "name": "FeeGrowth",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "v",
+            "type": "u128"
           }
-        },
-        {
-          "name": "slippageLimitLower",
-          "type": {
-            "defined": "Price"
+        ]
+      }
+    },
+    {
+      "name": "FixedPoint",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "v",
+            "type": "u128"
           }
-        },
-        {
-          "name": "slippageLimitUpper",
-          "type": {
-            "defined": "Price"
+        ]
+      }
+    },
+    {
+      "name": "SecondsPerLiquidity",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "v",
+            "type": "u128"
+          }
+        ]
+      }
+    },
+    {
+      "name": "FlashLoanCallbackArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amountX",
+            "type": "u64"
+          },
+          {
+            "name": "amountY",
+            "type": "u64"
+          },
+          {
+            "name": "feeX",
+            "type": "u64"
+          },
+          {
+            "name": "feeY",
+            "type": "u64"
+          },
+          {
+            "name": "data",
+            "type": "bytes"
           }
-        }
-      ]
+        ]
+      }
     },
     {
-      "name": "removePosition",
-      "accounts": [
-        {
-          "name": "state",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "removedPosition",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "positionList",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "lastPosition",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "pool",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "tickmap",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "lowerTick",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "upperTick",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "payer",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "owner",
-          "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "tokenX",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "tokenY",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "accountX",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "accountY",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "reserveX",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "reserveY",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "programAuthority",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "tokenXProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "tokenYProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "eventOptAcc",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "index",
-          "type": "u32"
-        },
-        {
-          "name": "lowerTickIndex",
-          "type": "i32"
-        },
-        {
-          "name": "upperTickIndex",
-          "type": "i32"
-        }
-      ]
+      "name": "FlashSwapCallbackArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "xToY",
+            "type": "bool"
+          },
+          {
+            "name": "amountIn",
+            "type": "u64"
+          },
+          {
+            "name": "amountOut",
+            "type": "u64"
+          },
+          {
+            "name": "data",
+            "type": "bytes"
+          }
+        ]
+      }
     },
     {
-      "name": "transferPositionOwnership",
-      "accounts": [
-        {
-          "name": "ownerList",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "recipientList",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "newPosition",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "removedPosition",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "lastPosition",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "payer",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "owner",
-          "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "recipient",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "rent",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "index",
-          "type": "u32"
-        }
-      ]
+      "name": "QuoteResult",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amountIn",
+            "type": "u64"
+          },
+          {
+            "name": "amountOut",
+            "type": "u64"
+          },
+          {
+            "name": "fee",
+            "type": "u64"
+          },
+          {
+            "name": "referralFee",
+            "type": "u64"
+          },
+          {
+            "name": "remainingAmount",
+            "type": "u64"
+          },
+          {
+            "name": "sqrtPrice",
+            "type": {
+              "defined": "Price"
+            }
+          },
+          {
+            "name": "crossedTicks",
+            "type": {
+              "vec": "i32"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "Record",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "timestamp",
+            "type": "u64"
+          },
+          {
+            "name": "price",
+            "type": {
+              "defined": "Price"
+            }
+          }
+        ]
+      }
     },
     {
-      "name": "claimFee",
-      "accounts": [
-        {
-          "name": "state",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "pool",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "position",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "lowerTick",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "upperTick",
-          "isMut": true,
-          "isSigner": false
-        },
+      "name": "Role",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "FeeTierManager"
+          },
+          {
+            "name": "Pauser"
+          },
+          {
+            "name": "ProtocolFeeManager"
+          }
+        ]
+      }
+    }
+  ],
+  "events": [
+    {
+      "name": "CreatePositionEvent",
+      "fields": [
         {
           "name": "owner",
-          "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "tokenX",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "tokenY",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "accountX",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "accountY",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "reserveX",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "reserveY",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "programAuthority",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "tokenXProgram",
-          "isMut": false,
-          "isSigner": false
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "tokenYProgram",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "index",
-          "type": "u32"
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "lowerTickIndex",
-          "type": "i32"
+          "name": "id",
+          "type": "u128",
+          "index": false
         },
         {
-          "name": "upperTickIndex",
-          "type": "i32"
-        }
-      ]
-    },
-    {
-      "name": "updateSecondsPerLiquidity",
-      "accounts": [
-        {
-          "name": "pool",
-          "isMut": true,
-          "isSigner": false
+          "name": "liquidity",
+          "type": {
+            "defined": "Liquidity"
+          },
+          "index": false
         },
         {
           "name": "lowerTick",
-          "isMut": false,
-          "isSigner": false
+          "type": "i32",
+          "index": false
         },
         {
           "name": "upperTick",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "position",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "tokenX",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "tokenY",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "owner",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "signer",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "rent",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "lowerTickIndex",
-          "type": "i32"
+          "type": "i32",
+          "index": false
         },
         {
-          "name": "upperTickIndex",
-          "type": "i32"
+          "name": "currentTimestamp",
+          "type": "u64",
+          "index": false
         },
         {
-          "name": "index",
-          "type": "i32"
+          "name": "secondsPerLiquidityInsideInitial",
+          "type": {
+            "defined": "SecondsPerLiquidity"
+          },
+          "index": false
         }
       ]
     },
     {
-      "name": "withdrawProtocolFee",
-      "accounts": [
+      "name": "RemovePositionEvent",
+      "fields": [
         {
-          "name": "state",
-          "isMut": false,
-          "isSigner": false
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
         },
         {
           "name": "pool",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "tokenX",
-          "isMut": false,
-          "isSigner": false
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "tokenY",
-          "isMut": false,
-          "isSigner": false
+          "name": "id",
+          "type": "u128",
+          "index": false
         },
         {
-          "name": "accountX",
-          "isMut": true,
-          "isSigner": false
+          "name": "liquidity",
+          "type": {
+            "defined": "Liquidity"
+          },
+          "index": false
         },
         {
-          "name": "accountY",
-          "isMut": true,
-          "isSigner": false
+          "name": "upperTick",
+          "type": "i32",
+          "index": false
         },
         {
-          "name": "reserveX",
-          "isMut": true,
-          "isSigner": false
+          "name": "currentTick",
+          "type": "i32",
+          "index": false
         },
         {
-          "name": "reserveY",
-          "isMut": true,
-          "isSigner": false
+          "name": "lowerTick",
+          "type": "i32",
+          "index": false
         },
         {
-          "name": "authority",
-          "isMut": false,
-          "isSigner": true
+          "name": "upperTickSecondsPerLiquidityOutside",
+          "type": {
+            "defined": "SecondsPerLiquidity"
+          },
+          "index": false
         },
         {
-          "name": "programAuthority",
-          "isMut": false,
-          "isSigner": false
+          "name": "lowerTickSecondsPerLiquidityOutside",
+          "type": {
+            "defined": "SecondsPerLiquidity"
+          },
+          "index": false
         },
         {
-          "name": "tokenXProgram",
-          "isMut": false,
-          "isSigner": false
+          "name": "poolSecondsPerLiquidityGlobal",
+          "type": {
+            "defined": "SecondsPerLiquidity"
+          },
+          "index": false
         },
         {
-          "name": "tokenYProgram",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": []
+          "name": "currentTimestamp",
+          "type": "u64",
+          "index": false
+        }
+      ]
     },
     {
-      "name": "changeProtocolFee",
-      "accounts": [
+      "name": "ChangeLiquidityEvent",
+      "fields": [
         {
-          "name": "state",
-          "isMut": false,
-          "isSigner": false
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
         },
         {
           "name": "pool",
-          "isMut": true,
-          "isSigner": false
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "tokenX",
-          "isMut": false,
-          "isSigner": false
+          "name": "id",
+          "type": "u128",
+          "index": false
         },
         {
-          "name": "tokenY",
-          "isMut": false,
-          "isSigner": false
+          "name": "liquidityDelta",
+          "type": {
+            "defined": "Liquidity"
+          },
+          "index": false
         },
         {
-          "name": "admin",
-          "isMut": false,
-          "isSigner": true
+          "name": "addLiquidity",
+          "type": "bool",
+          "index": false
         },
         {
-          "name": "programAuthority",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
+          "name": "amountX",
+          "type": "u64",
+          "index": false
+        },
         {
-          "name": "protocolFee",
-          "type": {
-            "defined": "FixedPoint"
-          }
+          "name": "amountY",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "currentTimestamp",
+          "type": "u64",
+          "index": false
         }
       ]
     },
     {
-      "name": "changeFeeReceiver",
-      "accounts": [
-        {
-          "name": "state",
-          "isMut": false,
-          "isSigner": false
-        },
+      "name": "SwapEvent",
+      "fields": [
         {
-          "name": "pool",
-          "isMut": true,
-          "isSigner": false
+          "name": "swapper",
+          "type": "publicKey",
+          "index": false
         },
         {
           "name": "tokenX",
-          "isMut": false,
-          "isSigner": false
+          "type": "publicKey",
+          "index": false
         },
         {
           "name": "tokenY",
-          "isMut": false,
-          "isSigner": false
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "admin",
-          "isMut": false,
-          "isSigner": true
+          "name": "xToY",
+          "type": "bool",
+          "index": false
         },
         {
-          "name": "feeReceiver",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": []
-    }
-  ],
-  "accounts": [
-    {
-      "name": "feeTier",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "fee",
-            "type": {
-              "defined": "FixedPoint"
-            }
-          },
-          {
-            "name": "tickSpacing",
-            "type": "u16"
-          },
-          {
-            "name": "bump",
-            "type": "u8"
-          }
-        ]
-      }
-    },
-    {
-      "name": "oracle",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "data",
-            "type": {
-              "array": [
-                {
-                  "defined": "Record"
-                },
-                256
-              ]
-            }
-          },
-          {
-            "name": "head",
-            "type": "u16"
-          },
-          {
-            "name": "amount",
-            "type": "u16"
-          },
-          {
-            "name": "size",
-            "type": "u16"
-          }
-        ]
-      }
-    },
-    {
-      "name": "pool",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "tokenX",
-            "type": "publicKey"
-          },
-          {
-            "name": "tokenY",
-            "type": "publicKey"
-          },
-          {
-            "name": "tokenXReserve",
-            "type": "publicKey"
-          },
-          {
-            "name": "tokenYReserve",
-            "type": "publicKey"
-          },
-          {
-            "name": "positionIterator",
-            "type": "u128"
-          },
-          {
-            "name": "tickSpacing",
-            "type": "u16"
-          },
-          {
-            "name": "fee",
-            "type": {
-              "defined": "FixedPoint"
-            }
-          },
-          {
-            "name": "protocolFee",
-            "type": {
-              "defined": "FixedPoint"
-            }
-          },
-          {
-            "name": "liquidity",
-            "type": {
-              "defined": "Liquidity"
-            }
-          },
-          {
-            "name": "sqrtPrice",
-            "type": {
-              "defined": "Price"
-            }
-          },
-          {
-            "name": "currentTickIndex",
-            "type": "i32"
-          },
-          {
-            "name": "tickmap",
-            "type": "publicKey"
-          },
-          {
-            "name": "feeGrowthGlobalX",
-            "type": {
-              "defined": "FeeGrowth"
-            }
-          },
-          {
-            "name": "feeGrowthGlobalY",
-            "type": {
-              "defined": "FeeGrowth"
-            }
-          },
-          {
-            "name": "feeProtocolTokenX",
-            "type": "u64"
-          },
-          {
-            "name": "feeProtocolTokenY",
-            "type": "u64"
-          },
-          {
-            "name": "secondsPerLiquidityGlobal",
-            "type": {
-              "defined": "SecondsPerLiquidity"
-            }
-          },
-          {
-            "name": "startTimestamp",
-            "type": "u64"
-          },
-          {
-            "name": "lastTimestamp",
-            "type": "u64"
+          "name": "fee",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "priceBeforeSwap",
+          "type": {
+            "defined": "Price"
           },
-          {
-            "name": "feeReceiver",
-            "type": "publicKey"
+          "index": false
+        },
+        {
+          "name": "priceAfterSwap",
+          "type": {
+            "defined": "Price"
           },
-          {
-            "name": "oracleAddress",
-            "type": "publicKey"
+          "index": false
+        },
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amountIn",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "amountOut",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "referralFee",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "liquidity",
+          "type": {
+            "defined": "Liquidity"
           },
-          {
-            "name": "oracleInitialized",
-            "type": "bool"
+          "index": false
+        },
+        {
+          "name": "currentTick",
+          "type": "i32",
+          "index": false
+        },
+        {
+          "name": "crossedTicks",
+          "type": {
+            "vec": "i32"
           },
-          {
-            "name": "bump",
-            "type": "u8"
-          }
-        ]
-      }
+          "index": false
+        },
+        {
+          "name": "remainingAmount",
+          "type": "u64",
+          "index": false
+        }
+      ]
     },
     {
-      "name": "positionList",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "head",
-            "type": "u32"
+      "name": "CrossTickEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "index",
+          "type": "i32",
+          "index": false
+        },
+        {
+          "name": "liquidity",
+          "type": {
+            "defined": "Liquidity"
           },
-          {
-            "name": "bump",
-            "type": "u8"
-          }
-        ]
-      }
+          "index": false
+        },
+        {
+          "name": "feeGrowthOutsideX",
+          "type": "u128",
+          "index": false
+        },
+        {
+          "name": "feeGrowthOutsideY",
+          "type": "u128",
+          "index": false
+        },
+        {
+          "name": "secondsPerLiquidityOutside",
+          "type": {
+            "defined": "SecondsPerLiquidity"
+          },
+          "index": false
+        },
+        {
+          "name": "currentTimestamp",
+          "type": "u64",
+          "index": false
+        }
+      ]
     },
     {
-      "name": "position",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "owner",
-            "type": "publicKey"
-          },
-          {
-            "name": "pool",
-            "type": "publicKey"
-          },
-          {
-            "name": "id",
-            "type": "u128"
-          },
-          {
-            "name": "liquidity",
-            "type": {
-              "defined": "Liquidity"
-            }
-          },
-          {
-            "name": "lowerTickIndex",
-            "type": "i32"
-          },
-          {
-            "name": "upperTickIndex",
-            "type": "i32"
-          },
-          {
-            "name": "feeGrowthInsideX",
-            "type": {
-              "defined": "FeeGrowth"
-            }
-          },
-          {
-            "name": "feeGrowthInsideY",
-            "type": {
-              "defined": "FeeGrowth"
-            }
-          },
-          {
-            "name": "secondsPerLiquidityInside",
-            "type": {
-              "defined": "SecondsPerLiquidity"
-            }
-          },
-          {
-            "name": "lastSlot",
-            "type": "u64"
-          },
-          {
-            "name": "tokensOwedX",
-            "type": {
-              "defined": "FixedPoint"
-            }
+      "name": "ClaimFeeEvent",
+      "fields": [
+        {
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "id",
+          "type": "u128",
+          "index": false
+        },
+        {
+          "name": "amountX",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "amountY",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "currentTimestamp",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ClaimRangeOrderEvent",
+      "fields": [
+        {
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "id",
+          "type": "u128",
+          "index": false
+        },
+        {
+          "name": "amountX",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "amountY",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "WithdrawProtocolFeeEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "feeReceiver",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amountX",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "amountY",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "DistributeProtocolFeeEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "recipient",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "share",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "amountX",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "amountY",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ChangeFeeSplitEvent",
+      "fields": [
+        {
+          "name": "recipients",
+          "type": {
+            "vec": "publicKey"
           },
-          {
-            "name": "tokensOwedY",
-            "type": {
-              "defined": "FixedPoint"
-            }
+          "index": false
+        },
+        {
+          "name": "shares",
+          "type": {
+            "vec": "u16"
           },
-          {
-            "name": "bump",
-            "type": "u8"
-          }
-        ]
-      }
+          "index": false
+        }
+      ]
     },
     {
-      "name": "state",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "admin",
-            "type": "publicKey"
+      "name": "CreatePoolEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "tokenX",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "tokenY",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "fee",
+          "type": {
+            "defined": "FixedPoint"
           },
-          {
-            "name": "nonce",
-            "type": "u8"
+          "index": false
+        },
+        {
+          "name": "tickSpacing",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "protocolFee",
+          "type": {
+            "defined": "FixedPoint"
           },
-          {
-            "name": "authority",
-            "type": "publicKey"
+          "index": false
+        },
+        {
+          "name": "feeReceiver",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "sqrtPrice",
+          "type": {
+            "defined": "Price"
           },
-          {
-            "name": "bump",
-            "type": "u8"
-          }
-        ]
-      }
+          "index": false
+        },
+        {
+          "name": "currentTick",
+          "type": "i32",
+          "index": false
+        },
+        {
+          "name": "tickmap",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "currentTimestamp",
+          "type": "u64",
+          "index": false
+        }
+      ]
     },
     {
-      "name": "tickmap",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "bitmap",
-            "type": {
-              "array": [
-                "u8",
-                11091
-              ]
-            }
-          }
-        ]
-      }
+      "name": "ClosePoolEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "admin",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
     },
     {
-      "name": "tick",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "pool",
-            "type": "publicKey"
-          },
-          {
-            "name": "index",
-            "type": "i32"
+      "name": "ClosePositionListEvent",
+      "fields": [
+        {
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "InitReservesEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "tokenXReserve",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "tokenYReserve",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "CreateTickEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "index",
+          "type": "i32",
+          "index": false
+        },
+        {
+          "name": "feeGrowthOutsideX",
+          "type": "u128",
+          "index": false
+        },
+        {
+          "name": "feeGrowthOutsideY",
+          "type": "u128",
+          "index": false
+        },
+        {
+          "name": "secondsOutside",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "secondsPerLiquidityOutside",
+          "type": {
+            "defined": "SecondsPerLiquidity"
           },
-          {
-            "name": "sign",
-            "type": "bool"
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ChangeProtocolFeeEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "protocolFee",
+          "type": {
+            "defined": "FixedPoint"
           },
-          {
-            "name": "liquidityChange",
-            "type": {
-              "defined": "Liquidity"
-            }
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ChangePauseEvent",
+      "fields": [
+        {
+          "name": "paused",
+          "type": "u8",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ChangePoolPauseEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "paused",
+          "type": "u8",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ChangeFeeTierEvent",
+      "fields": [
+        {
+          "name": "feeTier",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "disabled",
+          "type": "bool",
+          "index": false
+        },
+        {
+          "name": "minFee",
+          "type": {
+            "defined": "FixedPoint"
           },
-          {
-            "name": "liquidityGross",
-            "type": {
-              "defined": "Liquidity"
-            }
+          "index": false
+        },
+        {
+          "name": "maxFee",
+          "type": {
+            "defined": "FixedPoint"
           },
-          {
-            "name": "sqrtPrice",
-            "type": {
-              "defined": "Price"
-            }
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ChangePoolFeeEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "fee",
+          "type": {
+            "defined": "FixedPoint"
           },
-          {
-            "name": "feeGrowthOutsideX",
-            "type": {
-              "defined": "FeeGrowth"
-            }
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ChangeDynamicFeeEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "enabled",
+          "type": "bool",
+          "index": false
+        },
+        {
+          "name": "volatilityFeePerTick",
+          "type": {
+            "defined": "FixedPoint"
           },
-          {
-            "name": "feeGrowthOutsideY",
-            "type": {
-              "defined": "FeeGrowth"
-            }
+          "index": false
+        },
+        {
+          "name": "maxVolatilityFee",
+          "type": {
+            "defined": "FixedPoint"
           },
-          {
-            "name": "secondsPerLiquidityOutside",
-            "type": {
-              "defined": "SecondsPerLiquidity"
+          "index": false
+        },
+        {
+          "name": "volatilityHalfLife",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ChangePoolDefaultsEvent",
+      "fields": [
+        {
+          "name": "protocolFee",
+          "type": {
+            "defined": "FixedPoint"
+          },
+          "index": false
+        },
+        {
+          "name": "feeReceiver",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ChangeFeeTierPoolDefaultsEvent",
+      "fields": [
+        {
+          "name": "feeTier",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "protocolFee",
+          "type": {
+            "option": {
+              "defined": "FixedPoint"
             }
           },
-          {
-            "name": "secondsOutside",
-            "type": "u64"
+          "index": false
+        },
+        {
+          "name": "feeReceiver",
+          "type": {
+            "option": "publicKey"
           },
-          {
-            "name": "bump",
-            "type": "u8"
-          }
-        ]
-      }
-    }
-  ],
-  "types": [
+          "index": false
+        }
+      ]
+    },
     {
-      "name": "Price",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "v",
-            "type": "u128"
-          }
-        ]
-      }
+      "name": "ChangeFeeReceiverEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "feeReceiver",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
     },
     {
-      "name": "Liquidity",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "v",
-            "type": "u128"
-          }
-        ]
-      }
+      "name": "TransferPositionOwnershipEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "id",
+          "type": "u128",
+          "index": false
+        },
+        {
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "recipient",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
     },
     {
-      "name": "FeeGrowth",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "v",
-            "type": "u128"
-          }
-        ]
-      }
+      "name": "TokenizePositionEvent",
+      "fields": [
+        {
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "id",
+          "type": "u128",
+          "index": false
+        },
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
     },
     {
-      "name": "FixedPoint",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "v",
-            "type": "u128"
-          }
-        ]
-      }
+      "name": "RedeemPositionEvent",
+      "fields": [
+        {
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "id",
+          "type": "u128",
+          "index": false
+        },
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
     },
     {
-      "name": "SecondsPerLiquidity",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "v",
-            "type": "u128"
-          }
-        ]
-      }
+      "name": "InitializeOracleEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "oracle",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
     },
     {
-      "name": "Record",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "timestamp",
-            "type": "u64"
-          },
-          {
-            "name": "price",
-            "type": {
-              "defined": "Price"
-            }
-          }
-        ]
-      }
-    }
-  ],
-  "events": [
+      "name": "FlashLoanEvent",
+      "fields": [
+        {
+          "name": "borrower",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amountX",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "amountY",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "feeX",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "feeY",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
     {
-      "name": "CreatePositionEvent",
+      "name": "MigratePositionEvent",
       "fields": [
         {
           "name": "owner",
@@ -3490,38 +11175,74 @@ export const IDL: Invariant = {
           "index": false
         },
         {
-          "name": "liquidity",
-          "type": {
-            "defined": "Liquidity"
-          },
+          "name": "index",
+          "type": "u32",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "MigratePoolEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "MigrateStateEvent",
+      "fields": [
+        {
+          "name": "admin",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "MigrateFeeTierEvent",
+      "fields": [
+        {
+          "name": "feeTier",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ApprovePositionOperatorEvent",
+      "fields": [
+        {
+          "name": "owner",
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "lowerTick",
-          "type": "i32",
+          "name": "pool",
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "upperTick",
-          "type": "i32",
+          "name": "id",
+          "type": "u128",
           "index": false
         },
         {
-          "name": "currentTimestamp",
-          "type": "u64",
+          "name": "operator",
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "secondsPerLiquidityInsideInitial",
-          "type": {
-            "defined": "SecondsPerLiquidity"
-          },
+          "name": "permissions",
+          "type": "u8",
           "index": false
         }
       ]
     },
     {
-      "name": "RemovePositionEvent",
+      "name": "RevokePositionOperatorEvent",
       "fields": [
         {
           "name": "owner",
@@ -3539,46 +11260,50 @@ export const IDL: Invariant = {
           "index": false
         },
         {
-          "name": "liquidity",
-          "type": {
-            "defined": "Liquidity"
-          },
+          "name": "operator",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "CompoundFeesEvent",
+      "fields": [
+        {
+          "name": "authority",
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "upperTick",
-          "type": "i32",
+          "name": "owner",
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "currentTick",
-          "type": "i32",
+          "name": "pool",
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "lowerTick",
-          "type": "i32",
+          "name": "id",
+          "type": "u128",
           "index": false
         },
         {
-          "name": "upperTickSecondsPerLiquidityOutside",
+          "name": "liquidityDelta",
           "type": {
-            "defined": "SecondsPerLiquidity"
+            "defined": "Liquidity"
           },
           "index": false
         },
         {
-          "name": "lowerTickSecondsPerLiquidityOutside",
-          "type": {
-            "defined": "SecondsPerLiquidity"
-          },
+          "name": "amountX",
+          "type": "u64",
           "index": false
         },
         {
-          "name": "poolSecondsPerLiquidityGlobal",
-          "type": {
-            "defined": "SecondsPerLiquidity"
-          },
+          "name": "amountY",
+          "type": "u64",
           "index": false
         },
         {
@@ -3589,45 +11314,75 @@ export const IDL: Invariant = {
       ]
     },
     {
-      "name": "SwapEvent",
+      "name": "ProposeAdminEvent",
       "fields": [
         {
-          "name": "swapper",
+          "name": "admin",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "tokenX",
+          "name": "pendingAdmin",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "AcceptAdminEvent",
+      "fields": [
+        {
+          "name": "previousAdmin",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "tokenY",
+          "name": "admin",
           "type": "publicKey",
           "index": false
+        }
+      ]
+    },
+    {
+      "name": "SetRoleEvent",
+      "fields": [
+        {
+          "name": "role",
+          "type": {
+            "defined": "Role"
+          },
+          "index": false
         },
         {
-          "name": "xToY",
-          "type": "bool",
+          "name": "holder",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ChangeReferrerEvent",
+      "fields": [
+        {
+          "name": "referrer",
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "fee",
-          "type": "u64",
+          "name": "owner",
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "priceBeforeSwap",
+          "name": "feeShare",
           "type": {
-            "defined": "Price"
+            "defined": "FixedPoint"
           },
           "index": false
         },
         {
-          "name": "priceAfterSwap",
-          "type": {
-            "defined": "Price"
-          },
+          "name": "enabled",
+          "type": "bool",
           "index": false
         }
       ]
@@ -3808,6 +11563,151 @@ export const IDL: Invariant = {
       "code": 6034,
       "name": "UnsupportedExtension",
       "msg": "Not supported Token2022 extension for mint account"
+    },
+    {
+      "code": 6035,
+      "name": "InvalidOracle",
+      "msg": "Provided oracle is different than expected"
+    },
+    {
+      "code": 6036,
+      "name": "OracleNotFound",
+      "msg": "Oracle not found in context"
+    },
+    {
+      "code": 6037,
+      "name": "InsufficientOracleData",
+      "msg": "Oracle does not contain enough records for requested window"
+    },
+    {
+      "code": 6038,
+      "name": "InvalidTwapWindow",
+      "msg": "TWAP window cannot be zero"
+    },
+    {
+      "code": 6039,
+      "name": "InvalidRoute",
+      "msg": "Provided route is invalid"
+    },
+    {
+      "code": 6040,
+      "name": "AmountOutBelowMinimum",
+      "msg": "Amount out is lower than provided minimum"
+    },
+    {
+      "code": 6041,
+      "name": "AmountInAboveMaximum",
+      "msg": "Amount in is higher than provided maximum"
+    },
+    {
+      "code": 6042,
+      "name": "InvalidReferralFee",
+      "msg": "Invalid referral fee"
+    },
+    {
+      "code": 6043,
+      "name": "InvalidCallbackProgram",
+      "msg": "Invalid callback program"
+    },
+    {
+      "code": 6044,
+      "name": "InsufficientRepayment",
+      "msg": "Borrowed tokens were not repaid"
+    },
+    {
+      "code": 6045,
+      "name": "InvalidDynamicFee",
+      "msg": "Invalid dynamic fee parameters"
+    },
+    {
+      "code": 6046,
+      "name": "InvalidRangeOrder",
+      "msg": "Range order has to be placed entirely on one side of the price"
+    },
+    {
+      "code": 6047,
+      "name": "RangeOrderNotFilled",
+      "msg": "Range order is not filled yet"
+    },
+    {
+      "code": 6048,
+      "name": "RangeOrderSettled",
+      "msg": "Range order is already settled"
+    },
+    {
+      "code": 6049,
+      "name": "RangeOrderNotSettled",
+      "msg": "Range order is not settled yet"
+    },
+    {
+      "code": 6050,
+      "name": "InvalidPositionToken",
+      "msg": "Provided token account does not hold the position token"
+    },
+    {
+      "code": 6051,
+      "name": "InvalidRemainingAccounts",
+      "msg": "Remaining accounts are not (position, lower tick, upper tick) triples"
+    },
+    {
+      "code": 6052,
+      "name": "LiquidityBelowMinimum",
+      "msg": "Liquidity supported by the amounts is below the minimum"
+    },
+    {
+      "code": 6053,
+      "name": "InvalidPermissions",
+      "msg": "Invalid operator permissions"
+    },
+    {
+      "code": 6054,
+      "name": "PoolNotEmpty",
+      "msg": "Pool still holds liquidity, ticks or fees"
+    },
+    {
+      "code": 6055,
+      "name": "PositionListNotEmpty",
+      "msg": "Position list still contains positions"
+    },
+    {
+      "code": 6056,
+      "name": "OperationPaused",
+      "msg": "Operation is paused"
+    },
+    {
+      "code": 6057,
+      "name": "InvalidPauseFlags",
+      "msg": "Invalid pause flags"
+    },
+    {
+      "code": 6058,
+      "name": "FeeTierDisabled",
+      "msg": "Fee tier is disabled"
+    },
+    {
+      "code": 6059,
+      "name": "FeeOutOfBounds",
+      "msg": "Fee is outside of the fee tier bounds"
+    },
+    {
+      "code": 6060,
+      "name": "InvalidFeeSplit",
+      "msg": "Fee split needs up to 4 recipients with shares summing up to 10000 bps"
+    },
+    {
+      "code": 6061,
+      "name": "InsufficientProtocolFee",
+      "msg": "Requested amount exceeds collected protocol fee"
+    },
+    {
+      "code": 6062,
+      "name": "RouteAmountMismatch",
+      "msg": "Amount out of a route hop does not match amount in of the next one"
+    },
+    {
+      "code": 6063,
+      "name": "AlreadyMigrated",
+      "msg": "Account already has the current layout"
     }
   ]
 };
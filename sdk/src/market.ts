@@ -1,5 +1,6 @@
 import { AnchorProvider, BN, BorshEventCoder, Program, utils, web3 } from '@coral-xyz/anchor'
 import {
+  AccountMeta,
   AddressLookupTableAccount,
   Connection,
   Keypair,
@@ -51,12 +52,27 @@ import * as IDL from './idl/invariant.json'
 import { InvariantAutoswap } from './idl/invariant_autoswap'
 import * as autoswapIDL from './idl/invariant_autoswap.json'
 import { bs58 } from '@coral-xyz/anchor/dist/cjs/utils/bytes'
-import { getAssociatedTokenAddressSync, NATIVE_MINT } from '@solana/spl-token'
+import {
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  getAccount,
+  getAssociatedTokenAddressSync,
+  NATIVE_MINT
+} from '@solana/spl-token'
 
 const POSITION_SEED = 'positionv1'
 const TICK_SEED = 'tickv1'
 const POSITION_LIST_SEED = 'positionlistv1'
 const STATE_SEED = 'statev1'
+const REFERRER_SEED = 'referrerv1'
+const RANGE_ORDER_SEED = 'rangeorderv1'
+const POSITION_MINT_SEED = 'positionmintv1'
+const TOKENIZED_POSITION_SEED = 'tokenizedpositionv1'
+const STABLE_POSITION_SEED = 'positionv2'
+const STABLE_POSITION_LIST_SEED = 'stablepositionlistv1'
+const POSITION_ENTRY_SEED = 'positionentryv1'
+const POSITION_OPERATOR_SEED = 'positionoperatorv1'
+const TREASURY_SEED = 'treasuryv1'
+const FEE_SPLIT_SEED = 'feesplitv1'
 
 export const TOKEN_2022_PROGRAM_ID = new PublicKey('TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb')
 export const TICK_CROSSES_PER_IX_NATIVE_TOKEN = 11
@@ -740,6 +756,163 @@ export class Market {
     return parsePosition(await this.program.account.position.fetch(positionAddress)) as Position
   }
 
+  getPositionMintAddress(pool: PublicKey, id: BN) {
+    const [positionMintAddress, positionMintBump] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from(utils.bytes.utf8.encode(POSITION_MINT_SEED)),
+        pool.toBuffer(),
+        id.toArrayLike(Buffer, 'le', 16)
+      ],
+      this.program.programId
+    )
+
+    return {
+      positionMintAddress,
+      positionMintBump
+    }
+  }
+
+  getTokenizedPositionAddress(positionMint: PublicKey) {
+    const [tokenizedPositionAddress, tokenizedPositionBump] = PublicKey.findProgramAddressSync(
+      [Buffer.from(utils.bytes.utf8.encode(TOKENIZED_POSITION_SEED)), positionMint.toBuffer()],
+      this.program.programId
+    )
+
+    return {
+      tokenizedPositionAddress,
+      tokenizedPositionBump
+    }
+  }
+
+  // owner of tokenized position is its mint, the token holder acts on its behalf
+  async getTokenizedPosition(positionMint: PublicKey) {
+    const { tokenizedPositionAddress } = this.getTokenizedPositionAddress(positionMint)
+    return parsePosition(
+      await this.program.account.position.fetch(tokenizedPositionAddress)
+    ) as Position
+  }
+
+  // address of stable position does not change when other positions are removed or transferred
+  getStablePositionAddress(pool: PublicKey, id: BN) {
+    const [stablePositionAddress, stablePositionBump] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from(utils.bytes.utf8.encode(STABLE_POSITION_SEED)),
+        pool.toBuffer(),
+        id.toArrayLike(Buffer, 'le', 16)
+      ],
+      this.program.programId
+    )
+
+    return {
+      stablePositionAddress,
+      stablePositionBump
+    }
+  }
+
+  // default fee receiver of new pools, its fees are withdrawn by the protocol fee manager
+  getTreasuryAddress() {
+    const [treasuryAddress, treasuryBump] = PublicKey.findProgramAddressSync(
+      [Buffer.from(utils.bytes.utf8.encode(TREASURY_SEED))],
+      this.program.programId
+    )
+
+    return {
+      treasuryAddress,
+      treasuryBump
+    }
+  }
+
+  getStablePositionListAddress(owner: PublicKey) {
+    const [stablePositionListAddress, stablePositionListBump] = PublicKey.findProgramAddressSync(
+      [Buffer.from(utils.bytes.utf8.encode(STABLE_POSITION_LIST_SEED)), owner.toBuffer()],
+      this.program.programId
+    )
+
+    return {
+      stablePositionListAddress,
+      stablePositionListBump
+    }
+  }
+
+  getPositionEntryAddress(owner: PublicKey, index: number) {
+    const indexBuffer = Buffer.alloc(4)
+    indexBuffer.writeUInt32LE(index)
+
+    const [positionEntryAddress, positionEntryBump] = PublicKey.findProgramAddressSync(
+      [Buffer.from(utils.bytes.utf8.encode(POSITION_ENTRY_SEED)), owner.toBuffer(), indexBuffer],
+      this.program.programId
+    )
+
+    return {
+      positionEntryAddress,
+      positionEntryBump
+    }
+  }
+
+  async getStablePosition(pool: PublicKey, id: BN) {
+    const { stablePositionAddress } = this.getStablePositionAddress(pool, id)
+    return parsePosition(
+      await this.program.account.position.fetch(stablePositionAddress)
+    ) as Position
+  }
+
+  async getStablePositionList(owner: PublicKey) {
+    const { stablePositionListAddress } = this.getStablePositionListAddress(owner)
+    return (await this.program.account.positionList.fetch(
+      stablePositionListAddress
+    )) as PositionList
+  }
+
+  async getPositionEntry(owner: PublicKey, index: number) {
+    const { positionEntryAddress } = this.getPositionEntryAddress(owner, index)
+    return (await this.program.account.positionEntry.fetch(positionEntryAddress)) as PositionEntry
+  }
+
+  // approval of the operator is bound to the owner, so it is void once the position changes hands
+  getPositionOperatorAddress(owner: PublicKey, pool: PublicKey, id: BN, operator: PublicKey) {
+    const [positionOperatorAddress, positionOperatorBump] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from(utils.bytes.utf8.encode(POSITION_OPERATOR_SEED)),
+        owner.toBuffer(),
+        pool.toBuffer(),
+        id.toArrayLike(Buffer, 'le', 16),
+        operator.toBuffer()
+      ],
+      this.program.programId
+    )
+
+    return {
+      positionOperatorAddress,
+      positionOperatorBump
+    }
+  }
+
+  async getPositionOperator(owner: PublicKey, pool: PublicKey, id: BN, operator: PublicKey) {
+    const { positionOperatorAddress } = this.getPositionOperatorAddress(owner, pool, id, operator)
+    return (await this.program.account.positionOperator.fetch(
+      positionOperatorAddress
+    )) as PositionOperator
+  }
+
+  // stable positions of the owner in the order of their list entries
+  async getStablePositions(owner: PublicKey) {
+    const { head } = await this.getStablePositionList(owner)
+    const entryAddresses = Array.from(
+      { length: head },
+      (_, index) => this.getPositionEntryAddress(owner, index).positionEntryAddress
+    )
+    const entries = (await this.program.account.positionEntry.fetchMultiple(
+      entryAddresses
+    )) as PositionEntry[]
+    const positionAddresses = entries.map(
+      entry => this.getStablePositionAddress(entry.pool, entry.id).stablePositionAddress
+    )
+
+    return (await this.program.account.position.fetchMultiple(positionAddresses)).map(p =>
+      parsePosition(p as RawPosition)
+    ) as Position[]
+  }
+
   async getPositionsFromIndexes(owner: PublicKey, indexes: number[]) {
     const positionAddresses = indexes.map(i => this.getPositionAddress(owner, i).positionAddress)
     return (await this.program.account.position.fetchMultiple(positionAddresses))
@@ -789,6 +962,38 @@ export class Market {
     }
   }
 
+  getRangeOrderAddress(pair: Pair, owner: PublicKey, lowerTick: number) {
+    const indexBuffer = Buffer.alloc(4)
+    indexBuffer.writeInt32LE(lowerTick)
+
+    const [rangeOrderAddress, rangeOrderBump] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from(utils.bytes.utf8.encode(RANGE_ORDER_SEED)),
+        owner.toBuffer(),
+        pair.getAddress(this.program.programId).toBuffer(),
+        indexBuffer
+      ],
+      this.program.programId
+    )
+
+    return {
+      rangeOrderAddress,
+      rangeOrderBump
+    }
+  }
+
+  async getRangeOrder(pair: Pair, owner: PublicKey, lowerTick: number): Promise<RangeOrder> {
+    const { rangeOrderAddress } = this.getRangeOrderAddress(pair, owner, lowerTick)
+    const rangeOrder = await this.program.account.rangeOrder.fetch(rangeOrderAddress)
+
+    return {
+      position: parsePosition(rangeOrder.position as RawPosition),
+      xToY: rangeOrder.xToY,
+      settled: rangeOrder.settled,
+      bump: rangeOrder.bump
+    }
+  }
+
   getPositionListAddress(owner: PublicKey) {
     const [positionListAddress, positionListBump] = PublicKey.findProgramAddressSync(
       [Buffer.from(utils.bytes.utf8.encode(POSITION_LIST_SEED)), owner.toBuffer()],
@@ -801,6 +1006,30 @@ export class Market {
     }
   }
 
+  getReferrerAddress(owner: PublicKey) {
+    const [referrerAddress, referrerBump] = PublicKey.findProgramAddressSync(
+      [Buffer.from(utils.bytes.utf8.encode(REFERRER_SEED)), owner.toBuffer()],
+      this.program.programId
+    )
+
+    return {
+      referrerAddress,
+      referrerBump
+    }
+  }
+
+  // referrer of the owner of referral token account, has to be passed along with it to swap
+  async getReferralReferrerAddress(referralAccount: PublicKey, tokenProgram: PublicKey) {
+    const { owner } = await getAccount(this.connection, referralAccount, undefined, tokenProgram)
+    return this.getReferrerAddress(owner).referrerAddress
+  }
+
+  async getReferrer(owner: PublicKey) {
+    const { referrerAddress } = this.getReferrerAddress(owner)
+    const referrer = await this.program.account.referrer.fetch(referrerAddress)
+    return parseReferrer(referrer as RawReferrer)
+  }
+
   getPositionAddress(owner: PublicKey, index: number) {
     const indexBuffer = Buffer.alloc(4)
     indexBuffer.writeInt32LE(index)
@@ -868,6 +1097,33 @@ export class Market {
     await signAndSend(tx, [signer], this.connection)
   }
 
+  async changeFeeTierIx(changeFeeTier: ChangeFeeTier) {
+    const { feeTier, disabled, minFee, maxFee } = changeFeeTier
+    const admin = changeFeeTier.admin ?? this.wallet.publicKey
+    const { address } = this.getFeeTierAddress(feeTier)
+
+    return this.program.methods
+      .changeFeeTier(disabled, { v: minFee }, { v: maxFee })
+      .accounts({
+        state: this.stateAddress.address,
+        feeTier: address,
+        admin
+      })
+      .instruction()
+  }
+
+  async changeFeeTierTx(changeFeeTier: ChangeFeeTier) {
+    const ix = await this.changeFeeTierIx(changeFeeTier)
+    return new Transaction().add(ix)
+  }
+
+  // Fee tier manager function
+  async changeFeeTier(changeFeeTier: ChangeFeeTier, signer: Keypair) {
+    const tx = await this.changeFeeTierTx(changeFeeTier)
+
+    await signAndSend(tx, [signer], this.connection)
+  }
+
   async createStateIx(admin?: PublicKey) {
     admin = admin ?? this.wallet.publicKey
     const { address: programAuthority, bump: nonce } = this.programAuthority
@@ -1129,11 +1385,18 @@ export class Market {
         swapPool,
         swapTickmap,
         xToY,
-        (ticks as { tickCrosses: number }).tickCrosses - (referralAccount ? 1 : 0)
+        (ticks as { tickCrosses: number }).tickCrosses - (referralAccount ? 2 : 0)
       )
     const remainingAccounts = tickAddresses
     if (referralAccount) {
-      remainingAccounts.unshift(referralAccount)
+      const referrer = await this.getReferralReferrerAddress(
+        referralAccount,
+        xToY ? positionAccounts.tokenXProgram : positionAccounts.tokenYProgram
+      )
+      remainingAccounts.unshift(referralAccount, referrer)
+    }
+    if (swapPool.oracleInitialized) {
+      remainingAccounts.push(swapPool.oracleAddress)
     }
 
     // trunk-ignore(eslint)
@@ -1303,9 +1566,11 @@ export class Market {
       .accounts(accounts)
       .instruction()
   }
+  // `create_position` initializes missing ticks itself, they are created upfront only for other programs
   private async createAssociatedPositionAccountsTx(
     createPosition: Pick<CreatePosition, 'pair' | 'lowerTick' | 'upperTick' | 'owner'>,
-    cache: CreatePositionTransactionCache = {}
+    cache: CreatePositionTransactionCache = {},
+    createTicks: boolean = true
   ) {
     const { pair, lowerTick: lowerIndex, upperTick: upperIndex } = createPosition
     const payer = createPosition.owner ?? this.wallet.publicKey
@@ -1323,6 +1588,9 @@ export class Market {
     cache.pool = pool
 
     const checkTicks = async () => {
+      if (!createTicks) {
+        return
+      }
       let accountsToFetch: {
         lowerTick: boolean
         upperTick: boolean
@@ -1423,7 +1691,7 @@ export class Market {
     createPosition: CreatePosition,
     cache: CreatePositionTransactionCache = {}
   ) {
-    const tx = await this.createAssociatedPositionAccountsTx(createPosition, cache)
+    const tx = await this.createAssociatedPositionAccountsTx(createPosition, cache, false)
     const positionInstruction = await this.createPositionIx(createPosition, cache)
 
     return tx.add(positionInstruction)
@@ -1439,6 +1707,73 @@ export class Market {
     await signAndSend(tx, [signer], this.connection)
   }
 
+  // liquidity is derived on-chain from the maximum amounts
+  async createPositionByAmountsIx(
+    {
+      pair,
+      owner,
+      userTokenX,
+      userTokenY,
+      lowerTick,
+      upperTick,
+      maxAmountX,
+      maxAmountY,
+      minLiquidity,
+      knownPrice,
+      slippage
+    }: CreatePositionByAmounts,
+    cache: CreatePositionInstructionCache = {}
+  ) {
+    const slippageLimitLower = calculatePriceAfterSlippage(knownPrice, slippage, false)
+    const slippageLimitUpper = calculatePriceAfterSlippage(knownPrice, slippage, true)
+
+    const upperTickIndex = upperTick !== Infinity ? upperTick : getMaxTick(pair.tickSpacing)
+    const lowerTickIndex = lowerTick !== -Infinity ? lowerTick : getMinTick(pair.tickSpacing)
+
+    const accounts = await this.createPositionAccounts(
+      pair,
+      lowerTickIndex,
+      upperTickIndex,
+      userTokenX,
+      userTokenY,
+      owner,
+      cache
+    )
+
+    return this.program.methods
+      .createPositionByAmounts(
+        lowerTickIndex,
+        upperTickIndex,
+        maxAmountX,
+        maxAmountY,
+        { v: minLiquidity },
+        { v: slippageLimitLower },
+        { v: slippageLimitUpper }
+      )
+      .accounts(accounts)
+      .instruction()
+  }
+
+  async createPositionByAmountsTx(
+    createPosition: CreatePositionByAmounts,
+    cache: CreatePositionTransactionCache = {}
+  ) {
+    const tx = await this.createAssociatedPositionAccountsTx(createPosition, cache, false)
+    const positionInstruction = await this.createPositionByAmountsIx(createPosition, cache)
+
+    return tx.add(positionInstruction)
+  }
+
+  async createPositionByAmounts(
+    createPosition: CreatePositionByAmounts,
+    signer: Keypair,
+    cache: CreatePositionTransactionCache = {}
+  ) {
+    const tx = await this.createPositionByAmountsTx(createPosition, cache)
+
+    await signAndSend(tx, [signer], this.connection)
+  }
+
   async swapAndCreatePosition(
     createPosition: SwapAndCreatePosition,
     signer: Keypair,
@@ -1450,68 +1785,116 @@ export class Market {
     await signAndSend(tx, [signer], this.connection, { skipPreflight: true })
   }
 
-  // async changeLiquidity(changeLiquidity: ChangeLiquidity, signer: Keypair) {
-  //   const ix = await this.changeLiquidityIx(changeLiquidity, signer)
-  //   const tx = new Transaction().add(ix)
-  //   await signAndSend(tx, [signer], this.connection)
-  // }
-
-  // async changeLiquidityIx(
-  //   {
-  //     pair,
-  //     slippage,
-  //     knownPrice,
-  //     index,
-  //     liquidityDelta,
-  //     addLiquidity,
-  //     owner,
-  //     ...accounts
-  //   }: ChangeLiquidity,
-  //   signer?: Keypair
-  // ): Promise<TransactionInstruction> {
-  //   const payerPubkey = signer?.publicKey ?? this.wallet.publicKey
-  //   const ownerPubkey = owner ?? payerPubkey
-  //   const slippageLimitLower = calculatePriceAfterSlippage(knownPrice, slippage, false)
-  //   const slippageLimitUpper = calculatePriceAfterSlippage(knownPrice, slippage, true)
-
-  //   const { address: state } = await this.getStateAddress()
-  //   const { positionAddress: position } = await this.getPositionAddress(payerPubkey, index)
-  //   const [pool] = await pair.getAddressAndBump(this.program.programId)
-
-  //   let lowerTickIndex = accounts.lowerTickIndex
-  //   let upperTickIndex = accounts.upperTickIndex
-  //   if (!lowerTickIndex || !upperTickIndex) {
-  //     const position = await this.getPosition(ownerPubkey, index)
-  //     lowerTickIndex = position.lowerTickIndex
-  //     upperTickIndex = position.upperTickIndex
-  //   }
-  //   const { tickAddress: lowerTick } = await this.getTickAddress(pair, lowerTickIndex)
-  //   const { tickAddress: upperTick } = await this.getTickAddress(pair, upperTickIndex)
-  //   const poolStruct = await this.getPool(pair)
-  //   const tokenXProgram = await getTokenProgramAddress(this.connection, pair.tokenX)
-  //   const tokenYProgram = await getTokenProgramAddress(this.connection, pair.tokenY)
-
-  //   return this.program.methods
-  //     .changeLiquidity(index, liquidityDelta, addLiquidity, slippageLimitLower, slippageLimitUpper)
-  //     .accounts({
-  //       state,
-  //       position,
-  //       pool,
-  //       payer: payerPubkey,
-  //       owner: ownerPubkey,
-  //       lowerTick,
-  //       upperTick,
-  //       tokenX: pair.tokenX,
-  //       tokenY: pair.tokenY,
-  //       reserveX: poolStruct.tokenXReserve,
-  //       reserveY: poolStruct.tokenYReserve,
-  //       programAuthority: this.programAuthority,
-  //       tokenXProgram,
-  //       tokenYProgram,
-  //       ...accounts
-  //     })
-  //     .instruction()
-  // }
+  async increaseLiquidityIx(
+    increaseLiquidity: IncreaseLiquidity,
+    cache: ChangeLiquidityCache = {}
+  ): Promise<TransactionInstruction> {
+    const { maxAmountX, maxAmountY } = increaseLiquidity
+    return this.changeLiquidityIx(increaseLiquidity, true, maxAmountX, maxAmountY, cache)
+  }
+
+  async increaseLiquidityTx(
+    increaseLiquidity: IncreaseLiquidity,
+    cache: ChangeLiquidityCache = {}
+  ) {
+    const ix = await this.increaseLiquidityIx(increaseLiquidity, cache)
+    return new Transaction().add(ix)
+  }
+
+  async increaseLiquidity(
+    increaseLiquidity: IncreaseLiquidity,
+    signer: Keypair,
+    cache: ChangeLiquidityCache = {}
+  ) {
+    const tx = await this.increaseLiquidityTx(increaseLiquidity, cache)
+
+    await signAndSend(tx, [signer], this.connection)
+  }
+
+  async decreaseLiquidityIx(
+    decreaseLiquidity: DecreaseLiquidity,
+    cache: ChangeLiquidityCache = {}
+  ): Promise<TransactionInstruction> {
+    const { minAmountX, minAmountY } = decreaseLiquidity
+    return this.changeLiquidityIx(decreaseLiquidity, false, minAmountX, minAmountY, cache)
+  }
+
+  async decreaseLiquidityTx(
+    decreaseLiquidity: DecreaseLiquidity,
+    cache: ChangeLiquidityCache = {}
+  ) {
+    const ix = await this.decreaseLiquidityIx(decreaseLiquidity, cache)
+    return new Transaction().add(ix)
+  }
+
+  async decreaseLiquidity(
+    decreaseLiquidity: DecreaseLiquidity,
+    signer: Keypair,
+    cache: ChangeLiquidityCache = {}
+  ) {
+    const tx = await this.decreaseLiquidityTx(decreaseLiquidity, cache)
+
+    await signAndSend(tx, [signer], this.connection)
+  }
+
+  private async changeLiquidityIx(
+    changeLiquidity: ChangeLiquidity,
+    addLiquidity: boolean,
+    amountXLimit: BN,
+    amountYLimit: BN,
+    cache: ChangeLiquidityCache
+  ) {
+    const { pair, owner, operator, index, liquidityDelta, knownPrice, slippage } = changeLiquidity
+    const slippageLimitLower = calculatePriceAfterSlippage(knownPrice, slippage, false)
+    const slippageLimitUpper = calculatePriceAfterSlippage(knownPrice, slippage, true)
+
+    const [state, position, tokenXProgram, tokenYProgram] = await Promise.all([
+      cache.pool ?? this.getPool(pair),
+      cache.position ?? this.getPosition(owner, index),
+      cache.tokenXProgram ?? getTokenProgramAddress(this.connection, pair.tokenX),
+      cache.tokenYProgram ?? getTokenProgramAddress(this.connection, pair.tokenY)
+    ])
+
+    const { positionAddress } = this.getPositionAddress(owner, index)
+    const { tickAddress: lowerTickAddress } = this.getTickAddress(pair, position.lowerTickIndex)
+    const { tickAddress: upperTickAddress } = this.getTickAddress(pair, position.upperTickIndex)
+    const poolAddress = pair.getAddress(this.program.programId)
+    const accounts = {
+      state: this.stateAddress.address,
+      position: positionAddress,
+      pool: poolAddress,
+      owner: operator ?? owner,
+      lowerTick: lowerTickAddress,
+      upperTick: upperTickAddress,
+      tokenX: pair.tokenX,
+      tokenY: pair.tokenY,
+      accountX: changeLiquidity.accountX,
+      accountY: changeLiquidity.accountY,
+      reserveX: state.tokenXReserve,
+      reserveY: state.tokenYReserve,
+      programAuthority: this.programAuthority.address,
+      tokenXProgram,
+      tokenYProgram,
+      positionOperator: operator
+        ? this.getPositionOperatorAddress(owner, poolAddress, position.id, operator)
+            .positionOperatorAddress
+        : null
+    }
+    const method = addLiquidity
+      ? this.program.methods.increaseLiquidity
+      : this.program.methods.decreaseLiquidity
+
+    return method(
+      { v: liquidityDelta },
+      { v: slippageLimitLower },
+      { v: slippageLimitUpper },
+      amountXLimit,
+      amountYLimit
+    )
+      .accounts(accounts)
+      .instruction()
+  }
+
   async createPoolAndPositionTx(
     params: CreatePoolAndPosition,
     payer?: { publicKey: PublicKey },
@@ -1706,15 +2089,23 @@ export class Market {
         pool,
         tickmap,
         xToY,
-        (ticks as { tickCrosses: number }).tickCrosses - (referralAccount ? 1 : 0)
+        (ticks as { tickCrosses: number }).tickCrosses - (referralAccount ? 2 : 0)
       )
 
     const owner = swap.owner ?? this.wallet.publicKey
     const poolAddress = pair.getAddress(this.program.programId)
     const priceLimit = calculatePriceAfterSlippage(estimatedPriceAfterSwap, slippage, !xToY)
     const remainingAccounts = tickAddresses
-    if (swap.referralAccount) {
-      remainingAccounts.unshift(swap.referralAccount)
+    if (referralAccount) {
+      const referrer = await this.getReferralReferrerAddress(
+        referralAccount,
+        xToY ? tokenXProgram : tokenYProgram
+      )
+      remainingAccounts.unshift(referralAccount, referrer)
+    }
+    // initialized oracle is required to record the price
+    if (pool.oracleInitialized) {
+      remainingAccounts.push(pool.oracleAddress)
     }
 
     // trunk-ignore(eslint)
@@ -1724,7 +2115,15 @@ export class Market {
       })
 
     const swapIx = await this.program.methods
-      .swap(xToY, amount, byAmountIn, priceLimit)
+      .swap(
+        xToY,
+        amount,
+        byAmountIn,
+        priceLimit,
+        swap.minAmountOut ?? null,
+        swap.maxAmountIn ?? null,
+        swap.partialFill ?? false
+      )
       .accounts({
         state: this.stateAddress.address,
         pool: poolAddress,
@@ -1808,43 +2207,296 @@ export class Market {
     return signAndSend(tx, [signer], this.connection)
   }
 
-  async getReserveBalances(pair: Pair) {
-    const state = await this.getPool(pair)
-    const tokenXProgram = await getTokenProgramAddress(this.connection, pair.tokenX)
-    const tokenYProgram = await getTokenProgramAddress(this.connection, pair.tokenY)
+  async quoteSwapIx(
+    quoteSwap: QuoteSwap,
+    cache: SwapCache = {},
+    ticks: Ticks = { tickCrosses: TICK_CROSSES_PER_IX }
+  ) {
+    return (await this.quoteSwapMethod(quoteSwap, cache, ticks)).instruction()
+  }
 
-    const [x, y] = await Promise.all([
-      getBalance(this.connection, state.tokenXReserve, tokenXProgram),
-      getBalance(this.connection, state.tokenYReserve, tokenYProgram)
-    ])
+  // Simulates the swap without signing, no account is modified
+  async quoteSwap(
+    quoteSwap: QuoteSwap,
+    cache: SwapCache = {},
+    ticks: Ticks = { tickCrosses: TICK_CROSSES_PER_IX }
+  ): Promise<QuoteResult> {
+    const result = await (await this.quoteSwapMethod(quoteSwap, cache, ticks)).view()
 
-    return { x, y }
+    return {
+      ...result,
+      sqrtPrice: result.sqrtPrice.v
+    }
   }
 
-  async claimFeeIx(claimFee: ClaimFee, cache: ClaimFeeCache = {}) {
-    const { pair, userTokenX, userTokenY, index } = claimFee
-    const owner = claimFee.owner ?? this.wallet.publicKey
-
-    const [state, position, tokenXProgram, tokenYProgram] = await Promise.all([
-      cache.pool ?? this.getPool(pair),
-      cache.position ?? this.getPosition(owner, index),
-      cache.tokenXProgram ?? getTokenProgramAddress(this.connection, pair.tokenX),
-      cache.tokenYProgram ?? getTokenProgramAddress(this.connection, pair.tokenY)
+  private async quoteSwapMethod(quoteSwap: QuoteSwap, cache: SwapCache, ticks: Ticks) {
+    const {
+      pair,
+      xToY,
+      referralAccount,
+      amount,
+      byAmountIn,
+      estimatedPriceAfterSwap,
+      slippage,
+      partialFill
+    } = quoteSwap
+
+    const pool = cache.pool ?? (await this.getPool(pair))
+    const tickmap = cache.tickmap ?? (await this.getTickmap(pair, pool))
+
+    const tickAddresses =
+      ticks.tickAddresses ??
+      this.findTickAddressesForSwap(
+        pair,
+        pool,
+        tickmap,
+        xToY,
+        (ticks as { tickCrosses: number }).tickCrosses - (referralAccount ? 2 : 0)
+      )
+    const remainingAccounts = tickAddresses
+    if (referralAccount) {
+      const tokenProgram =
+        (xToY ? cache.tokenXProgram : cache.tokenYProgram) ??
+        (await getTokenProgramAddress(this.connection, xToY ? pair.tokenX : pair.tokenY))
+      const referrer = await this.getReferralReferrerAddress(referralAccount, tokenProgram)
+      remainingAccounts.unshift(referralAccount, referrer)
+    }
+    const priceLimit = calculatePriceAfterSlippage(estimatedPriceAfterSwap, slippage, !xToY)
+
+    return this.program.methods
+      .quoteSwap(xToY, amount, byAmountIn, priceLimit, partialFill ?? false)
+      .accounts({
+        pool: pair.getAddress(this.program.programId),
+        tickmap: pool.tickmap
+      })
+      .remainingAccounts(
+        remainingAccounts.map(pubkey => ({ pubkey, isWritable: false, isSigner: false }))
+      )
+  }
+
+  async swapRouteIx(swapRoute: SwapRoute, tickCrossesPerHop: number = TICK_CROSSES_PER_IX / 4) {
+    const { route, tokenIn, accountIn, accountOut, amount, byAmountIn, amountLimit } = swapRoute
+    const owner = swapRoute.owner ?? this.wallet.publicKey
+
+    const hopAccounts: PublicKey[] = []
+    const extraAccounts: PublicKey[] = []
+    let hopTokenIn = tokenIn
+
+    for (const pair of route) {
+      const pool = await this.getPool(pair)
+      const tickmap = await this.getTickmap(pair, pool)
+      const xToY = hopTokenIn.equals(pool.tokenX)
+      const tokenOut = xToY ? pool.tokenY : pool.tokenX
+      const tokenOutProgram = await getTokenProgramAddress(this.connection, tokenOut)
+
+      hopAccounts.push(
+        pair.getAddress(this.program.programId),
+        pool.tickmap,
+        xToY ? pool.tokenXReserve : pool.tokenYReserve,
+        xToY ? pool.tokenYReserve : pool.tokenXReserve,
+        tokenOut,
+        tokenOutProgram
+      )
+      extraAccounts.push(
+        ...this.findTickAddressesForSwap(pair, pool, tickmap, xToY, tickCrossesPerHop)
+      )
+      if (pool.oracleInitialized) {
+        extraAccounts.push(pool.oracleAddress)
+      }
+      hopTokenIn = tokenOut
+    }
+
+    const tokenInProgram = await getTokenProgramAddress(this.connection, tokenIn)
+
+    // trunk-ignore(eslint)
+    const ra: Array<{ pubkey: PublicKey; isWritable: boolean; isSigner: boolean }> = [
+      ...hopAccounts,
+      ...extraAccounts
+    ].map(pubkey => {
+      return { pubkey, isWritable: true, isSigner: false }
+    })
+
+    return this.program.methods
+      .swapRoute(route.length, amount, byAmountIn, amountLimit)
+      .accounts({
+        state: this.stateAddress.address,
+        tokenIn,
+        accountIn,
+        accountOut,
+        owner,
+        programAuthority: this.programAuthority.address,
+        tokenInProgram
+      })
+      .remainingAccounts(ra)
+      .instruction()
+  }
+
+  async swapRouteTx(swapRoute: SwapRoute) {
+    const setCuIx = computeUnitsInstruction(1_400_000, swapRoute.owner ?? this.wallet.publicKey)
+    const swapRouteIx = await this.swapRouteIx(swapRoute)
+    return new Transaction().add(setCuIx).add(swapRouteIx)
+  }
+
+  async swapRoute(swapRoute: SwapRoute, signer: Keypair) {
+    const tx = await this.swapRouteTx(swapRoute)
+
+    return signAndSend(tx, [signer], this.connection)
+  }
+
+  async flashSwapIx(
+    flashSwap: FlashSwap,
+    cache: SwapCache = {},
+    ticks: Ticks = {
+      tickCrosses: TICK_CROSSES_PER_IX
+    }
+  ) {
+    const { pair, xToY, amount, byAmountIn, sqrtPriceLimit, accountX, accountY } = flashSwap
+    const callbackAccounts = flashSwap.callbackAccounts ?? []
+
+    const [pool, tokenXProgram, tokenYProgram] = await Promise.all([
+      cache.pool ?? this.getPool(pair),
+      cache.tokenXProgram ?? getTokenProgramAddress(this.connection, pair.tokenX),
+      cache.tokenYProgram ?? getTokenProgramAddress(this.connection, pair.tokenY)
+    ])
+    const tickmap = cache.tickmap ?? (await this.getTickmap(pair, pool))
+
+    const tickAddresses =
+      ticks.tickAddresses ??
+      this.findTickAddressesForSwap(
+        pair,
+        pool,
+        tickmap,
+        xToY,
+        (ticks as { tickCrosses: number }).tickCrosses - callbackAccounts.length
+      )
+    if (pool.oracleInitialized) {
+      tickAddresses.push(pool.oracleAddress)
+    }
+
+    // remaining accounts are forwarded to the callback program, its own accounts go first
+    const ra: AccountMeta[] = callbackAccounts.concat(
+      tickAddresses.map(pubkey => {
+        return { pubkey, isWritable: true, isSigner: false }
+      })
+    )
+
+    return await this.program.methods
+      .flashSwap(xToY, amount, byAmountIn, sqrtPriceLimit, flashSwap.data ?? Buffer.alloc(0))
+      .accounts({
+        state: this.stateAddress.address,
+        pool: pair.getAddress(this.program.programId),
+        tickmap: pool.tickmap,
+        tokenX: pair.tokenX,
+        tokenY: pair.tokenY,
+        accountX,
+        accountY,
+        reserveX: pool.tokenXReserve,
+        reserveY: pool.tokenYReserve,
+        owner: flashSwap.owner ?? this.wallet.publicKey,
+        programAuthority: this.programAuthority.address,
+        callbackProgram: flashSwap.callbackProgram,
+        tokenXProgram,
+        tokenYProgram
+      })
+      .remainingAccounts(ra)
+      .instruction()
+  }
+
+  async flashSwapTx(
+    flashSwap: FlashSwap,
+    cache: SwapCache = {},
+    ticks: Ticks = { tickCrosses: TICK_CROSSES_PER_IX }
+  ) {
+    const setCuIx = computeUnitsInstruction(1_400_000, flashSwap.owner ?? this.wallet.publicKey)
+    const flashSwapIx = await this.flashSwapIx(flashSwap, cache, ticks)
+    return new Transaction().add(setCuIx).add(flashSwapIx)
+  }
+
+  async flashSwap(flashSwap: FlashSwap, signer: Keypair) {
+    const tx = await this.flashSwapTx(flashSwap)
+
+    return signAndSend(tx, [signer], this.connection)
+  }
+
+  async flashLoanIx(flashLoan: FlashLoan) {
+    const { pair, amountX, amountY, accountX, accountY } = flashLoan
+
+    const [pool, tokenXProgram, tokenYProgram] = await Promise.all([
+      this.getPool(pair),
+      getTokenProgramAddress(this.connection, pair.tokenX),
+      getTokenProgramAddress(this.connection, pair.tokenY)
+    ])
+
+    return await this.program.methods
+      .flashLoan(amountX, amountY, flashLoan.data ?? Buffer.alloc(0))
+      .accounts({
+        state: this.stateAddress.address,
+        pool: pair.getAddress(this.program.programId),
+        tokenX: pair.tokenX,
+        tokenY: pair.tokenY,
+        accountX,
+        accountY,
+        reserveX: pool.tokenXReserve,
+        reserveY: pool.tokenYReserve,
+        owner: flashLoan.owner ?? this.wallet.publicKey,
+        programAuthority: this.programAuthority.address,
+        callbackProgram: flashLoan.callbackProgram,
+        tokenXProgram,
+        tokenYProgram
+      })
+      .remainingAccounts(flashLoan.callbackAccounts ?? [])
+      .instruction()
+  }
+
+  async flashLoanTx(flashLoan: FlashLoan) {
+    const flashLoanIx = await this.flashLoanIx(flashLoan)
+    return new Transaction().add(flashLoanIx)
+  }
+
+  async flashLoan(flashLoan: FlashLoan, signer: Keypair) {
+    const tx = await this.flashLoanTx(flashLoan)
+
+    return signAndSend(tx, [signer], this.connection)
+  }
+
+  async getReserveBalances(pair: Pair) {
+    const state = await this.getPool(pair)
+    const tokenXProgram = await getTokenProgramAddress(this.connection, pair.tokenX)
+    const tokenYProgram = await getTokenProgramAddress(this.connection, pair.tokenY)
+
+    const [x, y] = await Promise.all([
+      getBalance(this.connection, state.tokenXReserve, tokenXProgram),
+      getBalance(this.connection, state.tokenYReserve, tokenYProgram)
+    ])
+
+    return { x, y }
+  }
+
+  async claimFeeIx(claimFee: ClaimFee, cache: ClaimFeeCache = {}) {
+    const { pair, userTokenX, userTokenY, index, operator } = claimFee
+    const owner = claimFee.owner ?? this.wallet.publicKey
+
+    const [state, position, tokenXProgram, tokenYProgram] = await Promise.all([
+      cache.pool ?? this.getPool(pair),
+      cache.position ?? this.getPosition(owner, index),
+      cache.tokenXProgram ?? getTokenProgramAddress(this.connection, pair.tokenX),
+      cache.tokenYProgram ?? getTokenProgramAddress(this.connection, pair.tokenY)
     ])
 
     const { positionAddress } = this.getPositionAddress(owner, index)
     const { tickAddress: lowerTickAddress } = this.getTickAddress(pair, position.lowerTickIndex)
     const { tickAddress: upperTickAddress } = this.getTickAddress(pair, position.upperTickIndex)
+    const poolAddress = pair.getAddress(this.program.programId)
 
     return this.program.methods
       .claimFee(index, position.lowerTickIndex, position.upperTickIndex)
       .accounts({
         state: this.stateAddress.address,
-        pool: pair.getAddress(this.program.programId),
+        pool: poolAddress,
         position: positionAddress,
         lowerTick: lowerTickAddress,
         upperTick: upperTickAddress,
-        owner,
+        owner: operator ?? owner,
         tokenX: pair.tokenX,
         tokenY: pair.tokenY,
         accountX: userTokenX,
@@ -1853,7 +2505,11 @@ export class Market {
         reserveY: state.tokenYReserve,
         programAuthority: this.programAuthority.address,
         tokenXProgram,
-        tokenYProgram
+        tokenYProgram,
+        positionOperator: operator
+          ? this.getPositionOperatorAddress(owner, poolAddress, position.id, operator)
+              .positionOperatorAddress
+          : null
       })
       .instruction()
   }
@@ -1869,211 +2525,1491 @@ export class Market {
     await signAndSend(tx, [signer], this.connection)
   }
 
-  async claimAllFees(params: ClaimAllFee, signer: Keypair) {
-    const txs = await this.claimAllFeesTxs(params)
-    for (const { tx, additionalSigner } of txs) {
-      if (additionalSigner) {
-        await signAndSend(tx, [signer, additionalSigner], this.connection)
-      } else {
-        await signAndSend(tx, [signer], this.connection)
-      }
-    }
-  }
-
-  async claimAllFeesTxs({
-    owner,
-    positions
-  }: ClaimAllFee): Promise<{ tx: Transaction; additionalSigner?: Keypair }[]> {
-    owner ??= this.wallet.publicKey
+  async claimFeeBatchIx(claimFeeBatch: ClaimFeeBatch, cache: ClaimFeeBatchCache = {}) {
+    const { pair, positions, userTokenX, userTokenY } = claimFeeBatch
+    const owner = claimFeeBatch.owner ?? this.wallet.publicKey
 
-    const pools: Record<string, PoolStructure> = {}
-    const ixs: TransactionInstruction[] = []
-    const nativeIxs: TransactionInstruction[] = []
-    const splPositions: ClaimAllFeePosition[] = []
-    const nativePositions: ClaimAllFeePosition[] = []
-    const atas: {
-      keypair: Keypair
-      createIx: TransactionInstruction
-      initIx: TransactionInstruction
-      unwrapIx: TransactionInstruction
-    }[] = []
+    const [state, tokenXProgram, tokenYProgram, fetchedPositions] = await Promise.all([
+      cache.pool ?? this.getPool(pair),
+      cache.tokenXProgram ?? getTokenProgramAddress(this.connection, pair.tokenX),
+      cache.tokenYProgram ?? getTokenProgramAddress(this.connection, pair.tokenY),
+      Promise.all(
+        positions.map(async address =>
+          parsePosition(await this.program.account.position.fetch(address))
+        )
+      )
+    ])
 
-    const tokenPubkeys: PublicKey[] = Array.from(
-      new Set(positions.map(p => [p.pair.tokenX, p.pair.tokenY]).flat())
+    // (position, lower tick, upper tick) triple for every position
+    const remainingAccounts = fetchedPositions.flatMap((position, i) =>
+      [
+        positions[i],
+        this.getTickAddressByPool(position.pool, position.lowerTickIndex).tickAddress,
+        this.getTickAddressByPool(position.pool, position.upperTickIndex).tickAddress
+      ].map(pubkey => ({ pubkey, isSigner: false, isWritable: true }))
     )
-    const pairs: Pair[] = Array.from(new Set(positions.map(p => p.pair)))
 
-    const promisedTokenPorgrams = await this.connection.getMultipleAccountsInfo(tokenPubkeys)
+    return this.program.methods
+      .claimFeeBatch()
+      .accounts({
+        state: this.stateAddress.address,
+        pool: pair.getAddress(this.program.programId),
+        owner,
+        tokenX: pair.tokenX,
+        tokenY: pair.tokenY,
+        accountX: userTokenX,
+        accountY: userTokenY,
+        reserveX: state.tokenXReserve,
+        reserveY: state.tokenYReserve,
+        programAuthority: this.programAuthority.address,
+        tokenXProgram,
+        tokenYProgram
+      })
+      .remainingAccounts(remainingAccounts)
+      .instruction()
+  }
 
-    const tokenPrograms: Record<string, PublicKey> = promisedTokenPorgrams.reduce(
-      (acc: Record<string, PublicKey>, cur: any, idx: number) => {
-        acc[tokenPubkeys[idx].toBase58()] = cur?.owner ?? TOKEN_2022_PROGRAM_ID
-        return acc
-      },
-      {}
-    )
+  async claimFeeBatchTx(claimFeeBatch: ClaimFeeBatch, cache: ClaimFeeBatchCache = {}) {
+    const ix = await this.claimFeeBatchIx(claimFeeBatch, cache)
+    return new Transaction().add(ix)
+  }
 
-    const poolStructures: [PublicKey, PoolStructure][] = await Promise.all([
-      ...pairs.map(pair => {
-        return new Promise(async res => {
-          res([pair.getAddress(this.program.programId), await this.getPool(pair)])
-        }) as Promise<[PublicKey, PoolStructure]>
-      })
-    ])
+  async claimFeeBatch(
+    claimFeeBatch: ClaimFeeBatch,
+    signer: Keypair,
+    cache: ClaimFeeBatchCache = {}
+  ) {
+    const tx = await this.claimFeeBatchTx(claimFeeBatch, cache)
 
-    poolStructures.forEach((p: [PublicKey, PoolStructure]) => (pools[p[0].toBase58()] = p[1]))
+    await signAndSend(tx, [signer], this.connection)
+  }
 
-    for (const position of positions) {
-      if (position.pair.tokenX.equals(NATIVE_MINT) || position.pair.tokenY.equals(NATIVE_MINT)) {
-        nativePositions.push(position)
-      } else {
-        splPositions.push(position)
-      }
-    }
+  async compoundFeesIx(compoundFees: CompoundFees, cache: CompoundFeesCache = {}) {
+    const { pair, index, operator } = compoundFees
+    const owner = compoundFees.owner ?? this.wallet.publicKey
 
-    if (nativePositions.length != 0) {
-      const requiredAtas = Math.ceil(nativePositions.length / MAX_NATIVE_POSITION_FOR_CLAIM_ALL)
+    const position = cache.position ?? (await this.getPosition(owner, index))
+    const { positionAddress } = this.getPositionAddress(owner, index)
+    const { tickAddress: lowerTickAddress } = this.getTickAddress(pair, position.lowerTickIndex)
+    const { tickAddress: upperTickAddress } = this.getTickAddress(pair, position.upperTickIndex)
+    const poolAddress = pair.getAddress(this.program.programId)
 
-      for (let i = 0; i < requiredAtas; i++) {
-        const nativeAta = Keypair.generate()
-        const { createIx, initIx, unwrapIx } = createNativeAtaInstructions(
-          nativeAta.publicKey,
-          owner,
-          this.network
-        )
-        atas.push({
-          keypair: nativeAta,
-          createIx,
-          initIx,
-          unwrapIx
-        })
+    return this.program.methods
+      .compoundFees()
+      .accounts({
+        state: this.stateAddress.address,
+        pool: poolAddress,
+        position: positionAddress,
+        lowerTick: lowerTickAddress,
+        upperTick: upperTickAddress,
+        authority: operator ?? owner,
+        positionOperator: operator
+          ? this.getPositionOperatorAddress(owner, poolAddress, position.id, operator)
+              .positionOperatorAddress
+          : null
+      })
+      .instruction()
+  }
+
+  async compoundFeesTx(compoundFees: CompoundFees, cache: CompoundFeesCache = {}) {
+    const ix = await this.compoundFeesIx(compoundFees, cache)
+    return new Transaction().add(ix)
+  }
+
+  async compoundFees(compoundFees: CompoundFees, signer: Keypair, cache: CompoundFeesCache = {}) {
+    const tx = await this.compoundFeesTx(compoundFees, cache)
+
+    return signAndSend(tx, [signer], this.connection)
+  }
+
+  async approvePositionOperatorIx(approvePositionOperator: ApprovePositionOperator) {
+    const { position, operator, permissions } = approvePositionOperator
+    const owner = approvePositionOperator.owner ?? this.wallet.publicKey
+
+    const { pool, id } = parsePosition(await this.program.account.position.fetch(position))
+    const { positionOperatorAddress } = this.getPositionOperatorAddress(owner, pool, id, operator)
+
+    return this.program.methods
+      .approvePositionOperator(permissions)
+      .accounts({
+        positionOperator: positionOperatorAddress,
+        position,
+        owner,
+        operator,
+        rent: SYSVAR_RENT_PUBKEY,
+        systemProgram: SystemProgram.programId
+      })
+      .instruction()
+  }
+
+  async approvePositionOperatorTx(approvePositionOperator: ApprovePositionOperator) {
+    const ix = await this.approvePositionOperatorIx(approvePositionOperator)
+    return new Transaction().add(ix)
+  }
+
+  async approvePositionOperator(
+    approvePositionOperator: ApprovePositionOperator,
+    signer: Keypair
+  ) {
+    const tx = await this.approvePositionOperatorTx(approvePositionOperator)
+
+    await signAndSend(tx, [signer], this.connection)
+  }
+
+  async revokePositionOperatorIx(revokePositionOperator: RevokePositionOperator) {
+    const { pool, id, operator } = revokePositionOperator
+    const owner = revokePositionOperator.owner ?? this.wallet.publicKey
+
+    const { positionOperatorAddress } = this.getPositionOperatorAddress(owner, pool, id, operator)
+
+    return this.program.methods
+      .revokePositionOperator()
+      .accounts({
+        positionOperator: positionOperatorAddress,
+        owner
+      })
+      .instruction()
+  }
+
+  async revokePositionOperatorTx(revokePositionOperator: RevokePositionOperator) {
+    const ix = await this.revokePositionOperatorIx(revokePositionOperator)
+    return new Transaction().add(ix)
+  }
+
+  async revokePositionOperator(revokePositionOperator: RevokePositionOperator, signer: Keypair) {
+    const tx = await this.revokePositionOperatorTx(revokePositionOperator)
+
+    await signAndSend(tx, [signer], this.connection)
+  }
+
+  async createRangeOrderIx(createRangeOrder: CreateRangeOrder) {
+    const { pair, lowerTick, liquidityDelta, userTokenX, userTokenY } = createRangeOrder
+    const owner = createRangeOrder.owner ?? this.wallet.publicKey
+    const payer = createRangeOrder.payer ?? owner
+
+    const [pool, tokenXProgram, tokenYProgram] = await Promise.all([
+      this.getPool(pair),
+      getTokenProgramAddress(this.connection, pair.tokenX),
+      getTokenProgramAddress(this.connection, pair.tokenY)
+    ])
+    const { rangeOrderAddress } = this.getRangeOrderAddress(pair, owner, lowerTick)
+    const { tickAddress: lowerTickAddress } = this.getTickAddress(pair, lowerTick)
+    const { tickAddress: upperTickAddress } = this.getTickAddress(
+      pair,
+      lowerTick + pool.tickSpacing
+    )
+
+    return this.program.methods
+      .createRangeOrder(lowerTick, { v: liquidityDelta })
+      .accounts({
+        state: this.stateAddress.address,
+        rangeOrder: rangeOrderAddress,
+        pool: pair.getAddress(this.program.programId),
+        payer,
+        owner,
+        lowerTick: lowerTickAddress,
+        upperTick: upperTickAddress,
+        tickmap: pool.tickmap,
+        tokenX: pair.tokenX,
+        tokenY: pair.tokenY,
+        accountX: userTokenX,
+        accountY: userTokenY,
+        reserveX: pool.tokenXReserve,
+        reserveY: pool.tokenYReserve,
+        programAuthority: this.programAuthority.address,
+        tokenXProgram,
+        tokenYProgram,
+        rent: SYSVAR_RENT_PUBKEY,
+        systemProgram: SystemProgram.programId
+      })
+      .instruction()
+  }
+
+  async createRangeOrderTx(createRangeOrder: CreateRangeOrder) {
+    const { pair, lowerTick } = createRangeOrder
+    const payer = createRangeOrder.payer ?? createRangeOrder.owner ?? this.wallet.publicKey
+    const pool = await this.getPool(pair)
+    const tx = new Transaction()
+
+    // order ticks have to exist before the order is placed
+    for (const index of [lowerTick, lowerTick + pool.tickSpacing]) {
+      const { tickAddress } = this.getTickAddress(pair, index)
+      if ((await this.connection.getAccountInfo(tickAddress)) === null) {
+        tx.add(await this.createTickIx({ pair, index, payer }, { pool }))
       }
+    }
 
-      for (const [n, { index, pair }] of nativePositions.entries()) {
-        const idx = Math.floor(n / MAX_NATIVE_POSITION_FOR_CLAIM_ALL)
+    return tx.add(await this.createRangeOrderIx(createRangeOrder))
+  }
 
-        const poolPubkey = pair.getAddress(this.program.programId).toBase58()
+  async createRangeOrder(createRangeOrder: CreateRangeOrder, signer: Keypair) {
+    const tx = await this.createRangeOrderTx(createRangeOrder)
 
-        const userTokenX = pair.tokenX.equals(NATIVE_MINT)
-          ? atas[idx].keypair.publicKey
-          : getAssociatedTokenAddressSync(
-              pair.tokenX,
-              owner,
-              false,
-              tokenPrograms[pair.tokenX.toBase58()]
-            )
-        const userTokenY = pair.tokenY.equals(NATIVE_MINT)
-          ? atas[idx].keypair.publicKey
-          : getAssociatedTokenAddressSync(
-              pair.tokenY,
-              owner,
-              false,
-              tokenPrograms[pair.tokenY.toBase58()]
-            )
+    await signAndSend(tx, [signer], this.connection)
+  }
 
-        const claimIx = await this.claimFeeIx(
-          {
-            index,
-            pair,
-            userTokenX,
-            userTokenY,
-            owner
-          },
-          {
-            pool: pools[poolPubkey],
-            tokenXProgram: tokenPrograms[pair.tokenX.toBase58()],
-            tokenYProgram: tokenPrograms[pair.tokenY.toBase58()]
-          }
-        )
+  async settleRangeOrderIx(settleRangeOrder: SettleRangeOrder) {
+    const { pair, owner, lowerTick } = settleRangeOrder
+    const settler = settleRangeOrder.settler ?? this.wallet.publicKey
+
+    const pool = await this.getPool(pair)
+    const { rangeOrderAddress } = this.getRangeOrderAddress(pair, owner, lowerTick)
+    const { tickAddress: lowerTickAddress } = this.getTickAddress(pair, lowerTick)
+    const { tickAddress: upperTickAddress } = this.getTickAddress(
+      pair,
+      lowerTick + pool.tickSpacing
+    )
+
+    return this.program.methods
+      .settleRangeOrder()
+      .accounts({
+        state: this.stateAddress.address,
+        rangeOrder: rangeOrderAddress,
+        pool: pair.getAddress(this.program.programId),
+        lowerTick: lowerTickAddress,
+        upperTick: upperTickAddress,
+        tickmap: pool.tickmap,
+        settler,
+        owner
+      })
+      .instruction()
+  }
+
+  async settleRangeOrderTx(settleRangeOrder: SettleRangeOrder) {
+    const ix = await this.settleRangeOrderIx(settleRangeOrder)
+    return new Transaction().add(ix)
+  }
+
+  async settleRangeOrder(settleRangeOrder: SettleRangeOrder, signer: Keypair) {
+    const tx = await this.settleRangeOrderTx(settleRangeOrder)
+
+    await signAndSend(tx, [signer], this.connection)
+  }
+
+  async claimRangeOrderIx(claimRangeOrder: ClaimRangeOrder) {
+    const { pair, lowerTick, userTokenX, userTokenY } = claimRangeOrder
+    const owner = claimRangeOrder.owner ?? this.wallet.publicKey
+
+    const [pool, tokenXProgram, tokenYProgram] = await Promise.all([
+      this.getPool(pair),
+      getTokenProgramAddress(this.connection, pair.tokenX),
+      getTokenProgramAddress(this.connection, pair.tokenY)
+    ])
+    const { rangeOrderAddress } = this.getRangeOrderAddress(pair, owner, lowerTick)
+
+    return this.program.methods
+      .claimRangeOrder()
+      .accounts({
+        state: this.stateAddress.address,
+        rangeOrder: rangeOrderAddress,
+        pool: pair.getAddress(this.program.programId),
+        owner,
+        tokenX: pair.tokenX,
+        tokenY: pair.tokenY,
+        accountX: userTokenX,
+        accountY: userTokenY,
+        reserveX: pool.tokenXReserve,
+        reserveY: pool.tokenYReserve,
+        programAuthority: this.programAuthority.address,
+        tokenXProgram,
+        tokenYProgram
+      })
+      .instruction()
+  }
+
+  async claimRangeOrderTx(claimRangeOrder: ClaimRangeOrder) {
+    const ix = await this.claimRangeOrderIx(claimRangeOrder)
+    return new Transaction().add(ix)
+  }
+
+  async claimRangeOrder(claimRangeOrder: ClaimRangeOrder, signer: Keypair) {
+    const tx = await this.claimRangeOrderTx(claimRangeOrder)
+
+    await signAndSend(tx, [signer], this.connection)
+  }
+
+  async tokenizePositionIx(tokenizePosition: TokenizePosition) {
+    const { owner, index } = tokenizePosition
+    const payer = tokenizePosition.payer ?? owner
+
+    const [positionList, position] = await Promise.all([
+      this.getPositionList(owner),
+      this.getPosition(owner, index)
+    ])
+    const { positionMintAddress } = this.getPositionMintAddress(position.pool, position.id)
+    const { tokenizedPositionAddress } = this.getTokenizedPositionAddress(positionMintAddress)
+
+    return this.program.methods
+      .tokenizePosition(index)
+      .accounts({
+        state: this.stateAddress.address,
+        positionList: this.getPositionListAddress(owner).positionListAddress,
+        removedPosition: this.getPositionAddress(owner, index).positionAddress,
+        lastPosition: this.getPositionAddress(owner, positionList.head - 1).positionAddress,
+        tokenizedPosition: tokenizedPositionAddress,
+        positionMint: positionMintAddress,
+        ownerTokenAccount: getAssociatedTokenAddressSync(
+          positionMintAddress,
+          owner,
+          false,
+          TOKEN_2022_PROGRAM_ID
+        ),
+        payer,
+        owner,
+        programAuthority: this.programAuthority.address,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        rent: SYSVAR_RENT_PUBKEY,
+        systemProgram: SystemProgram.programId
+      })
+      .instruction()
+  }
+
+  async tokenizePositionTx(tokenizePosition: TokenizePosition) {
+    const setCuIx = computeUnitsInstruction(
+      1_400_000,
+      tokenizePosition.payer ?? tokenizePosition.owner
+    )
+    const ix = await this.tokenizePositionIx(tokenizePosition)
+    return new Transaction().add(setCuIx).add(ix)
+  }
+
+  async tokenizePosition(tokenizePosition: TokenizePosition, signer: Keypair) {
+    const tx = await this.tokenizePositionTx(tokenizePosition)
+
+    await signAndSend(tx, [signer], this.connection)
+  }
+
+  async claimTokenizedFeeIx(claimTokenizedFee: ClaimTokenizedFee) {
+    const { pair, positionMint, userTokenX, userTokenY } = claimTokenizedFee
+    const owner = claimTokenizedFee.owner ?? this.wallet.publicKey
+
+    const [pool, position, tokenXProgram, tokenYProgram] = await Promise.all([
+      this.getPool(pair),
+      this.getTokenizedPosition(positionMint),
+      getTokenProgramAddress(this.connection, pair.tokenX),
+      getTokenProgramAddress(this.connection, pair.tokenY)
+    ])
+    const { tickAddress: lowerTickAddress } = this.getTickAddress(pair, position.lowerTickIndex)
+    const { tickAddress: upperTickAddress } = this.getTickAddress(pair, position.upperTickIndex)
+
+    return this.program.methods
+      .claimTokenizedFee(position.lowerTickIndex, position.upperTickIndex)
+      .accounts({
+        state: this.stateAddress.address,
+        pool: pair.getAddress(this.program.programId),
+        position: this.getTokenizedPositionAddress(positionMint).tokenizedPositionAddress,
+        positionMint,
+        ownerTokenAccount: getAssociatedTokenAddressSync(
+          positionMint,
+          owner,
+          false,
+          TOKEN_2022_PROGRAM_ID
+        ),
+        lowerTick: lowerTickAddress,
+        upperTick: upperTickAddress,
+        owner,
+        tokenX: pair.tokenX,
+        tokenY: pair.tokenY,
+        accountX: userTokenX,
+        accountY: userTokenY,
+        reserveX: pool.tokenXReserve,
+        reserveY: pool.tokenYReserve,
+        programAuthority: this.programAuthority.address,
+        tokenXProgram,
+        tokenYProgram,
+        positionTokenProgram: TOKEN_2022_PROGRAM_ID
+      })
+      .instruction()
+  }
+
+  async claimTokenizedFeeTx(claimTokenizedFee: ClaimTokenizedFee) {
+    const ix = await this.claimTokenizedFeeIx(claimTokenizedFee)
+    return new Transaction().add(ix)
+  }
+
+  async claimTokenizedFee(claimTokenizedFee: ClaimTokenizedFee, signer: Keypair) {
+    const tx = await this.claimTokenizedFeeTx(claimTokenizedFee)
+
+    await signAndSend(tx, [signer], this.connection)
+  }
+
+  // returns tokenized position to the position list of the holder, burning its token
+  async redeemPositionIx(redeemPosition: RedeemPosition) {
+    const { positionMint } = redeemPosition
+    const holder = redeemPosition.holder ?? this.wallet.publicKey
+
+    const positionList = await this.getPositionList(holder)
+
+    return this.program.methods
+      .redeemPosition()
+      .accounts({
+        state: this.stateAddress.address,
+        positionList: this.getPositionListAddress(holder).positionListAddress,
+        position: this.getPositionAddress(holder, positionList.head).positionAddress,
+        tokenizedPosition: this.getTokenizedPositionAddress(positionMint).tokenizedPositionAddress,
+        positionMint,
+        holderTokenAccount: getAssociatedTokenAddressSync(
+          positionMint,
+          holder,
+          false,
+          TOKEN_2022_PROGRAM_ID
+        ),
+        holder,
+        programAuthority: this.programAuthority.address,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        rent: SYSVAR_RENT_PUBKEY,
+        systemProgram: SystemProgram.programId
+      })
+      .instruction()
+  }
+
+  async redeemPositionTx(redeemPosition: RedeemPosition) {
+    const ix = await this.redeemPositionIx(redeemPosition)
+    return new Transaction().add(ix)
+  }
+
+  async redeemPosition(redeemPosition: RedeemPosition, signer: Keypair) {
+    const tx = await this.redeemPositionTx(redeemPosition)
+
+    await signAndSend(tx, [signer], this.connection)
+  }
+
+  async createStablePositionListIx(owner: PublicKey, signer?: PublicKey) {
+    signer = signer ?? owner ?? this.wallet.publicKey
+    const { stablePositionListAddress } = this.getStablePositionListAddress(owner)
+
+    return this.program.methods
+      .createStablePositionList()
+      .accounts({
+        positionList: stablePositionListAddress,
+        owner,
+        signer,
+        rent: SYSVAR_RENT_PUBKEY,
+        systemProgram: SystemProgram.programId
+      })
+      .instruction()
+  }
+
+  async createStablePositionListTx(owner: PublicKey, signer?: PublicKey) {
+    const ix = await this.createStablePositionListIx(owner, signer)
+    return new Transaction().add(ix)
+  }
+
+  async createStablePositionList(owner: PublicKey, signer: Keypair) {
+    const tx = await this.createStablePositionListTx(owner, signer.publicKey)
+
+    await signAndSend(tx, [signer], this.connection)
+  }
+
+  // missing ticks are initialized by the program, so only the list may need to be created
+  async createStablePositionIx(createPosition: CreatePosition) {
+    const { pair, userTokenX, userTokenY, liquidityDelta, knownPrice, slippage } = createPosition
+    const owner = createPosition.owner ?? this.wallet.publicKey
+    const slippageLimitLower = calculatePriceAfterSlippage(knownPrice, slippage, false)
+    const slippageLimitUpper = calculatePriceAfterSlippage(knownPrice, slippage, true)
+    const upperTick =
+      createPosition.upperTick !== Infinity
+        ? createPosition.upperTick
+        : getMaxTick(pair.tickSpacing)
+    const lowerTick =
+      createPosition.lowerTick !== -Infinity
+        ? createPosition.lowerTick
+        : getMinTick(pair.tickSpacing)
+
+    const poolAddress = pair.getAddress(this.program.programId)
+    const [pool, head, tokenXProgram, tokenYProgram] = await Promise.all([
+      this.getPool(pair),
+      this.getStablePositionList(owner)
+        .then(list => list.head)
+        .catch(() => 0),
+      getTokenProgramAddress(this.connection, pair.tokenX),
+      getTokenProgramAddress(this.connection, pair.tokenY)
+    ])
+
+    return this.program.methods
+      .createStablePosition(
+        lowerTick,
+        upperTick,
+        { v: liquidityDelta },
+        { v: slippageLimitLower },
+        { v: slippageLimitUpper }
+      )
+      .accounts({
+        state: this.stateAddress.address,
+        position: this.getStablePositionAddress(poolAddress, pool.positionIterator)
+          .stablePositionAddress,
+        positionList: this.getStablePositionListAddress(owner).stablePositionListAddress,
+        positionEntry: this.getPositionEntryAddress(owner, head).positionEntryAddress,
+        pool: poolAddress,
+        payer: owner,
+        owner,
+        lowerTick: this.getTickAddress(pair, lowerTick).tickAddress,
+        upperTick: this.getTickAddress(pair, upperTick).tickAddress,
+        tickmap: pool.tickmap,
+        tokenX: pair.tokenX,
+        tokenY: pair.tokenY,
+        accountX: userTokenX,
+        accountY: userTokenY,
+        reserveX: pool.tokenXReserve,
+        reserveY: pool.tokenYReserve,
+        programAuthority: this.programAuthority.address,
+        tokenXProgram,
+        tokenYProgram,
+        rent: SYSVAR_RENT_PUBKEY,
+        systemProgram: SystemProgram.programId,
+        eventOptAcc: this.getEventOptAccount(poolAddress).address
+      })
+      .instruction()
+  }
+
+  async createStablePositionTx(createPosition: CreatePosition) {
+    const owner = createPosition.owner ?? this.wallet.publicKey
+    const tx = new Transaction()
+
+    const { stablePositionListAddress } = this.getStablePositionListAddress(owner)
+    if ((await this.connection.getAccountInfo(stablePositionListAddress)) === null) {
+      tx.add(await this.createStablePositionListIx(owner))
+    }
+
+    return tx.add(await this.createStablePositionIx(createPosition))
+  }
+
+  async createStablePosition(createPosition: CreatePosition, signer: Keypair) {
+    const tx = await this.createStablePositionTx(createPosition)
+
+    await signAndSend(tx, [signer], this.connection)
+  }
+
+  // index of the entry in the stable position list of the owner
+  async removeStablePositionIx(removePosition: RemovePosition) {
+    const { owner, operator, pair, index, userTokenX, userTokenY } = removePosition
+    const payer = removePosition.payer ?? operator ?? owner
+
+    const poolAddress = pair.getAddress(this.program.programId)
+    const [positionList, entry, pool, tokenXProgram, tokenYProgram] = await Promise.all([
+      this.getStablePositionList(owner),
+      this.getPositionEntry(owner, index),
+      this.getPool(pair),
+      getTokenProgramAddress(this.connection, pair.tokenX),
+      getTokenProgramAddress(this.connection, pair.tokenY)
+    ])
+    const position = await this.getStablePosition(entry.pool, entry.id)
+
+    return this.program.methods
+      .removeStablePosition(index, position.lowerTickIndex, position.upperTickIndex)
+      .accounts({
+        state: this.stateAddress.address,
+        removedPosition: this.getStablePositionAddress(entry.pool, entry.id).stablePositionAddress,
+        positionList: this.getStablePositionListAddress(owner).stablePositionListAddress,
+        removedEntry: this.getPositionEntryAddress(owner, index).positionEntryAddress,
+        lastEntry: this.getPositionEntryAddress(owner, positionList.head - 1).positionEntryAddress,
+        pool: poolAddress,
+        tickmap: pool.tickmap,
+        lowerTick: this.getTickAddress(pair, position.lowerTickIndex).tickAddress,
+        upperTick: this.getTickAddress(pair, position.upperTickIndex).tickAddress,
+        payer,
+        owner: operator ?? owner,
+        positionOwner: owner,
+        tokenX: pair.tokenX,
+        tokenY: pair.tokenY,
+        accountX: userTokenX,
+        accountY: userTokenY,
+        reserveX: pool.tokenXReserve,
+        reserveY: pool.tokenYReserve,
+        programAuthority: this.programAuthority.address,
+        tokenXProgram,
+        tokenYProgram,
+        positionOperator: operator
+          ? this.getPositionOperatorAddress(owner, entry.pool, entry.id, operator)
+              .positionOperatorAddress
+          : null,
+        eventOptAcc: this.getEventOptAccount(poolAddress).address
+      })
+      .instruction()
+  }
+
+  async removeStablePositionTx(removePosition: RemovePosition) {
+    const ix = await this.removeStablePositionIx(removePosition)
+    return new Transaction().add(ix)
+  }
+
+  async removeStablePosition(removePosition: RemovePosition, signer: Keypair) {
+    const tx = await this.removeStablePositionTx(removePosition)
+
+    await signAndSend(tx, [signer], this.connection)
+  }
+
+  async transferStablePositionIx(transferPositionOwnership: TransferPositionOwnership) {
+    const { index } = transferPositionOwnership
+    const owner = transferPositionOwnership.owner ?? this.wallet.publicKey
+    const recipient = transferPositionOwnership.recipient ?? this.wallet.publicKey
+
+    const [ownerPositionList, recipientPositionList, entry] = await Promise.all([
+      this.getStablePositionList(owner),
+      this.getStablePositionList(recipient),
+      this.getPositionEntry(owner, index)
+    ])
+
+    return this.program.methods
+      .transferStablePosition(index)
+      .accounts({
+        position: this.getStablePositionAddress(entry.pool, entry.id).stablePositionAddress,
+        ownerList: this.getStablePositionListAddress(owner).stablePositionListAddress,
+        recipientList: this.getStablePositionListAddress(recipient).stablePositionListAddress,
+        removedEntry: this.getPositionEntryAddress(owner, index).positionEntryAddress,
+        lastEntry: this.getPositionEntryAddress(owner, ownerPositionList.head - 1)
+          .positionEntryAddress,
+        newEntry: this.getPositionEntryAddress(recipient, recipientPositionList.head)
+          .positionEntryAddress,
+        payer: owner,
+        owner,
+        recipient,
+        rent: SYSVAR_RENT_PUBKEY,
+        systemProgram: SystemProgram.programId
+      })
+      .instruction()
+  }
+
+  async transferStablePositionTx(transferPositionOwnership: TransferPositionOwnership) {
+    const ix = await this.transferStablePositionIx(transferPositionOwnership)
+    return new Transaction().add(ix)
+  }
+
+  async transferStablePosition(
+    transferPositionOwnership: TransferPositionOwnership,
+    signer: Keypair
+  ) {
+    const tx = await this.transferStablePositionTx(transferPositionOwnership)
+
+    await signAndSend(tx, [signer], this.connection)
+  }
+
+  // moves legacy position to its stable address, legacy list is compacted as in `removePosition`
+  async migratePositionIx(migratePosition: MigratePosition) {
+    const { index } = migratePosition
+    const owner = migratePosition.owner ?? this.wallet.publicKey
+    const payer = migratePosition.payer ?? owner
+
+    const [positionList, stableHead, position] = await Promise.all([
+      this.getPositionList(owner),
+      this.getStablePositionList(owner)
+        .then(list => list.head)
+        .catch(() => 0),
+      this.getPosition(owner, index)
+    ])
+
+    return this.program.methods
+      .migratePosition(index)
+      .accounts({
+        positionList: this.getPositionListAddress(owner).positionListAddress,
+        stablePosition: this.getStablePositionAddress(position.pool, position.id)
+          .stablePositionAddress,
+        stablePositionList: this.getStablePositionListAddress(owner).stablePositionListAddress,
+        positionEntry: this.getPositionEntryAddress(owner, stableHead).positionEntryAddress,
+        removedPosition: this.getPositionAddress(owner, index).positionAddress,
+        lastPosition: this.getPositionAddress(owner, positionList.head - 1).positionAddress,
+        payer,
+        owner,
+        rent: SYSVAR_RENT_PUBKEY,
+        systemProgram: SystemProgram.programId
+      })
+      .instruction()
+  }
+
+  async migratePositionTx(migratePosition: MigratePosition) {
+    const owner = migratePosition.owner ?? this.wallet.publicKey
+    const tx = new Transaction()
+
+    const { stablePositionListAddress } = this.getStablePositionListAddress(owner)
+    if ((await this.connection.getAccountInfo(stablePositionListAddress)) === null) {
+      tx.add(await this.createStablePositionListIx(owner, migratePosition.payer))
+    }
+
+    return tx.add(await this.migratePositionIx(migratePosition))
+  }
+
+  async migratePosition(migratePosition: MigratePosition, signer: Keypair) {
+    const tx = await this.migratePositionTx(migratePosition)
+
+    await signAndSend(tx, [signer], this.connection)
+  }
+
+  async claimAllFees(params: ClaimAllFee, signer: Keypair) {
+    const txs = await this.claimAllFeesTxs(params)
+    for (const { tx, additionalSigner } of txs) {
+      if (additionalSigner) {
+        await signAndSend(tx, [signer, additionalSigner], this.connection)
+      } else {
+        await signAndSend(tx, [signer], this.connection)
+      }
+    }
+  }
+
+  async claimAllFeesTxs({
+    owner,
+    positions
+  }: ClaimAllFee): Promise<{ tx: Transaction; additionalSigner?: Keypair }[]> {
+    owner ??= this.wallet.publicKey
+
+    const pools: Record<string, PoolStructure> = {}
+    const ixs: TransactionInstruction[] = []
+    const nativeIxs: TransactionInstruction[] = []
+    const splPositions: ClaimAllFeePosition[] = []
+    const nativePositions: ClaimAllFeePosition[] = []
+    const atas: {
+      keypair: Keypair
+      createIx: TransactionInstruction
+      initIx: TransactionInstruction
+      unwrapIx: TransactionInstruction
+    }[] = []
+
+    const tokenPubkeys: PublicKey[] = Array.from(
+      new Set(positions.map(p => [p.pair.tokenX, p.pair.tokenY]).flat())
+    )
+    const pairs: Pair[] = Array.from(new Set(positions.map(p => p.pair)))
+
+    const promisedTokenPorgrams = await this.connection.getMultipleAccountsInfo(tokenPubkeys)
+
+    const tokenPrograms: Record<string, PublicKey> = promisedTokenPorgrams.reduce(
+      (acc: Record<string, PublicKey>, cur: any, idx: number) => {
+        acc[tokenPubkeys[idx].toBase58()] = cur?.owner ?? TOKEN_2022_PROGRAM_ID
+        return acc
+      },
+      {}
+    )
+
+    const poolStructures: [PublicKey, PoolStructure][] = await Promise.all([
+      ...pairs.map(pair => {
+        return new Promise(async res => {
+          res([pair.getAddress(this.program.programId), await this.getPool(pair)])
+        }) as Promise<[PublicKey, PoolStructure]>
+      })
+    ])
+
+    poolStructures.forEach((p: [PublicKey, PoolStructure]) => (pools[p[0].toBase58()] = p[1]))
+
+    for (const position of positions) {
+      if (position.pair.tokenX.equals(NATIVE_MINT) || position.pair.tokenY.equals(NATIVE_MINT)) {
+        nativePositions.push(position)
+      } else {
+        splPositions.push(position)
+      }
+    }
+
+    if (nativePositions.length != 0) {
+      const requiredAtas = Math.ceil(nativePositions.length / MAX_NATIVE_POSITION_FOR_CLAIM_ALL)
+
+      for (let i = 0; i < requiredAtas; i++) {
+        const nativeAta = Keypair.generate()
+        const { createIx, initIx, unwrapIx } = createNativeAtaInstructions(
+          nativeAta.publicKey,
+          owner,
+          this.network
+        )
+        atas.push({
+          keypair: nativeAta,
+          createIx,
+          initIx,
+          unwrapIx
+        })
+      }
+
+      for (const [n, { index, pair }] of nativePositions.entries()) {
+        const idx = Math.floor(n / MAX_NATIVE_POSITION_FOR_CLAIM_ALL)
+
+        const poolPubkey = pair.getAddress(this.program.programId).toBase58()
+
+        const userTokenX = pair.tokenX.equals(NATIVE_MINT)
+          ? atas[idx].keypair.publicKey
+          : getAssociatedTokenAddressSync(
+              pair.tokenX,
+              owner,
+              false,
+              tokenPrograms[pair.tokenX.toBase58()]
+            )
+        const userTokenY = pair.tokenY.equals(NATIVE_MINT)
+          ? atas[idx].keypair.publicKey
+          : getAssociatedTokenAddressSync(
+              pair.tokenY,
+              owner,
+              false,
+              tokenPrograms[pair.tokenY.toBase58()]
+            )
+
+        const claimIx = await this.claimFeeIx(
+          {
+            index,
+            pair,
+            userTokenX,
+            userTokenY,
+            owner
+          },
+          {
+            pool: pools[poolPubkey],
+            tokenXProgram: tokenPrograms[pair.tokenX.toBase58()],
+            tokenYProgram: tokenPrograms[pair.tokenY.toBase58()]
+          }
+        )
+
+        nativeIxs.push(claimIx)
+      }
+    }
+
+    if (splPositions.length != 0) {
+      for (const position of splPositions) {
+        const { pair, index } = position
+
+        const poolPubkey = pair.getAddress(this.program.programId).toBase58()
+
+        const userTokenX = getAssociatedTokenAddressSync(
+          pair.tokenX,
+          owner,
+          false,
+          tokenPrograms[pair.tokenX.toBase58()]
+        )
+        const userTokenY = getAssociatedTokenAddressSync(
+          pair.tokenY,
+          owner,
+          false,
+          tokenPrograms[pair.tokenY.toBase58()]
+        )
+
+        const claimIx = await this.claimFeeIx(
+          {
+            index,
+            pair,
+            userTokenX,
+            userTokenY,
+            owner
+          },
+          {
+            pool: pools[poolPubkey],
+            tokenXProgram: tokenPrograms[pair.tokenX.toBase58()],
+            tokenYProgram: tokenPrograms[pair.tokenY.toBase58()]
+          }
+        )
+
+        ixs.push(claimIx)
+      }
+    }
+
+    let txs: { tx: Transaction; additionalSigner?: Keypair }[] = []
+
+    for (let i = 0; i < ixs.length; i += MAX_SPL_POSITION_FOR_CLAIM_ALL) {
+      txs.push({ tx: new Transaction().add(...ixs.slice(i, i + MAX_SPL_POSITION_FOR_CLAIM_ALL)) })
+    }
+
+    for (let i = 0; i < nativeIxs.length; i += MAX_NATIVE_POSITION_FOR_CLAIM_ALL) {
+      const idx = i === 0 ? 0 : Math.floor(i / MAX_SPL_POSITION_FOR_CLAIM_ALL)
+      txs.push({
+        tx: new Transaction()
+          .add(atas[idx].createIx)
+          .add(atas[idx].initIx)
+          .add(...nativeIxs.slice(i, i + MAX_NATIVE_POSITION_FOR_CLAIM_ALL))
+          .add(atas[idx].unwrapIx),
+        additionalSigner: atas[idx].keypair
+      })
+    }
+
+    return txs
+  }
+
+  async withdrawProtocolFeeIx(
+    withdrawProtocolFee: WithdrawProtocolFee,
+    cache: WithdrawProtocolFeeCache = {}
+  ) {
+    const { pair, accountX, accountY } = withdrawProtocolFee
+    const admin = withdrawProtocolFee.admin ?? this.wallet.publicKey
+
+    const [pool, tokenXProgram, tokenYProgram] = await Promise.all([
+      cache.pool ?? this.getPool(pair),
+      cache.tokenXProgram ?? getTokenProgramAddress(this.connection, pair.tokenX),
+      cache.tokenYProgram ?? getTokenProgramAddress(this.connection, pair.tokenY)
+    ])
+    // whole collected fee is withdrawn by default
+    const amountX = withdrawProtocolFee.amountX ?? pool.feeProtocolTokenX
+    const amountY = withdrawProtocolFee.amountY ?? pool.feeProtocolTokenY
+
+    return this.program.methods
+      .withdrawProtocolFee(amountX, amountY)
+      .accounts({
+        state: this.stateAddress.address,
+        pool: pair.getAddress(this.program.programId),
+        tokenX: pool.tokenX,
+        tokenY: pool.tokenY,
+        reserveX: pool.tokenXReserve,
+        reserveY: pool.tokenYReserve,
+        accountX,
+        accountY,
+        authority: admin,
+        programAuthority: this.programAuthority.address,
+        tokenXProgram,
+        tokenYProgram
+      })
+      .instruction()
+  }
+
+  async withdrawProtocolFeeTx(
+    withdrawProtocolFee: WithdrawProtocolFee,
+    cache: WithdrawProtocolFeeCache = {}
+  ) {
+    const ix = await this.withdrawProtocolFeeIx(withdrawProtocolFee, cache)
+    return new Transaction().add(ix)
+  }
+
+  // Admin function
+  async withdrawProtocolFee(
+    withdrawProtocolFee: WithdrawProtocolFee,
+    signer: Keypair,
+    cache: WithdrawProtocolFeeCache = {}
+  ) {
+    const tx = await this.withdrawProtocolFeeTx(withdrawProtocolFee, cache)
+
+    await signAndSend(tx, [signer], this.connection)
+  }
+
+  getFeeSplitAddress() {
+    const [feeSplitAddress, feeSplitBump] = PublicKey.findProgramAddressSync(
+      [Buffer.from(utils.bytes.utf8.encode(FEE_SPLIT_SEED))],
+      this.program.programId
+    )
+
+    return {
+      feeSplitAddress,
+      feeSplitBump
+    }
+  }
+
+  async getFeeSplit() {
+    const { feeSplitAddress } = this.getFeeSplitAddress()
+    return (await this.program.account.feeSplit.fetch(feeSplitAddress)) as FeeSplit
+  }
+
+  // Admin function
+  async createFeeSplitIx(changeFeeSplit: ChangeFeeSplit) {
+    const { recipients, shares } = changeFeeSplit
+    const admin = changeFeeSplit.admin ?? this.wallet.publicKey
+
+    return this.program.methods
+      .createFeeSplit(recipients, shares)
+      .accounts({
+        state: this.stateAddress.address,
+        feeSplit: this.getFeeSplitAddress().feeSplitAddress,
+        admin,
+        rent: SYSVAR_RENT_PUBKEY,
+        systemProgram: SystemProgram.programId
+      })
+      .instruction()
+  }
+
+  async createFeeSplitTx(changeFeeSplit: ChangeFeeSplit) {
+    const ix = await this.createFeeSplitIx(changeFeeSplit)
+    return new Transaction().add(ix)
+  }
+
+  async createFeeSplit(changeFeeSplit: ChangeFeeSplit, signer: Keypair) {
+    const tx = await this.createFeeSplitTx(changeFeeSplit)
+
+    await signAndSend(tx, [signer], this.connection)
+  }
+
+  // Admin function
+  async changeFeeSplitIx(changeFeeSplit: ChangeFeeSplit) {
+    const { recipients, shares } = changeFeeSplit
+    const admin = changeFeeSplit.admin ?? this.wallet.publicKey
+
+    return this.program.methods
+      .changeFeeSplit(recipients, shares)
+      .accounts({
+        state: this.stateAddress.address,
+        feeSplit: this.getFeeSplitAddress().feeSplitAddress,
+        admin
+      })
+      .instruction()
+  }
+
+  async changeFeeSplitTx(changeFeeSplit: ChangeFeeSplit) {
+    const ix = await this.changeFeeSplitIx(changeFeeSplit)
+    return new Transaction().add(ix)
+  }
+
+  async changeFeeSplit(changeFeeSplit: ChangeFeeSplit, signer: Keypair) {
+    const tx = await this.changeFeeSplitTx(changeFeeSplit)
+
+    await signAndSend(tx, [signer], this.connection)
+  }
+
+  async distributeProtocolFeeIx(distributeProtocolFee: DistributeProtocolFee) {
+    const { pair, recipientAccounts } = distributeProtocolFee
+    const admin = distributeProtocolFee.admin ?? this.wallet.publicKey
+
+    const [pool, tokenXProgram, tokenYProgram] = await Promise.all([
+      this.getPool(pair),
+      getTokenProgramAddress(this.connection, pair.tokenX),
+      getTokenProgramAddress(this.connection, pair.tokenY)
+    ])
+    const amountX = distributeProtocolFee.amountX ?? pool.feeProtocolTokenX
+    const amountY = distributeProtocolFee.amountY ?? pool.feeProtocolTokenY
+
+    const remainingAccounts = recipientAccounts.flatMap(({ accountX, accountY }) => [
+      { pubkey: accountX, isWritable: true, isSigner: false },
+      { pubkey: accountY, isWritable: true, isSigner: false }
+    ])
+
+    return this.program.methods
+      .distributeProtocolFee(amountX, amountY)
+      .accounts({
+        state: this.stateAddress.address,
+        pool: pair.getAddress(this.program.programId),
+        feeSplit: this.getFeeSplitAddress().feeSplitAddress,
+        tokenX: pair.tokenX,
+        tokenY: pair.tokenY,
+        reserveX: pool.tokenXReserve,
+        reserveY: pool.tokenYReserve,
+        authority: admin,
+        programAuthority: this.programAuthority.address,
+        tokenXProgram,
+        tokenYProgram
+      })
+      .remainingAccounts(remainingAccounts)
+      .instruction()
+  }
+
+  async distributeProtocolFeeTx(distributeProtocolFee: DistributeProtocolFee) {
+    const ix = await this.distributeProtocolFeeIx(distributeProtocolFee)
+    return new Transaction().add(ix)
+  }
+
+  async distributeProtocolFee(distributeProtocolFee: DistributeProtocolFee, signer: Keypair) {
+    const tx = await this.distributeProtocolFeeTx(distributeProtocolFee)
+
+    await signAndSend(tx, [signer], this.connection)
+  }
+
+  async removePositionIx(
+    removePosition: RemovePosition,
+    cache: RemovePositionCache = {}
+  ): Promise<TransactionInstruction> {
+    const { owner, operator, pair, index, userTokenX, userTokenY } = removePosition
+    const payer = removePosition.payer ?? operator ?? owner
+
+    const [positionList, state, position, tokenXProgram, tokenYProgram] = await Promise.all([
+      cache.positionList ?? this.getPositionList(owner),
+      cache.pool ?? this.getPool(pair),
+      cache.position ?? this.getPosition(owner, index),
+      cache.tokenXProgram ?? getTokenProgramAddress(this.connection, pair.tokenX),
+      cache.tokenYProgram ?? getTokenProgramAddress(this.connection, pair.tokenY)
+    ])
+
+    const { positionListAddress } = this.getPositionListAddress(owner)
+    const { positionAddress: removedPositionAddress } = this.getPositionAddress(owner, index)
+    const { positionAddress: lastPositionAddress } = this.getPositionAddress(
+      owner,
+      positionList.head - 1
+    )
+
+    const { tickAddress: lowerTickAddress } = this.getTickAddress(pair, position.lowerTickIndex)
+    const { tickAddress: upperTickAddress } = this.getTickAddress(pair, position.upperTickIndex)
+    const poolAddress = pair.getAddress(this.program.programId)
+    return this.program.methods
+      .removePosition(index, position.lowerTickIndex, position.upperTickIndex)
+      .accounts({
+        state: this.stateAddress.address,
+        owner: operator ?? owner,
+        payer,
+        removedPosition: removedPositionAddress,
+        positionList: positionListAddress,
+        lastPosition: lastPositionAddress,
+        pool: poolAddress,
+        tickmap: state.tickmap,
+        lowerTick: lowerTickAddress,
+        upperTick: upperTickAddress,
+        tokenX: pair.tokenX,
+        tokenY: pair.tokenY,
+        accountX: userTokenX,
+        accountY: userTokenY,
+        reserveX: state.tokenXReserve,
+        reserveY: state.tokenYReserve,
+        programAuthority: this.programAuthority.address,
+        tokenXProgram,
+        tokenYProgram,
+        eventOptAcc: this.getEventOptAccount(poolAddress).address,
+        positionOperator: operator
+          ? this.getPositionOperatorAddress(owner, poolAddress, position.id, operator)
+              .positionOperatorAddress
+          : null
+      })
+      .instruction()
+  }
+
+  async removePositionTx(removePosition: RemovePosition, cache: RemovePositionCache = {}) {
+    const ix = await this.removePositionIx(removePosition, cache)
+    return new Transaction().add(ix)
+  }
+
+  async removePosition(
+    removePosition: RemovePosition,
+    signer: Keypair,
+    cache: RemovePositionCache = {}
+  ) {
+    const tx = await this.removePositionTx(removePosition, cache)
+
+    await signAndSend(tx, [signer], this.connection)
+  }
+
+  async transferPositionOwnershipIx(
+    transferPositionOwnership: TransferPositionOwnership,
+    cache: TransferPositionCache = {}
+  ): Promise<TransactionInstruction> {
+    const { index } = transferPositionOwnership
+    const owner = transferPositionOwnership.owner ?? this.wallet.publicKey
+    const recipient = transferPositionOwnership.recipient ?? this.wallet.publicKey
+
+    const { positionListAddress: ownerListAddress } = this.getPositionListAddress(owner)
+    const { positionListAddress: recipientListAddress } = this.getPositionListAddress(recipient)
+    const [ownerPositionList, recipientPositionList] = await Promise.all([
+      cache.ownerPositionList ?? (await this.getPositionList(owner)),
+      cache.recipientPositionList ?? (await this.getPositionList(recipient))
+    ])
+
+    const { positionAddress: newPosition } = this.getPositionAddress(
+      recipient,
+      recipientPositionList.head
+    )
+
+    const { positionAddress: removedPosition } = this.getPositionAddress(owner, index)
+    const { positionAddress: lastPosition } = this.getPositionAddress(
+      owner,
+      ownerPositionList.head - 1
+    )
+
+    return this.program.methods
+      .transferPositionOwnership(index)
+      .accounts({
+        payer: owner,
+        owner,
+        recipient,
+        ownerList: ownerListAddress,
+        recipientList: recipientListAddress,
+        lastPosition,
+        removedPosition,
+        newPosition,
+        rent: SYSVAR_RENT_PUBKEY,
+        systemProgram: SystemProgram.programId
+      })
+      .instruction()
+  }
+
+  async transferPositionOwnershipTx(
+    transferPositionOwnership: TransferPositionOwnership,
+    cache: TransferPositionCache = {}
+  ) {
+    const ix = await this.transferPositionOwnershipIx(transferPositionOwnership, cache)
+    return new Transaction().add(ix)
+  }
+
+  async transferPositionOwnership(
+    transferPositionOwnership: TransferPositionOwnership,
+    signer: Keypair,
+    cache: TransferPositionCache = {}
+  ) {
+    const tx = await this.transferPositionOwnershipTx(transferPositionOwnership, cache)
+
+    await signAndSend(tx, [signer], this.connection)
+  }
+
+  async updateSecondsPerLiquidityIx(updateSecondsPerLiquidity: UpdateSecondsPerLiquidity) {
+    const { pair, signer, lowerTickIndex, upperTickIndex, index } = updateSecondsPerLiquidity
+    const owner = updateSecondsPerLiquidity.owner ?? this.wallet.publicKey
+
+    const { tickAddress: lowerTickAddress } = this.getTickAddress(pair, lowerTickIndex)
+    const { tickAddress: upperTickAddress } = this.getTickAddress(pair, upperTickIndex)
+    const poolAddress = pair.getAddress(this.program.programId)
+    const { positionAddress } = this.getPositionAddress(owner, index)
+
+    return this.program.methods
+      .updateSecondsPerLiquidity(lowerTickIndex, upperTickIndex, index)
+      .accounts({
+        pool: poolAddress,
+        lowerTick: lowerTickAddress,
+        upperTick: upperTickAddress,
+        position: positionAddress,
+        tokenX: pair.tokenX,
+        tokenY: pair.tokenY,
+        owner,
+        signer: signer ?? owner,
+        rent: SYSVAR_RENT_PUBKEY,
+        systemProgram: SystemProgram.programId
+      })
+      .instruction()
+  }
+
+  async updateSecondsPerLiquidityTx(updateSecondsPerLiquidity: UpdateSecondsPerLiquidity) {
+    const ix = await this.updateSecondsPerLiquidityIx(updateSecondsPerLiquidity)
+    return new Transaction().add(ix)
+  }
+
+  async updateSecondsPerLiquidity(
+    updateSecondsPerLiquidity: UpdateSecondsPerLiquidity,
+    signer: Keypair
+  ) {
+    const tx = await this.updateSecondsPerLiquidityTx(updateSecondsPerLiquidity)
+
+    await signAndSend(tx, [signer], this.connection)
+  }
+
+  async initializeOracle(pair: Pair, payer: Keypair) {
+    const oracleKeypair = Keypair.generate()
+    const tx = await this.initializeOracleTx(pair, oracleKeypair, payer)
+
+    return signAndSend(tx, [payer, oracleKeypair], this.connection)
+  }
+
+  async initializeOracleTx(pair: Pair, oracleKeypair: Keypair, payer: Keypair) {
+    const initAccount = await this.program.account.oracle.createInstruction(oracleKeypair)
+    const initOracleIx = await this.initializeOracleIx(pair, oracleKeypair.publicKey, payer)
+
+    const tx = new Transaction().add(initAccount).add(initOracleIx)
+    return tx
+  }
+
+  async initializeOracleIx(pair: Pair, oraclePublicKey: PublicKey, payer: Keypair) {
+    const poolAddress = pair.getAddress(this.program.programId)
+
+    return this.program.methods
+      .initializeOracle()
+      .accounts({
+        pool: poolAddress,
+        oracle: oraclePublicKey,
+        tokenX: pair.tokenX,
+        tokenY: pair.tokenY,
+        payer: payer.publicKey,
+        rent: SYSVAR_RENT_PUBKEY,
+        systemProgram: SystemProgram.programId
+      })
+      .instruction()
+  }
+
+  // extends a pool created before the account layout grew, new fields are zeroed
+  async migratePoolIx(pair: Pair, payer?: PublicKey) {
+    return this.program.methods
+      .migratePool()
+      .accounts({
+        pool: pair.getAddress(this.program.programId),
+        payer: payer ?? this.wallet.publicKey,
+        systemProgram: SystemProgram.programId
+      })
+      .instruction()
+  }
+
+  async migratePoolTx(pair: Pair, payer?: PublicKey) {
+    const ix = await this.migratePoolIx(pair, payer)
+    return new Transaction().add(ix)
+  }
+
+  async migratePool(pair: Pair, payer: Keypair) {
+    const tx = await this.migratePoolTx(pair, payer.publicKey)
+
+    return signAndSend(tx, [payer], this.connection)
+  }
+
+  // fee tiers created before the fee bounds were introduced are left with bounds at their fee
+  async migrateFeeTierIx(feeTier: FeeTier, payer?: PublicKey) {
+    return this.program.methods
+      .migrateFeeTier()
+      .accounts({
+        feeTier: this.getFeeTierAddress(feeTier).address,
+        payer: payer ?? this.wallet.publicKey,
+        systemProgram: SystemProgram.programId
+      })
+      .instruction()
+  }
+
+  async migrateFeeTierTx(feeTier: FeeTier, payer?: PublicKey) {
+    const ix = await this.migrateFeeTierIx(feeTier, payer)
+    return new Transaction().add(ix)
+  }
+
+  async migrateFeeTier(feeTier: FeeTier, payer: Keypair) {
+    const tx = await this.migrateFeeTierTx(feeTier, payer.publicKey)
+
+    return signAndSend(tx, [payer], this.connection)
+  }
+
+  // Admin function, extends the state created before roles, pause flags and pool defaults
+  async migrateStateIx(admin?: PublicKey) {
+    return this.program.methods
+      .migrateState()
+      .accounts({
+        state: this.stateAddress.address,
+        admin: admin ?? this.wallet.publicKey,
+        systemProgram: SystemProgram.programId
+      })
+      .instruction()
+  }
+
+  async migrateStateTx(admin?: PublicKey) {
+    const ix = await this.migrateStateIx(admin)
+    return new Transaction().add(ix)
+  }
+
+  async migrateState(admin: Keypair) {
+    const tx = await this.migrateStateTx(admin.publicKey)
+
+    return signAndSend(tx, [admin], this.connection)
+  }
+
+  // Admin function, the proposed admin has to accept the transfer
+  async proposeAdminIx(pendingAdmin: PublicKey, admin?: PublicKey) {
+    return this.program.methods
+      .proposeAdmin()
+      .accounts({
+        state: this.stateAddress.address,
+        admin: admin ?? this.wallet.publicKey,
+        pendingAdmin
+      })
+      .instruction()
+  }
+
+  async proposeAdminTx(pendingAdmin: PublicKey, admin?: PublicKey) {
+    const ix = await this.proposeAdminIx(pendingAdmin, admin)
+    return new Transaction().add(ix)
+  }
+
+  async proposeAdmin(pendingAdmin: PublicKey, admin: Keypair) {
+    const tx = await this.proposeAdminTx(pendingAdmin, admin.publicKey)
+
+    await signAndSend(tx, [admin], this.connection)
+  }
+
+  async acceptAdminIx(pendingAdmin?: PublicKey) {
+    return this.program.methods
+      .acceptAdmin()
+      .accounts({
+        state: this.stateAddress.address,
+        pendingAdmin: pendingAdmin ?? this.wallet.publicKey
+      })
+      .instruction()
+  }
+
+  async acceptAdminTx(pendingAdmin?: PublicKey) {
+    const ix = await this.acceptAdminIx(pendingAdmin)
+    return new Transaction().add(ix)
+  }
+
+  async acceptAdmin(pendingAdmin: Keypair) {
+    const tx = await this.acceptAdminTx(pendingAdmin.publicKey)
+
+    await signAndSend(tx, [pendingAdmin], this.connection)
+  }
 
-        nativeIxs.push(claimIx)
-      }
-    }
+  // Admin function
+  async setRoleIx(setRole: SetRole) {
+    const { role, holder } = setRole
+    const admin = setRole.admin ?? this.wallet.publicKey
 
-    if (splPositions.length != 0) {
-      for (const position of splPositions) {
-        const { pair, index } = position
+    return this.program.methods
+      .setRole(role)
+      .accounts({
+        state: this.stateAddress.address,
+        admin,
+        holder
+      })
+      .instruction()
+  }
 
-        const poolPubkey = pair.getAddress(this.program.programId).toBase58()
+  async setRoleTx(setRole: SetRole) {
+    const ix = await this.setRoleIx(setRole)
+    return new Transaction().add(ix)
+  }
 
-        const userTokenX = getAssociatedTokenAddressSync(
-          pair.tokenX,
-          owner,
-          false,
-          tokenPrograms[pair.tokenX.toBase58()]
-        )
-        const userTokenY = getAssociatedTokenAddressSync(
-          pair.tokenY,
-          owner,
-          false,
-          tokenPrograms[pair.tokenY.toBase58()]
-        )
+  async setRole(setRole: SetRole, signer: Keypair) {
+    const tx = await this.setRoleTx(setRole)
 
-        const claimIx = await this.claimFeeIx(
-          {
-            index,
-            pair,
-            userTokenX,
-            userTokenY,
-            owner
-          },
-          {
-            pool: pools[poolPubkey],
-            tokenXProgram: tokenPrograms[pair.tokenX.toBase58()],
-            tokenYProgram: tokenPrograms[pair.tokenY.toBase58()]
-          }
-        )
+    await signAndSend(tx, [signer], this.connection)
+  }
 
-        ixs.push(claimIx)
-      }
-    }
+  // Pauser function, flags apply to every pool on top of its own ones
+  async changePauseIx(paused: number, pauser?: PublicKey) {
+    return this.program.methods
+      .changePause(paused)
+      .accounts({
+        state: this.stateAddress.address,
+        pauser: pauser ?? this.wallet.publicKey
+      })
+      .instruction()
+  }
 
-    let txs: { tx: Transaction; additionalSigner?: Keypair }[] = []
+  async changePauseTx(paused: number, pauser?: PublicKey) {
+    const ix = await this.changePauseIx(paused, pauser)
+    return new Transaction().add(ix)
+  }
 
-    for (let i = 0; i < ixs.length; i += MAX_SPL_POSITION_FOR_CLAIM_ALL) {
-      txs.push({ tx: new Transaction().add(...ixs.slice(i, i + MAX_SPL_POSITION_FOR_CLAIM_ALL)) })
-    }
+  async changePause(paused: number, pauser: Keypair) {
+    const tx = await this.changePauseTx(paused, pauser.publicKey)
 
-    for (let i = 0; i < nativeIxs.length; i += MAX_NATIVE_POSITION_FOR_CLAIM_ALL) {
-      const idx = i === 0 ? 0 : Math.floor(i / MAX_SPL_POSITION_FOR_CLAIM_ALL)
-      txs.push({
-        tx: new Transaction()
-          .add(atas[idx].createIx)
-          .add(atas[idx].initIx)
-          .add(...nativeIxs.slice(i, i + MAX_NATIVE_POSITION_FOR_CLAIM_ALL))
-          .add(atas[idx].unwrapIx),
-        additionalSigner: atas[idx].keypair
+    await signAndSend(tx, [pauser], this.connection)
+  }
+
+  // Pauser function
+  async changePoolPauseIx(changePoolPause: ChangePoolPause) {
+    const { pair, paused } = changePoolPause
+    const pauser = changePoolPause.pauser ?? this.wallet.publicKey
+
+    return this.program.methods
+      .changePoolPause(paused)
+      .accounts({
+        state: this.stateAddress.address,
+        pool: pair.getAddress(this.program.programId),
+        tokenX: pair.tokenX,
+        tokenY: pair.tokenY,
+        pauser
       })
-    }
+      .instruction()
+  }
 
-    return txs
+  async changePoolPauseTx(changePoolPause: ChangePoolPause) {
+    const ix = await this.changePoolPauseIx(changePoolPause)
+    return new Transaction().add(ix)
   }
 
-  async withdrawProtocolFeeIx(
-    withdrawProtocolFee: WithdrawProtocolFee,
-    cache: WithdrawProtocolFeeCache = {}
-  ) {
-    const { pair, accountX, accountY } = withdrawProtocolFee
-    const admin = withdrawProtocolFee.admin ?? this.wallet.publicKey
+  async changePoolPause(changePoolPause: ChangePoolPause, signer: Keypair) {
+    const tx = await this.changePoolPauseTx(changePoolPause)
+
+    await signAndSend(tx, [signer], this.connection)
+  }
+
+  async getOracle(pair: Pair, pool?: { oracleAddress: PublicKey }) {
+    pool = pool ?? (await this.getPool(pair))
+    return this.program.account.oracle.fetch(pool.oracleAddress)
+  }
+
+  // Admin function, reserve dust is sent to the receiver token accounts
+  async closePoolIx(closePool: ClosePool) {
+    const { pair, receiverAccountX, receiverAccountY } = closePool
+    const admin = closePool.admin ?? this.wallet.publicKey
 
     const [pool, tokenXProgram, tokenYProgram] = await Promise.all([
-      cache.pool ?? this.getPool(pair),
-      cache.tokenXProgram ?? getTokenProgramAddress(this.connection, pair.tokenX),
-      cache.tokenYProgram ?? getTokenProgramAddress(this.connection, pair.tokenY)
+      this.getPool(pair),
+      getTokenProgramAddress(this.connection, pair.tokenX),
+      getTokenProgramAddress(this.connection, pair.tokenY)
     ])
 
     return this.program.methods
-      .withdrawProtocolFee()
+      .closePool()
       .accounts({
         state: this.stateAddress.address,
         pool: pair.getAddress(this.program.programId),
-        tokenX: pool.tokenX,
-        tokenY: pool.tokenY,
-        reserveX: pool.tokenXReserve,
-        reserveY: pool.tokenYReserve,
-        accountX,
-        accountY,
-        authority: admin,
+        tickmap: pool.tickmap,
+        oracle: pool.oracleInitialized ? pool.oracleAddress : null,
+        tokenX: pair.tokenX,
+        tokenY: pair.tokenY,
+        reserveX: pool.tokenXReserve.equals(DEFAULT_PUBLIC_KEY) ? null : pool.tokenXReserve,
+        reserveY: pool.tokenYReserve.equals(DEFAULT_PUBLIC_KEY) ? null : pool.tokenYReserve,
+        admin,
+        receiverAccountX: receiverAccountX ?? null,
+        receiverAccountY: receiverAccountY ?? null,
         programAuthority: this.programAuthority.address,
         tokenXProgram,
         tokenYProgram
@@ -2081,286 +4017,218 @@ export class Market {
       .instruction()
   }
 
-  async withdrawProtocolFeeTx(
-    withdrawProtocolFee: WithdrawProtocolFee,
-    cache: WithdrawProtocolFeeCache = {}
-  ) {
-    const ix = await this.withdrawProtocolFeeIx(withdrawProtocolFee, cache)
+  async closePoolTx(closePool: ClosePool) {
+    const ix = await this.closePoolIx(closePool)
     return new Transaction().add(ix)
   }
 
-  // Admin function
-  async withdrawProtocolFee(
-    withdrawProtocolFee: WithdrawProtocolFee,
-    signer: Keypair,
-    cache: WithdrawProtocolFeeCache = {}
-  ) {
-    const tx = await this.withdrawProtocolFeeTx(withdrawProtocolFee, cache)
+  async closePool(closePool: ClosePool, signer: Keypair) {
+    const tx = await this.closePoolTx(closePool)
 
     await signAndSend(tx, [signer], this.connection)
   }
 
-  async removePositionIx(
-    removePosition: RemovePosition,
-    cache: RemovePositionCache = {}
-  ): Promise<TransactionInstruction> {
-    const { owner, pair, index, userTokenX, userTokenY } = removePosition
-    const payer = removePosition.payer ?? owner
-
-    const [positionList, state, position, tokenXProgram, tokenYProgram] = await Promise.all([
-      cache.positionList ?? this.getPositionList(owner),
-      cache.pool ?? this.getPool(pair),
-      cache.position ?? this.getPosition(owner, index),
-      cache.tokenXProgram ?? getTokenProgramAddress(this.connection, pair.tokenX),
-      cache.tokenYProgram ?? getTokenProgramAddress(this.connection, pair.tokenY)
-    ])
-
-    const { positionListAddress } = this.getPositionListAddress(owner)
-    const { positionAddress: removedPositionAddress } = this.getPositionAddress(owner, index)
-    const { positionAddress: lastPositionAddress } = this.getPositionAddress(
-      owner,
-      positionList.head - 1
-    )
+  async changeProtocolFeeIx(changeProtocolFee: ChangeProtocolFee) {
+    let { pair, admin, protocolFee } = changeProtocolFee
+    admin = admin ?? this.wallet.publicKey
 
-    const { tickAddress: lowerTickAddress } = this.getTickAddress(pair, position.lowerTickIndex)
-    const { tickAddress: upperTickAddress } = this.getTickAddress(pair, position.upperTickIndex)
     const poolAddress = pair.getAddress(this.program.programId)
+
     return this.program.methods
-      .removePosition(index, position.lowerTickIndex, position.upperTickIndex)
+      .changeProtocolFee({ v: protocolFee })
       .accounts({
         state: this.stateAddress.address,
-        owner,
-        payer,
-        removedPosition: removedPositionAddress,
-        positionList: positionListAddress,
-        lastPosition: lastPositionAddress,
         pool: poolAddress,
-        tickmap: state.tickmap,
-        lowerTick: lowerTickAddress,
-        upperTick: upperTickAddress,
         tokenX: pair.tokenX,
         tokenY: pair.tokenY,
-        accountX: userTokenX,
-        accountY: userTokenY,
-        reserveX: state.tokenXReserve,
-        reserveY: state.tokenYReserve,
-        programAuthority: this.programAuthority.address,
-        tokenXProgram,
-        tokenYProgram,
-        eventOptAcc: this.getEventOptAccount(poolAddress).address
+        admin,
+        programAuthority: this.programAuthority.address
       })
       .instruction()
   }
 
-  async removePositionTx(removePosition: RemovePosition, cache: RemovePositionCache = {}) {
-    const ix = await this.removePositionIx(removePosition, cache)
+  async changeProtocolFeeTx(changeProtocolFee: ChangeProtocolFee) {
+    const ix = await this.changeProtocolFeeIx(changeProtocolFee)
     return new Transaction().add(ix)
   }
 
-  async removePosition(
-    removePosition: RemovePosition,
-    signer: Keypair,
-    cache: RemovePositionCache = {}
-  ) {
-    const tx = await this.removePositionTx(removePosition, cache)
+  async changeProtocolFee(changeProtocolFee: ChangeProtocolFee, signer: Keypair) {
+    const tx = await this.changeProtocolFeeTx(changeProtocolFee)
 
     await signAndSend(tx, [signer], this.connection)
   }
 
-  async transferPositionOwnershipIx(
-    transferPositionOwnership: TransferPositionOwnership,
-    cache: TransferPositionCache = {}
-  ): Promise<TransactionInstruction> {
-    const { index } = transferPositionOwnership
-    const owner = transferPositionOwnership.owner ?? this.wallet.publicKey
-    const recipient = transferPositionOwnership.recipient ?? this.wallet.publicKey
-
-    const { positionListAddress: ownerListAddress } = this.getPositionListAddress(owner)
-    const { positionListAddress: recipientListAddress } = this.getPositionListAddress(recipient)
-    const [ownerPositionList, recipientPositionList] = await Promise.all([
-      cache.ownerPositionList ?? (await this.getPositionList(owner)),
-      cache.recipientPositionList ?? (await this.getPositionList(recipient))
-    ])
-
-    const { positionAddress: newPosition } = this.getPositionAddress(
-      recipient,
-      recipientPositionList.head
-    )
-
-    const { positionAddress: removedPosition } = this.getPositionAddress(owner, index)
-    const { positionAddress: lastPosition } = this.getPositionAddress(
-      owner,
-      ownerPositionList.head - 1
-    )
+  async changePoolFeeIx(changePoolFee: ChangePoolFee) {
+    const { pair, fee } = changePoolFee
+    const admin = changePoolFee.admin ?? this.wallet.publicKey
+    // tier the pool was created with, the pool address derives from the same fee
+    const { address: feeTierAddress } = this.getFeeTierAddress(pair.feeTier)
 
     return this.program.methods
-      .transferPositionOwnership(index)
+      .changePoolFee({ v: fee })
       .accounts({
-        payer: owner,
-        owner,
-        recipient,
-        ownerList: ownerListAddress,
-        recipientList: recipientListAddress,
-        lastPosition,
-        removedPosition,
-        newPosition,
-        rent: SYSVAR_RENT_PUBKEY,
-        systemProgram: SystemProgram.programId
+        state: this.stateAddress.address,
+        pool: pair.getAddress(this.program.programId),
+        feeTier: feeTierAddress,
+        tokenX: pair.tokenX,
+        tokenY: pair.tokenY,
+        admin
       })
       .instruction()
   }
 
-  async transferPositionOwnershipTx(
-    transferPositionOwnership: TransferPositionOwnership,
-    cache: TransferPositionCache = {}
-  ) {
-    const ix = await this.transferPositionOwnershipIx(transferPositionOwnership, cache)
+  async changePoolFeeTx(changePoolFee: ChangePoolFee) {
+    const ix = await this.changePoolFeeIx(changePoolFee)
     return new Transaction().add(ix)
   }
 
-  async transferPositionOwnership(
-    transferPositionOwnership: TransferPositionOwnership,
-    signer: Keypair,
-    cache: TransferPositionCache = {}
-  ) {
-    const tx = await this.transferPositionOwnershipTx(transferPositionOwnership, cache)
+  // Fee tier manager function
+  async changePoolFee(changePoolFee: ChangePoolFee, signer: Keypair) {
+    const tx = await this.changePoolFeeTx(changePoolFee)
 
     await signAndSend(tx, [signer], this.connection)
   }
 
-  async updateSecondsPerLiquidityIx(updateSecondsPerLiquidity: UpdateSecondsPerLiquidity) {
-    const { pair, signer, lowerTickIndex, upperTickIndex, index } = updateSecondsPerLiquidity
-    const owner = updateSecondsPerLiquidity.owner ?? this.wallet.publicKey
-
-    const { tickAddress: lowerTickAddress } = this.getTickAddress(pair, lowerTickIndex)
-    const { tickAddress: upperTickAddress } = this.getTickAddress(pair, upperTickIndex)
+  async changeFeeReceiverIx(changeFeeReceiver: ChangeFeeReceiver) {
+    const { pair, feeReceiver } = changeFeeReceiver
+    const adminPubkey = changeFeeReceiver.admin ?? this.wallet.publicKey
     const poolAddress = pair.getAddress(this.program.programId)
-    const { positionAddress } = this.getPositionAddress(owner, index)
 
     return this.program.methods
-      .updateSecondsPerLiquidity(lowerTickIndex, upperTickIndex, index)
+      .changeFeeReceiver()
       .accounts({
+        state: this.stateAddress.address,
         pool: poolAddress,
-        lowerTick: lowerTickAddress,
-        upperTick: upperTickAddress,
-        position: positionAddress,
         tokenX: pair.tokenX,
         tokenY: pair.tokenY,
-        owner,
-        signer: signer ?? owner,
-        rent: SYSVAR_RENT_PUBKEY,
-        systemProgram: SystemProgram.programId
+        admin: adminPubkey,
+        feeReceiver: feeReceiver
       })
       .instruction()
   }
 
-  async updateSecondsPerLiquidityTx(updateSecondsPerLiquidity: UpdateSecondsPerLiquidity) {
-    const ix = await this.updateSecondsPerLiquidityIx(updateSecondsPerLiquidity)
+  async changeFeeReceiverTx(changeFeeReceiver: ChangeFeeReceiver) {
+    const ix = await this.changeFeeReceiverIx(changeFeeReceiver)
+
     return new Transaction().add(ix)
   }
 
-  async updateSecondsPerLiquidity(
-    updateSecondsPerLiquidity: UpdateSecondsPerLiquidity,
-    signer: Keypair
-  ) {
-    const tx = await this.updateSecondsPerLiquidityTx(updateSecondsPerLiquidity)
+  async changeFeeReceiver(changeFeeReceiver: ChangeFeeReceiver, signer: Keypair) {
+    const tx = await this.changeFeeReceiverTx(changeFeeReceiver)
 
     await signAndSend(tx, [signer], this.connection)
   }
 
-  async initializeOracle(pair: Pair, payer: Keypair) {
-    const oracleKeypair = Keypair.generate()
-    const tx = await this.initializeOracleTx(pair, oracleKeypair, payer)
+  async changePoolDefaultsIx(changePoolDefaults: ChangePoolDefaults) {
+    const { protocolFee, feeReceiver } = changePoolDefaults
+    const admin = changePoolDefaults.admin ?? this.wallet.publicKey
 
-    return signAndSend(tx, [payer, oracleKeypair], this.connection)
+    return this.program.methods
+      .changePoolDefaults({ v: protocolFee })
+      .accounts({
+        state: this.stateAddress.address,
+        admin,
+        feeReceiver
+      })
+      .instruction()
   }
 
-  async initializeOracleTx(pair: Pair, oracleKeypair: Keypair, payer: Keypair) {
-    const initAccount = await this.program.account.oracle.createInstruction(oracleKeypair)
-    const initOracleIx = await this.initializeOracleIx(pair, oracleKeypair.publicKey, payer)
+  async changePoolDefaultsTx(changePoolDefaults: ChangePoolDefaults) {
+    const ix = await this.changePoolDefaultsIx(changePoolDefaults)
+    return new Transaction().add(ix)
+  }
 
-    const tx = new Transaction().add(initAccount).add(initOracleIx)
-    return tx
+  // Protocol fee manager function
+  async changePoolDefaults(changePoolDefaults: ChangePoolDefaults, signer: Keypair) {
+    const tx = await this.changePoolDefaultsTx(changePoolDefaults)
+
+    await signAndSend(tx, [signer], this.connection)
   }
 
-  async initializeOracleIx(pair: Pair, oraclePublicKey: PublicKey, payer: Keypair) {
-    const poolAddress = pair.getAddress(this.program.programId)
+  async changeFeeTierPoolDefaultsIx(changeFeeTierPoolDefaults: ChangeFeeTierPoolDefaults) {
+    const { feeTier, protocolFee, feeReceiver } = changeFeeTierPoolDefaults
+    const admin = changeFeeTierPoolDefaults.admin ?? this.wallet.publicKey
+    const { address } = this.getFeeTierAddress(feeTier)
 
+    // missing override falls back to the state defaults
     return this.program.methods
-      .initializeOracle()
+      .changeFeeTierPoolDefaults(protocolFee ? { v: protocolFee } : null, feeReceiver ?? null)
       .accounts({
-        pool: poolAddress,
-        oracle: oraclePublicKey,
-        tokenX: pair.tokenX,
-        tokenY: pair.tokenY,
-        payer: payer.publicKey,
-        rent: SYSVAR_RENT_PUBKEY,
-        systemProgram: SystemProgram.programId
+        state: this.stateAddress.address,
+        feeTier: address,
+        admin
       })
       .instruction()
   }
 
-  async getOracle(pair: Pair, pool?: { oracleAddress: PublicKey }) {
-    pool = pool ?? (await this.getPool(pair))
-    return this.program.account.oracle.fetch(pool.oracleAddress)
+  async changeFeeTierPoolDefaultsTx(changeFeeTierPoolDefaults: ChangeFeeTierPoolDefaults) {
+    const ix = await this.changeFeeTierPoolDefaultsIx(changeFeeTierPoolDefaults)
+    return new Transaction().add(ix)
   }
 
-  async changeProtocolFeeIx(changeProtocolFee: ChangeProtocolFee) {
-    let { pair, admin, protocolFee } = changeProtocolFee
-    admin = admin ?? this.wallet.publicKey
+  // Protocol fee manager function
+  async changeFeeTierPoolDefaults(
+    changeFeeTierPoolDefaults: ChangeFeeTierPoolDefaults,
+    signer: Keypair
+  ) {
+    const tx = await this.changeFeeTierPoolDefaultsTx(changeFeeTierPoolDefaults)
 
-    const poolAddress = pair.getAddress(this.program.programId)
+    await signAndSend(tx, [signer], this.connection)
+  }
+
+  async createReferrerIx(createReferrer: CreateReferrer) {
+    const { owner, feeShare } = createReferrer
+    const admin = createReferrer.admin ?? this.wallet.publicKey
+    const { referrerAddress } = this.getReferrerAddress(owner)
 
     return this.program.methods
-      .changeProtocolFee({ v: protocolFee })
+      .createReferrer({ v: feeShare })
       .accounts({
+        referrer: referrerAddress,
         state: this.stateAddress.address,
-        pool: poolAddress,
-        tokenX: pair.tokenX,
-        tokenY: pair.tokenY,
         admin,
-        programAuthority: this.programAuthority.address
+        owner,
+        rent: SYSVAR_RENT_PUBKEY,
+        systemProgram: SystemProgram.programId
       })
       .instruction()
   }
 
-  async changeProtocolFeeTx(changeProtocolFee: ChangeProtocolFee) {
-    const ix = await this.changeProtocolFeeIx(changeProtocolFee)
+  async createReferrerTx(createReferrer: CreateReferrer) {
+    const ix = await this.createReferrerIx(createReferrer)
+
     return new Transaction().add(ix)
   }
 
-  async changeProtocolFee(changeProtocolFee: ChangeProtocolFee, signer: Keypair) {
-    const tx = await this.changeProtocolFeeTx(changeProtocolFee)
+  async createReferrer(createReferrer: CreateReferrer, signer: Keypair) {
+    const tx = await this.createReferrerTx(createReferrer)
 
     await signAndSend(tx, [signer], this.connection)
   }
 
-  async changeFeeReceiverIx(changeFeeReceiver: ChangeFeeReceiver) {
-    const { pair, feeReceiver } = changeFeeReceiver
-    const adminPubkey = changeFeeReceiver.admin ?? this.wallet.publicKey
-    const poolAddress = pair.getAddress(this.program.programId)
+  async changeReferrerIx(changeReferrer: ChangeReferrer) {
+    const { owner, feeShare, enabled } = changeReferrer
+    const admin = changeReferrer.admin ?? this.wallet.publicKey
+    const { referrerAddress } = this.getReferrerAddress(owner)
 
     return this.program.methods
-      .changeFeeReceiver()
+      .changeReferrer({ v: feeShare }, enabled)
       .accounts({
+        referrer: referrerAddress,
         state: this.stateAddress.address,
-        pool: poolAddress,
-        tokenX: pair.tokenX,
-        tokenY: pair.tokenY,
-        admin: adminPubkey,
-        feeReceiver: feeReceiver
+        admin
       })
       .instruction()
   }
 
-  async changeFeeReceiverTx(changeFeeReceiver: ChangeFeeReceiver) {
-    const ix = await this.changeFeeReceiverIx(changeFeeReceiver)
+  async changeReferrerTx(changeReferrer: ChangeReferrer) {
+    const ix = await this.changeReferrerIx(changeReferrer)
 
     return new Transaction().add(ix)
   }
 
-  async changeFeeReceiver(changeFeeReceiver: ChangeFeeReceiver, signer: Keypair) {
-    const tx = await this.changeFeeReceiverTx(changeFeeReceiver)
+  async changeReferrer(changeReferrer: ChangeReferrer, signer: Keypair) {
+    const tx = await this.changeReferrerTx(changeReferrer)
 
     await signAndSend(tx, [signer], this.connection)
   }
@@ -2520,12 +4388,31 @@ export const parsePool = (pool: RawPoolStructure): any => {
     feeReceiver: pool.feeReceiver,
     oracleAddress: pool.oracleAddress,
     oracleInitialized: pool.oracleInitialized,
-    bump: pool.bump
+    bump: pool.bump,
+    dynamicFeeEnabled: pool.dynamicFeeEnabled,
+    volatilityFeePerTick: pool.volatilityFeePerTick.v,
+    maxVolatilityFee: pool.maxVolatilityFee.v,
+    volatilityHalfLife: pool.volatilityHalfLife,
+    volatilityAccumulator: pool.volatilityAccumulator,
+    volatilityLastUpdate: pool.volatilityLastUpdate,
+    paused: pool.paused,
+    feeOverride: pool.feeOverride.v,
+    feeOverridden: pool.feeOverridden,
+    openRangeOrders: pool.openRangeOrders
   }
 
   return parsedPool as unknown
 }
 
+export const parseReferrer = (referrer: RawReferrer): Referrer => {
+  return {
+    owner: referrer.owner,
+    feeShare: referrer.feeShare.v,
+    enabled: referrer.enabled,
+    bump: referrer.bump
+  }
+}
+
 export const parseFeeTier = (feeTier: RawFeeTierStructure): FeeTier => {
   let parsedFeeTier: FeeTier = {
     fee: feeTier.fee.v,
@@ -2548,18 +4435,45 @@ export interface State {
   nonce: number
   authority: PublicKey
   bump: number
+  pendingAdmin: PublicKey
+  feeTierManager: PublicKey
+  pauser: PublicKey
+  protocolFeeManager: PublicKey
+  paused: number
+  defaultProtocolFee: Decimal
+  defaultFeeReceiver: PublicKey
+}
+
+// holder of each role is stored in the state, admin holds all of them after creation
+export const Role = {
+  FeeTierManager: { feeTierManager: {} },
+  Pauser: { pauser: {} },
+  ProtocolFeeManager: { protocolFeeManager: {} }
 }
+export type Role = (typeof Role)[keyof typeof Role]
 
 export interface RawFeeTierStructure {
   fee: Decimal
   tickSpacing: number
   bump: number
+  disabled: boolean
+  minFee: Decimal
+  maxFee: Decimal
+  protocolFeeOverride: Decimal
+  protocolFeeOverridden: boolean
+  feeReceiverOverride: PublicKey
 }
 
 export interface FeeTierStructure {
   fee: BN
   tickSpacing: number
   bump: number
+  disabled: boolean
+  minFee: BN
+  maxFee: BN
+  protocolFeeOverride: BN
+  protocolFeeOverridden: boolean
+  feeReceiverOverride: PublicKey
 }
 
 export interface RawPoolStructure {
@@ -2586,6 +4500,16 @@ export interface RawPoolStructure {
   oracleAddress: PublicKey
   oracleInitialized: boolean
   bump: number
+  dynamicFeeEnabled: boolean
+  volatilityFeePerTick: Decimal
+  maxVolatilityFee: Decimal
+  volatilityHalfLife: BN
+  volatilityAccumulator: BN
+  volatilityLastUpdate: BN
+  paused: number
+  feeOverride: Decimal
+  feeOverridden: boolean
+  openRangeOrders: number
 }
 
 export interface PoolStructure {
@@ -2612,6 +4536,16 @@ export interface PoolStructure {
   oracleAddress: PublicKey
   oracleInitialized: boolean
   bump: number
+  dynamicFeeEnabled: boolean
+  volatilityFeePerTick: BN
+  maxVolatilityFee: BN
+  volatilityHalfLife: BN
+  volatilityAccumulator: BN
+  volatilityLastUpdate: BN
+  paused: number
+  feeOverride: BN
+  feeOverridden: boolean
+  openRangeOrders: number
 }
 
 export interface PoolData {
@@ -2633,6 +4567,28 @@ export interface PositionList {
   bump: number
 }
 
+export interface PositionEntry {
+  pool: PublicKey
+  id: BN
+  bump: number
+}
+
+export interface FeeSplit {
+  recipients: PublicKey[]
+  shares: number[]
+  count: number
+  bump: number
+}
+
+export interface PositionOperator {
+  owner: PublicKey
+  pool: PublicKey
+  positionId: BN
+  operator: PublicKey
+  permissions: number
+  bump: number
+}
+
 export interface RawTick {
   pool: PublicKey
   index: number
@@ -2727,6 +4683,21 @@ export enum Errors {
   TickArrayAreTheSame = '0x137' // 11
 }
 
+export enum OperatorPermission {
+  ClaimFee = 1,
+  ChangeLiquidity = 1 << 1,
+  Remove = 1 << 2,
+  Compound = 1 << 3,
+  All = ClaimFee | ChangeLiquidity | Remove | Compound
+}
+
+export enum PauseFlag {
+  Swap = 1,
+  AddLiquidity = 1 << 1,
+  RemoveLiquidity = 1 << 2,
+  All = Swap | AddLiquidity | RemoveLiquidity
+}
+
 export interface CreatePosition {
   pair: Pair
   owner?: PublicKey
@@ -2739,20 +4710,35 @@ export interface CreatePosition {
   slippage: BN
 }
 
+export interface CreatePositionByAmounts extends Omit<CreatePosition, 'liquidityDelta'> {
+  maxAmountX: BN
+  maxAmountY: BN
+  minLiquidity: BN
+}
+
 export interface ChangeLiquidity {
   pair: Pair
-  knownPrice: BN
-  slippage: BN
+  owner: PublicKey
+  // signs in place of the owner, tokens still move from and to the owner accounts
+  operator?: PublicKey
   index: number
-  lowerTickIndex?: number
-  upperTickIndex?: number
   liquidityDelta: BN
-  addLiquidity: boolean
-  owner?: PublicKey
+  knownPrice: BN
+  slippage: BN
   accountX: PublicKey
   accountY: PublicKey
 }
 
+export interface IncreaseLiquidity extends ChangeLiquidity {
+  maxAmountX: BN
+  maxAmountY: BN
+}
+
+export interface DecreaseLiquidity extends ChangeLiquidity {
+  minAmountX: BN
+  minAmountY: BN
+}
+
 export interface CreatePoolAndPosition extends CreatePosition {
   initTick?: number
 }
@@ -2788,11 +4774,84 @@ export interface ClaimAllFeePosition {
 }
 
 export interface ClaimFee {
+  pair: Pair
+  owner?: PublicKey
+  // signs in place of the owner, fees are still sent to the owner accounts
+  operator?: PublicKey
+  userTokenX: PublicKey
+  userTokenY: PublicKey
+  index: number
+}
+export interface CompoundFees {
+  pair: Pair
+  owner?: PublicKey
+  // signs in place of the owner
+  operator?: PublicKey
+  index: number
+}
+export interface ClaimFeeBatch {
   pair: Pair
   owner?: PublicKey
   userTokenX: PublicKey
   userTokenY: PublicKey
+  // addresses of legacy or stable positions of the owner in the pool
+  positions: PublicKey[]
+}
+export interface ApprovePositionOperator {
+  owner?: PublicKey
+  position: PublicKey
+  operator: PublicKey
+  permissions: number
+}
+export interface RevokePositionOperator {
+  owner?: PublicKey
+  pool: PublicKey
+  id: BN
+  operator: PublicKey
+}
+export interface TokenizePosition {
+  owner: PublicKey
   index: number
+  payer?: PublicKey
+}
+export interface ClaimTokenizedFee {
+  pair: Pair
+  positionMint: PublicKey
+  owner?: PublicKey // holder of the position token
+  userTokenX: PublicKey
+  userTokenY: PublicKey
+}
+export interface RedeemPosition {
+  positionMint: PublicKey
+  holder?: PublicKey
+}
+export interface RangeOrder {
+  position: Position
+  xToY: boolean
+  settled: boolean
+  bump: number
+}
+export interface CreateRangeOrder {
+  pair: Pair
+  owner?: PublicKey
+  payer?: PublicKey
+  lowerTick: number // order spans a single tick spacing above it
+  liquidityDelta: BN
+  userTokenX: PublicKey
+  userTokenY: PublicKey
+}
+export interface SettleRangeOrder {
+  pair: Pair
+  owner: PublicKey
+  lowerTick: number
+  settler?: PublicKey
+}
+export interface ClaimRangeOrder {
+  pair: Pair
+  owner?: PublicKey
+  lowerTick: number
+  userTokenX: PublicKey
+  userTokenY: PublicKey
 }
 export interface Swap {
   pair: Pair
@@ -2805,6 +4864,61 @@ export interface Swap {
   accountY: PublicKey
   byAmountIn: boolean
   referralAccount?: PublicKey
+  minAmountOut?: BN
+  maxAmountIn?: BN
+  partialFill?: boolean // stop at the price limit instead of failing
+}
+
+export type QuoteSwap = Omit<
+  Swap,
+  'owner' | 'accountX' | 'accountY' | 'minAmountOut' | 'maxAmountIn'
+>
+
+export interface QuoteResult {
+  amountIn: BN
+  amountOut: BN
+  fee: BN
+  referralFee: BN
+  remainingAmount: BN
+  sqrtPrice: BN
+  crossedTicks: number[]
+}
+
+export interface SwapRoute {
+  route: Pair[]
+  owner?: PublicKey
+  tokenIn: PublicKey
+  accountIn: PublicKey
+  accountOut: PublicKey
+  amount: BN
+  byAmountIn: boolean
+  amountLimit: BN // minimum amount out or maximum amount in
+}
+
+export interface FlashSwap {
+  pair: Pair
+  owner?: PublicKey
+  xToY: boolean
+  amount: BN
+  byAmountIn: boolean
+  sqrtPriceLimit: BN
+  accountX: PublicKey
+  accountY: PublicKey
+  callbackProgram: PublicKey
+  callbackAccounts?: AccountMeta[] // passed to the callback along with crossed ticks
+  data?: Buffer // passed to the callback untouched
+}
+
+export interface FlashLoan {
+  pair: Pair
+  owner?: PublicKey
+  amountX: BN
+  amountY: BN
+  accountX: PublicKey
+  accountY: PublicKey
+  callbackProgram: PublicKey
+  callbackAccounts?: AccountMeta[]
+  data?: Buffer // passed to the callback untouched
 }
 
 export interface UpdateSecondsPerLiquidity {
@@ -2821,10 +4935,29 @@ export interface ChangeProtocolFee {
   admin?: PublicKey
   protocolFee: BN
 }
+export interface ChangePoolFee {
+  pair: Pair
+  admin?: PublicKey
+  fee: BN
+}
+export interface ClosePool {
+  pair: Pair
+  admin?: PublicKey
+  // accounts of the admin, required only when the reserve holds rounding dust
+  receiverAccountX?: PublicKey
+  receiverAccountY?: PublicKey
+}
 export interface CreateFeeTier {
   feeTier: FeeTier
   admin?: PublicKey
 }
+export interface ChangeFeeTier {
+  feeTier: FeeTier
+  disabled: boolean
+  minFee: BN
+  maxFee: BN
+  admin?: PublicKey
+}
 export interface CreateTick {
   pair: Pair
   index: number
@@ -2835,10 +4968,28 @@ export interface WithdrawProtocolFee {
   accountX: PublicKey
   accountY: PublicKey
   admin?: PublicKey
+  amountX?: BN
+  amountY?: BN
+}
+export interface ChangeFeeSplit {
+  recipients: PublicKey[]
+  // basis points summing up to 10000
+  shares: number[]
+  admin?: PublicKey
+}
+export interface DistributeProtocolFee {
+  pair: Pair
+  // token accounts of every fee split recipient in order
+  recipientAccounts: Array<{ accountX: PublicKey; accountY: PublicKey }>
+  admin?: PublicKey
+  amountX?: BN
+  amountY?: BN
 }
 export interface RemovePosition {
   pair: Pair
   owner: PublicKey
+  // signs in place of the owner, tokens are still sent to the owner accounts
+  operator?: PublicKey
   payer?: PublicKey
   index: number
   userTokenX: PublicKey
@@ -2849,6 +5000,23 @@ export interface TransferPositionOwnership {
   recipient?: PublicKey
   index: number
 }
+export interface MigratePosition {
+  owner?: PublicKey
+  index: number
+  payer?: PublicKey
+}
+
+export interface SetRole {
+  role: Role
+  holder: PublicKey
+  admin?: PublicKey
+}
+
+export interface ChangePoolPause {
+  pair: Pair
+  paused: number // PauseFlag bits
+  pauser?: PublicKey
+}
 
 export interface ChangeFeeReceiver {
   pair: Pair
@@ -2856,6 +5024,46 @@ export interface ChangeFeeReceiver {
   feeReceiver: PublicKey
 }
 
+export interface ChangePoolDefaults {
+  protocolFee: BN
+  feeReceiver: PublicKey
+  admin?: PublicKey
+}
+
+export interface ChangeFeeTierPoolDefaults {
+  feeTier: FeeTier
+  protocolFee?: BN | null
+  feeReceiver?: PublicKey | null
+  admin?: PublicKey
+}
+
+export interface CreateReferrer {
+  owner: PublicKey
+  feeShare: BN
+  admin?: PublicKey
+}
+
+export interface ChangeReferrer {
+  owner: PublicKey
+  feeShare: BN
+  enabled: boolean
+  admin?: PublicKey
+}
+
+export interface RawReferrer {
+  owner: PublicKey
+  feeShare: Decimal
+  enabled: boolean
+  bump: number
+}
+
+export interface Referrer {
+  owner: PublicKey
+  feeShare: BN
+  enabled: boolean
+  bump: number
+}
+
 export interface PositionInitData {
   lowerTick: number
   upperTick: number
@@ -2914,6 +5122,23 @@ export interface ClaimFeeCache {
   tokenYProgram?: PublicKey
 }
 
+export interface ClaimFeeBatchCache {
+  pool?: PoolStructure
+  tokenXProgram?: PublicKey
+  tokenYProgram?: PublicKey
+}
+
+export interface CompoundFeesCache {
+  position?: Position
+}
+
+export interface ChangeLiquidityCache {
+  position?: Position
+  pool?: PoolStructure
+  tokenXProgram?: PublicKey
+  tokenYProgram?: PublicKey
+}
+
 export interface WithdrawProtocolFeeCache {
   pool?: PoolStructure
   tokenXProgram?: PublicKey
@@ -2976,6 +5201,14 @@ export interface SwapEvent {
   fee: BN
   priceBeforeSwap: BN
   priceAfterSwap: BN
+  pool: PublicKey
+  amountIn: BN
+  amountOut: BN
+  referralFee: BN
+  liquidity: BN
+  currentTick: number
+  crossedTicks: number[]
+  remainingAmount: BN
 }
 type TickAddresses = {
   tickAddresses: PublicKey[]
@@ -121,7 +121,9 @@ export enum INVARIANT_ERRORS {
   INVALID_POOL_LIQUIDITY = '0x177c',
   INVALID_POSITION_INDEX = '0x177d',
   POSITION_WITHOUT_LIQUIDITY = '0x177e',
+  UNAUTHORIZED = '0x177f',
   INVALID_POOL_TOKEN_ADDRESSES = '0x1780',
+  INVALID_PROTOCOL_FEE = '0x1784',
   NO_GAIN_SWAP = '0x1785',
   INVALID_TOKEN_ACCOUNT = '0x1786',
   INVALID_ADMIN = '0x1787',
@@ -133,7 +135,24 @@ export enum INVARIANT_ERRORS {
   INVALID_LIST_OWNER = '0x178d',
   INVALID_TICK_SPACING = '0x178e',
   DISABLED_POOL = '0x1791',
-  UNSUPPORTED_EXTENSION = '0x1792'
+  UNSUPPORTED_EXTENSION = '0x1792',
+  ORACLE_NOT_FOUND = '0x1794',
+  AMOUNT_OUT_BELOW_MINIMUM = '0x1798',
+  AMOUNT_IN_ABOVE_MAXIMUM = '0x1799',
+  INVALID_CALLBACK_PROGRAM = '0x179b',
+  INSUFFICIENT_REPAYMENT = '0x179c',
+  RANGE_ORDER_NOT_FILLED = '0x179f',
+  RANGE_ORDER_SETTLED = '0x17a0',
+  RANGE_ORDER_NOT_SETTLED = '0x17a1',
+  LIQUIDITY_BELOW_MINIMUM = '0x17a4',
+  POOL_NOT_EMPTY = '0x17a6',
+  OPERATION_PAUSED = '0x17a8',
+  INVALID_PAUSE_FLAGS = '0x17a9',
+  FEE_TIER_DISABLED = '0x17aa',
+  FEE_OUT_OF_BOUNDS = '0x17ab',
+  INVALID_FEE_SPLIT = '0x17ac',
+  INSUFFICIENT_PROTOCOL_FEE = '0x17ad',
+  ALREADY_MIGRATED = '0x17af'
 }
 
 export interface SimulateSwapPrice {
@@ -2151,7 +2170,8 @@ export const parseEvent = (
       return {
         ...raw,
         priceBeforeSwap: (raw.priceBeforeSwap as any).v,
-        priceAfterSwap: (raw.priceAfterSwap as any).v
+        priceAfterSwap: (raw.priceAfterSwap as any).v,
+        liquidity: (raw.liquidity as any).v
       } as SwapEvent
     default:
       throw new Error('Invalid event name')
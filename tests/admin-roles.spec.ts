@@ -0,0 +1,99 @@
+import * as anchor from '@coral-xyz/anchor'
+import { AnchorProvider, BN } from '@coral-xyz/anchor'
+import { Keypair } from '@solana/web3.js'
+import { assert } from 'chai'
+import { assertThrowsAsync } from './testUtils'
+import { Market, Network, sleep, INVARIANT_ERRORS } from '@invariant-labs/sdk'
+import { fromFee } from '@invariant-labs/sdk/src/utils'
+import { DEFAULT_PUBLIC_KEY, FeeTier, Role } from '@invariant-labs/sdk/src/market'
+
+describe('admin roles', () => {
+  const provider = AnchorProvider.local()
+  const connection = provider.connection
+  const admin = Keypair.generate()
+  const newAdmin = Keypair.generate()
+  const outsider = Keypair.generate()
+  const feeTier: FeeTier = { fee: fromFee(new BN(600)), tickSpacing: 10 }
+  let market: Market
+
+  before(async () => {
+    market = await Market.build(
+      Network.LOCAL,
+      provider.wallet,
+      connection,
+      anchor.workspace.Invariant.programId
+    )
+
+    await Promise.all([
+      connection.requestAirdrop(admin.publicKey, 1e10),
+      connection.requestAirdrop(newAdmin.publicKey, 1e10),
+      connection.requestAirdrop(outsider.publicKey, 1e10)
+    ])
+    await sleep(500)
+
+    await market.createState(admin.publicKey, admin)
+  })
+
+  it('#createState() assigns every role to the admin', async () => {
+    const state = await market.getState()
+    assert.ok(state.feeTierManager.equals(admin.publicKey))
+    assert.ok(state.pauser.equals(admin.publicKey))
+    assert.ok(state.protocolFeeManager.equals(admin.publicKey))
+    assert.ok(state.pendingAdmin.equals(DEFAULT_PUBLIC_KEY))
+  })
+
+  it('#proposeAdmin()', async () => {
+    await assertThrowsAsync(
+      market.proposeAdmin(outsider.publicKey, outsider),
+      INVARIANT_ERRORS.INVALID_ADMIN
+    )
+
+    await market.proposeAdmin(newAdmin.publicKey, admin)
+
+    const state = await market.getState()
+    assert.ok(state.pendingAdmin.equals(newAdmin.publicKey))
+    assert.ok(state.admin.equals(admin.publicKey))
+  })
+
+  it('#acceptAdmin() only by the pending admin', async () => {
+    await assertThrowsAsync(market.acceptAdmin(outsider), INVARIANT_ERRORS.INVALID_ADMIN)
+    await assertThrowsAsync(market.acceptAdmin(admin), INVARIANT_ERRORS.INVALID_ADMIN)
+
+    await market.acceptAdmin(newAdmin)
+
+    const state = await market.getState()
+    assert.ok(state.admin.equals(newAdmin.publicKey))
+    assert.ok(state.pendingAdmin.equals(DEFAULT_PUBLIC_KEY))
+
+    // transfer is consumed and the previous admin lost its rights
+    await assertThrowsAsync(market.acceptAdmin(newAdmin), INVARIANT_ERRORS.INVALID_ADMIN)
+    await assertThrowsAsync(
+      market.proposeAdmin(admin.publicKey, admin),
+      INVARIANT_ERRORS.INVALID_ADMIN
+    )
+  })
+
+  it('#setRole()', async () => {
+    await assertThrowsAsync(
+      market.setRole(
+        { role: Role.FeeTierManager, holder: admin.publicKey, admin: admin.publicKey },
+        admin
+      ),
+      INVARIANT_ERRORS.INVALID_ADMIN
+    )
+
+    await market.setRole(
+      { role: Role.FeeTierManager, holder: outsider.publicKey, admin: newAdmin.publicKey },
+      newAdmin
+    )
+    assert.ok((await market.getState()).feeTierManager.equals(outsider.publicKey))
+
+    // role is checked instead of the admin, who no longer holds it
+    await assertThrowsAsync(
+      market.createFeeTier({ feeTier, admin: admin.publicKey }, admin),
+      INVARIANT_ERRORS.INVALID_ADMIN
+    )
+    await market.createFeeTier({ feeTier, admin: outsider.publicKey }, outsider)
+    assert.ok((await market.getFeeTier(feeTier)).fee.eq(feeTier.fee))
+  })
+})
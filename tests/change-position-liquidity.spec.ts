@@ -0,0 +1,251 @@
+import * as anchor from '@coral-xyz/anchor'
+import { AnchorProvider, BN } from '@coral-xyz/anchor'
+import { Keypair, PublicKey } from '@solana/web3.js'
+import { assert } from 'chai'
+import { approve } from '@solana/spl-token'
+import { assertThrowsAsync, createTokensAndPool, createUserWithTokens } from './testUtils'
+import {
+  Market,
+  Network,
+  Pair,
+  sleep,
+  calculatePriceSqrt,
+  INVARIANT_ERRORS
+} from '@invariant-labs/sdk'
+import { getBalance } from '@invariant-labs/sdk/src/utils'
+import {
+  CreatePosition,
+  DecreaseLiquidity,
+  IncreaseLiquidity,
+  OperatorPermission,
+  PauseFlag
+} from '@invariant-labs/sdk/src/market'
+
+describe('change position liquidity', () => {
+  const provider = AnchorProvider.local()
+  const connection = provider.connection
+  const admin = Keypair.generate()
+  const operator = Keypair.generate()
+  const liquidityDelta = new BN(10).pow(new BN(12))
+  let market: Market
+  let pair: Pair
+  let poolAddress: PublicKey
+  let owner: Keypair
+  let userAccountX: PublicKey
+  let userAccountY: PublicKey
+
+  const getBalances = async () => ({
+    x: await getBalance(connection, userAccountX),
+    y: await getBalance(connection, userAccountY)
+  })
+
+  const increaseLiquidityVars = (maxAmountX: BN, maxAmountY: BN): IncreaseLiquidity => ({
+    pair,
+    owner: owner.publicKey,
+    index: 0,
+    liquidityDelta,
+    knownPrice: calculatePriceSqrt(0),
+    slippage: new BN(0),
+    accountX: userAccountX,
+    accountY: userAccountY,
+    maxAmountX,
+    maxAmountY
+  })
+
+  const decreaseLiquidityVars = (minAmountX: BN, minAmountY: BN): DecreaseLiquidity => ({
+    pair,
+    owner: owner.publicKey,
+    index: 0,
+    liquidityDelta,
+    knownPrice: calculatePriceSqrt(0),
+    slippage: new BN(0),
+    accountX: userAccountX,
+    accountY: userAccountY,
+    minAmountX,
+    minAmountY
+  })
+
+  before(async () => {
+    market = await Market.build(
+      Network.LOCAL,
+      provider.wallet,
+      connection,
+      anchor.workspace.Invariant.programId
+    )
+
+    await Promise.all([
+      connection.requestAirdrop(admin.publicKey, 1e12),
+      connection.requestAirdrop(operator.publicKey, 1e9)
+    ])
+    await sleep(500)
+
+    await market.createState(admin.publicKey, admin)
+
+    const createdPool = await createTokensAndPool(market, connection, admin)
+    pair = createdPool.pair
+    poolAddress = pair.getAddress(market.program.programId)
+    const user = await createUserWithTokens(
+      pair,
+      connection,
+      createdPool.mintAuthority,
+      new BN(10).pow(new BN(12))
+    )
+    owner = user.owner
+    userAccountX = user.userAccountX
+    userAccountY = user.userAccountY
+
+    const createPositionVars: CreatePosition = {
+      pair,
+      owner: owner.publicKey,
+      userTokenX: userAccountX,
+      userTokenY: userAccountY,
+      lowerTick: -1000,
+      upperTick: 1000,
+      liquidityDelta,
+      knownPrice: calculatePriceSqrt(0),
+      slippage: new BN(0)
+    }
+    await market.createPositionList(owner.publicKey, owner)
+    await market.createPosition(createPositionVars, owner)
+  })
+
+  it('#increaseLiquidity() within maximum amounts', async () => {
+    const balancesBefore = await getBalances()
+    await market.increaseLiquidity(increaseLiquidityVars(balancesBefore.x, balancesBefore.y), owner)
+    const balancesAfter = await getBalances()
+    const amountX = balancesBefore.x.sub(balancesAfter.x)
+    const amountY = balancesBefore.y.sub(balancesAfter.y)
+    assert.ok(amountX.gtn(0) && amountY.gtn(0))
+    assert.ok((await market.getPosition(owner.publicKey, 0)).liquidity.eq(liquidityDelta.muln(2)))
+
+    // the same change costs the same amounts, so one token less is not enough
+    await assertThrowsAsync(
+      market.increaseLiquidity(increaseLiquidityVars(amountX.subn(1), amountY), owner),
+      INVARIANT_ERRORS.AMOUNT_IN_ABOVE_MAXIMUM
+    )
+    await assertThrowsAsync(
+      market.increaseLiquidity(increaseLiquidityVars(amountX, amountY.subn(1)), owner),
+      INVARIANT_ERRORS.AMOUNT_IN_ABOVE_MAXIMUM
+    )
+
+    await market.increaseLiquidity(increaseLiquidityVars(amountX, amountY), owner)
+    const balancesFinal = await getBalances()
+    assert.ok(balancesAfter.x.sub(balancesFinal.x).eq(amountX))
+    assert.ok(balancesAfter.y.sub(balancesFinal.y).eq(amountY))
+    assert.ok((await market.getPosition(owner.publicKey, 0)).liquidity.eq(liquidityDelta.muln(3)))
+  })
+
+  it('#decreaseLiquidity() within minimum amounts', async () => {
+    const balancesBefore = await getBalances()
+    await market.decreaseLiquidity(decreaseLiquidityVars(new BN(0), new BN(0)), owner)
+    const balancesAfter = await getBalances()
+    const amountX = balancesAfter.x.sub(balancesBefore.x)
+    const amountY = balancesAfter.y.sub(balancesBefore.y)
+    assert.ok(amountX.gtn(0) && amountY.gtn(0))
+    assert.ok((await market.getPosition(owner.publicKey, 0)).liquidity.eq(liquidityDelta.muln(2)))
+
+    await assertThrowsAsync(
+      market.decreaseLiquidity(decreaseLiquidityVars(amountX.addn(1), amountY), owner),
+      INVARIANT_ERRORS.AMOUNT_OUT_BELOW_MINIMUM
+    )
+    await assertThrowsAsync(
+      market.decreaseLiquidity(decreaseLiquidityVars(amountX, amountY.addn(1)), owner),
+      INVARIANT_ERRORS.AMOUNT_OUT_BELOW_MINIMUM
+    )
+
+    await market.decreaseLiquidity(decreaseLiquidityVars(amountX, amountY), owner)
+    const balancesFinal = await getBalances()
+    assert.ok(balancesFinal.x.sub(balancesAfter.x).eq(amountX))
+    assert.ok(balancesFinal.y.sub(balancesAfter.y).eq(amountY))
+    assert.ok((await market.getPosition(owner.publicKey, 0)).liquidity.eq(liquidityDelta))
+  })
+
+  it('#increaseLiquidity() and #decreaseLiquidity() by operator', async () => {
+    const { positionAddress } = market.getPositionAddress(owner.publicKey, 0)
+    const position = await market.getPosition(owner.publicKey, 0)
+    const { x: maxAmountX, y: maxAmountY } = await getBalances()
+    const increaseVars = {
+      ...increaseLiquidityVars(maxAmountX, maxAmountY),
+      operator: operator.publicKey
+    }
+    const decreaseVars = {
+      ...decreaseLiquidityVars(new BN(0), new BN(0)),
+      operator: operator.publicKey
+    }
+    // operator adds liquidity from the owner accounts as their delegate
+    await approve(connection, owner, userAccountX, operator.publicKey, owner, 1e12)
+    await approve(connection, owner, userAccountY, operator.publicKey, owner, 1e12)
+
+    await assertThrowsAsync(market.increaseLiquidity(increaseVars, operator))
+
+    await market.approvePositionOperator(
+      {
+        owner: owner.publicKey,
+        position: positionAddress,
+        operator: operator.publicKey,
+        permissions: OperatorPermission.ClaimFee
+      },
+      owner
+    )
+    await assertThrowsAsync(
+      market.increaseLiquidity(increaseVars, operator),
+      INVARIANT_ERRORS.UNAUTHORIZED
+    )
+    await assertThrowsAsync(
+      market.decreaseLiquidity(decreaseVars, operator),
+      INVARIANT_ERRORS.UNAUTHORIZED
+    )
+
+    await market.revokePositionOperator(
+      { owner: owner.publicKey, pool: poolAddress, id: position.id, operator: operator.publicKey },
+      owner
+    )
+    await market.approvePositionOperator(
+      {
+        owner: owner.publicKey,
+        position: positionAddress,
+        operator: operator.publicKey,
+        permissions: OperatorPermission.ChangeLiquidity
+      },
+      owner
+    )
+
+    await market.increaseLiquidity(increaseVars, operator)
+    assert.ok((await market.getPosition(owner.publicKey, 0)).liquidity.eq(liquidityDelta.muln(2)))
+
+    // tokens are sent to the owner
+    const balancesBefore = await getBalances()
+    await market.decreaseLiquidity(decreaseVars, operator)
+    const balancesAfter = await getBalances()
+    assert.ok(balancesAfter.x.gt(balancesBefore.x))
+    assert.ok(balancesAfter.y.gt(balancesBefore.y))
+    assert.ok((await market.getPosition(owner.publicKey, 0)).liquidity.eq(liquidityDelta))
+  })
+
+  for (const level of ['state', 'pool'] as const) {
+    it(`${level} pause of liquidity changes`, async () => {
+      const setPause = async (paused: number) =>
+        level === 'state'
+          ? market.changePause(paused, admin)
+          : market.changePoolPause({ pair, paused, pauser: admin.publicKey }, admin)
+      const { x: maxAmountX, y: maxAmountY } = await getBalances()
+
+      await setPause(PauseFlag.RemoveLiquidity)
+      await assertThrowsAsync(
+        market.decreaseLiquidity(decreaseLiquidityVars(new BN(0), new BN(0)), owner),
+        INVARIANT_ERRORS.OPERATION_PAUSED
+      )
+      await market.increaseLiquidity(increaseLiquidityVars(maxAmountX, maxAmountY), owner)
+
+      await setPause(PauseFlag.AddLiquidity)
+      await assertThrowsAsync(
+        market.increaseLiquidity(increaseLiquidityVars(maxAmountX, maxAmountY), owner),
+        INVARIANT_ERRORS.OPERATION_PAUSED
+      )
+      await market.decreaseLiquidity(decreaseLiquidityVars(new BN(0), new BN(0)), owner)
+
+      await setPause(0)
+      assert.ok((await market.getPosition(owner.publicKey, 0)).liquidity.eq(liquidityDelta))
+    })
+  }
+})
@@ -0,0 +1,244 @@
+import * as anchor from '@coral-xyz/anchor'
+import { AnchorProvider, BN } from '@coral-xyz/anchor'
+import { Keypair, PublicKey, Transaction } from '@solana/web3.js'
+import { assert } from 'chai'
+import { TOKEN_2022_PROGRAM_ID, TOKEN_PROGRAM_ID } from '@solana/spl-token'
+import { assertThrowsAsync, createToken, createUserWithTokens, initMarket } from './testUtils'
+import {
+  Market,
+  Network,
+  Pair,
+  sleep,
+  calculatePriceSqrt,
+  signAndSend,
+  INVARIANT_ERRORS
+} from '@invariant-labs/sdk'
+import { FEE_TIERS, getBalance, toDecimal } from '@invariant-labs/sdk/src/utils'
+import { ClaimFeeBatch } from '@invariant-labs/sdk/src/market'
+
+interface TestPool {
+  pair: Pair
+  // token accounts of the owner and of the twin holding identical positions
+  accounts: Map<Keypair, { accountX: PublicKey; accountY: PublicKey }>
+  indexes: number[]
+  tokenProgram: PublicKey
+}
+
+describe('claim fee batch', () => {
+  const provider = AnchorProvider.local()
+  const connection = provider.connection
+  const admin = Keypair.generate()
+  const owner = Keypair.generate()
+  const twin = Keypair.generate()
+  const ranges = [
+    [-1000, 1000],
+    [-200, 200]
+  ]
+  let market: Market
+  let splPool: TestPool
+  let token2022Pool: TestPool
+
+  const setupPool = async (isToken2022: boolean, firstIndex: number): Promise<TestPool> => {
+    const mintAuthority = Keypair.generate()
+    await connection.requestAirdrop(mintAuthority.publicKey, 1e9)
+    await sleep(500)
+
+    const [token0, token1] = await Promise.all([
+      createToken(connection, admin, mintAuthority, 6, null, isToken2022),
+      createToken(connection, admin, mintAuthority, 6, null, isToken2022)
+    ])
+    const pair = new Pair(token0, token1, FEE_TIERS[5])
+    await initMarket(market, [pair], admin)
+
+    const accounts = new Map<Keypair, { accountX: PublicKey; accountY: PublicKey }>()
+    for (const user of [owner, twin]) {
+      const { userAccountX, userAccountY } = await createUserWithTokens(
+        pair,
+        connection,
+        mintAuthority,
+        new BN(10).pow(new BN(12)),
+        user
+      )
+      accounts.set(user, { accountX: userAccountX, accountY: userAccountY })
+
+      for (const [lowerTick, upperTick] of ranges) {
+        await market.createPosition(
+          {
+            pair,
+            owner: user.publicKey,
+            userTokenX: userAccountX,
+            userTokenY: userAccountY,
+            lowerTick,
+            upperTick,
+            liquidityDelta: new BN(10).pow(new BN(14)),
+            knownPrice: calculatePriceSqrt(0),
+            slippage: new BN(0)
+          },
+          user
+        )
+      }
+    }
+
+    // generate fees in both tokens
+    const swapper = await createUserWithTokens(pair, connection, mintAuthority)
+    for (const xToY of [true, false]) {
+      await market.swap(
+        {
+          pair,
+          xToY,
+          owner: swapper.owner.publicKey,
+          amount: new BN(1e6),
+          estimatedPriceAfterSwap: (await market.getPool(pair)).sqrtPrice,
+          slippage: toDecimal(1, 1),
+          accountX: swapper.userAccountX,
+          accountY: swapper.userAccountY,
+          byAmountIn: true
+        },
+        swapper.owner
+      )
+    }
+
+    return {
+      pair,
+      accounts,
+      indexes: ranges.map((_, i) => firstIndex + i),
+      tokenProgram: isToken2022 ? TOKEN_2022_PROGRAM_ID : TOKEN_PROGRAM_ID
+    }
+  }
+
+  const claimFeeBatchVars = (pool: TestPool, positions: PublicKey[]): ClaimFeeBatch => ({
+    pair: pool.pair,
+    owner: owner.publicKey,
+    userTokenX: pool.accounts.get(owner)!.accountX,
+    userTokenY: pool.accounts.get(owner)!.accountY,
+    positions
+  })
+
+  const getBalances = async (pool: TestPool, user: Keypair) => {
+    const { accountX, accountY } = pool.accounts.get(user)!
+    return {
+      x: await getBalance(connection, accountX, pool.tokenProgram),
+      y: await getBalance(connection, accountY, pool.tokenProgram)
+    }
+  }
+
+  // twin claims every position on its own, so both have to receive the same fees
+  const assertBatchMatchesClaims = async (pool: TestPool) => {
+    const positions = pool.indexes.map(
+      index => market.getPositionAddress(owner.publicKey, index).positionAddress
+    )
+    const [ownerBefore, twinBefore] = await Promise.all([
+      getBalances(pool, owner),
+      getBalances(pool, twin)
+    ])
+
+    await market.claimFeeBatch(claimFeeBatchVars(pool, positions), owner)
+    for (const index of pool.indexes) {
+      const { accountX, accountY } = pool.accounts.get(twin)!
+      await market.claimFee(
+        {
+          pair: pool.pair,
+          owner: twin.publicKey,
+          userTokenX: accountX,
+          userTokenY: accountY,
+          index
+        },
+        twin
+      )
+    }
+
+    const [ownerAfter, twinAfter] = await Promise.all([
+      getBalances(pool, owner),
+      getBalances(pool, twin)
+    ])
+    const claimedX = ownerAfter.x.sub(ownerBefore.x)
+    const claimedY = ownerAfter.y.sub(ownerBefore.y)
+    assert.ok(claimedX.gtn(0) && claimedY.gtn(0))
+    assert.ok(claimedX.eq(twinAfter.x.sub(twinBefore.x)))
+    assert.ok(claimedY.eq(twinAfter.y.sub(twinBefore.y)))
+
+    for (const index of pool.indexes) {
+      const position = await market.getPosition(owner.publicKey, index)
+      const twinPosition = await market.getPosition(twin.publicKey, index)
+      assert.ok(position.feeGrowthInsideX.eq(twinPosition.feeGrowthInsideX))
+      assert.ok(position.feeGrowthInsideY.eq(twinPosition.feeGrowthInsideY))
+      assert.ok(position.tokensOwedX.eq(twinPosition.tokensOwedX))
+      assert.ok(position.tokensOwedY.eq(twinPosition.tokensOwedY))
+    }
+  }
+
+  before(async () => {
+    market = await Market.build(
+      Network.LOCAL,
+      provider.wallet,
+      connection,
+      anchor.workspace.Invariant.programId
+    )
+
+    await Promise.all([
+      connection.requestAirdrop(admin.publicKey, 1e12),
+      connection.requestAirdrop(owner.publicKey, 1e9),
+      connection.requestAirdrop(twin.publicKey, 1e9)
+    ])
+    await sleep(500)
+
+    await market.createState(admin.publicKey, admin)
+    await market.createPositionList(owner.publicKey, owner)
+    await market.createPositionList(twin.publicKey, twin)
+
+    splPool = await setupPool(false, 0)
+    token2022Pool = await setupPool(true, ranges.length)
+  })
+
+  it('#claimFeeBatch() matches claims of single positions', async () => {
+    await assertBatchMatchesClaims(splPool)
+  })
+
+  it('#claimFeeBatch() of a foreign position fails', async () => {
+    const { positionAddress } = market.getPositionAddress(owner.publicKey, splPool.indexes[0])
+    const { positionAddress: foreignAddress } = market.getPositionAddress(
+      twin.publicKey,
+      splPool.indexes[0]
+    )
+
+    await assertThrowsAsync(
+      market.claimFeeBatch(claimFeeBatchVars(splPool, [positionAddress, foreignAddress]), owner),
+      INVARIANT_ERRORS.INVALID_OWNER
+    )
+  })
+
+  it('#claimFeeBatch() of a position from another pool fails', async () => {
+    const { positionAddress } = market.getPositionAddress(owner.publicKey, splPool.indexes[0])
+    const { positionAddress: otherPoolAddress } = market.getPositionAddress(
+      owner.publicKey,
+      token2022Pool.indexes[0]
+    )
+
+    await assertThrowsAsync(
+      market.claimFeeBatch(claimFeeBatchVars(splPool, [positionAddress, otherPoolAddress]), owner),
+      INVARIANT_ERRORS.INVALID_POSITION_INDEX
+    )
+  })
+
+  it('#claimFeeBatch() with a mismatched tick fails', async () => {
+    const [wideIndex, narrowIndex] = splPool.indexes
+    const { positionAddress } = market.getPositionAddress(owner.publicKey, wideIndex)
+    const narrowPosition = await market.getPosition(owner.publicKey, narrowIndex)
+
+    // upper tick of another position of the owner closes the triple
+    const ix = await market.claimFeeBatchIx(claimFeeBatchVars(splPool, [positionAddress]))
+    ix.keys[ix.keys.length - 1].pubkey = market.getTickAddress(
+      splPool.pair,
+      narrowPosition.upperTickIndex
+    ).tickAddress
+
+    await assertThrowsAsync(
+      signAndSend(new Transaction().add(ix), [owner], connection),
+      INVARIANT_ERRORS.WRONG_TICK
+    )
+  })
+
+  it('#claimFeeBatch() on token 2022 pair', async () => {
+    await assertBatchMatchesClaims(token2022Pool)
+  })
+})
@@ -0,0 +1,206 @@
+import * as anchor from '@coral-xyz/anchor'
+import { AnchorProvider, BN } from '@coral-xyz/anchor'
+import { Keypair, PublicKey } from '@solana/web3.js'
+import { assert } from 'chai'
+import { transfer } from '@solana/spl-token'
+import { assertThrowsAsync, createTokensAndPool, createUserWithTokens } from './testUtils'
+import {
+  Market,
+  Network,
+  Pair,
+  sleep,
+  calculatePriceSqrt,
+  INVARIANT_ERRORS
+} from '@invariant-labs/sdk'
+import { getBalance } from '@invariant-labs/sdk/src/utils'
+import { ClosePool, CreatePosition, RemovePosition } from '@invariant-labs/sdk/src/market'
+
+describe('close pool', () => {
+  const provider = AnchorProvider.local()
+  const connection = provider.connection
+  const admin = Keypair.generate()
+  let market: Market
+
+  // pool with a single position of the owner and token accounts of the admin receiving the dust
+  const setupPool = async () => {
+    const { pair, mintAuthority } = await createTokensAndPool(market, connection, admin)
+    const user = await createUserWithTokens(pair, connection, mintAuthority)
+    const { userAccountX: adminAccountX, userAccountY: adminAccountY } =
+      await createUserWithTokens(pair, connection, mintAuthority, new BN(1e9), admin)
+
+    const createPositionVars: CreatePosition = {
+      pair,
+      owner: user.owner.publicKey,
+      userTokenX: user.userAccountX,
+      userTokenY: user.userAccountY,
+      lowerTick: -11,
+      upperTick: 13,
+      liquidityDelta: new BN(10).pow(new BN(11)).addn(7),
+      knownPrice: calculatePriceSqrt(0),
+      slippage: new BN(0)
+    }
+    await market.createPositionList(user.owner.publicKey, user.owner)
+    await market.createPosition(createPositionVars, user.owner)
+
+    const closePoolVars: ClosePool = {
+      pair,
+      admin: admin.publicKey,
+      receiverAccountX: adminAccountX,
+      receiverAccountY: adminAccountY
+    }
+    return { pair, mintAuthority, user, closePoolVars }
+  }
+
+  const removePosition = async (
+    pair: Pair,
+    user: { owner: Keypair; userAccountX: PublicKey; userAccountY: PublicKey }
+  ) => {
+    const removePositionVars: RemovePosition = {
+      pair,
+      owner: user.owner.publicKey,
+      index: 0,
+      userTokenX: user.userAccountX,
+      userTokenY: user.userAccountY
+    }
+    await market.removePosition(removePositionVars, user.owner)
+  }
+
+  before(async () => {
+    market = await Market.build(
+      Network.LOCAL,
+      provider.wallet,
+      connection,
+      anchor.workspace.Invariant.programId
+    )
+
+    await connection.requestAirdrop(admin.publicKey, 1e12)
+    await sleep(500)
+
+    await market.createState(admin.publicKey, admin)
+  })
+
+  it('#closePool() sweeps reserve dust to the admin', async () => {
+    const { pair, user, closePoolVars } = await setupPool()
+    const pool = await market.getPool(pair)
+
+    await assertThrowsAsync(market.closePool(closePoolVars, admin), INVARIANT_ERRORS.POOL_NOT_EMPTY)
+    await removePosition(pair, user)
+
+    // dust can not be sent to accounts of anyone but the admin
+    await assertThrowsAsync(
+      market.closePool(
+        {
+          ...closePoolVars,
+          receiverAccountX: user.userAccountX,
+          receiverAccountY: user.userAccountY
+        },
+        admin
+      ),
+      INVARIANT_ERRORS.INVALID_OWNER
+    )
+
+    // deposits round up and withdrawals round down, so dust stays in the reserves
+    const dust = await market.getReserveBalances(pair)
+    const balanceXBefore = await getBalance(connection, closePoolVars.receiverAccountX!)
+    const balanceYBefore = await getBalance(connection, closePoolVars.receiverAccountY!)
+    const lamportsBefore = await connection.getBalance(admin.publicKey)
+
+    await market.closePool(closePoolVars, admin)
+
+    assert.ok(
+      (await getBalance(connection, closePoolVars.receiverAccountX!)).eq(balanceXBefore.add(dust.x))
+    )
+    assert.ok(
+      (await getBalance(connection, closePoolVars.receiverAccountY!)).eq(balanceYBefore.add(dust.y))
+    )
+    assert.ok((await connection.getBalance(admin.publicKey)) > lamportsBefore)
+    for (const address of [
+      pair.getAddress(market.program.programId),
+      pool.tickmap,
+      pool.tokenXReserve,
+      pool.tokenYReserve
+    ]) {
+      assert.isNull(await connection.getAccountInfo(address))
+    }
+  })
+
+  it('#closePool() with more than dust in the reserves fails', async () => {
+    const { pair, user, closePoolVars } = await setupPool()
+    const pool = await market.getPool(pair)
+    await removePosition(pair, user)
+
+    // tokens above the dust threshold are not swept, even if they were sent to the reserve directly
+    await transfer(connection, user.owner, user.userAccountX, pool.tokenXReserve, user.owner, 1001)
+    await assertThrowsAsync(market.closePool(closePoolVars, admin), INVARIANT_ERRORS.POOL_NOT_EMPTY)
+  })
+
+  it('#closePool() with an unclaimed range order fails', async () => {
+    const { pair, mintAuthority, user, closePoolVars } = await setupPool()
+    const lowerTick = 20
+    await removePosition(pair, user)
+
+    await market.createRangeOrder(
+      {
+        pair,
+        owner: user.owner.publicKey,
+        lowerTick,
+        liquidityDelta: new BN(10).pow(new BN(12)),
+        userTokenX: user.userAccountX,
+        userTokenY: user.userAccountY
+      },
+      user.owner
+    )
+
+    // fill the order by moving the price past its upper tick and settle it
+    const swapper = await createUserWithTokens(pair, connection, mintAuthority)
+    await market.swap(
+      {
+        pair,
+        xToY: false,
+        owner: swapper.owner.publicKey,
+        amount: new BN(1e8),
+        estimatedPriceAfterSwap: calculatePriceSqrt(lowerTick + 20),
+        slippage: new BN(0),
+        accountX: swapper.userAccountX,
+        accountY: swapper.userAccountY,
+        byAmountIn: true,
+        partialFill: true
+      },
+      swapper.owner
+    )
+    await market.settleRangeOrder(
+      { pair, owner: user.owner.publicKey, lowerTick, settler: admin.publicKey },
+      admin
+    )
+    await market.withdrawProtocolFee(
+      {
+        pair,
+        accountX: closePoolVars.receiverAccountX!,
+        accountY: closePoolVars.receiverAccountY!,
+        admin: admin.publicKey
+      },
+      admin
+    )
+
+    // the settled order is out of the tickmap, but its tokens are still owed to the owner
+    const pool = await market.getPool(pair)
+    assert.ok(pool.liquidity.eqn(0))
+    assert.equal(pool.openRangeOrders, 1)
+    await assertThrowsAsync(market.closePool(closePoolVars, admin), INVARIANT_ERRORS.POOL_NOT_EMPTY)
+
+    await market.claimRangeOrder(
+      {
+        pair,
+        owner: user.owner.publicKey,
+        lowerTick,
+        userTokenX: user.userAccountX,
+        userTokenY: user.userAccountY
+      },
+      user.owner
+    )
+    assert.equal((await market.getPool(pair)).openRangeOrders, 0)
+
+    await market.closePool(closePoolVars, admin)
+    assert.isNull(await connection.getAccountInfo(pair.getAddress(market.program.programId)))
+  })
+})
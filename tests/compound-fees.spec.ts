@@ -0,0 +1,228 @@
+import * as anchor from '@coral-xyz/anchor'
+import { AnchorProvider, BN } from '@coral-xyz/anchor'
+import { Keypair, PublicKey, TransactionSignature } from '@solana/web3.js'
+import { assert } from 'chai'
+import { assertThrowsAsync, createTokensAndPool, createUserWithTokens } from './testUtils'
+import {
+  Market,
+  Network,
+  Pair,
+  sleep,
+  calculatePriceSqrt,
+  INVARIANT_ERRORS
+} from '@invariant-labs/sdk'
+import { FEE_TIERS, getBalance, toDecimal } from '@invariant-labs/sdk/src/utils'
+import { OperatorPermission } from '@invariant-labs/sdk/src/market'
+
+describe('compound fees', () => {
+  const provider = AnchorProvider.local()
+  const connection = provider.connection
+  const admin = Keypair.generate()
+  const operator = Keypair.generate()
+  let market: Market
+  let pair: Pair
+  let poolAddress: PublicKey
+  let mintAuthority: Keypair
+  // owner compounds, twin holds an identical position and claims its fees instead
+  const users: Array<{ owner: Keypair; userAccountX: PublicKey; userAccountY: PublicKey }> = []
+
+  const generateFees = async () => {
+    const swapper = await createUserWithTokens(pair, connection, mintAuthority)
+    for (const xToY of [true, false]) {
+      await market.swap(
+        {
+          pair,
+          xToY,
+          owner: swapper.owner.publicKey,
+          amount: new BN(1e6),
+          estimatedPriceAfterSwap: (await market.getPool(pair)).sqrtPrice,
+          slippage: toDecimal(1, 1),
+          accountX: swapper.userAccountX,
+          accountY: swapper.userAccountY,
+          byAmountIn: true
+        },
+        swapper.owner
+      )
+    }
+  }
+
+  const getBalances = async ({ userAccountX, userAccountY }: (typeof users)[number]) => ({
+    x: await getBalance(connection, userAccountX),
+    y: await getBalance(connection, userAccountY)
+  })
+
+  const claimFee = async (user: (typeof users)[number]) => {
+    const before = await getBalances(user)
+    await market.claimFee(
+      {
+        pair,
+        owner: user.owner.publicKey,
+        userTokenX: user.userAccountX,
+        userTokenY: user.userAccountY,
+        index: 0
+      },
+      user.owner
+    )
+    const after = await getBalances(user)
+    return { x: after.x.sub(before.x), y: after.y.sub(before.y) }
+  }
+
+  const getCompoundFeesEvent = async (signature: TransactionSignature) => {
+    const transaction = await connection.getParsedTransaction(signature, 'confirmed')
+    const event = (transaction?.meta?.logMessages ?? [])
+      .filter(log => log.startsWith('Program data: '))
+      .map(log => market.eventDecoder.decode(log.split('Program data: ')[1]))
+      .find(event => event?.name === 'CompoundFeesEvent')
+
+    return {
+      liquidityDelta: (event?.data.liquidityDelta as any).v as BN,
+      amountX: event?.data.amountX as BN,
+      amountY: event?.data.amountY as BN
+    }
+  }
+
+  before(async () => {
+    market = await Market.build(
+      Network.LOCAL,
+      provider.wallet,
+      connection,
+      anchor.workspace.Invariant.programId
+    )
+
+    await Promise.all([
+      connection.requestAirdrop(admin.publicKey, 1e12),
+      connection.requestAirdrop(operator.publicKey, 1e9)
+    ])
+    await sleep(500)
+
+    await market.createState(admin.publicKey, admin)
+
+    const createdPool = await createTokensAndPool(market, connection, admin, 0, FEE_TIERS[5])
+    pair = createdPool.pair
+    poolAddress = pair.getAddress(market.program.programId)
+    mintAuthority = createdPool.mintAuthority
+
+    for (let i = 0; i < 2; i++) {
+      const user = await createUserWithTokens(
+        pair,
+        connection,
+        mintAuthority,
+        new BN(10).pow(new BN(12))
+      )
+      await market.createPositionList(user.owner.publicKey, user.owner)
+      await market.createPosition(
+        {
+          pair,
+          owner: user.owner.publicKey,
+          userTokenX: user.userAccountX,
+          userTokenY: user.userAccountY,
+          lowerTick: -1000,
+          upperTick: 1000,
+          liquidityDelta: new BN(10).pow(new BN(14)),
+          knownPrice: calculatePriceSqrt(0),
+          slippage: new BN(0)
+        },
+        user.owner
+      )
+      users.push(user)
+    }
+
+    await generateFees()
+  })
+
+  it('#compoundFees() moves claimable fees into liquidity', async () => {
+    const [owner, twin] = users
+    const positionBefore = await market.getPosition(owner.owner.publicKey, 0)
+    const reservesBefore = await market.getReserveBalances(pair)
+    const balancesBefore = await getBalances(owner)
+
+    // fees the owner could claim instead
+    const claimable = await claimFee(twin)
+    assert.ok(claimable.x.gtn(0) && claimable.y.gtn(0))
+
+    const signature = await market.compoundFees(
+      { pair, owner: owner.owner.publicKey, index: 0 },
+      owner.owner
+    )
+    const { liquidityDelta, amountX, amountY } = await getCompoundFeesEvent(signature)
+
+    const positionAfter = await market.getPosition(owner.owner.publicKey, 0)
+    assert.ok(liquidityDelta.gtn(0))
+    assert.ok(positionAfter.liquidity.eq(positionBefore.liquidity.add(liquidityDelta)))
+    assert.ok(amountX.lte(claimable.x) && amountY.lte(claimable.y))
+
+    // tokens stay in the reserves and nothing is sent to the owner
+    const reservesAfter = await market.getReserveBalances(pair)
+    assert.ok(reservesAfter.x.eq(reservesBefore.x) && reservesAfter.y.eq(reservesBefore.y))
+    const balancesAfter = await getBalances(owner)
+    assert.ok(balancesAfter.x.eq(balancesBefore.x) && balancesAfter.y.eq(balancesBefore.y))
+
+    // what was not compounded is still owed
+    const leftover = await claimFee(owner)
+    assert.ok(leftover.x.eq(claimable.x.sub(amountX)))
+    assert.ok(leftover.y.eq(claimable.y.sub(amountY)))
+
+    // with nothing owed there is no liquidity to add
+    await assertThrowsAsync(
+      market.compoundFees({ pair, owner: owner.owner.publicKey, index: 0 }, owner.owner),
+      INVARIANT_ERRORS.ZERO_AMOUNT
+    )
+  })
+
+  it('#compoundFees() by operator', async () => {
+    const [owner] = users
+    const { positionAddress } = market.getPositionAddress(owner.owner.publicKey, 0)
+    const position = await market.getPosition(owner.owner.publicKey, 0)
+    const compoundFeesVars = {
+      pair,
+      owner: owner.owner.publicKey,
+      operator: operator.publicKey,
+      index: 0
+    }
+    await generateFees()
+
+    await assertThrowsAsync(market.compoundFees(compoundFeesVars, operator))
+
+    await market.approvePositionOperator(
+      {
+        owner: owner.owner.publicKey,
+        position: positionAddress,
+        operator: operator.publicKey,
+        permissions: OperatorPermission.ClaimFee | OperatorPermission.ChangeLiquidity
+      },
+      owner.owner
+    )
+    await assertThrowsAsync(
+      market.compoundFees(compoundFeesVars, operator),
+      INVARIANT_ERRORS.UNAUTHORIZED
+    )
+
+    await market.revokePositionOperator(
+      {
+        owner: owner.owner.publicKey,
+        pool: poolAddress,
+        id: position.id,
+        operator: operator.publicKey
+      },
+      owner.owner
+    )
+    await market.approvePositionOperator(
+      {
+        owner: owner.owner.publicKey,
+        position: positionAddress,
+        operator: operator.publicKey,
+        permissions: OperatorPermission.Compound
+      },
+      owner.owner
+    )
+
+    const signature = await market.compoundFees(compoundFeesVars, operator)
+    const { liquidityDelta } = await getCompoundFeesEvent(signature)
+    assert.ok(liquidityDelta.gtn(0))
+    assert.ok(
+      (await market.getPosition(owner.owner.publicKey, 0)).liquidity.eq(
+        position.liquidity.add(liquidityDelta)
+      )
+    )
+  })
+})
@@ -1,7 +1,7 @@
 import * as anchor from '@coral-xyz/anchor'
 import { AnchorProvider, BN } from '@coral-xyz/anchor'
 import { Keypair } from '@solana/web3.js'
-import { Network, Market, Pair } from '@invariant-labs/sdk'
+import { Network, Market, Pair, INVARIANT_ERRORS } from '@invariant-labs/sdk'
 import { assertThrowsAsync, createToken, initMarket } from './testUtils'
 import { assert } from 'chai'
 import { fromFee } from '@invariant-labs/sdk/lib/utils'
@@ -166,4 +166,37 @@ describe('create-pool', () => {
 
     assert.ok(sqrtPrice.eq(pool.sqrtPrice))
   })
+
+  it('#migratePool()', async () => {
+    const pair = pairs[5]
+    const poolAddress = pair.getAddress(market.program.programId)
+    const poolBefore = await market.getPool(pair)
+    const sizeBefore = (await connection.getAccountInfo(poolAddress))?.data.length
+
+    // pool created with the current layout is left as it is
+    await market.migratePool(pair, admin)
+
+    const poolAfter = await market.getPool(pair)
+    const sizeAfter = (await connection.getAccountInfo(poolAddress))?.data.length
+    assert.equal(sizeAfter, sizeBefore)
+    assert.equal(sizeAfter, market.program.account.pool.size)
+    assert.deepEqual(poolAfter, poolBefore)
+    assert.isFalse(poolAfter.dynamicFeeEnabled)
+  })
+
+  it('#migrateState()', async () => {
+    const stateAddress = market.stateAddress.address
+    const stateBefore = await market.program.account.state.fetch(stateAddress)
+
+    // state created with the current layout can not be migrated again
+    await assertThrowsAsync(market.migrateState(admin), INVARIANT_ERRORS.ALREADY_MIGRATED)
+
+    const stateAfter = await market.program.account.state.fetch(stateAddress)
+    assert.equal(
+      (await connection.getAccountInfo(stateAddress))?.data.length,
+      market.program.account.state.size
+    )
+    assert.deepEqual(stateAfter, stateBefore)
+    assert.ok(stateAfter.pauser.equals(admin.publicKey))
+  })
 })
@@ -0,0 +1,140 @@
+import * as anchor from '@coral-xyz/anchor'
+import { AnchorProvider, BN } from '@coral-xyz/anchor'
+import { Keypair, PublicKey } from '@solana/web3.js'
+import { assert } from 'chai'
+import { assertThrowsAsync, createTokensAndPool, createUserWithTokens } from './testUtils'
+import {
+  Market,
+  Network,
+  Pair,
+  sleep,
+  calculatePriceSqrt,
+  INVARIANT_ERRORS
+} from '@invariant-labs/sdk'
+import { FEE_TIERS, getBalance } from '@invariant-labs/sdk/src/utils'
+import { CreatePositionByAmounts } from '@invariant-labs/sdk/src/market'
+
+describe('create position by amounts', () => {
+  const provider = AnchorProvider.local()
+  const connection = provider.connection
+  const admin = Keypair.generate()
+  const maxAmount = new BN(1e6)
+  let market: Market
+  let pair: Pair
+  let owner: Keypair
+  let userAccountX: PublicKey
+  let userAccountY: PublicKey
+
+  const createPositionVars = (
+    lowerTick: number,
+    upperTick: number,
+    maxAmountX: BN,
+    maxAmountY: BN,
+    minLiquidity: BN = new BN(0)
+  ): CreatePositionByAmounts => ({
+    pair,
+    owner: owner.publicKey,
+    userTokenX: userAccountX,
+    userTokenY: userAccountY,
+    lowerTick,
+    upperTick,
+    maxAmountX,
+    maxAmountY,
+    minLiquidity,
+    knownPrice: calculatePriceSqrt(0),
+    slippage: new BN(0)
+  })
+
+  // amounts transferred by the creation of the next position
+  const createPosition = async (createPositionVars: CreatePositionByAmounts) => {
+    const xBefore = await getBalance(connection, userAccountX)
+    const yBefore = await getBalance(connection, userAccountY)
+    await market.createPositionByAmounts(createPositionVars, owner)
+
+    const { head } = await market.getPositionList(owner.publicKey)
+    return {
+      x: xBefore.sub(await getBalance(connection, userAccountX)),
+      y: yBefore.sub(await getBalance(connection, userAccountY)),
+      position: await market.getPosition(owner.publicKey, head - 1)
+    }
+  }
+
+  before(async () => {
+    market = await Market.build(
+      Network.LOCAL,
+      provider.wallet,
+      connection,
+      anchor.workspace.Invariant.programId
+    )
+
+    await connection.requestAirdrop(admin.publicKey, 1e12)
+    await sleep(500)
+
+    await market.createState(admin.publicKey, admin)
+
+    const createdPool = await createTokensAndPool(market, connection, admin, 0, FEE_TIERS[3])
+    pair = createdPool.pair
+    const user = await createUserWithTokens(pair, connection, createdPool.mintAuthority)
+    owner = user.owner
+    userAccountX = user.userAccountX
+    userAccountY = user.userAccountY
+
+    await market.createPositionList(owner.publicKey, owner)
+  })
+
+  it('#createPositionByAmounts() in range', async () => {
+    const { x, y, position } = await createPosition(
+      createPositionVars(-100, 100, maxAmount, maxAmount.divn(2))
+    )
+
+    assert.ok(position.liquidity.gtn(0))
+    assert.ok(x.gtn(0) && x.lte(maxAmount))
+    assert.ok(y.gtn(0) && y.lte(maxAmount.divn(2)))
+    // price is in the middle of the range, so token y limits the liquidity
+    assert.ok(y.gte(maxAmount.divn(2).muln(999).divn(1000)))
+    assert.ok((await market.getPool(pair)).liquidity.eq(position.liquidity))
+  })
+
+  it('#createPositionByAmounts() below range', async () => {
+    const { x, y, position } = await createPosition(
+      createPositionVars(-200, -100, new BN(0), maxAmount)
+    )
+
+    assert.ok(position.liquidity.gtn(0))
+    assert.ok(x.eqn(0))
+    assert.ok(y.gtn(0) && y.lte(maxAmount))
+  })
+
+  it('#createPositionByAmounts() above range', async () => {
+    const { x, y, position } = await createPosition(
+      createPositionVars(100, 200, maxAmount, new BN(0))
+    )
+
+    assert.ok(position.liquidity.gtn(0))
+    assert.ok(x.gtn(0) && x.lte(maxAmount))
+    assert.ok(y.eqn(0))
+  })
+
+  it('#createPositionByAmounts() below minimum liquidity fails', async () => {
+    // the same amounts at the same price give the same liquidity
+    const { position } = await createPosition(createPositionVars(-50, 50, maxAmount, maxAmount))
+
+    await assertThrowsAsync(
+      market.createPositionByAmounts(
+        createPositionVars(-50, 50, maxAmount, maxAmount, position.liquidity.addn(1)),
+        owner
+      ),
+      INVARIANT_ERRORS.LIQUIDITY_BELOW_MINIMUM
+    )
+    // token x alone can not back liquidity of a range holding the current price
+    await assertThrowsAsync(
+      market.createPositionByAmounts(createPositionVars(-50, 50, maxAmount, new BN(0)), owner),
+      INVARIANT_ERRORS.LIQUIDITY_BELOW_MINIMUM
+    )
+
+    const { x, y } = await createPosition(
+      createPositionVars(-50, 50, maxAmount, maxAmount, position.liquidity)
+    )
+    assert.ok(x.lte(maxAmount) && y.lte(maxAmount))
+  })
+})
@@ -465,6 +465,14 @@ describe('Decode events', () => {
         assert.ok(eventData.tokenX.equals(pair.tokenX))
         assert.ok(eventData.tokenY.equals(pair.tokenY))
         assert.ok(eventData.priceBeforeSwap.gt(eventData.priceAfterSwap))
+        assert.ok(eventData.pool.equals(pair.getAddress(market.program.programId)))
+        assert.ok(eventData.amountIn.eqn(1000))
+        assert.ok(eventData.amountOut.eqn(993))
+        assert.ok(eventData.referralFee.eqn(0))
+        assert.ok(eventData.liquidity.eq((await market.getPool(pair)).liquidity))
+        assert.equal(eventData.currentTick, -20)
+        assert.deepEqual(eventData.crossedTicks, [])
+        assert.ok(eventData.remainingAmount.eqn(0))
       }
     })
   })
@@ -0,0 +1,222 @@
+import * as anchor from '@coral-xyz/anchor'
+import { AnchorProvider, BN } from '@coral-xyz/anchor'
+import { Keypair, PublicKey } from '@solana/web3.js'
+import { assert } from 'chai'
+import { createAssociatedTokenAccount } from '@solana/spl-token'
+import { assertThrowsAsync, createTokensAndPool, createUserWithTokens } from './testUtils'
+import {
+  Market,
+  Network,
+  Pair,
+  sleep,
+  calculatePriceSqrt,
+  INVARIANT_ERRORS
+} from '@invariant-labs/sdk'
+import { FEE_TIERS, fromFee, getBalance, toDecimal } from '@invariant-labs/sdk/src/utils'
+import { CreatePosition, Swap } from '@invariant-labs/sdk/src/market'
+
+describe('fee split', () => {
+  const provider = AnchorProvider.local()
+  const connection = provider.connection
+  const admin = Keypair.generate()
+  const recipients = [Keypair.generate(), Keypair.generate(), Keypair.generate()]
+  const recipientAccounts: Array<{ accountX: PublicKey; accountY: PublicKey }> = []
+  let market: Market
+  let pair: Pair
+
+  before(async () => {
+    market = await Market.build(
+      Network.LOCAL,
+      provider.wallet,
+      connection,
+      anchor.workspace.Invariant.programId
+    )
+
+    await connection.requestAirdrop(admin.publicKey, 1e12)
+    await sleep(500)
+
+    await market.createState(admin.publicKey, admin)
+
+    const createdPool = await createTokensAndPool(market, connection, admin, 0, FEE_TIERS[5])
+    pair = createdPool.pair
+    await market.changeProtocolFee(
+      { pair, protocolFee: fromFee(new BN(20000)), admin: admin.publicKey },
+      admin
+    )
+
+    for (const recipient of recipients) {
+      const [accountX, accountY] = await Promise.all([
+        createAssociatedTokenAccount(connection, admin, pair.tokenX, recipient.publicKey),
+        createAssociatedTokenAccount(connection, admin, pair.tokenY, recipient.publicKey)
+      ])
+      recipientAccounts.push({ accountX, accountY })
+    }
+
+    const { owner, userAccountX, userAccountY } = await createUserWithTokens(
+      pair,
+      connection,
+      createdPool.mintAuthority
+    )
+    const createPositionVars: CreatePosition = {
+      pair,
+      owner: owner.publicKey,
+      userTokenX: userAccountX,
+      userTokenY: userAccountY,
+      lowerTick: -1000,
+      upperTick: 1000,
+      liquidityDelta: new BN(10).pow(new BN(14)),
+      knownPrice: calculatePriceSqrt(0),
+      slippage: new BN(0)
+    }
+    await market.createPositionList(owner.publicKey, owner)
+    await market.createPosition(createPositionVars, owner)
+
+    // generate protocol fee
+    const swapper = await createUserWithTokens(pair, connection, createdPool.mintAuthority)
+    const swapVars: Swap = {
+      pair,
+      xToY: true,
+      owner: swapper.owner.publicKey,
+      amount: new BN(1e6),
+      estimatedPriceAfterSwap: (await market.getPool(pair)).sqrtPrice,
+      slippage: toDecimal(1, 1),
+      accountX: swapper.userAccountX,
+      accountY: swapper.userAccountY,
+      byAmountIn: true
+    }
+    await market.swap(swapVars, swapper.owner)
+  })
+
+  it('#createFeeSplit()', async () => {
+    await assertThrowsAsync(
+      market.createFeeSplit(
+        {
+          recipients: [recipients[0].publicKey, recipients[1].publicKey],
+          shares: [3000, 6000],
+          admin: admin.publicKey
+        },
+        admin
+      ),
+      INVARIANT_ERRORS.INVALID_FEE_SPLIT
+    )
+
+    await market.createFeeSplit(
+      {
+        recipients: [recipients[0].publicKey, recipients[1].publicKey],
+        shares: [3000, 7000],
+        admin: admin.publicKey
+      },
+      admin
+    )
+
+    const feeSplit = await market.getFeeSplit()
+    assert.equal(feeSplit.count, 2)
+    assert.ok(feeSplit.recipients[0].equals(recipients[0].publicKey))
+    assert.ok(feeSplit.recipients[1].equals(recipients[1].publicKey))
+    assert.deepEqual(feeSplit.shares.slice(0, 2), [3000, 7000])
+  })
+
+  it('#distributeProtocolFee() partially', async () => {
+    const poolBefore = await market.getPool(pair)
+    assert.ok(poolBefore.feeProtocolTokenX.gtn(100))
+
+    await assertThrowsAsync(
+      market.distributeProtocolFee(
+        {
+          pair,
+          recipientAccounts: recipientAccounts.slice(0, 2),
+          admin: admin.publicKey,
+          amountX: poolBefore.feeProtocolTokenX.addn(1),
+          amountY: new BN(0)
+        },
+        admin
+      ),
+      INVARIANT_ERRORS.INSUFFICIENT_PROTOCOL_FEE
+    )
+    // recipient accounts have to follow the fee split order
+    await assertThrowsAsync(
+      market.distributeProtocolFee(
+        {
+          pair,
+          recipientAccounts: [recipientAccounts[1], recipientAccounts[0]],
+          admin: admin.publicKey,
+          amountX: new BN(100),
+          amountY: new BN(0)
+        },
+        admin
+      ),
+      INVARIANT_ERRORS.INVALID_OWNER
+    )
+
+    const reservesBefore = await market.getReserveBalances(pair)
+    await market.distributeProtocolFee(
+      {
+        pair,
+        recipientAccounts: recipientAccounts.slice(0, 2),
+        admin: admin.publicKey,
+        amountX: new BN(100),
+        amountY: new BN(0)
+      },
+      admin
+    )
+
+    const poolAfter = await market.getPool(pair)
+    const reservesAfter = await market.getReserveBalances(pair)
+    assert.ok((await getBalance(connection, recipientAccounts[0].accountX)).eqn(30))
+    assert.ok((await getBalance(connection, recipientAccounts[1].accountX)).eqn(70))
+    assert.ok(poolAfter.feeProtocolTokenX.eq(poolBefore.feeProtocolTokenX.subn(100)))
+    assert.ok(poolAfter.feeProtocolTokenY.eq(poolBefore.feeProtocolTokenY))
+    assert.ok(reservesBefore.x.sub(reservesAfter.x).eqn(100))
+  })
+
+  it('#changeFeeSplit()', async () => {
+    const outsider = Keypair.generate()
+    await connection.requestAirdrop(outsider.publicKey, 1e9)
+    await sleep(500)
+
+    await assertThrowsAsync(
+      market.changeFeeSplit(
+        { recipients: [outsider.publicKey], shares: [10000], admin: outsider.publicKey },
+        outsider
+      ),
+      INVARIANT_ERRORS.INVALID_ADMIN
+    )
+
+    await market.changeFeeSplit(
+      {
+        recipients: recipients.map(recipient => recipient.publicKey),
+        shares: [3333, 3333, 3334],
+        admin: admin.publicKey
+      },
+      admin
+    )
+
+    const feeSplit = await market.getFeeSplit()
+    assert.equal(feeSplit.count, 3)
+    assert.ok(feeSplit.recipients[2].equals(recipients[2].publicKey))
+    assert.deepEqual(feeSplit.shares.slice(0, 3), [3333, 3333, 3334])
+  })
+
+  it('#distributeProtocolFee() whole fee', async () => {
+    const { feeProtocolTokenX } = await market.getPool(pair)
+    const balancesBefore = await Promise.all(
+      recipientAccounts.map(({ accountX }) => getBalance(connection, accountX))
+    )
+
+    await market.distributeProtocolFee({ pair, recipientAccounts, admin: admin.publicKey }, admin)
+
+    // rounding dust goes to the last recipient
+    const part = feeProtocolTokenX.muln(3333).divn(10000)
+    const expectedParts = [part, part, feeProtocolTokenX.sub(part).sub(part)]
+    const balancesAfter = await Promise.all(
+      recipientAccounts.map(({ accountX }) => getBalance(connection, accountX))
+    )
+    balancesAfter.forEach((balance, i) =>
+      assert.ok(balance.sub(balancesBefore[i]).eq(expectedParts[i]))
+    )
+
+    const poolAfter = await market.getPool(pair)
+    assert.ok(poolAfter.feeProtocolTokenX.eqn(0))
+    assert.ok(poolAfter.feeProtocolTokenY.eqn(0))
+  })
+})
@@ -1,11 +1,12 @@
 import { Network, Market } from '@invariant-labs/sdk'
 import { sleep } from '@invariant-labs/sdk/lib/utils'
 import { CreateFeeTier, FeeTier } from '@invariant-labs/sdk/src/market'
-import { INVARIANT_ERRORS, fromFee } from '@invariant-labs/sdk/src/utils'
+import { INVARIANT_ERRORS, fromFee, toDecimal } from '@invariant-labs/sdk/src/utils'
 import * as anchor from '@coral-xyz/anchor'
 import { BN } from '@coral-xyz/anchor'
 import { Keypair } from '@solana/web3.js'
-import { assertThrowsAsync } from './testUtils'
+import { assert } from 'chai'
+import { assertThrowsAsync, createTokensAndPool } from './testUtils'
 
 describe('fee-tier', () => {
   const provider = anchor.AnchorProvider.local()
@@ -74,4 +75,106 @@ describe('fee-tier', () => {
       INVARIANT_ERRORS.INVALID_ADMIN
     )
   })
+  it('#migrateFeeTier()', async () => {
+    const { address } = market.getFeeTierAddress(feeTierAdmin)
+    const sizeBefore = (await connection.getAccountInfo(address))?.data.length
+
+    // fee tier created with the current layout can not be migrated again
+    await assertThrowsAsync(
+      market.migrateFeeTier(feeTierAdmin, admin),
+      INVARIANT_ERRORS.ALREADY_MIGRATED
+    )
+
+    const feeTier = await market.program.account.feeTier.fetch(address)
+    assert.equal(sizeBefore, market.program.account.feeTier.size)
+    assert.ok(feeTier.minFee.v.eq(feeTierAdmin.fee))
+    assert.ok(feeTier.maxFee.v.eq(feeTierAdmin.fee))
+  })
+  it('#changeFeeTier()', async () => {
+    const minFee = feeTierAdmin.fee.divn(2)
+    const maxFee = feeTierAdmin.fee.muln(2)
+
+    await assertThrowsAsync(
+      market.changeFeeTier(
+        { feeTier: feeTierAdmin, disabled: false, minFee, maxFee, admin: user.publicKey },
+        user
+      ),
+      INVARIANT_ERRORS.INVALID_ADMIN
+    )
+    // bounds have to contain the fee of the tier
+    await assertThrowsAsync(
+      market.changeFeeTier(
+        {
+          feeTier: feeTierAdmin,
+          disabled: false,
+          minFee: feeTierAdmin.fee.addn(1),
+          maxFee,
+          admin: admin.publicKey
+        },
+        admin
+      ),
+      INVARIANT_ERRORS.FEE_OUT_OF_BOUNDS
+    )
+    await assertThrowsAsync(
+      market.changeFeeTier(
+        {
+          feeTier: feeTierAdmin,
+          disabled: false,
+          minFee,
+          maxFee: toDecimal(1, 0),
+          admin: admin.publicKey
+        },
+        admin
+      ),
+      INVARIANT_ERRORS.FEE_OUT_OF_BOUNDS
+    )
+
+    await market.changeFeeTier(
+      { feeTier: feeTierAdmin, disabled: false, minFee, maxFee, admin: admin.publicKey },
+      admin
+    )
+
+    const { address } = market.getFeeTierAddress(feeTierAdmin)
+    const feeTier = await market.program.account.feeTier.fetch(address)
+    assert.ok(feeTier.minFee.v.eq(minFee))
+    assert.ok(feeTier.maxFee.v.eq(maxFee))
+    assert.isFalse(feeTier.disabled)
+  })
+  it('#changePoolFee()', async () => {
+    const { pair } = await createTokensAndPool(market, connection, admin, 0, feeTierAdmin)
+    const fee = feeTierAdmin.fee.muln(2)
+
+    await assertThrowsAsync(
+      market.changePoolFee({ pair, fee, admin: user.publicKey }, user),
+      INVARIANT_ERRORS.INVALID_ADMIN
+    )
+    await assertThrowsAsync(
+      market.changePoolFee({ pair, fee: fee.addn(1), admin: admin.publicKey }, admin),
+      INVARIANT_ERRORS.FEE_OUT_OF_BOUNDS
+    )
+
+    await market.changePoolFee({ pair, fee, admin: admin.publicKey }, admin)
+
+    const pool = await market.getPool(pair)
+    assert.ok(pool.fee.eq(feeTierAdmin.fee))
+    assert.ok(pool.feeOverride.eq(fee))
+    assert.isTrue(pool.feeOverridden)
+  })
+  it('#createPool() on disabled fee tier should failed', async () => {
+    await market.changeFeeTier(
+      {
+        feeTier: feeTierAdmin,
+        disabled: true,
+        minFee: feeTierAdmin.fee,
+        maxFee: feeTierAdmin.fee,
+        admin: admin.publicKey
+      },
+      admin
+    )
+
+    await assertThrowsAsync(
+      createTokensAndPool(market, connection, admin, 0, feeTierAdmin),
+      INVARIANT_ERRORS.FEE_TIER_DISABLED
+    )
+  })
 })
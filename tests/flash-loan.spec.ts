@@ -0,0 +1,161 @@
+import * as anchor from '@coral-xyz/anchor'
+import { AnchorProvider, BN } from '@coral-xyz/anchor'
+import { AccountMeta, Keypair, PublicKey } from '@solana/web3.js'
+import { TOKEN_PROGRAM_ID } from '@solana/spl-token'
+import { assert } from 'chai'
+import { assertThrowsAsync, createPoolWithLiquidity, createUserWithTokens } from './testUtils'
+import { Market, Network, Pair, sleep, INVARIANT_ERRORS } from '@invariant-labs/sdk'
+import { getBalance } from '@invariant-labs/sdk/src/utils'
+import { FlashLoan } from '@invariant-labs/sdk/src/market'
+
+describe('flash loan', () => {
+  const provider = AnchorProvider.local()
+  const connection = provider.connection
+  const admin = Keypair.generate()
+  const callbackProgram: PublicKey = anchor.workspace.FlashCallback.programId
+  const amount = new BN(1e6)
+  let market: Market
+
+  // accounts of `flash_loan_callback` repaying both tokens from the owner accounts
+  const callbackAccounts = async (
+    pair: Pair,
+    owner: PublicKey,
+    accountX: PublicKey,
+    accountY: PublicKey
+  ): Promise<AccountMeta[]> => {
+    const pool = await market.getPool(pair)
+    return [
+      { pubkey: accountX, isWritable: true, isSigner: false },
+      { pubkey: accountY, isWritable: true, isSigner: false },
+      { pubkey: pool.tokenXReserve, isWritable: true, isSigner: false },
+      { pubkey: pool.tokenYReserve, isWritable: true, isSigner: false },
+      { pubkey: pair.tokenX, isWritable: false, isSigner: false },
+      { pubkey: pair.tokenY, isWritable: false, isSigner: false },
+      { pubkey: owner, isWritable: false, isSigner: true },
+      { pubkey: TOKEN_PROGRAM_ID, isWritable: false, isSigner: false },
+      { pubkey: TOKEN_PROGRAM_ID, isWritable: false, isSigner: false }
+    ]
+  }
+
+  const shortfall = (amount: number) => new BN(amount).toArrayLike(Buffer, 'le', 8)
+
+  before(async () => {
+    market = await Market.build(
+      Network.LOCAL,
+      provider.wallet,
+      connection,
+      anchor.workspace.Invariant.programId
+    )
+
+    await connection.requestAirdrop(admin.publicKey, 1e12)
+    await sleep(500)
+
+    await market.createState(admin.publicKey, admin)
+  })
+
+  it('#flashLoan() fee shared with liquidity providers', async () => {
+    const { pair, mintAuthority } = await createPoolWithLiquidity(market, connection, admin)
+    const { owner, userAccountX, userAccountY } = await createUserWithTokens(
+      pair,
+      connection,
+      mintAuthority
+    )
+    const poolBefore = await market.getPool(pair)
+    const reservesBefore = await market.getReserveBalances(pair)
+
+    const flashLoanVars: FlashLoan = {
+      pair,
+      owner: owner.publicKey,
+      amountX: amount,
+      amountY: amount,
+      accountX: userAccountX,
+      accountY: userAccountY,
+      callbackProgram,
+      callbackAccounts: await callbackAccounts(pair, owner.publicKey, userAccountX, userAccountY)
+    }
+    await market.flashLoan(flashLoanVars, owner)
+
+    const feeX = new BN(1e9).sub(await getBalance(connection, userAccountX))
+    const feeY = new BN(1e9).sub(await getBalance(connection, userAccountY))
+    const reservesAfter = await market.getReserveBalances(pair)
+    const poolAfter = await market.getPool(pair)
+
+    assert.ok(feeX.gtn(0))
+    assert.ok(feeY.gtn(0))
+    assert.ok(reservesAfter.x.eq(reservesBefore.x.add(feeX)))
+    assert.ok(reservesAfter.y.eq(reservesBefore.y.add(feeY)))
+    assert.ok(poolAfter.feeGrowthGlobalX.gt(poolBefore.feeGrowthGlobalX))
+    assert.ok(poolAfter.feeGrowthGlobalY.gt(poolBefore.feeGrowthGlobalY))
+    assert.ok(poolAfter.feeProtocolTokenX.sub(poolBefore.feeProtocolTokenX).lt(feeX))
+    assert.ok(poolAfter.feeProtocolTokenY.sub(poolBefore.feeProtocolTokenY).lt(feeY))
+  })
+
+  it('#flashLoan() without liquidity in range', async () => {
+    // position above the current price holds only token x
+    const { pair, mintAuthority } = await createPoolWithLiquidity(
+      market,
+      connection,
+      admin,
+      new BN(10).pow(new BN(16)),
+      0,
+      1000,
+      2000
+    )
+    const { owner, userAccountX, userAccountY } = await createUserWithTokens(
+      pair,
+      connection,
+      mintAuthority
+    )
+    const poolBefore = await market.getPool(pair)
+    assert.ok(poolBefore.liquidity.eqn(0))
+
+    const flashLoanVars: FlashLoan = {
+      pair,
+      owner: owner.publicKey,
+      amountX: amount,
+      amountY: new BN(0),
+      accountX: userAccountX,
+      accountY: userAccountY,
+      callbackProgram,
+      callbackAccounts: await callbackAccounts(pair, owner.publicKey, userAccountX, userAccountY)
+    }
+    await market.flashLoan(flashLoanVars, owner)
+
+    // whole fee is kept by the protocol
+    const feeX = new BN(1e9).sub(await getBalance(connection, userAccountX))
+    const poolAfter = await market.getPool(pair)
+    assert.ok(feeX.gtn(0))
+    assert.ok(poolAfter.feeProtocolTokenX.sub(poolBefore.feeProtocolTokenX).eq(feeX))
+    assert.ok(poolAfter.feeGrowthGlobalX.eq(poolBefore.feeGrowthGlobalX))
+  })
+
+  it('#flashLoan() not repaid in full', async () => {
+    const { pair, mintAuthority } = await createPoolWithLiquidity(market, connection, admin)
+    const { owner, userAccountX, userAccountY } = await createUserWithTokens(
+      pair,
+      connection,
+      mintAuthority
+    )
+    const reservesBefore = await market.getReserveBalances(pair)
+
+    const flashLoanVars: FlashLoan = {
+      pair,
+      owner: owner.publicKey,
+      amountX: amount,
+      amountY: new BN(0),
+      accountX: userAccountX,
+      accountY: userAccountY,
+      callbackProgram,
+      callbackAccounts: await callbackAccounts(pair, owner.publicKey, userAccountX, userAccountY),
+      data: shortfall(1)
+    }
+    await assertThrowsAsync(
+      market.flashLoan(flashLoanVars, owner),
+      INVARIANT_ERRORS.INSUFFICIENT_REPAYMENT
+    )
+
+    const reservesAfter = await market.getReserveBalances(pair)
+    assert.ok(reservesAfter.x.eq(reservesBefore.x))
+    assert.ok((await getBalance(connection, userAccountX)).eq(new BN(1e9)))
+  })
+})
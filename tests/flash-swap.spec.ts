@@ -0,0 +1,162 @@
+import * as anchor from '@coral-xyz/anchor'
+import { AnchorProvider, BN } from '@coral-xyz/anchor'
+import { AccountMeta, Keypair, PublicKey } from '@solana/web3.js'
+import { TOKEN_PROGRAM_ID } from '@solana/spl-token'
+import { assert } from 'chai'
+import { assertThrowsAsync, createPoolWithLiquidity, createUserWithTokens } from './testUtils'
+import { Market, Network, Pair, sleep, INVARIANT_ERRORS } from '@invariant-labs/sdk'
+import { getBalance, toDecimal } from '@invariant-labs/sdk/src/utils'
+import { calculatePriceAfterSlippage } from '@invariant-labs/sdk/src/math'
+import { FlashSwap } from '@invariant-labs/sdk/src/market'
+
+describe('flash swap', () => {
+  const provider = AnchorProvider.local()
+  const connection = provider.connection
+  const admin = Keypair.generate()
+  const callbackProgram: PublicKey = anchor.workspace.FlashCallback.programId
+  const amount = new BN(1e6)
+  let market: Market
+  let pair: Pair
+  let mintAuthority: Keypair
+
+  // accounts of `flash_swap_callback` repaying the input token from the owner account
+  const callbackAccounts = async (
+    xToY: boolean,
+    owner: PublicKey,
+    accountX: PublicKey,
+    accountY: PublicKey
+  ): Promise<AccountMeta[]> => {
+    const pool = await market.getPool(pair)
+    return [
+      { pubkey: xToY ? accountX : accountY, isWritable: true, isSigner: false },
+      { pubkey: xToY ? pool.tokenXReserve : pool.tokenYReserve, isWritable: true, isSigner: false },
+      { pubkey: xToY ? pair.tokenX : pair.tokenY, isWritable: false, isSigner: false },
+      { pubkey: owner, isWritable: false, isSigner: true },
+      { pubkey: TOKEN_PROGRAM_ID, isWritable: false, isSigner: false }
+    ]
+  }
+
+  const shortfall = (amount: number) => new BN(amount).toArrayLike(Buffer, 'le', 8)
+
+  before(async () => {
+    market = await Market.build(
+      Network.LOCAL,
+      provider.wallet,
+      connection,
+      anchor.workspace.Invariant.programId
+    )
+
+    await connection.requestAirdrop(admin.publicKey, 1e12)
+    await sleep(500)
+
+    await market.createState(admin.publicKey, admin)
+
+    const createdPool = await createPoolWithLiquidity(market, connection, admin)
+    pair = createdPool.pair
+    mintAuthority = createdPool.mintAuthority
+  })
+
+  it('#flashSwap() repaid by the callback', async () => {
+    const { owner, userAccountX, userAccountY } = await createUserWithTokens(
+      pair,
+      connection,
+      mintAuthority
+    )
+    const poolBefore = await market.getPool(pair)
+    const reservesBefore = await market.getReserveBalances(pair)
+
+    const flashSwapVars: FlashSwap = {
+      pair,
+      xToY: true,
+      owner: owner.publicKey,
+      amount,
+      byAmountIn: true,
+      sqrtPriceLimit: calculatePriceAfterSlippage(poolBefore.sqrtPrice, toDecimal(1, 1), false),
+      accountX: userAccountX,
+      accountY: userAccountY,
+      callbackProgram,
+      callbackAccounts: await callbackAccounts(true, owner.publicKey, userAccountX, userAccountY)
+    }
+    await market.flashSwap(flashSwapVars, owner)
+
+    const amountX = await getBalance(connection, userAccountX)
+    const amountY = await getBalance(connection, userAccountY)
+    const reservesAfter = await market.getReserveBalances(pair)
+    const poolAfter = await market.getPool(pair)
+
+    assert.ok(amountX.eq(new BN(1e9).sub(amount)))
+    assert.ok(amountY.gt(new BN(1e9)))
+    assert.ok(reservesAfter.x.eq(reservesBefore.x.add(amount)))
+    assert.ok(reservesBefore.y.sub(reservesAfter.y).eq(amountY.sub(new BN(1e9))))
+    assert.ok(poolAfter.sqrtPrice.lt(poolBefore.sqrtPrice))
+  })
+
+  it('#flashSwap() not repaid in full', async () => {
+    const { owner, userAccountX, userAccountY } = await createUserWithTokens(
+      pair,
+      connection,
+      mintAuthority
+    )
+    const poolBefore = await market.getPool(pair)
+    const reservesBefore = await market.getReserveBalances(pair)
+
+    const flashSwapVars: FlashSwap = {
+      pair,
+      xToY: false,
+      owner: owner.publicKey,
+      amount,
+      byAmountIn: false,
+      sqrtPriceLimit: calculatePriceAfterSlippage(poolBefore.sqrtPrice, toDecimal(1, 1), true),
+      accountX: userAccountX,
+      accountY: userAccountY,
+      callbackProgram,
+      callbackAccounts: await callbackAccounts(false, owner.publicKey, userAccountX, userAccountY),
+      data: shortfall(1)
+    }
+    await assertThrowsAsync(
+      market.flashSwap(flashSwapVars, owner),
+      INVARIANT_ERRORS.INSUFFICIENT_REPAYMENT
+    )
+
+    // whole swap is reverted
+    const reservesAfter = await market.getReserveBalances(pair)
+    const poolAfter = await market.getPool(pair)
+    assert.ok(reservesAfter.x.eq(reservesBefore.x))
+    assert.ok(reservesAfter.y.eq(reservesBefore.y))
+    assert.ok(poolAfter.sqrtPrice.eq(poolBefore.sqrtPrice))
+    assert.ok((await getBalance(connection, userAccountX)).eq(new BN(1e9)))
+
+    flashSwapVars.data = undefined
+    await market.flashSwap(flashSwapVars, owner)
+
+    const amountX = await getBalance(connection, userAccountX)
+    const amountY = await getBalance(connection, userAccountY)
+    assert.ok(amountX.eq(new BN(1e9).add(amount)))
+    assert.ok(amountY.lt(new BN(1e9)))
+  })
+
+  it('#flashSwap() with invariant as the callback', async () => {
+    const { owner, userAccountX, userAccountY } = await createUserWithTokens(
+      pair,
+      connection,
+      mintAuthority
+    )
+    const pool = await market.getPool(pair)
+
+    const flashSwapVars: FlashSwap = {
+      pair,
+      xToY: true,
+      owner: owner.publicKey,
+      amount,
+      byAmountIn: true,
+      sqrtPriceLimit: calculatePriceAfterSlippage(pool.sqrtPrice, toDecimal(1, 1), false),
+      accountX: userAccountX,
+      accountY: userAccountY,
+      callbackProgram: market.program.programId
+    }
+    await assertThrowsAsync(
+      market.flashSwap(flashSwapVars, owner),
+      INVARIANT_ERRORS.INVALID_CALLBACK_PROGRAM
+    )
+  })
+})
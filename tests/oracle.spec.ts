@@ -1,10 +1,20 @@
 import * as anchor from '@coral-xyz/anchor'
 import { AnchorProvider } from '@coral-xyz/anchor'
-import { Keypair } from '@solana/web3.js'
+import { Keypair, Transaction } from '@solana/web3.js'
 import { assert } from 'chai'
-import { assertThrowsAsync, createPoolWithLiquidity } from './testUtils'
-import { Market, Pair, TICK_LIMIT, Network, sleep } from '@invariant-labs/sdk'
-import { DEFAULT_PUBLIC_KEY } from '@invariant-labs/sdk/src/market'
+import { assertThrowsAsync, createPoolWithLiquidity, createUserWithTokens } from './testUtils'
+import {
+  Market,
+  Pair,
+  TICK_LIMIT,
+  Network,
+  sleep,
+  signAndSend,
+  INVARIANT_ERRORS
+} from '@invariant-labs/sdk'
+import { DEFAULT_PUBLIC_KEY, Swap } from '@invariant-labs/sdk/src/market'
+import { toDecimal } from '@invariant-labs/sdk/src/utils'
+import { BN } from '@coral-xyz/anchor'
 
 describe('oracle', () => {
   const provider = AnchorProvider.local()
@@ -14,6 +24,7 @@ describe('oracle', () => {
   const admin = Keypair.generate()
   let market: Market
   let pair: Pair
+  let mintAuthority: Keypair
 
   before(async () => {
     market = await Market.build(
@@ -29,6 +40,7 @@ describe('oracle', () => {
 
     const createdPool = await createPoolWithLiquidity(market, connection, admin)
     pair = createdPool.pair
+    mintAuthority = createdPool.mintAuthority
   })
 
   it('#create()', async () => {
@@ -57,4 +69,63 @@ describe('oracle', () => {
   it('#initializeOracle() again', async () => {
     await assertThrowsAsync(market.initializeOracle(pair, wallet))
   })
+
+  it('#swap() records price', async () => {
+    const { owner, userAccountX, userAccountY } = await createUserWithTokens(
+      pair,
+      connection,
+      mintAuthority
+    )
+    const pool = await market.getPool(pair)
+
+    const swapVars: Swap = {
+      pair,
+      owner: owner.publicKey,
+      xToY: true,
+      amount: new BN(1000),
+      estimatedPriceAfterSwap: pool.sqrtPrice,
+      slippage: toDecimal(1, 2),
+      accountX: userAccountX,
+      accountY: userAccountY,
+      byAmountIn: true
+    }
+    await market.swap(swapVars, owner)
+
+    const oracle = await market.getOracle(pair)
+    assert.equal(oracle.amount, 1)
+    assert.equal(oracle.head, 0)
+  })
+
+  it('#swap() without oracle account fails', async () => {
+    const { owner, userAccountX, userAccountY } = await createUserWithTokens(
+      pair,
+      connection,
+      mintAuthority
+    )
+    const pool = await market.getPool(pair)
+    await sleep(1000)
+
+    const swapVars: Swap = {
+      pair,
+      owner: owner.publicKey,
+      xToY: false,
+      amount: new BN(1000),
+      estimatedPriceAfterSwap: pool.sqrtPrice,
+      slippage: toDecimal(1, 2),
+      accountX: userAccountX,
+      accountY: userAccountY,
+      byAmountIn: true
+    }
+    // price could be moved without a record if the oracle was optional
+    const swapIx = await market.swapIx(swapVars)
+    swapIx.keys = swapIx.keys.filter(key => !key.pubkey.equals(pool.oracleAddress))
+    await assertThrowsAsync(
+      signAndSend(new Transaction().add(swapIx), [owner], connection),
+      INVARIANT_ERRORS.ORACLE_NOT_FOUND
+    )
+
+    const oracle = await market.getOracle(pair)
+    assert.equal(oracle.amount, 1)
+    assert.ok((await market.getPool(pair)).sqrtPrice.eq(pool.sqrtPrice))
+  })
 })
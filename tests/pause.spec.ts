@@ -0,0 +1,165 @@
+import * as anchor from '@coral-xyz/anchor'
+import { AnchorProvider, BN } from '@coral-xyz/anchor'
+import { Keypair, PublicKey } from '@solana/web3.js'
+import { assert } from 'chai'
+import { assertThrowsAsync, createTokensAndPool, createUserWithTokens } from './testUtils'
+import { Market, Network, Pair, sleep, INVARIANT_ERRORS } from '@invariant-labs/sdk'
+import { toDecimal } from '@invariant-labs/sdk/src/utils'
+import { PauseFlag, Role } from '@invariant-labs/sdk/src/market'
+
+describe('pause', () => {
+  const provider = AnchorProvider.local()
+  const connection = provider.connection
+  const admin = Keypair.generate()
+  const pauser = Keypair.generate()
+  let market: Market
+  let pair: Pair
+  let owner: Keypair
+  let userAccountX: PublicKey
+  let userAccountY: PublicKey
+
+  const swap = async () => {
+    const pool = await market.getPool(pair)
+    return market.swap(
+      {
+        pair,
+        owner: owner.publicKey,
+        xToY: true,
+        amount: new BN(1000),
+        estimatedPriceAfterSwap: pool.sqrtPrice,
+        slippage: toDecimal(1, 1),
+        accountX: userAccountX,
+        accountY: userAccountY,
+        byAmountIn: true
+      },
+      owner
+    )
+  }
+
+  const createPosition = async () => {
+    const pool = await market.getPool(pair)
+    return market.createPosition(
+      {
+        pair,
+        owner: owner.publicKey,
+        userTokenX: userAccountX,
+        userTokenY: userAccountY,
+        lowerTick: -1000,
+        upperTick: 1000,
+        liquidityDelta: new BN(10).pow(new BN(12)),
+        knownPrice: pool.sqrtPrice,
+        slippage: new BN(0)
+      },
+      owner
+    )
+  }
+
+  const removePosition = async () =>
+    market.removePosition(
+      {
+        pair,
+        owner: owner.publicKey,
+        index: 0,
+        userTokenX: userAccountX,
+        userTokenY: userAccountY
+      },
+      owner
+    )
+
+  const setPause = async (level: 'state' | 'pool', paused: number) =>
+    level === 'state'
+      ? market.changePause(paused, pauser)
+      : market.changePoolPause({ pair, paused, pauser: pauser.publicKey }, pauser)
+
+  before(async () => {
+    market = await Market.build(
+      Network.LOCAL,
+      provider.wallet,
+      connection,
+      anchor.workspace.Invariant.programId
+    )
+
+    await Promise.all([
+      connection.requestAirdrop(admin.publicKey, 1e12),
+      connection.requestAirdrop(pauser.publicKey, 1e9)
+    ])
+    await sleep(500)
+
+    await market.createState(admin.publicKey, admin)
+    await market.setRole(
+      { role: Role.Pauser, holder: pauser.publicKey, admin: admin.publicKey },
+      admin
+    )
+
+    const createdPool = await createTokensAndPool(market, connection, admin)
+    pair = createdPool.pair
+    const user = await createUserWithTokens(
+      pair,
+      connection,
+      createdPool.mintAuthority,
+      new BN(10).pow(new BN(12))
+    )
+    owner = user.owner
+    userAccountX = user.userAccountX
+    userAccountY = user.userAccountY
+
+    await market.createPositionList(owner.publicKey, owner)
+    await createPosition()
+  })
+
+  it('#changePause() only by the pauser', async () => {
+    await assertThrowsAsync(
+      market.changePause(PauseFlag.All, admin),
+      INVARIANT_ERRORS.INVALID_ADMIN
+    )
+    await assertThrowsAsync(
+      market.changePoolPause({ pair, paused: PauseFlag.All, pauser: admin.publicKey }, admin),
+      INVARIANT_ERRORS.INVALID_ADMIN
+    )
+    await assertThrowsAsync(
+      market.changePause(1 << 3, pauser),
+      INVARIANT_ERRORS.INVALID_PAUSE_FLAGS
+    )
+    await assertThrowsAsync(
+      market.changePoolPause({ pair, paused: 1 << 3, pauser: pauser.publicKey }, pauser),
+      INVARIANT_ERRORS.INVALID_PAUSE_FLAGS
+    )
+
+    await market.changePause(PauseFlag.All, pauser)
+    await market.changePoolPause({ pair, paused: PauseFlag.Swap, pauser: pauser.publicKey }, pauser)
+    assert.equal((await market.getState()).paused, PauseFlag.All)
+    assert.equal((await market.getPool(pair)).paused, PauseFlag.Swap)
+
+    await market.changePause(0, pauser)
+    await market.changePoolPause({ pair, paused: 0, pauser: pauser.publicKey }, pauser)
+  })
+
+  for (const level of ['state', 'pool'] as const) {
+    it(`${level} pause of swaps`, async () => {
+      await setPause(level, PauseFlag.Swap)
+      await assertThrowsAsync(swap(), INVARIANT_ERRORS.OPERATION_PAUSED)
+      await createPosition()
+
+      await setPause(level, 0)
+      await swap()
+    })
+
+    it(`${level} pause of adding liquidity`, async () => {
+      await setPause(level, PauseFlag.AddLiquidity)
+      await assertThrowsAsync(createPosition(), INVARIANT_ERRORS.OPERATION_PAUSED)
+      await swap()
+
+      await setPause(level, 0)
+      await createPosition()
+    })
+
+    it(`${level} pause of removing liquidity`, async () => {
+      await setPause(level, PauseFlag.RemoveLiquidity)
+      await assertThrowsAsync(removePosition(), INVARIANT_ERRORS.OPERATION_PAUSED)
+      await swap()
+
+      await setPause(level, 0)
+      await removePosition()
+    })
+  }
+})
@@ -0,0 +1,136 @@
+import * as anchor from '@coral-xyz/anchor'
+import { AnchorProvider, BN } from '@coral-xyz/anchor'
+import { Keypair } from '@solana/web3.js'
+import { assert } from 'chai'
+import { assertThrowsAsync, createTokensAndPool } from './testUtils'
+import { Market, Network, sleep, INVARIANT_ERRORS } from '@invariant-labs/sdk'
+import { FEE_TIERS, fromFee, toDecimal } from '@invariant-labs/sdk/src/utils'
+import { Role } from '@invariant-labs/sdk/src/market'
+
+describe('pool defaults', () => {
+  const provider = AnchorProvider.local()
+  const connection = provider.connection
+  const admin = Keypair.generate()
+  const manager = Keypair.generate()
+  const defaultReceiver = Keypair.generate()
+  const tierReceiver = Keypair.generate()
+  const defaultProtocolFee = fromFee(new BN(5000))
+  const tierProtocolFee = fromFee(new BN(30000))
+  let market: Market
+
+  before(async () => {
+    market = await Market.build(
+      Network.LOCAL,
+      provider.wallet,
+      connection,
+      anchor.workspace.Invariant.programId
+    )
+
+    await Promise.all([
+      connection.requestAirdrop(admin.publicKey, 1e12),
+      connection.requestAirdrop(manager.publicKey, 1e9)
+    ])
+    await sleep(500)
+
+    await market.createState(admin.publicKey, admin)
+    await market.createFeeTier({ feeTier: FEE_TIERS[1], admin: admin.publicKey }, admin)
+    await market.setRole(
+      { role: Role.ProtocolFeeManager, holder: manager.publicKey, admin: admin.publicKey },
+      admin
+    )
+  })
+
+  it('#changePoolDefaults() only by the protocol fee manager', async () => {
+    await assertThrowsAsync(
+      market.changePoolDefaults(
+        {
+          protocolFee: defaultProtocolFee,
+          feeReceiver: defaultReceiver.publicKey,
+          admin: admin.publicKey
+        },
+        admin
+      ),
+      INVARIANT_ERRORS.INVALID_ADMIN
+    )
+    await assertThrowsAsync(
+      market.changePoolDefaults(
+        {
+          protocolFee: toDecimal(1, 0).addn(1),
+          feeReceiver: defaultReceiver.publicKey,
+          admin: manager.publicKey
+        },
+        manager
+      ),
+      INVARIANT_ERRORS.INVALID_PROTOCOL_FEE
+    )
+
+    const { pair: poolBefore } = await createTokensAndPool(market, connection, admin)
+    await market.changePoolDefaults(
+      {
+        protocolFee: defaultProtocolFee,
+        feeReceiver: defaultReceiver.publicKey,
+        admin: manager.publicKey
+      },
+      manager
+    )
+
+    const state = await market.getState()
+    assert.ok(state.defaultProtocolFee.v.eq(defaultProtocolFee))
+    assert.ok(state.defaultFeeReceiver.equals(defaultReceiver.publicKey))
+
+    // only new pools take the defaults
+    const { pair } = await createTokensAndPool(market, connection, admin)
+    const pool = await market.getPool(pair)
+    assert.ok(pool.protocolFee.eq(defaultProtocolFee))
+    assert.ok(pool.feeReceiver.equals(defaultReceiver.publicKey))
+    assert.ok(!(await market.getPool(poolBefore)).protocolFee.eq(defaultProtocolFee))
+  })
+
+  it('#changeFeeTierPoolDefaults() only by the protocol fee manager', async () => {
+    await assertThrowsAsync(
+      market.changeFeeTierPoolDefaults(
+        {
+          feeTier: FEE_TIERS[1],
+          protocolFee: tierProtocolFee,
+          feeReceiver: tierReceiver.publicKey,
+          admin: admin.publicKey
+        },
+        admin
+      ),
+      INVARIANT_ERRORS.INVALID_ADMIN
+    )
+
+    await market.changeFeeTierPoolDefaults(
+      {
+        feeTier: FEE_TIERS[1],
+        protocolFee: tierProtocolFee,
+        feeReceiver: tierReceiver.publicKey,
+        admin: manager.publicKey
+      },
+      manager
+    )
+
+    const { pair: tierPair } = await createTokensAndPool(market, connection, admin, 0, FEE_TIERS[1])
+    const tierPool = await market.getPool(tierPair)
+    assert.ok(tierPool.protocolFee.eq(tierProtocolFee))
+    assert.ok(tierPool.feeReceiver.equals(tierReceiver.publicKey))
+
+    // other tiers keep the state defaults
+    const { pair } = await createTokensAndPool(market, connection, admin)
+    const pool = await market.getPool(pair)
+    assert.ok(pool.protocolFee.eq(defaultProtocolFee))
+    assert.ok(pool.feeReceiver.equals(defaultReceiver.publicKey))
+  })
+
+  it('#changeFeeTierPoolDefaults() clears the overrides', async () => {
+    await market.changeFeeTierPoolDefaults(
+      { feeTier: FEE_TIERS[1], admin: manager.publicKey },
+      manager
+    )
+
+    const { pair } = await createTokensAndPool(market, connection, admin, 0, FEE_TIERS[1])
+    const pool = await market.getPool(pair)
+    assert.ok(pool.protocolFee.eq(defaultProtocolFee))
+    assert.ok(pool.feeReceiver.equals(defaultReceiver.publicKey))
+  })
+})
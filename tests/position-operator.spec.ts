@@ -0,0 +1,193 @@
+import * as anchor from '@coral-xyz/anchor'
+import { AnchorProvider, BN } from '@coral-xyz/anchor'
+import { Keypair, PublicKey } from '@solana/web3.js'
+import { assert } from 'chai'
+import { assertThrowsAsync, createTokensAndPool, createUserWithTokens } from './testUtils'
+import {
+  Market,
+  Network,
+  Pair,
+  sleep,
+  calculatePriceSqrt,
+  INVARIANT_ERRORS
+} from '@invariant-labs/sdk'
+import { getBalance, toDecimal } from '@invariant-labs/sdk/src/utils'
+import {
+  ClaimFee,
+  CreatePosition,
+  OperatorPermission,
+  RemovePosition,
+  Swap
+} from '@invariant-labs/sdk/src/market'
+
+describe('position operator', () => {
+  const provider = AnchorProvider.local()
+  const connection = provider.connection
+  const admin = Keypair.generate()
+  const operator = Keypair.generate()
+  let market: Market
+  let pair: Pair
+  let poolAddress: PublicKey
+  let owner: Keypair
+  let userAccountX: PublicKey
+  let userAccountY: PublicKey
+
+  before(async () => {
+    market = await Market.build(
+      Network.LOCAL,
+      provider.wallet,
+      connection,
+      anchor.workspace.Invariant.programId
+    )
+
+    await Promise.all([
+      connection.requestAirdrop(admin.publicKey, 1e12),
+      connection.requestAirdrop(operator.publicKey, 1e9)
+    ])
+    await sleep(500)
+
+    await market.createState(admin.publicKey, admin)
+
+    const createdPool = await createTokensAndPool(market, connection, admin)
+    pair = createdPool.pair
+    poolAddress = pair.getAddress(market.program.programId)
+    const user = await createUserWithTokens(pair, connection, createdPool.mintAuthority)
+    owner = user.owner
+    userAccountX = user.userAccountX
+    userAccountY = user.userAccountY
+
+    const createPositionVars: CreatePosition = {
+      pair,
+      owner: owner.publicKey,
+      userTokenX: userAccountX,
+      userTokenY: userAccountY,
+      lowerTick: -1000,
+      upperTick: 1000,
+      liquidityDelta: new BN(10).pow(new BN(14)),
+      knownPrice: calculatePriceSqrt(0),
+      slippage: new BN(0)
+    }
+    await market.createPositionList(owner.publicKey, owner)
+    await market.createPosition(createPositionVars, owner)
+
+    // generate fees
+    const swapper = await createUserWithTokens(pair, connection, createdPool.mintAuthority)
+    const swapVars: Swap = {
+      pair,
+      xToY: true,
+      owner: swapper.owner.publicKey,
+      amount: new BN(1e6),
+      estimatedPriceAfterSwap: (await market.getPool(pair)).sqrtPrice,
+      slippage: toDecimal(1, 1),
+      accountX: swapper.userAccountX,
+      accountY: swapper.userAccountY,
+      byAmountIn: true
+    }
+    await market.swap(swapVars, swapper.owner)
+  })
+
+  it('#approvePositionOperator() limits operator to its permissions', async () => {
+    const { positionAddress } = market.getPositionAddress(owner.publicKey, 0)
+    await market.approvePositionOperator(
+      {
+        owner: owner.publicKey,
+        position: positionAddress,
+        operator: operator.publicKey,
+        permissions: OperatorPermission.ClaimFee
+      },
+      owner
+    )
+
+    const position = await market.getPosition(owner.publicKey, 0)
+    const approval = await market.getPositionOperator(
+      owner.publicKey,
+      poolAddress,
+      position.id,
+      operator.publicKey
+    )
+    assert.ok(approval.operator.equals(operator.publicKey))
+    assert.equal(approval.permissions, OperatorPermission.ClaimFee)
+
+    // fees claimed by the operator are sent to the owner
+    const claimFeeVars: ClaimFee = {
+      pair,
+      owner: owner.publicKey,
+      operator: operator.publicKey,
+      userTokenX: userAccountX,
+      userTokenY: userAccountY,
+      index: 0
+    }
+    const ownerXBefore = await getBalance(connection, userAccountX)
+    await market.claimFee(claimFeeVars, operator)
+    assert.ok((await getBalance(connection, userAccountX)).gt(ownerXBefore))
+
+    // removal is outside of the approved scope
+    const removePositionVars: RemovePosition = {
+      pair,
+      owner: owner.publicKey,
+      operator: operator.publicKey,
+      index: 0,
+      userTokenX: userAccountX,
+      userTokenY: userAccountY
+    }
+    await assertThrowsAsync(
+      market.removePosition(removePositionVars, operator),
+      INVARIANT_ERRORS.UNAUTHORIZED
+    )
+  })
+
+  it('#revokePositionOperator()', async () => {
+    const position = await market.getPosition(owner.publicKey, 0)
+    await market.revokePositionOperator(
+      { owner: owner.publicKey, pool: poolAddress, id: position.id, operator: operator.publicKey },
+      owner
+    )
+
+    const { positionOperatorAddress } = market.getPositionOperatorAddress(
+      owner.publicKey,
+      poolAddress,
+      position.id,
+      operator.publicKey
+    )
+    assert.isNull(await connection.getAccountInfo(positionOperatorAddress))
+
+    const claimFeeVars: ClaimFee = {
+      pair,
+      owner: owner.publicKey,
+      operator: operator.publicKey,
+      userTokenX: userAccountX,
+      userTokenY: userAccountY,
+      index: 0
+    }
+    await assertThrowsAsync(market.claimFee(claimFeeVars, operator))
+  })
+
+  it('#removePosition() by operator', async () => {
+    const { positionAddress } = market.getPositionAddress(owner.publicKey, 0)
+    await market.approvePositionOperator(
+      {
+        owner: owner.publicKey,
+        position: positionAddress,
+        operator: operator.publicKey,
+        permissions: OperatorPermission.Remove
+      },
+      owner
+    )
+
+    const removePositionVars: RemovePosition = {
+      pair,
+      owner: owner.publicKey,
+      operator: operator.publicKey,
+      index: 0,
+      userTokenX: userAccountX,
+      userTokenY: userAccountY
+    }
+    const ownerXBefore = await getBalance(connection, userAccountX)
+    const ownerYBefore = await getBalance(connection, userAccountY)
+    await market.removePosition(removePositionVars, operator)
+
+    assert.equal((await market.getPositionList(owner.publicKey)).head, 0)
+    assert.ok((await getBalance(connection, userAccountX)).gt(ownerXBefore))
+    assert.ok((await getBalance(connection, userAccountY)).gt(ownerYBefore))
+  })
+})
@@ -557,4 +557,52 @@ describe('position', () => {
       yOwnerAmount = userTokenYBalance
     })
   })
+  describe('#initPosition on uninitialized ticks', () => {
+    const lowerTick = -30000
+    const upperTick = -25000
+
+    it('init position', async () => {
+      const { tickAddress: lowerTickAddress } = market.getTickAddress(pair, lowerTick)
+      const { tickAddress: upperTickAddress } = market.getTickAddress(pair, upperTick)
+      assert.isNull(await connection.getAccountInfo(lowerTickAddress))
+      assert.isNull(await connection.getAccountInfo(upperTickAddress))
+
+      const liquidityDelta = LIQUIDITY_DENOMINATOR.muln(10_000)
+      const positionIndex = 3
+      const initPositionVars: CreatePosition = {
+        pair,
+        owner: positionOwner.publicKey,
+        userTokenX: userTokenXAccount,
+        userTokenY: userTokenYAccount,
+        lowerTick,
+        upperTick,
+        liquidityDelta,
+        knownPrice: calculatePriceSqrt(initTick),
+        slippage: new BN(0)
+      }
+
+      // ticks are initialized by the program, no separate instructions are sent
+      const tx = await market.createPositionTx(initPositionVars)
+      assert.equal(tx.instructions.length, 1)
+      await market.createPosition(initPositionVars, positionOwner)
+
+      const positionState = await market.getPosition(positionOwner.publicKey, positionIndex)
+      const lowerTickState = await market.getTick(pair, lowerTick)
+      const upperTickState = await market.getTick(pair, upperTick)
+      const { tickBump: lowerTickBump } = market.getTickAddress(pair, lowerTick)
+
+      assert.ok(lowerTickState.index === lowerTick)
+      assert.ok(lowerTickState.sign)
+      assert.ok(lowerTickState.liquidityGross.eq(liquidityDelta))
+      assert.ok(lowerTickState.bump === lowerTickBump)
+      assert.ok(upperTickState.index === upperTick)
+      assert.ok(!upperTickState.sign)
+      assert.ok(upperTickState.liquidityGross.eq(liquidityDelta))
+
+      assert.ok(positionState.id.eqn(3))
+      assert.ok(positionState.liquidity.eq(liquidityDelta))
+      assert.ok(positionState.lowerTickIndex === lowerTick)
+      assert.ok(positionState.upperTickIndex === upperTick)
+    })
+  })
 })
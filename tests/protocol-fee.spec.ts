@@ -230,6 +230,10 @@ describe('protocol-fee', () => {
       new BN(1e9) as any
     )
 
+    // treasury is the default fee receiver, the admin withdraws as the protocol fee manager
+    const { treasuryAddress } = market.getTreasuryAddress()
+    assert.ok((await market.getPool(pair)).feeReceiver.equals(treasuryAddress))
+
     const reservesBeforeClaim = await market.getReserveBalances(pair)
     const adminAccountXBeforeClaim = await getBalance(connection, adminAccountX)
 
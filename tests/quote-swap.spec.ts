@@ -0,0 +1,146 @@
+import * as anchor from '@coral-xyz/anchor'
+import { AnchorProvider, BN } from '@coral-xyz/anchor'
+import { Keypair, PublicKey, TransactionSignature } from '@solana/web3.js'
+import { assert } from 'chai'
+import { createTokensAndPool, createUserWithTokens } from './testUtils'
+import { Market, Network, Pair, sleep, calculatePriceSqrt } from '@invariant-labs/sdk'
+import { FEE_TIERS, parseEvent, toDecimal } from '@invariant-labs/sdk/src/utils'
+import { CreatePosition, QuoteResult, Swap, SwapEvent } from '@invariant-labs/sdk/src/market'
+
+describe('quote swap', () => {
+  const provider = AnchorProvider.local()
+  const connection = provider.connection
+  const admin = Keypair.generate()
+  let market: Market
+  let pair: Pair
+  let owner: Keypair
+  let userAccountX: PublicKey
+  let userAccountY: PublicKey
+
+  const getSwapEvent = async (signature: TransactionSignature) => {
+    const transaction = await connection.getParsedTransaction(signature, 'confirmed')
+    const events = (transaction?.meta?.logMessages ?? [])
+      .filter(log => log.startsWith('Program data: '))
+      .map(log => market.eventDecoder.decode(log.split('Program data: ')[1]))
+
+    return parseEvent(events.find(event => event?.name === 'SwapEvent') ?? null) as SwapEvent
+  }
+
+  const getAccountsData = async (addresses: PublicKey[]) =>
+    (await connection.getMultipleAccountsInfo(addresses)).map(account => account?.data)
+
+  const assertQuoteEqualsSwap = (quote: QuoteResult, event: SwapEvent) => {
+    assert.ok(quote.amountIn.eq(event.amountIn))
+    assert.ok(quote.amountOut.eq(event.amountOut))
+    assert.ok(quote.fee.eq(event.fee))
+    assert.ok(quote.referralFee.eq(event.referralFee))
+    assert.ok(quote.remainingAmount.eq(event.remainingAmount))
+    assert.ok(quote.sqrtPrice.eq(event.priceAfterSwap))
+    assert.deepEqual(quote.crossedTicks, event.crossedTicks)
+  }
+
+  before(async () => {
+    market = await Market.build(
+      Network.LOCAL,
+      provider.wallet,
+      connection,
+      anchor.workspace.Invariant.programId
+    )
+
+    await connection.requestAirdrop(admin.publicKey, 1e12)
+    await sleep(500)
+
+    await market.createState(admin.publicKey, admin)
+
+    const createdPool = await createTokensAndPool(market, connection, admin, 0, FEE_TIERS[1])
+    pair = createdPool.pair
+    const user = await createUserWithTokens(
+      pair,
+      connection,
+      createdPool.mintAuthority,
+      new BN(10).pow(new BN(12))
+    )
+    owner = user.owner
+    userAccountX = user.userAccountX
+    userAccountY = user.userAccountY
+
+    // nested ranges, so a swap leaving [-40, 40] crosses initialized ticks
+    await market.createPositionList(owner.publicKey, owner)
+    for (const [lowerTick, upperTick] of [
+      [-200, 200],
+      [-40, 40]
+    ]) {
+      const createPositionVars: CreatePosition = {
+        pair,
+        owner: owner.publicKey,
+        userTokenX: userAccountX,
+        userTokenY: userAccountY,
+        lowerTick,
+        upperTick,
+        liquidityDelta: new BN(10).pow(new BN(12)),
+        knownPrice: calculatePriceSqrt(0),
+        slippage: new BN(0)
+      }
+      await market.createPosition(createPositionVars, owner)
+    }
+  })
+
+  it('#quoteSwap() by amount in matches the swap', async () => {
+    const pool = await market.getPool(pair)
+    const swapVars: Swap = {
+      pair,
+      owner: owner.publicKey,
+      xToY: true,
+      amount: new BN(6000),
+      estimatedPriceAfterSwap: pool.sqrtPrice,
+      slippage: toDecimal(5, 1),
+      accountX: userAccountX,
+      accountY: userAccountY,
+      byAmountIn: true
+    }
+    const touchedAccounts = [
+      pair.getAddress(market.program.programId),
+      pool.tickmap,
+      market.getTickAddress(pair, -40).tickAddress,
+      market.getTickAddress(pair, -200).tickAddress
+    ]
+
+    const dataBefore = await getAccountsData(touchedAccounts)
+    const quote = await market.quoteSwap(swapVars)
+    assert.deepEqual(await getAccountsData(touchedAccounts), dataBefore)
+    assert.deepEqual(quote.crossedTicks, [-40])
+
+    const signature = await market.swap(swapVars, owner)
+    assertQuoteEqualsSwap(quote, await getSwapEvent(signature))
+  })
+
+  it('#quoteSwap() by amount out matches the swap', async () => {
+    const pool = await market.getPool(pair)
+    const swapVars: Swap = {
+      pair,
+      owner: owner.publicKey,
+      xToY: false,
+      amount: new BN(5000),
+      estimatedPriceAfterSwap: pool.sqrtPrice,
+      slippage: toDecimal(5, 1),
+      accountX: userAccountX,
+      accountY: userAccountY,
+      byAmountIn: false
+    }
+    const touchedAccounts = [
+      pair.getAddress(market.program.programId),
+      pool.tickmap,
+      market.getTickAddress(pair, -40).tickAddress,
+      market.getTickAddress(pair, 40).tickAddress
+    ]
+
+    const dataBefore = await getAccountsData(touchedAccounts)
+    const quote = await market.quoteSwap(swapVars)
+    assert.deepEqual(await getAccountsData(touchedAccounts), dataBefore)
+    assert.deepEqual(quote.crossedTicks, [-40])
+    assert.ok(quote.amountOut.eqn(5000))
+
+    const signature = await market.swap(swapVars, owner)
+    assertQuoteEqualsSwap(quote, await getSwapEvent(signature))
+  })
+})
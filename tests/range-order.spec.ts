@@ -0,0 +1,141 @@
+import * as anchor from '@coral-xyz/anchor'
+import { AnchorProvider, BN } from '@coral-xyz/anchor'
+import { Keypair } from '@solana/web3.js'
+import { assert } from 'chai'
+import { assertThrowsAsync, createPoolWithLiquidity, createUserWithTokens } from './testUtils'
+import {
+  Market,
+  Network,
+  Pair,
+  sleep,
+  calculatePriceSqrt,
+  INVARIANT_ERRORS
+} from '@invariant-labs/sdk'
+import { getBalance } from '@invariant-labs/sdk/src/utils'
+import { ClaimRangeOrder, CreateRangeOrder, Swap } from '@invariant-labs/sdk/src/market'
+
+describe('range order', () => {
+  const provider = AnchorProvider.local()
+  const connection = provider.connection
+  const admin = Keypair.generate()
+  const settler = Keypair.generate()
+  const lowerTick = 10
+  let market: Market
+  let pair: Pair
+  let mintAuthority: Keypair
+
+  before(async () => {
+    market = await Market.build(
+      Network.LOCAL,
+      provider.wallet,
+      connection,
+      anchor.workspace.Invariant.programId
+    )
+
+    await Promise.all([
+      connection.requestAirdrop(admin.publicKey, 1e12),
+      connection.requestAirdrop(settler.publicKey, 1e9)
+    ])
+    await sleep(500)
+
+    await market.createState(admin.publicKey, admin)
+
+    const createdPool = await createPoolWithLiquidity(market, connection, admin)
+    pair = createdPool.pair
+    mintAuthority = createdPool.mintAuthority
+  })
+
+  it('#createRangeOrder() -> #settleRangeOrder() -> #claimRangeOrder()', async () => {
+    const { owner, userAccountX, userAccountY } = await createUserWithTokens(
+      pair,
+      connection,
+      mintAuthority
+    )
+
+    // order above the price sells token x
+    const createRangeOrderVars: CreateRangeOrder = {
+      pair,
+      owner: owner.publicKey,
+      lowerTick,
+      liquidityDelta: new BN(10).pow(new BN(14)),
+      userTokenX: userAccountX,
+      userTokenY: userAccountY
+    }
+    await market.createRangeOrder(createRangeOrderVars, owner)
+    const claimRangeOrderVars: ClaimRangeOrder = {
+      pair,
+      owner: owner.publicKey,
+      lowerTick,
+      userTokenX: userAccountX,
+      userTokenY: userAccountY
+    }
+
+    const order = await market.getRangeOrder(pair, owner.publicKey, lowerTick)
+    const amountX = new BN(1e9).sub(await getBalance(connection, userAccountX))
+    assert.ok(order.xToY)
+    assert.isFalse(order.settled)
+    assert.ok(amountX.gtn(0))
+    assert.ok((await getBalance(connection, userAccountY)).eq(new BN(1e9)))
+
+    // only owner can cancel unfilled order
+    await assertThrowsAsync(
+      market.settleRangeOrder(
+        { pair, owner: owner.publicKey, lowerTick, settler: settler.publicKey },
+        settler
+      ),
+      INVARIANT_ERRORS.RANGE_ORDER_NOT_FILLED
+    )
+    await assertThrowsAsync(
+      market.claimRangeOrder(claimRangeOrderVars, owner),
+      INVARIANT_ERRORS.RANGE_ORDER_NOT_SETTLED
+    )
+
+    // fill the order by moving the price past its upper tick
+    const swapper = await createUserWithTokens(pair, connection, mintAuthority)
+    const swapVars: Swap = {
+      pair,
+      xToY: false,
+      owner: swapper.owner.publicKey,
+      amount: new BN(1e8),
+      estimatedPriceAfterSwap: calculatePriceSqrt(lowerTick + 10),
+      slippage: new BN(0),
+      accountX: swapper.userAccountX,
+      accountY: swapper.userAccountY,
+      byAmountIn: true,
+      partialFill: true
+    }
+    await market.swap(swapVars, swapper.owner)
+    assert.ok((await market.getPool(pair)).currentTickIndex >= lowerTick + 1)
+
+    // rent of emptied ticks is refunded to the owner, not the settler
+    const ownerLamportsBefore = await connection.getBalance(owner.publicKey)
+    await market.settleRangeOrder(
+      { pair, owner: owner.publicKey, lowerTick, settler: settler.publicKey },
+      settler
+    )
+    const ownerLamportsAfter = await connection.getBalance(owner.publicKey)
+    assert.ok(ownerLamportsAfter > ownerLamportsBefore)
+
+    const { tickAddress } = market.getTickAddress(pair, lowerTick)
+    assert.isNull(await connection.getAccountInfo(tickAddress))
+
+    const settled = await market.getRangeOrder(pair, owner.publicKey, lowerTick)
+    assert.ok(settled.settled)
+    assert.ok(settled.position.tokensOwedY.gtn(0))
+    await assertThrowsAsync(
+      market.settleRangeOrder(
+        { pair, owner: owner.publicKey, lowerTick, settler: settler.publicKey },
+        settler
+      ),
+      INVARIANT_ERRORS.RANGE_ORDER_SETTLED
+    )
+
+    await market.claimRangeOrder(claimRangeOrderVars, owner)
+
+    const amountY = (await getBalance(connection, userAccountY)).sub(new BN(1e9))
+    assert.ok(amountY.gtn(0))
+    assert.ok(amountY.lte(amountX.muln(11).divn(10)))
+    const { rangeOrderAddress } = market.getRangeOrderAddress(pair, owner.publicKey, lowerTick)
+    assert.isNull(await connection.getAccountInfo(rangeOrderAddress))
+  })
+})
@@ -2,7 +2,7 @@ import * as anchor from '@coral-xyz/anchor'
 import { AnchorProvider, BN } from '@coral-xyz/anchor'
 import { Keypair } from '@solana/web3.js'
 import { assert } from 'chai'
-import { createToken, initMarket } from './testUtils'
+import { assertThrowsAsync, createToken, initMarket } from './testUtils'
 import {
   Market,
   Pair,
@@ -16,7 +16,11 @@ import { FeeTier, Tick } from '@invariant-labs/sdk/lib/market'
 import { fromFee, getBalance, simulateSwap, SimulationStatus } from '@invariant-labs/sdk/lib/utils'
 import { toDecimal } from '@invariant-labs/sdk/src/utils'
 import { CreateTick, CreatePosition, Swap } from '@invariant-labs/sdk/src/market'
-import { createAssociatedTokenAccount, mintTo } from '@solana/spl-token'
+import {
+  createAssociatedTokenAccount,
+  getAssociatedTokenAddressSync,
+  mintTo
+} from '@solana/spl-token'
 
 describe('Referral swap', () => {
   const provider = AnchorProvider.local()
@@ -29,6 +33,8 @@ describe('Referral swap', () => {
     fee: fromFee(new BN(500)),
     tickSpacing: 5
   }
+  const referralOwner = Keypair.generate()
+  const referralFeeShare = toDecimal(2, 1)
   let market: Market
   let pair: Pair
 
@@ -58,6 +64,31 @@ describe('Referral swap', () => {
     await initMarket(market, [pair], admin)
   })
 
+  it('#createReferrer()', async () => {
+    await assertThrowsAsync(
+      market.createReferrer(
+        { owner: referralOwner.publicKey, feeShare: referralFeeShare, admin: wallet.publicKey },
+        wallet
+      )
+    )
+    await assertThrowsAsync(
+      market.createReferrer(
+        { owner: referralOwner.publicKey, feeShare: toDecimal(11, 1), admin: admin.publicKey },
+        admin
+      )
+    )
+
+    await market.createReferrer(
+      { owner: referralOwner.publicKey, feeShare: referralFeeShare, admin: admin.publicKey },
+      admin
+    )
+
+    const referrer = await market.getReferrer(referralOwner.publicKey)
+    assert.ok(referrer.owner.equals(referralOwner.publicKey))
+    assert.ok(referrer.feeShare.eq(referralFeeShare))
+    assert.isTrue(referrer.enabled)
+  })
+
   it('#swap() crossing tick with referral swap', async () => {
     // Deposit
     const upperTick = 10
@@ -77,7 +108,6 @@ describe('Referral swap', () => {
     await market.createTick(createTickVars2, admin)
 
     const positionOwner = Keypair.generate()
-    const referralAccount = Keypair.generate()
     await connection.requestAirdrop(positionOwner.publicKey, 1e9)
     await sleep(1000)
     const userTokenXAccount = await createAssociatedTokenAccount(
@@ -96,7 +126,7 @@ describe('Referral swap', () => {
       connection,
       admin,
       pair.tokenX,
-      referralAccount.publicKey
+      referralOwner.publicKey
     )
 
     const mintAmount = new BN(10).pow(new BN(10))
@@ -271,4 +301,81 @@ describe('Referral swap', () => {
     assert.ok(priceImpact.eq(new BN('171292689333')))
     assert.ok(minReceived.eqn(0)) // due extremely low price limit
   })
+
+  it('#changeReferrer()', async () => {
+    await assertThrowsAsync(
+      market.changeReferrer(
+        {
+          owner: referralOwner.publicKey,
+          feeShare: referralFeeShare,
+          enabled: false,
+          admin: wallet.publicKey
+        },
+        wallet
+      )
+    )
+
+    await market.changeReferrer(
+      {
+        owner: referralOwner.publicKey,
+        feeShare: toDecimal(1, 1),
+        enabled: false,
+        admin: admin.publicKey
+      },
+      admin
+    )
+
+    const referrer = await market.getReferrer(referralOwner.publicKey)
+    assert.ok(referrer.feeShare.eq(toDecimal(1, 1)))
+    assert.isFalse(referrer.enabled)
+  })
+
+  it('#swap() with disabled referrer', async () => {
+    const owner = Keypair.generate()
+    await connection.requestAirdrop(owner.publicKey, 1e9)
+    await sleep(1000)
+
+    const amount = new BN(1000)
+    const accountX = await createAssociatedTokenAccount(
+      connection,
+      mintAuthority,
+      pair.tokenX,
+      owner.publicKey
+    )
+    const accountY = await createAssociatedTokenAccount(
+      connection,
+      mintAuthority,
+      pair.tokenY,
+      owner.publicKey
+    )
+    await mintTo(connection, mintAuthority, pair.tokenX, accountX, mintAuthority, amount as any)
+
+    const referralTokenXAccount = getAssociatedTokenAddressSync(
+      pair.tokenX,
+      referralOwner.publicKey
+    )
+    const poolDataBefore = await market.getPool(pair)
+    const reserveXBefore = await getBalance(connection, poolDataBefore.tokenXReserve)
+    const referralTokenXBefore = await getBalance(connection, referralTokenXAccount)
+
+    const swapVars: Swap = {
+      pair,
+      xToY: true,
+      amount,
+      estimatedPriceAfterSwap: poolDataBefore.sqrtPrice,
+      slippage: toDecimal(1, 1),
+      accountX,
+      accountY,
+      byAmountIn: true,
+      owner: owner.publicKey,
+      referralAccount: referralTokenXAccount
+    }
+    await market.swap(swapVars, owner)
+
+    // whole input goes to the pool
+    const reserveXAfter = await getBalance(connection, poolDataBefore.tokenXReserve)
+    const referralTokenXAfter = await getBalance(connection, referralTokenXAccount)
+    assert.ok(reserveXAfter.sub(reserveXBefore).eq(amount))
+    assert.ok(referralTokenXAfter.eq(referralTokenXBefore))
+  })
 })
@@ -0,0 +1,152 @@
+import * as anchor from '@coral-xyz/anchor'
+import { AnchorProvider, BN } from '@coral-xyz/anchor'
+import { Keypair, PublicKey } from '@solana/web3.js'
+import { assert } from 'chai'
+import { createTokensAndPool, createUserWithTokens } from './testUtils'
+import { Market, Network, Pair, sleep, calculatePriceSqrt } from '@invariant-labs/sdk'
+import { CreatePosition, RemovePosition } from '@invariant-labs/sdk/src/market'
+
+describe('stable position', () => {
+  const provider = AnchorProvider.local()
+  const connection = provider.connection
+  const admin = Keypair.generate()
+  const recipient = Keypair.generate()
+  const liquidityDelta = new BN(10).pow(new BN(12))
+  let market: Market
+  let pair: Pair
+  let poolAddress: PublicKey
+  let owner: Keypair
+  let userAccountX: PublicKey
+  let userAccountY: PublicKey
+
+  const createPositionVars = (lowerTick: number, upperTick: number): CreatePosition => ({
+    pair,
+    owner: owner.publicKey,
+    userTokenX: userAccountX,
+    userTokenY: userAccountY,
+    lowerTick,
+    upperTick,
+    liquidityDelta,
+    knownPrice: calculatePriceSqrt(0),
+    slippage: new BN(0)
+  })
+
+  before(async () => {
+    market = await Market.build(
+      Network.LOCAL,
+      provider.wallet,
+      connection,
+      anchor.workspace.Invariant.programId
+    )
+
+    await Promise.all([
+      connection.requestAirdrop(admin.publicKey, 1e12),
+      connection.requestAirdrop(recipient.publicKey, 1e9)
+    ])
+    await sleep(500)
+
+    await market.createState(admin.publicKey, admin)
+
+    const createdPool = await createTokensAndPool(market, connection, admin)
+    pair = createdPool.pair
+    poolAddress = pair.getAddress(market.program.programId)
+    const user = await createUserWithTokens(pair, connection, createdPool.mintAuthority)
+    owner = user.owner
+    userAccountX = user.userAccountX
+    userAccountY = user.userAccountY
+  })
+
+  it('#createStablePosition()', async () => {
+    // list is created along with the first position
+    await market.createStablePosition(createPositionVars(-10, 10), owner)
+    await market.createStablePosition(createPositionVars(-20, 20), owner)
+    await market.createStablePosition(createPositionVars(-30, 30), owner)
+
+    const positionList = await market.getStablePositionList(owner.publicKey)
+    const positions = await market.getStablePositions(owner.publicKey)
+    assert.equal(positionList.head, 3)
+    assert.deepEqual(positions.map(p => p.lowerTickIndex), [-10, -20, -30])
+
+    for (const [index, position] of positions.entries()) {
+      const entry = await market.getPositionEntry(owner.publicKey, index)
+      assert.ok(entry.pool.equals(poolAddress))
+      assert.ok(entry.id.eq(position.id))
+      assert.ok(position.owner.equals(owner.publicKey))
+      assert.ok(position.liquidity.eq(liquidityDelta))
+    }
+  })
+
+  it('#removeStablePosition() keeps addresses of other positions', async () => {
+    const [removed, , last] = await market.getStablePositions(owner.publicKey)
+    const { stablePositionAddress: removedAddress } = market.getStablePositionAddress(
+      poolAddress,
+      removed.id
+    )
+    const { stablePositionAddress: lastAddress } = market.getStablePositionAddress(
+      poolAddress,
+      last.id
+    )
+
+    const removePositionVars: RemovePosition = {
+      pair,
+      owner: owner.publicKey,
+      index: 0,
+      userTokenX: userAccountX,
+      userTokenY: userAccountY
+    }
+    await market.removeStablePosition(removePositionVars, owner)
+
+    // last entry takes the removed slot, the position itself does not move
+    const positionList = await market.getStablePositionList(owner.publicKey)
+    const entry = await market.getPositionEntry(owner.publicKey, 0)
+    const { positionEntryAddress } = market.getPositionEntryAddress(owner.publicKey, 2)
+    assert.equal(positionList.head, 2)
+    assert.ok(entry.id.eq(last.id))
+    assert.isNull(await connection.getAccountInfo(removedAddress))
+    assert.isNull(await connection.getAccountInfo(positionEntryAddress))
+    assert.isNotNull(await connection.getAccountInfo(lastAddress))
+    assert.ok((await market.getStablePosition(poolAddress, last.id)).liquidity.eq(liquidityDelta))
+  })
+
+  it('#transferStablePosition()', async () => {
+    const transferred = await market.getStablePositions(owner.publicKey).then(p => p[0])
+    const { stablePositionAddress } = market.getStablePositionAddress(poolAddress, transferred.id)
+
+    await market.createStablePositionList(recipient.publicKey, recipient)
+    await market.transferStablePosition(
+      { owner: owner.publicKey, recipient: recipient.publicKey, index: 0 },
+      owner
+    )
+
+    const ownerPositions = await market.getStablePositions(owner.publicKey)
+    const recipientPositions = await market.getStablePositions(recipient.publicKey)
+    assert.equal(ownerPositions.length, 1)
+    assert.equal(recipientPositions.length, 1)
+    assert.ok(recipientPositions[0].id.eq(transferred.id))
+    assert.ok(recipientPositions[0].owner.equals(recipient.publicKey))
+    assert.ok(
+      market
+        .getStablePositionAddress(poolAddress, recipientPositions[0].id)
+        .stablePositionAddress.equals(stablePositionAddress)
+    )
+  })
+
+  it('#migratePosition()', async () => {
+    await market.createPositionList(owner.publicKey, owner)
+    await market.createPosition(createPositionVars(-40, 40), owner)
+    const legacy = await market.getPosition(owner.publicKey, 0)
+
+    await market.migratePosition({ owner: owner.publicKey, index: 0 }, owner)
+
+    const positionList = await market.getPositionList(owner.publicKey)
+    const stablePositionList = await market.getStablePositionList(owner.publicKey)
+    const entry = await market.getPositionEntry(owner.publicKey, stablePositionList.head - 1)
+    const migrated = await market.getStablePosition(poolAddress, legacy.id)
+    assert.equal(positionList.head, 0)
+    assert.equal(stablePositionList.head, 2)
+    assert.ok(entry.id.eq(legacy.id))
+    assert.ok(migrated.owner.equals(owner.publicKey))
+    assert.ok(migrated.liquidity.eq(legacy.liquidity))
+    assert.equal(migrated.lowerTickIndex, -40)
+  })
+})
@@ -0,0 +1,131 @@
+import * as anchor from '@coral-xyz/anchor'
+import { AnchorProvider, BN } from '@coral-xyz/anchor'
+import { Keypair } from '@solana/web3.js'
+import { assert } from 'chai'
+import { assertThrowsAsync, createPoolWithLiquidity, createUserWithTokens } from './testUtils'
+import { Market, Network, Pair, sleep, INVARIANT_ERRORS } from '@invariant-labs/sdk'
+import { getBalance, toDecimal } from '@invariant-labs/sdk/src/utils'
+import { Swap } from '@invariant-labs/sdk/src/market'
+
+describe('swap amount guards', () => {
+  const provider = AnchorProvider.local()
+  const connection = provider.connection
+  const admin = Keypair.generate()
+  const amount = new BN(1e6)
+  let market: Market
+  let pair: Pair
+  let mintAuthority: Keypair
+
+  before(async () => {
+    market = await Market.build(
+      Network.LOCAL,
+      provider.wallet,
+      connection,
+      anchor.workspace.Invariant.programId
+    )
+
+    await connection.requestAirdrop(admin.publicKey, 1e12)
+    await sleep(500)
+
+    await market.createState(admin.publicKey, admin)
+
+    const createdPool = await createPoolWithLiquidity(market, connection, admin)
+    pair = createdPool.pair
+    mintAuthority = createdPool.mintAuthority
+  })
+
+  it('#swap() below minimum amount out', async () => {
+    const { owner, userAccountX, userAccountY } = await createUserWithTokens(
+      pair,
+      connection,
+      mintAuthority
+    )
+
+    const swapVars: Swap = {
+      pair,
+      xToY: true,
+      owner: owner.publicKey,
+      amount,
+      estimatedPriceAfterSwap: (await market.getPool(pair)).sqrtPrice,
+      slippage: toDecimal(1, 1),
+      accountX: userAccountX,
+      accountY: userAccountY,
+      byAmountIn: true,
+      minAmountOut: amount
+    }
+    await assertThrowsAsync(
+      market.swap(swapVars, owner),
+      INVARIANT_ERRORS.AMOUNT_OUT_BELOW_MINIMUM
+    )
+
+    swapVars.minAmountOut = amount.muln(99).divn(100)
+    await market.swap(swapVars, owner)
+
+    const amountY = await getBalance(connection, userAccountY)
+    assert.ok(amountY.sub(new BN(1e9)).gte(swapVars.minAmountOut))
+  })
+
+  it('#swap() above maximum amount in', async () => {
+    const { owner, userAccountX, userAccountY } = await createUserWithTokens(
+      pair,
+      connection,
+      mintAuthority
+    )
+
+    const swapVars: Swap = {
+      pair,
+      xToY: false,
+      owner: owner.publicKey,
+      amount,
+      estimatedPriceAfterSwap: (await market.getPool(pair)).sqrtPrice,
+      slippage: toDecimal(1, 1),
+      accountX: userAccountX,
+      accountY: userAccountY,
+      byAmountIn: false,
+      maxAmountIn: amount
+    }
+    await assertThrowsAsync(
+      market.swap(swapVars, owner),
+      INVARIANT_ERRORS.AMOUNT_IN_ABOVE_MAXIMUM
+    )
+
+    swapVars.maxAmountIn = amount.muln(101).divn(100)
+    await market.swap(swapVars, owner)
+
+    const amountX = await getBalance(connection, userAccountX)
+    assert.ok(amountX.sub(new BN(1e9)).eq(amount))
+  })
+
+  it('#swap() with partial fill', async () => {
+    const { owner, userAccountX, userAccountY } = await createUserWithTokens(
+      pair,
+      connection,
+      mintAuthority
+    )
+    const poolBefore = await market.getPool(pair)
+    const bigAmount = new BN(1e9)
+
+    const swapVars: Swap = {
+      pair,
+      xToY: true,
+      owner: owner.publicKey,
+      amount: bigAmount,
+      estimatedPriceAfterSwap: poolBefore.sqrtPrice,
+      slippage: toDecimal(1, 4),
+      accountX: userAccountX,
+      accountY: userAccountY,
+      byAmountIn: true
+    }
+    await assertThrowsAsync(market.swap(swapVars, owner), INVARIANT_ERRORS.PRICE_LIMIT_REACHED)
+
+    swapVars.partialFill = true
+    await market.swap(swapVars, owner)
+
+    // swap stops at the price limit and only part of the amount is taken
+    const poolAfter = await market.getPool(pair)
+    const amountX = await getBalance(connection, userAccountX)
+    assert.ok(poolAfter.sqrtPrice.lt(poolBefore.sqrtPrice))
+    assert.ok(amountX.gtn(0))
+    assert.ok(amountX.lt(bigAmount))
+  })
+})
@@ -0,0 +1,204 @@
+import * as anchor from '@coral-xyz/anchor'
+import { AnchorProvider, BN } from '@coral-xyz/anchor'
+import { Keypair, PublicKey } from '@solana/web3.js'
+import { assert } from 'chai'
+import { assertThrowsAsync, createPosition, createToken, initMarket } from './testUtils'
+import { Market, Pair, Network, sleep } from '@invariant-labs/sdk'
+import { FeeTier } from '@invariant-labs/sdk/lib/market'
+import { fromFee } from '@invariant-labs/sdk/lib/utils'
+import { getBalance } from '@invariant-labs/sdk/src/utils'
+import { SwapRoute } from '@invariant-labs/sdk/src/market'
+import { createAssociatedTokenAccount, mintTo } from '@solana/spl-token'
+
+describe('swap route', () => {
+  const provider = AnchorProvider.local()
+  const connection = provider.connection
+  // @ts-expect-error
+  const wallet = provider.wallet.payer as Keypair
+  const mintAuthority = Keypair.generate()
+  const admin = Keypair.generate()
+  const owner = Keypair.generate()
+  const feeTier: FeeTier = {
+    fee: fromFee(new BN(600)),
+    tickSpacing: 10
+  }
+  const liquidity = new BN(10).pow(new BN(16))
+  let market: Market
+  let tokens: PublicKey[]
+  let accounts: PublicKey[]
+  let pairAB: Pair
+  let pairBC: Pair
+
+  before(async () => {
+    market = await Market.build(
+      Network.LOCAL,
+      provider.wallet,
+      connection,
+      anchor.workspace.Invariant.programId
+    )
+
+    await Promise.all([
+      connection.requestAirdrop(mintAuthority.publicKey, 1e9),
+      connection.requestAirdrop(admin.publicKey, 1e9),
+      connection.requestAirdrop(owner.publicKey, 1e9)
+    ])
+    await sleep(500)
+
+    tokens = await Promise.all([
+      createToken(connection, wallet, mintAuthority),
+      createToken(connection, wallet, mintAuthority),
+      createToken(connection, wallet, mintAuthority)
+    ])
+    pairAB = new Pair(tokens[0], tokens[1], feeTier)
+    pairBC = new Pair(tokens[1], tokens[2], feeTier)
+
+    accounts = await Promise.all(
+      tokens.map(token => createAssociatedTokenAccount(connection, owner, token, owner.publicKey))
+    )
+  })
+
+  it('#init()', async () => {
+    await initMarket(market, [pairAB, pairBC], admin)
+
+    for (const pair of [pairAB, pairBC]) {
+      const positionOwner = Keypair.generate()
+      await connection.requestAirdrop(positionOwner.publicKey, 1e9)
+      await sleep(500)
+      const accountX = await createAssociatedTokenAccount(
+        connection,
+        positionOwner,
+        pair.tokenX,
+        positionOwner.publicKey
+      )
+      const accountY = await createAssociatedTokenAccount(
+        connection,
+        positionOwner,
+        pair.tokenY,
+        positionOwner.publicKey
+      )
+      await market.createPositionList(positionOwner.publicKey, positionOwner)
+      await createPosition(
+        connection,
+        -1000,
+        1000,
+        liquidity,
+        positionOwner,
+        accountX,
+        accountY,
+        pair,
+        market,
+        wallet,
+        mintAuthority
+      )
+    }
+
+    await mintTo(connection, mintAuthority, tokens[0], accounts[0], mintAuthority, 1e6)
+  })
+
+  it('#swapRoute() by amount in', async () => {
+    const amount = new BN(1000)
+    const [balanceABefore, balanceBBefore, balanceCBefore] = await Promise.all(
+      accounts.map(account => getBalance(connection, account))
+    )
+
+    const swapRouteVars: SwapRoute = {
+      route: [pairAB, pairBC],
+      owner: owner.publicKey,
+      tokenIn: tokens[0],
+      accountIn: accounts[0],
+      accountOut: accounts[2],
+      amount,
+      byAmountIn: true,
+      amountLimit: new BN(1)
+    }
+    await market.swapRoute(swapRouteVars, owner)
+
+    const [balanceA, balanceB, balanceC] = await Promise.all(
+      accounts.map(account => getBalance(connection, account))
+    )
+    assert.ok(balanceABefore.sub(balanceA).eq(amount))
+    assert.ok(balanceB.eq(balanceBBefore))
+    assert.ok(balanceC.gt(balanceCBefore))
+    assert.ok(balanceC.sub(balanceCBefore).lt(amount))
+  })
+
+  it('#swapRoute() by amount in below minimum out', async () => {
+    const swapRouteVars: SwapRoute = {
+      route: [pairAB, pairBC],
+      owner: owner.publicKey,
+      tokenIn: tokens[0],
+      accountIn: accounts[0],
+      accountOut: accounts[2],
+      amount: new BN(1000),
+      byAmountIn: true,
+      amountLimit: new BN(1000)
+    }
+    await assertThrowsAsync(market.swapRoute(swapRouteVars, owner))
+  })
+
+  it('#swapRoute() by amount out', async () => {
+    const amount = new BN(1000)
+    const amountLimit = new BN(1100)
+    const [balanceABefore, balanceBBefore, balanceCBefore] = await Promise.all(
+      accounts.map(account => getBalance(connection, account))
+    )
+    const poolBC = await market.getPool(pairBC)
+    const intermediateReserve = pairBC.tokenX.equals(tokens[1])
+      ? poolBC.tokenXReserve
+      : poolBC.tokenYReserve
+    const intermediateReserveBefore = await getBalance(connection, intermediateReserve)
+
+    const swapRouteVars: SwapRoute = {
+      route: [pairAB, pairBC],
+      owner: owner.publicKey,
+      tokenIn: tokens[0],
+      accountIn: accounts[0],
+      accountOut: accounts[2],
+      amount,
+      byAmountIn: false,
+      amountLimit
+    }
+    await market.swapRoute(swapRouteVars, owner)
+
+    const [balanceA, balanceB, balanceC] = await Promise.all(
+      accounts.map(account => getBalance(connection, account))
+    )
+    const amountIn = balanceABefore.sub(balanceA)
+    assert.ok(balanceC.sub(balanceCBefore).eq(amount))
+    assert.ok(balanceB.eq(balanceBBefore))
+    assert.ok(amountIn.gt(amount))
+    assert.ok(amountIn.lte(amountLimit))
+
+    // second pool received the intermediate tokens it accounted for
+    const intermediateReserveAfter = await getBalance(connection, intermediateReserve)
+    assert.ok(intermediateReserveAfter.gt(intermediateReserveBefore))
+  })
+
+  it('#swapRoute() by amount out above maximum in', async () => {
+    const swapRouteVars: SwapRoute = {
+      route: [pairAB, pairBC],
+      owner: owner.publicKey,
+      tokenIn: tokens[0],
+      accountIn: accounts[0],
+      accountOut: accounts[2],
+      amount: new BN(1000),
+      byAmountIn: false,
+      amountLimit: new BN(1000)
+    }
+    await assertThrowsAsync(market.swapRoute(swapRouteVars, owner))
+  })
+
+  it('#swapRoute() with mismatched route', async () => {
+    const swapRouteVars: SwapRoute = {
+      route: [pairBC, pairAB],
+      owner: owner.publicKey,
+      tokenIn: tokens[0],
+      accountIn: accounts[0],
+      accountOut: accounts[2],
+      amount: new BN(1000),
+      byAmountIn: true,
+      amountLimit: new BN(1)
+    }
+    await assertThrowsAsync(market.swapRoute(swapRouteVars, owner))
+  })
+})
@@ -179,11 +179,13 @@ export const createUserWithTokens = async (
   pair: Pair,
   connection: Connection,
   mintAuthority: Keypair,
-  mintAmount: BN = new BN(1e9)
+  mintAmount: BN = new BN(1e9),
+  owner: Keypair = Keypair.generate()
 ) => {
-  const owner = Keypair.generate()
-  const tokenXProgram = TOKEN_PROGRAM_ID
-  const tokenYProgram = TOKEN_PROGRAM_ID
+  const [tokenXProgram, tokenYProgram] = await Promise.all([
+    getTokenProgramAddress(connection, pair.tokenX),
+    getTokenProgramAddress(connection, pair.tokenY)
+  ])
   const [userAccountX, userAccountY] = await Promise.all([
     createAssociatedTokenAccount(
       connection,
@@ -0,0 +1,191 @@
+import * as anchor from '@coral-xyz/anchor'
+import { AnchorProvider, BN } from '@coral-xyz/anchor'
+import { Keypair, PublicKey } from '@solana/web3.js'
+import { assert } from 'chai'
+import {
+  createAssociatedTokenAccount,
+  getAssociatedTokenAddressSync,
+  transferChecked,
+  TOKEN_2022_PROGRAM_ID
+} from '@solana/spl-token'
+import { assertThrowsAsync, createTokensAndPool, createUserWithTokens } from './testUtils'
+import { Market, Network, Pair, sleep, calculatePriceSqrt } from '@invariant-labs/sdk'
+import { getBalance, toDecimal } from '@invariant-labs/sdk/src/utils'
+import {
+  ClaimTokenizedFee,
+  CreatePosition,
+  RemovePosition,
+  Swap
+} from '@invariant-labs/sdk/src/market'
+
+describe('tokenize position', () => {
+  const provider = AnchorProvider.local()
+  const connection = provider.connection
+  const admin = Keypair.generate()
+  let market: Market
+  let pair: Pair
+  let mintAuthority: Keypair
+  let positionMint: PublicKey
+  let owner: { owner: Keypair; userAccountX: PublicKey; userAccountY: PublicKey }
+  let holder: { owner: Keypair; userAccountX: PublicKey; userAccountY: PublicKey }
+
+  before(async () => {
+    market = await Market.build(
+      Network.LOCAL,
+      provider.wallet,
+      connection,
+      anchor.workspace.Invariant.programId
+    )
+
+    await connection.requestAirdrop(admin.publicKey, 1e12)
+    await sleep(500)
+
+    await market.createState(admin.publicKey, admin)
+
+    const createdPool = await createTokensAndPool(market, connection, admin)
+    pair = createdPool.pair
+    mintAuthority = createdPool.mintAuthority
+
+    owner = await createUserWithTokens(pair, connection, mintAuthority)
+    holder = await createUserWithTokens(pair, connection, mintAuthority)
+    await sleep(500)
+  })
+
+  it('#tokenizePosition()', async () => {
+    const createPositionVars: CreatePosition = {
+      pair,
+      owner: owner.owner.publicKey,
+      userTokenX: owner.userAccountX,
+      userTokenY: owner.userAccountY,
+      lowerTick: -1000,
+      upperTick: 1000,
+      liquidityDelta: new BN(10).pow(new BN(14)),
+      knownPrice: calculatePriceSqrt(0),
+      slippage: new BN(0)
+    }
+    await market.createPositionList(owner.owner.publicKey, owner.owner)
+    await market.createPosition(createPositionVars, owner.owner)
+
+    const position = await market.getPosition(owner.owner.publicKey, 0)
+    positionMint = market.getPositionMintAddress(position.pool, position.id).positionMintAddress
+
+    await market.tokenizePosition({ owner: owner.owner.publicKey, index: 0 }, owner.owner)
+
+    // position leaves the owner list and is held by its mint
+    const positionList = await market.getPositionList(owner.owner.publicKey)
+    const tokenizedPosition = await market.getTokenizedPosition(positionMint)
+    const ownerTokenAccount = getAssociatedTokenAddressSync(
+      positionMint,
+      owner.owner.publicKey,
+      false,
+      TOKEN_2022_PROGRAM_ID
+    )
+    assert.equal(positionList.head, 0)
+    assert.ok(tokenizedPosition.owner.equals(positionMint))
+    assert.ok(tokenizedPosition.liquidity.eq(position.liquidity))
+    assert.ok(tokenizedPosition.id.eq(position.id))
+    assert.ok((await getBalance(connection, ownerTokenAccount, TOKEN_2022_PROGRAM_ID)).eqn(1))
+  })
+
+  it('#claimTokenizedFee() follows the token', async () => {
+    // generate fees
+    const swapper = await createUserWithTokens(pair, connection, mintAuthority)
+    const swapVars: Swap = {
+      pair,
+      xToY: true,
+      owner: swapper.owner.publicKey,
+      amount: new BN(1e6),
+      estimatedPriceAfterSwap: (await market.getPool(pair)).sqrtPrice,
+      slippage: toDecimal(1, 1),
+      accountX: swapper.userAccountX,
+      accountY: swapper.userAccountY,
+      byAmountIn: true
+    }
+    await market.swap(swapVars, swapper.owner)
+
+    // hand the token over
+    const ownerTokenAccount = getAssociatedTokenAddressSync(
+      positionMint,
+      owner.owner.publicKey,
+      false,
+      TOKEN_2022_PROGRAM_ID
+    )
+    const holderTokenAccount = await createAssociatedTokenAccount(
+      connection,
+      holder.owner,
+      positionMint,
+      holder.owner.publicKey,
+      undefined,
+      TOKEN_2022_PROGRAM_ID
+    )
+    await transferChecked(
+      connection,
+      owner.owner,
+      ownerTokenAccount,
+      positionMint,
+      holderTokenAccount,
+      owner.owner,
+      1,
+      0,
+      [],
+      undefined,
+      TOKEN_2022_PROGRAM_ID
+    )
+
+    // previous owner has no authority over the position
+    const ownerClaimVars: ClaimTokenizedFee = {
+      pair,
+      positionMint,
+      owner: owner.owner.publicKey,
+      userTokenX: owner.userAccountX,
+      userTokenY: owner.userAccountY
+    }
+    await assertThrowsAsync(market.claimTokenizedFee(ownerClaimVars, owner.owner))
+
+    const holderXBefore = await getBalance(connection, holder.userAccountX)
+    const holderClaimVars: ClaimTokenizedFee = {
+      pair,
+      positionMint,
+      owner: holder.owner.publicKey,
+      userTokenX: holder.userAccountX,
+      userTokenY: holder.userAccountY
+    }
+    await market.claimTokenizedFee(holderClaimVars, holder.owner)
+
+    const holderXAfter = await getBalance(connection, holder.userAccountX)
+    assert.ok(holderXAfter.gt(holderXBefore))
+  })
+
+  it('#redeemPosition() -> #removePosition() by the holder', async () => {
+    const tokenizedPosition = await market.getTokenizedPosition(positionMint)
+
+    // previous owner can not redeem the position
+    await assertThrowsAsync(
+      market.redeemPosition({ positionMint, holder: owner.owner.publicKey }, owner.owner)
+    )
+
+    await market.createPositionList(holder.owner.publicKey, holder.owner)
+    await market.redeemPosition({ positionMint, holder: holder.owner.publicKey }, holder.owner)
+
+    const position = await market.getPosition(holder.owner.publicKey, 0)
+    assert.ok(position.owner.equals(holder.owner.publicKey))
+    assert.ok(position.id.eq(tokenizedPosition.id))
+    assert.ok(position.liquidity.eq(tokenizedPosition.liquidity))
+    assert.isNull(await connection.getAccountInfo(positionMint))
+
+    const reservesBefore = await market.getReserveBalances(pair)
+    const removePositionVars: RemovePosition = {
+      pair,
+      index: 0,
+      owner: holder.owner.publicKey,
+      userTokenX: holder.userAccountX,
+      userTokenY: holder.userAccountY
+    }
+    await market.removePosition(removePositionVars, holder.owner)
+
+    const reservesAfter = await market.getReserveBalances(pair)
+    assert.ok(reservesAfter.x.lt(reservesBefore.x))
+    assert.ok(reservesAfter.y.lt(reservesBefore.y))
+    assert.equal((await market.getPositionList(holder.owner.publicKey)).head, 0)
+  })
+})
@@ -14,7 +14,12 @@ import {
 } from '@invariant-labs/sdk'
 import { fromFee, getBalance, toDecimal } from '@invariant-labs/sdk/lib/utils'
 import { Decimal, FeeTier, RemovePosition } from '@invariant-labs/sdk/lib/market'
-import { CreateTick, CreatePosition, Swap } from '@invariant-labs/sdk/src/market'
+import {
+  CreateTick,
+  CreatePosition,
+  Swap,
+  WithdrawProtocolFee
+} from '@invariant-labs/sdk/src/market'
 import { createAssociatedTokenAccount, mintTo, TOKEN_PROGRAM_ID } from '@solana/spl-token'
 
 describe('withdraw', () => {
@@ -287,6 +292,42 @@ describe('withdraw', () => {
     assert.ok(poolData.feeProtocolTokenX.eqn(1))
     assert.ok(poolData.feeProtocolTokenY.eqn(0))
 
+    // Withdraw protocol fee partially
+    const adminAccountX = await createAssociatedTokenAccount(
+      connection,
+      admin,
+      pair.tokenX,
+      admin.publicKey
+    )
+    const adminAccountY = await createAssociatedTokenAccount(
+      connection,
+      admin,
+      pair.tokenY,
+      admin.publicKey
+    )
+    const withdrawProtocolFeeVars: WithdrawProtocolFee = {
+      pair,
+      accountX: adminAccountX,
+      accountY: adminAccountY,
+      admin: admin.publicKey,
+      amountX: new BN(2),
+      amountY: new BN(0)
+    }
+    await assertThrowsAsync(
+      market.withdrawProtocolFee(withdrawProtocolFeeVars, admin),
+      INVARIANT_ERRORS.INSUFFICIENT_PROTOCOL_FEE
+    )
+
+    withdrawProtocolFeeVars.amountX = new BN(0)
+    await market.withdrawProtocolFee(withdrawProtocolFeeVars, admin)
+    assert.ok((await market.getPool(pair)).feeProtocolTokenX.eqn(1))
+    assert.ok((await getBalance(connection, adminAccountX)).eqn(0))
+
+    withdrawProtocolFeeVars.amountX = new BN(1)
+    await market.withdrawProtocolFee(withdrawProtocolFeeVars, admin)
+    assert.ok((await market.getPool(pair)).feeProtocolTokenX.eqn(0))
+    assert.ok((await getBalance(connection, adminAccountX)).eqn(1))
+
     // Remove position
     const reservesBeforeRemove = await market.getReserveBalances(pair)
 